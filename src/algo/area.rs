@@ -3,7 +3,10 @@
 //! This module provides functions for calculating areas of various geometric shapes,
 //! including polygons defined by points and complex shapes bounded by arc sequences.
 
+use crate::constants::GEOMETRIC_EPSILON;
 use crate::prelude::*;
+use std::f64::consts::PI;
+use std::fmt::Display;
 
 /// Calculates the area of a simple polygon defined by a series of points.
 ///
@@ -51,6 +54,42 @@ pub fn pointline_area(points: &Pointline) -> f64 {
     area / 2.0
 }
 
+/// Calculates the signed area between an open polyline and a horizontal baseline.
+///
+/// Integrates `y - baseline_y` along x with the trapezoidal rule, one trapezoid
+/// per edge. Unlike [`pointline_area`] the polyline is not closed: the area is
+/// bounded by the polyline, the baseline and the vertical lines through the end
+/// points. Parts above the baseline count positive and parts below negative for
+/// edges going in +x; edges going in -x contribute with the opposite sign. An
+/// edge crossing the baseline contributes the difference of its two triangles.
+///
+/// # Arguments
+///
+/// * `points` - The polyline vertices in order
+/// * `baseline_y` - The y coordinate of the baseline
+///
+/// # Returns
+///
+/// The signed area, zero for fewer than 2 points
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let ramp = vec![point(0.0, 1.0), point(2.0, 3.0)];
+/// assert_eq!(pointline_area_under(&ramp, 0.0), 4.0);
+/// // Half above and half below the baseline
+/// assert_eq!(pointline_area_under(&ramp, 2.0), 0.0);
+/// ```
+#[must_use]
+pub fn pointline_area_under(points: &Pointline, baseline_y: f64) -> f64 {
+    points
+        .windows(2)
+        .map(|e| 0.5 * (e[1].x - e[0].x) * (e[0].y + e[1].y - 2.0 * baseline_y))
+        .sum()
+}
+
 /// Calculates the area of a region enclosed by an arcline (sequence of arcs and line segments).
 ///
 /// This function computes the area by treating each arc segment appropriately:
@@ -123,6 +162,50 @@ pub fn arcline_area(arcs: &Arcline) -> f64 {
     total_area
 }
 
+/// Computes the signed area contribution of each element of an arcline.
+///
+/// Each entry is the term that [`arcline_area`] adds for the element: the
+/// shoelace term of its chord, plus the circular segment between the chord and
+/// the arc for arcs. The contributions depend on the position of the origin,
+/// only their sum is meaningful as an area. They help to find the element
+/// responsible for an unexpected total, e.g. a misoriented arc.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline, closed and CCW oriented as for [`arcline_area`]
+///
+/// # Returns
+///
+/// One contribution per element, summing to `arcline_area(arcs)`
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let contributions = arcline_area_contributions(&square);
+/// assert_eq!(contributions, vec![0.0, 0.5, 0.5, 0.0]);
+/// assert_eq!(contributions.iter().sum::<f64>(), arcline_area(&square));
+/// ```
+#[must_use]
+pub fn arcline_area_contributions(arcs: &Arcline) -> Vec<f64> {
+    arcs.iter()
+        .map(|arc| {
+            if arc.is_seg() {
+                arc.a.perp(arc.b) / 2.0
+            } else {
+                arc_area_contribution(arc)
+            }
+        })
+        .collect()
+}
+
 #[doc(hidden)]
 /// Computes the area contribution of a circular arc.
 ///
@@ -192,6 +275,511 @@ fn arc_area_contribution(arc: &Arc) -> f64 {
     line_contribution + arc_curvature_contribution
 }
 
+/// Calculates the area of the overlap (lens) of two circles.
+///
+/// Uses the circular segment formula: the lens is the union of two circular
+/// segments cut off by the common chord.
+///
+/// # Arguments
+///
+/// * `c1` - The first circle
+/// * `c2` - The second circle
+///
+/// # Returns
+///
+/// The overlap area: 0.0 for disjoint or externally touching circles,
+/// the area of the smaller circle when one circle lies inside the other
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::circle_circle_intersection_area;
+///
+/// let c1 = circle(point(0.0, 0.0), 1.0);
+/// let c2 = circle(point(3.0, 0.0), 1.0);
+/// assert_eq!(circle_circle_intersection_area(&c1, &c2), 0.0);
+/// ```
+#[must_use]
+pub fn circle_circle_intersection_area(c1: &Circle, c2: &Circle) -> f64 {
+    let d = (c2.c - c1.c).norm();
+    let (r1, r2) = (c1.r, c2.r);
+    if d >= r1 + r2 {
+        return 0.0;
+    }
+    if d <= (r1 - r2).abs() {
+        let r = r1.min(r2);
+        return PI * r * r;
+    }
+
+    // Half angles of the circular segments, seen from each center
+    let alpha = ((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let beta = ((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2))
+        .clamp(-1.0, 1.0)
+        .acos();
+    // Twice the area of the triangle formed by the centers and one intersection point
+    let kite = ((-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2))
+        .max(0.0)
+        .sqrt();
+    r1 * r1 * alpha + r2 * r2 * beta - 0.5 * kite
+}
+
+/// Computes the overlap region (lens) of two circles as a closed arcline.
+///
+/// The lens is bounded by the arc of `c1` that lies inside `c2` and the arc of `c2`
+/// that lies inside `c1`. Both arcs are CCW and traversed forward, so the result
+/// is a closed CCW arcline whose [`arcline_area`] equals
+/// [`circle_circle_intersection_area`].
+///
+/// # Arguments
+///
+/// * `c1` - The first circle
+/// * `c2` - The second circle
+///
+/// # Returns
+///
+/// The lens boundary as two arcs, or `None` when the circles are disjoint,
+/// touching, nested or identical (no proper lens)
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::circle_circle_intersection_region;
+///
+/// let c1 = circle(point(0.0, 0.0), 1.0);
+/// let c2 = circle(point(1.0, 0.0), 1.0);
+/// let lens = circle_circle_intersection_region(&c1, &c2).unwrap();
+/// assert_eq!(lens.len(), 2);
+/// assert_eq!(lens[0].b, lens[1].a);
+/// assert_eq!(lens[1].b, lens[0].a);
+/// ```
+#[must_use]
+pub fn circle_circle_intersection_region(c1: &Circle, c2: &Circle) -> Option<Arcline> {
+    match int_circle_circle(*c1, *c2) {
+        CircleCircleConfig::NoncocircularTwoPoints(p0, p1) => {
+            // p0 is left and p1 right of the direction c1 -> c2, so the arc of c1
+            // from p1 to p0 bulges towards c2 and the arc of c2 from p0 to p1 towards c1
+            Some(vec![arc(p1, p0, c1.c, c1.r), arc(p0, p1, c2.c, c2.r)])
+        }
+        _ => None,
+    }
+}
+
+/// Computes the region inside both a circle and a rectangle as a closed arcline.
+///
+/// The rectangle edges are walked CCW and clipped to the disc. Consecutive
+/// clipped edge pieces that do not meet at a rectangle corner are joined by
+/// the CCW arc of the circle between them, so the result is a closed CCW
+/// arcline of edge segments and arc pieces, usable with [`arcline_area`].
+///
+/// # Arguments
+///
+/// * `c` - The circle
+/// * `r` - The rectangle
+///
+/// # Returns
+///
+/// The boundary of the common region:
+/// - `None` when the circle and the rectangle are disjoint or only touch
+/// - the full circle as a single arc when the circle is inside the rectangle
+/// - the four rectangle edges when the rectangle is inside the circle
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::circle_rect_intersection;
+///
+/// // Quarter of a unit disc
+/// let c = circle(point(0.0, 0.0), 1.0);
+/// let quarter = circle_rect_intersection(&c, &rect(point(0.0, 0.0), point(2.0, 2.0))).unwrap();
+/// assert_eq!(quarter.len(), 3);
+/// assert!((arcline_area(&quarter) - std::f64::consts::PI / 4.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn circle_rect_intersection(c: &Circle, r: &Rect) -> Option<Arcline> {
+    let min = point(r.p1.x.min(r.p2.x), r.p1.y.min(r.p2.y));
+    let max = point(r.p1.x.max(r.p2.x), r.p1.y.max(r.p2.y));
+    if c.r <= 0.0 || min.x >= max.x || min.y >= max.y {
+        return None;
+    }
+    let nearest = point(c.c.x.clamp(min.x, max.x), c.c.y.clamp(min.y, max.y));
+    if (nearest - c.c).norm() >= c.r {
+        return None;
+    }
+    if c.c.x - c.r >= min.x && c.c.x + c.r <= max.x && c.c.y - c.r >= min.y && c.c.y + c.r <= max.y {
+        let start = c.c + point(c.r, 0.0);
+        return Some(vec![arc(start, start, c.c, c.r)]);
+    }
+
+    let corners = [min, point(max.x, min.y), max, point(min.x, max.y)];
+    let pieces: Vec<(Point, Point)> = (0..4)
+        .filter_map(|i| clip_segment_to_disc(corners[i], corners[(i + 1) % 4], c))
+        .collect();
+    let mut result = Vec::with_capacity(2 * pieces.len());
+    for (i, &(start, end)) in pieces.iter().enumerate() {
+        result.push(arcseg(start, end));
+        let next = pieces[(i + 1) % pieces.len()].0;
+        if !end.close_enough(next, GEOMETRIC_EPSILON) {
+            result.push(arc(end, next, c.c, c.r));
+        }
+    }
+    Some(result)
+}
+
+// Part of the segment inside the disc, if it has a positive length
+fn clip_segment_to_disc(a: Point, b: Point, c: &Circle) -> Option<(Point, Point)> {
+    let d = b - a;
+    let f = a - c.c;
+    let qa = d.dot(d);
+    let qb = 2.0 * f.dot(d);
+    let qc = f.dot(f) - c.r * c.r;
+    let discr = qb * qb - 4.0 * qa * qc;
+    if discr <= 0.0 {
+        return None;
+    }
+    let root = discr.sqrt();
+    let t0 = ((-qb - root) / (2.0 * qa)).max(0.0);
+    let t1 = ((-qb + root) / (2.0 * qa)).min(1.0);
+    if (t1 - t0) * qa.sqrt() < GEOMETRIC_EPSILON {
+        return None;
+    }
+    // Keep the rectangle corners exact so that the pieces join exactly
+    let start = if t0 == 0.0 { a } else { a + d * t0 };
+    let end = if t1 == 1.0 { b } else { a + d * t1 };
+    Some((start, end))
+}
+
+/// Tolerance of the element checks in [`arcline_area_checked`],
+/// the same as used by [`arcline_is_valid`].
+const AREA_CHECK_TOLERANCE: f64 = 1e-8;
+
+/// Reason why [`arcline_area_checked`] refused to compute the area.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AreaError {
+    /// The arcline has no elements.
+    Empty,
+    /// Indices of degenerate elements: non-finite coordinates, collapsed segments,
+    /// collapsed radius, or endpoints not on the arc's circle.
+    DegenerateElements(Vec<usize>),
+    /// Indices of elements that do not connect to the next element
+    /// (the last element must connect back to the first one).
+    NotClosed(Vec<usize>),
+}
+
+impl Display for AreaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AreaError::Empty => write!(f, "empty arcline"),
+            AreaError::DegenerateElements(indices) => {
+                write!(f, "degenerate elements at {:?}", indices)
+            }
+            AreaError::NotClosed(indices) => {
+                write!(f, "arcline is not closed after elements {:?}", indices)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AreaError {}
+
+fn is_degenerate_element(arc: &Arc) -> bool {
+    if !(arc.a.x.is_finite() && arc.a.y.is_finite() && arc.b.x.is_finite() && arc.b.y.is_finite()) {
+        return true;
+    }
+    if arc.is_seg() {
+        return arc.is_collapsed_ends(AREA_CHECK_TOLERANCE);
+    }
+    // Full circles (a == b) are allowed
+    !(arc.c.x.is_finite() && arc.c.y.is_finite())
+        || arc.is_collapsed_radius(AREA_CHECK_TOLERANCE)
+        || !arc.is_consistent(AREA_CHECK_TOLERANCE)
+}
+
+fn are_connected(arc1: &Arc, arc2: &Arc) -> bool {
+    // Segments are traversed from a to b, while arcs can be traversed
+    // backward (concave arcs), so either of their endpoints can connect
+    let ends: &[Point] = if arc1.is_seg() {
+        &[arc1.b]
+    } else {
+        &[arc1.a, arc1.b]
+    };
+    let starts: &[Point] = if arc2.is_seg() {
+        &[arc2.a]
+    } else {
+        &[arc2.a, arc2.b]
+    };
+    ends.iter().any(|e| {
+        starts
+            .iter()
+            .any(|s| e.close_enough(*s, AREA_CHECK_TOLERANCE))
+    })
+}
+
+/// Calculates the area of a region enclosed by an arcline, checking the input first.
+///
+/// Unlike the lenient [`arcline_area`], which silently returns a meaningless number
+/// for broken input, this function validates the elements and the closure of the
+/// arcline and returns an error describing the problem.
+///
+/// # Arguments
+///
+/// * `arcs` - A closed arcline (last point connects back to first)
+///
+/// # Returns
+///
+/// The area of the region (see [`arcline_area`]), or
+///
+/// # Errors
+///
+/// * [`AreaError::Empty`] if the arcline has no elements
+/// * [`AreaError::DegenerateElements`] listing degenerate elements
+/// * [`AreaError::NotClosed`] listing elements not connected to the next element
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::{AreaError, arcline_area_checked};
+///
+/// let open = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+/// ];
+/// assert_eq!(arcline_area_checked(&open), Err(AreaError::NotClosed(vec![1])));
+/// ```
+pub fn arcline_area_checked(arcs: &Arcline) -> Result<f64, AreaError> {
+    if arcs.is_empty() {
+        return Err(AreaError::Empty);
+    }
+
+    let degenerate: Vec<usize> = (0..arcs.len())
+        .filter(|&i| is_degenerate_element(&arcs[i]))
+        .collect();
+    if !degenerate.is_empty() {
+        return Err(AreaError::DegenerateElements(degenerate));
+    }
+
+    let n = arcs.len();
+    let gaps: Vec<usize> = (0..n)
+        .filter(|&i| !are_connected(&arcs[i], &arcs[(i + 1) % n]))
+        .collect();
+    if !gaps.is_empty() {
+        return Err(AreaError::NotClosed(gaps));
+    }
+
+    Ok(arcline_area(arcs))
+}
+
+/// Calculates the area-weighted centroid of a region enclosed by an arcline.
+///
+/// The region is decomposed (Green's theorem) into signed triangles spanned by
+/// each element's chord and, for circular arcs, the circular segment between the
+/// chord and the arc. Arcs traversed backward in the arcline (concave arcs, see
+/// [`is_arc_convex`]) contribute with negative sign.
+///
+/// The arcline is assumed to be closed (last point connects back to first).
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline defining the boundary of the region
+///
+/// # Returns
+///
+/// The centroid of the region, or `None` if the enclosed area is zero
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::arcline_centroid;
+///
+/// // Disc made of two half-circle arcs centered at (2, 3)
+/// let disc = vec![
+///     arc(point(3.0, 3.0), point(1.0, 3.0), point(2.0, 3.0), 1.0),
+///     arc(point(1.0, 3.0), point(3.0, 3.0), point(2.0, 3.0), 1.0),
+/// ];
+/// let c = arcline_centroid(&disc).unwrap();
+/// assert!(c.close_enough(point(2.0, 3.0), 1e-10));
+/// ```
+#[must_use]
+pub fn arcline_centroid(arcs: &Arcline) -> Option<Point> {
+    if arcs.is_empty() {
+        return None;
+    }
+
+    // Use the first vertex as local origin for better numerical stability
+    let origin = arcs[0].a;
+    let mut total_area = 0.0;
+    let mut moment = point(0.0, 0.0);
+
+    for (i, arc) in arcs.iter().enumerate() {
+        let a = arc.a - origin;
+        let b = arc.b - origin;
+
+        // Triangle (origin, a, b) spanned by the chord
+        let triangle_area = a.perp(b) / 2.0;
+        let mut area = triangle_area;
+        let mut m = (a + b) * (triangle_area / 3.0);
+
+        if arc.is_arc() {
+            // Circular segment between the chord and the arc
+            let (segment_area, segment_centroid) = circular_segment(arc);
+            area += segment_area;
+            m = m + (segment_centroid - origin) * segment_area;
+
+            if !is_arc_convex(arcs, i) {
+                // Arc is traversed from b to a
+                area = -area;
+                m = -m;
+            }
+        }
+
+        total_area += area;
+        moment = moment + m;
+    }
+
+    if total_area.abs() < GEOMETRIC_EPSILON {
+        return None;
+    }
+
+    Some(origin + moment / total_area)
+}
+
+/// Computes the area and centroid of the circular segment bounded by
+/// the arc and its chord.
+fn circular_segment(arc: &Arc) -> (f64, Point) {
+    let start_vector = arc.a - arc.c;
+    let end_vector = arc.b - arc.c;
+    let start_angle = start_vector.y.atan2(start_vector.x);
+    let end_angle = end_vector.y.atan2(end_vector.x);
+
+    let mut sweep = end_angle - start_angle;
+    if sweep < 0.0 {
+        sweep += 2.0 * std::f64::consts::PI;
+    }
+    // Full circle (start == end)
+    if arc.a.close_enough(arc.b, 1e-10) {
+        sweep = 2.0 * std::f64::consts::PI;
+    }
+
+    let area = 0.5 * arc.r * arc.r * (sweep - sweep.sin());
+    if area <= 0.0 {
+        return (0.0, arc.c);
+    }
+
+    // Distance from the center to the segment centroid, along the arc bisector
+    let half = 0.5 * sweep;
+    let dist = 4.0 * arc.r * half.sin().powi(3) / (3.0 * (sweep - sweep.sin()));
+    let mid_angle = start_angle + half;
+    let centroid = arc.c + point(mid_angle.cos(), mid_angle.sin()) * dist;
+
+    (area, centroid)
+}
+
+/// Calculates the area enclosed by an arcline by integrating `x dy` along its boundary.
+///
+/// Every element contributes its line integral in closed form: segments
+/// `(x_a + x_b) / 2 * (y_b - y_a)`, circular arcs the exact integral of
+/// `(c_x + r cos t) r cos t dt` over their sweep. Arcs traversed backward in the
+/// arcline (concave arcs, see [`is_arc_convex`]) contribute with negative sign.
+///
+/// The arcline is assumed to be closed (last point connects back to first).
+/// The result is positive for CCW boundaries.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline defining the boundary of the region
+///
+/// # Returns
+///
+/// The signed enclosed area
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::arcline_area_green;
+///
+/// // Half disc of radius 1 above the x axis
+/// let half = vec![
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// assert!((arcline_area_green(&half) - std::f64::consts::PI / 2.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn arcline_area_green(arcs: &Arcline) -> f64 {
+    let mut total = 0.0;
+    for (i, arc) in arcs.iter().enumerate() {
+        if arc.is_seg() {
+            total += 0.5 * (arc.a.x + arc.b.x) * (arc.b.y - arc.a.y);
+            continue;
+        }
+        let va = arc.a - arc.c;
+        let t0 = va.y.atan2(va.x);
+        let t1 = t0 + arc.sweep_angle();
+        // Antiderivative of (cx + r cos t) r cos t
+        let integral = |t: f64| {
+            arc.c.x * arc.r * t.sin() + arc.r * arc.r * (0.5 * t + 0.25 * (2.0 * t).sin())
+        };
+        let contribution = integral(t1) - integral(t0);
+        if is_arc_convex(arcs, i) {
+            total += contribution;
+        } else {
+            total -= contribution;
+        }
+    }
+    total
+}
+
+/// Orients the rings of a shape with holes: the outer ring CCW and the holes CW.
+///
+/// The outer ring is the one enclosing the largest area; every other ring is a
+/// hole. Rings with the wrong orientation, judged by the sign of
+/// [`arcline_area_green`], are reversed in place with [`arcline_reverse`].
+/// Afterwards the signed areas of the rings add up to the net area of the shape.
+///
+/// # Arguments
+///
+/// * `rings` - The outer ring and the holes of the shape, in any order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::area::{arcline_area_green, normalize_ring_orientations};
+///
+/// let square = |x: f64, y: f64, s: f64| {
+///     vec![
+///         arcseg(point(x, y), point(x + s, y)),
+///         arcseg(point(x + s, y), point(x + s, y + s)),
+///         arcseg(point(x + s, y + s), point(x, y + s)),
+///         arcseg(point(x, y + s), point(x, y)),
+///     ]
+/// };
+/// // Both rings CCW: the hole adds to the area instead of removing it
+/// let mut rings = vec![square(1.0, 1.0, 1.0), square(0.0, 0.0, 3.0)];
+/// normalize_ring_orientations(&mut rings);
+/// let net: f64 = rings.iter().map(arcline_area_green).sum();
+/// assert!((net - 8.0).abs() < 1e-12);
+/// ```
+pub fn normalize_ring_orientations(rings: &mut [Arcline]) {
+    let areas: Vec<f64> = rings.iter().map(arcline_area_green).collect();
+    let Some(outer) = (0..rings.len()).max_by(|&i, &j| areas[i].abs().total_cmp(&areas[j].abs())) else {
+        return;
+    };
+    for (i, ring) in rings.iter_mut().enumerate() {
+        let want_ccw = i == outer;
+        if (areas[i] < 0.0 && want_ccw) || (areas[i] > 0.0 && !want_ccw) {
+            *ring = arcline_reverse(ring);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_pointline_area {
     use super::*;
@@ -216,6 +804,40 @@ mod test_pointline_area {
     }
 }
 
+#[cfg(test)]
+mod test_pointline_area_under {
+    use super::*;
+
+    #[test]
+    fn test_ramp_above_baseline() {
+        let ramp = vec![point(0.0, 1.0), point(1.0, 2.0), point(3.0, 2.0)];
+        // Trapezoid of area 1.5 then a rectangle of area 4
+        assert_eq!(pointline_area_under(&ramp, 0.0), 5.5);
+        assert_eq!(pointline_area_under(&ramp, 1.0), 2.5);
+        // Reversed direction flips the sign
+        let reversed: Pointline = ramp.iter().rev().copied().collect();
+        assert_eq!(pointline_area_under(&reversed, 0.0), -5.5);
+    }
+
+    #[test]
+    fn test_crossing_below_baseline() {
+        // Triangle of area 2 above the baseline, then one of area 4 below
+        let zigzag = vec![point(0.0, 0.0), point(1.0, 2.0), point(2.0, 0.0), point(3.0, -4.0), point(4.0, 0.0)];
+        assert_eq!(pointline_area_under(&zigzag, 0.0), -2.0);
+        // A single edge crossing the baseline: triangles of area 1 and 0.25
+        let edge = vec![point(0.0, 2.0), point(1.5, -1.0)];
+        assert_eq!(pointline_area_under(&edge, 0.0), 0.75);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(pointline_area_under(&vec![], 1.0), 0.0);
+        assert_eq!(pointline_area_under(&vec![point(1.0, 5.0)], 1.0), 0.0);
+        // Vertical edges enclose no area
+        assert_eq!(pointline_area_under(&vec![point(1.0, 0.0), point(1.0, 5.0)], 1.0), 0.0);
+    }
+}
+
 #[cfg(test)]
 mod test_arcline_area {
     use super::*;
@@ -438,3 +1060,550 @@ mod test_arcline_area {
         );
     }
 }
+
+#[cfg(test)]
+mod test_arcline_area_contributions {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_contributions_disc_of_arcs() {
+        // Unit disc at the origin made of four quarter arcs
+        let c = point(0.0, 0.0);
+        let disc = vec![
+            arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0),
+            arc(point(0.0, 1.0), point(-1.0, 0.0), c, 1.0),
+            arc(point(-1.0, 0.0), point(0.0, -1.0), c, 1.0),
+            arc(point(0.0, -1.0), point(1.0, 0.0), c, 1.0),
+        ];
+        let contributions = arcline_area_contributions(&disc);
+        assert_eq!(contributions.len(), 4);
+        for value in &contributions {
+            assert!((value - PI / 4.0).abs() < 1e-12);
+        }
+        let total: f64 = contributions.iter().sum();
+        assert!((total - PI).abs() < 1e-12);
+        assert_eq!(total, arcline_area(&disc));
+    }
+
+    #[test]
+    fn test_contributions_mixed_sum_to_area() {
+        // Rounded slot away from the origin
+        let arcs = vec![
+            arcseg(point(2.0, 1.0), point(6.0, 1.0)),
+            arc(point(6.0, 1.0), point(6.0, 3.0), point(6.0, 2.0), 1.0),
+            arcseg(point(6.0, 3.0), point(2.0, 3.0)),
+            arc(point(2.0, 3.0), point(2.0, 1.0), point(2.0, 2.0), 1.0),
+        ];
+        let contributions = arcline_area_contributions(&arcs);
+        assert_eq!(contributions.iter().sum::<f64>(), arcline_area(&arcs));
+        assert!((arcline_area(&arcs) - (8.0 + PI)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_contributions_find_misoriented_arc() {
+        // The same disc with one arc stored reversed: only its entry changes
+        let c = point(0.0, 0.0);
+        let mut disc = arc(point(1.0, 0.0), point(1.0, 0.0), c, 1.0).split_equal(4);
+        let good = arcline_area_contributions(&disc);
+        disc[2] = arc(disc[2].b, disc[2].a, c, 1.0);
+        let bad = arcline_area_contributions(&disc);
+        for i in [0, 1, 3] {
+            assert_eq!(good[i], bad[i]);
+        }
+        assert!(bad[2] > good[2] + 1.0);
+        assert!(arcline_area_contributions(&vec![]).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_centroid {
+    use super::*;
+
+    #[test]
+    fn test_arcline_centroid_empty() {
+        let empty: Arcline = vec![];
+        assert_eq!(arcline_centroid(&empty), None);
+    }
+
+    #[test]
+    fn test_arcline_centroid_square() {
+        let square = vec![
+            arcseg(point(1.0, 1.0), point(3.0, 1.0)),
+            arcseg(point(3.0, 1.0), point(3.0, 3.0)),
+            arcseg(point(3.0, 3.0), point(1.0, 3.0)),
+            arcseg(point(1.0, 3.0), point(1.0, 1.0)),
+        ];
+        let c = arcline_centroid(&square).unwrap();
+        assert!(c.close_enough(point(2.0, 2.0), 1e-12), "got {}", c);
+    }
+
+    #[test]
+    fn test_arcline_centroid_disc_of_arcs() {
+        let center = point(5.0, -2.0);
+        let disc = vec![
+            arc(point(6.0, -2.0), point(5.0, -1.0), center, 1.0),
+            arc(point(5.0, -1.0), point(4.0, -2.0), center, 1.0),
+            arc(point(4.0, -2.0), point(5.0, -3.0), center, 1.0),
+            arc(point(5.0, -3.0), point(6.0, -2.0), center, 1.0),
+        ];
+        let c = arcline_centroid(&disc).unwrap();
+        assert!(c.close_enough(center, 1e-12), "got {}", c);
+    }
+
+    #[test]
+    fn test_arcline_centroid_full_circle() {
+        let center = point(1.0, 2.0);
+        let full = vec![arc(point(3.0, 2.0), point(3.0, 2.0), center, 2.0)];
+        let c = arcline_centroid(&full).unwrap();
+        assert!(c.close_enough(center, 1e-12), "got {}", c);
+    }
+
+    #[test]
+    fn test_arcline_centroid_half_disc() {
+        let half_disc = vec![
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+        ];
+        let c = arcline_centroid(&half_disc).unwrap();
+        let expected = point(0.0, 4.0 / (3.0 * std::f64::consts::PI));
+        assert!(c.close_enough(expected, 1e-12), "got {}", c);
+    }
+
+    #[test]
+    fn test_arcline_centroid_concave_arc() {
+        // Square with the top edge replaced by an inward-bulging (concave) arc.
+        // Symmetric about x = 1, and the bite lowers the centroid below y = 1.
+        let concave = arc_from_bulge(point(2.0, 2.0), point(0.0, 2.0), -0.5);
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            concave,
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let c = arcline_centroid(&arcs).unwrap();
+        assert!((c.x - 1.0).abs() < 1e-12, "got {}", c);
+        assert!(c.y < 1.0, "got {}", c);
+    }
+
+    // Centroid of a closed polygon by the shoelace moments
+    fn polygon_centroid(points: &Pointline) -> Point {
+        let n = points.len();
+        let mut area = 0.0;
+        let mut moment = point(0.0, 0.0);
+        for i in 0..n {
+            let a = points[i];
+            let b = points[(i + 1) % n];
+            let cross = a.perp(b);
+            area += cross / 2.0;
+            moment = moment + (a + b) * (cross / 6.0);
+        }
+        moment / area
+    }
+
+    #[test]
+    fn test_arcline_centroid_off_center_half_disc_flattened() {
+        // Half disc of radius 2 centered at (3, 1), cut along the line at 30 degrees
+        let c = point(3.0, 1.0);
+        let t: f64 = PI / 6.0;
+        let a = c + point(t.cos(), t.sin()) * 2.0;
+        let b = c - point(t.cos(), t.sin()) * 2.0;
+        let half_disc = vec![arc(a, b, c, 2.0), arcseg(b, a)];
+        let centroid = arcline_centroid(&half_disc).unwrap();
+        let polygon: Pointline = arcline_to_polyline(&half_disc, 1e-10).iter().map(|v| v.p).collect();
+        let flat = polygon_centroid(&polygon);
+        assert!(centroid.close_enough(flat, 1e-7), "{} vs {}", centroid, flat);
+        // Exact: 4r / (3 pi) along the normal of the cut
+        let normal = point(-t.sin(), t.cos());
+        let expected = c + normal * (8.0 / (3.0 * PI));
+        assert!(centroid.close_enough(expected, 1e-12), "got {}", centroid);
+    }
+
+    #[test]
+    fn test_arcline_centroid_mixed_shape_flattened() {
+        // Rectangle with a convex cap on the right and a concave bite on the top
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arc(point(4.0, 0.0), point(4.0, 2.0), point(4.0, 1.0), 1.0),
+            arcseg(point(4.0, 2.0), point(3.0, 2.0)),
+            arc(point(1.0, 2.0), point(3.0, 2.0), point(2.0, 2.5), 5.0_f64.sqrt() / 2.0),
+            arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let centroid = arcline_centroid(&arcs).unwrap();
+        let polygon: Pointline = arcline_to_polyline(&arcs, 1e-10).iter().map(|v| v.p).collect();
+        let flat = polygon_centroid(&polygon);
+        assert!(centroid.close_enough(flat, 1e-7), "{} vs {}", centroid, flat);
+    }
+
+    #[test]
+    fn test_arcline_centroid_zero_area() {
+        let degenerate = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(0.0, 0.0)),
+        ];
+        assert_eq!(arcline_centroid(&degenerate), None);
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_area_checked {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn square() -> Arcline {
+        vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_arcline_area_checked_valid() {
+        assert_eq!(arcline_area_checked(&square()), Ok(1.0));
+        let full = vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)];
+        let area = arcline_area_checked(&full).unwrap();
+        assert!((area - PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_arcline_area_checked_empty() {
+        let empty: Arcline = vec![];
+        assert_eq!(arcline_area_checked(&empty), Err(AreaError::Empty));
+    }
+
+    #[test]
+    fn test_arcline_area_checked_not_closed() {
+        let mut open = square();
+        let _ = open.pop();
+        assert_eq!(
+            arcline_area_checked(&open),
+            Err(AreaError::NotClosed(vec![2]))
+        );
+
+        let mut gap = square();
+        gap[2] = arcseg(point(1.0, 1.5), point(0.0, 1.0));
+        assert_eq!(
+            arcline_area_checked(&gap),
+            Err(AreaError::NotClosed(vec![1]))
+        );
+    }
+
+    #[test]
+    fn test_arcline_area_checked_degenerate() {
+        let mut arcs = square();
+        arcs.insert(1, arcseg(point(1.0, 0.0), point(1.0, 0.0)));
+        assert_eq!(
+            arcline_area_checked(&arcs),
+            Err(AreaError::DegenerateElements(vec![1]))
+        );
+
+        let mut arcs = square();
+        arcs[0] = arc(point(0.0, 0.0), point(1.0, 0.0), point(0.5, -1.0), 0.5);
+        arcs[3] = arcseg(point(0.0, 1.0), point(f64::NAN, 0.0));
+        assert_eq!(
+            arcline_area_checked(&arcs),
+            Err(AreaError::DegenerateElements(vec![0, 3]))
+        );
+    }
+
+    #[test]
+    fn test_area_error_display() {
+        assert_eq!(AreaError::Empty.to_string(), "empty arcline");
+        assert_eq!(
+            AreaError::NotClosed(vec![1]).to_string(),
+            "arcline is not closed after elements [1]"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_circle_circle_intersection_area {
+    use super::*;
+
+    #[test]
+    fn test_intersection_area_equal_circles() {
+        let c = circle(point(1.0, 2.0), 3.0);
+        let area = circle_circle_intersection_area(&c, &c);
+        assert!((area - 9.0 * PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intersection_area_nested() {
+        let big = circle(point(0.0, 0.0), 5.0);
+        let small = circle(point(1.0, 1.0), 2.0);
+        assert!((circle_circle_intersection_area(&big, &small) - 4.0 * PI).abs() < 1e-10);
+        assert!((circle_circle_intersection_area(&small, &big) - 4.0 * PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intersection_area_partial() {
+        // Unit circles with centers 1 apart: 2π/3 - √3/2
+        let c1 = circle(point(0.0, 0.0), 1.0);
+        let c2 = circle(point(1.0, 0.0), 1.0);
+        let expected = 2.0 * PI / 3.0 - 3.0_f64.sqrt() / 2.0;
+        let area = circle_circle_intersection_area(&c1, &c2);
+        assert!((area - expected).abs() < 1e-12, "{} vs {}", area, expected);
+
+        // Different radii: the area does not depend on the order of the circles
+        let c3 = circle(point(0.0, 2.0), 1.5);
+        let a12 = circle_circle_intersection_area(&c1, &c3);
+        let a21 = circle_circle_intersection_area(&c3, &c1);
+        assert!((a12 - a21).abs() < 1e-12);
+        assert!(a12 > 0.0 && a12 < PI);
+    }
+
+    #[test]
+    fn test_intersection_area_disjoint() {
+        let c1 = circle(point(0.0, 0.0), 1.0);
+        let c2 = circle(point(3.0, 0.0), 1.0);
+        assert_eq!(circle_circle_intersection_area(&c1, &c2), 0.0);
+        // Externally touching
+        let c3 = circle(point(2.0, 0.0), 1.0);
+        assert_eq!(circle_circle_intersection_area(&c1, &c3), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod test_circle_circle_intersection_region {
+    use super::*;
+
+    #[test]
+    fn test_intersection_region_closed() {
+        let c1 = circle(point(0.0, 0.0), 2.0);
+        let c2 = circle(point(1.5, 1.0), 1.0);
+        let lens = circle_circle_intersection_region(&c1, &c2).unwrap();
+        assert_eq!(lens.len(), 2);
+        assert_eq!(lens[0].b, lens[1].a);
+        assert_eq!(lens[1].b, lens[0].a);
+        assert_eq!(arcline_area_checked(&lens).map(|_| ()), Ok(()));
+        assert_eq!(arcline_is_valid(&lens), ArclineValidation::Valid);
+    }
+
+    #[test]
+    fn test_intersection_region_area() {
+        let cases = [
+            (circle(point(0.0, 0.0), 1.0), circle(point(1.0, 0.0), 1.0)),
+            (circle(point(0.0, 0.0), 2.0), circle(point(1.5, 1.0), 1.0)),
+            (circle(point(-1.0, 3.0), 0.5), circle(point(-1.2, 2.2), 1.0)),
+        ];
+        for (c1, c2) in cases {
+            let lens = circle_circle_intersection_region(&c1, &c2).unwrap();
+            let expected = circle_circle_intersection_area(&c1, &c2);
+            let area = arcline_area(&lens);
+            assert!((area - expected).abs() < 1e-10, "{} vs {}", area, expected);
+        }
+    }
+
+    #[test]
+    fn test_intersection_region_none() {
+        let c1 = circle(point(0.0, 0.0), 1.0);
+        // Disjoint
+        assert!(circle_circle_intersection_region(&c1, &circle(point(3.0, 0.0), 1.0)).is_none());
+        // Nested
+        assert!(circle_circle_intersection_region(&c1, &circle(point(0.1, 0.0), 0.5)).is_none());
+        // Touching
+        assert!(circle_circle_intersection_region(&c1, &circle(point(2.0, 0.0), 1.0)).is_none());
+        // Same
+        assert!(circle_circle_intersection_region(&c1, &c1).is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_circle_rect_intersection {
+    use super::*;
+
+    #[test]
+    fn test_circle_partly_inside() {
+        let c = circle(point(0.0, 0.0), 1.0);
+        // Quarter disc: two edge segments and an arc
+        let quarter = circle_rect_intersection(&c, &rect(point(0.0, 0.0), point(2.0, 2.0))).unwrap();
+        assert_eq!(
+            quarter,
+            vec![
+                arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+                arc(point(1.0, 0.0), point(0.0, 1.0), c.c, 1.0),
+                arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+            ]
+        );
+        assert_eq!(arcline_is_valid(&quarter), ArclineValidation::Valid);
+
+        // Vertical strip through the disc: two edge segments and two arcs
+        let strip = circle_rect_intersection(&c, &rect(point(-0.5, -2.0), point(0.5, 2.0))).unwrap();
+        assert_eq!(strip.len(), 4);
+        assert_eq!(strip.iter().filter(|e| e.is_seg()).count(), 2);
+        assert_eq!(arcline_is_valid(&strip), ArclineValidation::Valid);
+        let expected = 2.0 * (0.5 * 0.75_f64.sqrt() + 0.5_f64.asin());
+        assert!((arcline_area(&strip) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_circle_inside_rect() {
+        let c = circle(point(1.0, 2.0), 0.5);
+        let region = circle_rect_intersection(&c, &rect(point(0.0, 0.0), point(3.0, 3.0))).unwrap();
+        assert_eq!(region.len(), 1);
+        assert!(region[0].is_full_circle());
+        assert_eq!((region[0].c, region[0].r), (c.c, c.r));
+        assert!((arcline_area(&region) - PI * 0.25).abs() < 1e-12);
+        // Touching the rect from inside is still contained
+        let touching = circle(point(0.5, 2.0), 0.5);
+        let region = circle_rect_intersection(&touching, &rect(point(0.0, 0.0), point(3.0, 3.0))).unwrap();
+        assert!(region.len() == 1 && region[0].is_full_circle());
+    }
+
+    #[test]
+    fn test_rect_inside_circle() {
+        let c = circle(point(0.0, 0.0), 2.0);
+        let square = circle_rect_intersection(&c, &rect(point(-0.5, -0.5), point(0.5, 0.5))).unwrap();
+        assert_eq!(square.len(), 4);
+        assert!(square.iter().all(|e| e.is_seg()));
+        assert_eq!(arcline_area(&square), 1.0);
+    }
+
+    #[test]
+    fn test_circle_rect_disjoint() {
+        let c = circle(point(0.0, 0.0), 1.0);
+        assert!(circle_rect_intersection(&c, &rect(point(2.0, 2.0), point(3.0, 3.0))).is_none());
+        // Diagonal from the corner, outside the circle
+        assert!(circle_rect_intersection(&c, &rect(point(0.8, 0.8), point(3.0, 3.0))).is_none());
+        // Touching from outside
+        assert!(circle_rect_intersection(&c, &rect(point(1.0, -1.0), point(3.0, 1.0))).is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_area_green {
+    use super::*;
+
+    fn check(arcs: &Arcline, expected: f64) {
+        let green = arcline_area_green(arcs);
+        assert!((green - expected).abs() < 1e-12, "{} != {}", green, expected);
+        // The flattening error is bounded by the chord sagitta
+        let polygon: Pointline = arcline_to_polyline(arcs, 1e-10).iter().map(|v| v.p).collect();
+        let flat = pointline_area(&polygon);
+        assert!((green - flat).abs() < 1e-6 * expected.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_disc() {
+        let c = point(3.0, -2.0);
+        let disc = vec![
+            arc(point(5.0, -2.0), point(1.0, -2.0), c, 2.0),
+            arc(point(1.0, -2.0), point(5.0, -2.0), c, 2.0),
+        ];
+        check(&disc, 4.0 * PI);
+        let full = vec![arc(point(5.0, -2.0), point(5.0, -2.0), c, 2.0)];
+        check(&full, 4.0 * PI);
+    }
+
+    #[test]
+    fn test_rounded_rectangle() {
+        // 4 x 2 rectangle with corners rounded by radius 0.5
+        let r = 0.5;
+        let arcs = vec![
+            arcseg(point(0.5, 0.0), point(3.5, 0.0)),
+            arc(point(3.5, 0.0), point(4.0, 0.5), point(3.5, 0.5), r),
+            arcseg(point(4.0, 0.5), point(4.0, 1.5)),
+            arc(point(4.0, 1.5), point(3.5, 2.0), point(3.5, 1.5), r),
+            arcseg(point(3.5, 2.0), point(0.5, 2.0)),
+            arc(point(0.5, 2.0), point(0.0, 1.5), point(0.5, 1.5), r),
+            arcseg(point(0.0, 1.5), point(0.0, 0.5)),
+            arc(point(0.0, 0.5), point(0.5, 0.0), point(0.5, 0.5), r),
+        ];
+        check(&arcs, 8.0 - (4.0 - PI) * r * r);
+    }
+
+    #[test]
+    fn test_concave_bite() {
+        // 4 x 4 square with a half disc of radius 1 removed from the top edge
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arcseg(point(4.0, 0.0), point(4.0, 4.0)),
+            arcseg(point(4.0, 4.0), point(3.0, 4.0)),
+            arc(point(1.0, 4.0), point(3.0, 4.0), point(2.0, 4.0), 1.0),
+            arcseg(point(1.0, 4.0), point(0.0, 4.0)),
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        check(&arcs, 16.0 - PI / 2.0);
+    }
+
+    #[test]
+    fn test_cw_boundary_is_negative() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(0.0, 0.0)),
+        ];
+        assert_eq!(arcline_area_green(&square), -1.0);
+        assert_eq!(arcline_area_green(&vec![]), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod test_normalize_ring_orientations {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn square(x: f64, y: f64, s: f64) -> Arcline {
+        vec![
+            arcseg(point(x, y), point(x + s, y)),
+            arcseg(point(x + s, y), point(x + s, y + s)),
+            arcseg(point(x + s, y + s), point(x, y + s)),
+            arcseg(point(x, y + s), point(x, y)),
+        ]
+    }
+
+    // Half disc of radius 1 above (cx, cy), CCW, with the base in two pieces
+    fn half_disc(cx: f64, cy: f64) -> Arcline {
+        vec![
+            arcseg(point(cx - 1.0, cy), point(cx, cy)),
+            arcseg(point(cx, cy), point(cx + 1.0, cy)),
+            arc(point(cx + 1.0, cy), point(cx - 1.0, cy), point(cx, cy), 1.0),
+        ]
+    }
+
+    fn net_area(rings: &[Arcline]) -> f64 {
+        rings.iter().map(arcline_area_green).sum()
+    }
+
+    #[test]
+    fn test_normalize_flips_ccw_hole() {
+        let outer = square(0.0, 0.0, 6.0);
+        let hole = half_disc(3.0, 3.0);
+        let mut rings = vec![outer.clone(), hole.clone(), arcline_reverse(&square(1.0, 1.0, 1.0))];
+        // The half disc hole is wrongly CCW
+        assert!((net_area(&rings) - (36.0 + PI / 2.0 - 1.0)).abs() < 1e-12);
+
+        normalize_ring_orientations(&mut rings);
+        assert_eq!(rings[0], outer);
+        assert_eq!(rings[1], arcline_reverse(&hole));
+        assert!(arcline_area_green(&rings[1]) < 0.0);
+        assert!((net_area(&rings) - (36.0 - PI / 2.0 - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_flips_cw_outer() {
+        let outer = arcline_reverse(&half_disc(0.0, 0.0));
+        let hole = square(-0.25, 0.25, 0.5);
+        let mut rings = vec![hole, outer];
+        normalize_ring_orientations(&mut rings);
+        assert!(arcline_area_green(&rings[1]) > 0.0);
+        assert!(arcline_area_green(&rings[0]) < 0.0);
+        assert!((net_area(&rings) - (PI / 2.0 - 0.25)).abs() < 1e-12);
+
+        // Already normalized rings are left unchanged
+        let before = rings.clone();
+        normalize_ring_orientations(&mut rings);
+        assert_eq!(rings, before);
+    }
+
+    #[test]
+    fn test_normalize_empty() {
+        let mut rings: Vec<Arcline> = vec![];
+        normalize_ring_orientations(&mut rings);
+        assert!(rings.is_empty());
+    }
+}
@@ -0,0 +1,325 @@
+//! Boolean operations on simple polygons.
+//!
+//! This module provides set operations on polygons given as point sequences.
+
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+
+/// Computes the symmetric difference of two simple polygons.
+///
+/// The result covers the points inside exactly one of the polygons. The edges
+/// of both polygons are split where they cross or touch the other polygon.
+/// Each piece outside the other polygon bounds the result as it is, each piece
+/// inside bounds it reversed, and pieces shared by both boundaries are dropped.
+/// The pieces are then linked into rings, turning as far left as possible at
+/// vertices where several pieces meet.
+///
+/// Both polygons are closed implicitly (last point connects back to first)
+/// and may be oriented CCW or CW.
+///
+/// # Arguments
+///
+/// * `a` - The first simple polygon
+/// * `b` - The second simple polygon
+///
+/// # Returns
+///
+/// The rings bounding the result, with the result always on the left of the
+/// ring edges: outer boundaries are CCW and holes are CW. Regions meeting
+/// along a shared edge are merged, while regions touching only at a vertex
+/// are returned as separate rings.
+/// Polygons with fewer than 3 points are treated as empty.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::boolean::pointline_symmetric_difference;
+///
+/// // Two overlapping squares leave two L-shaped regions
+/// let a = vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)];
+/// let b = vec![point(1.0, 1.0), point(3.0, 1.0), point(3.0, 3.0), point(1.0, 3.0)];
+/// let rings = pointline_symmetric_difference(&a, &b);
+/// assert_eq!(rings.len(), 2);
+/// for ring in &rings {
+///     assert_eq!(ring.len(), 6);
+///     assert_eq!(pointline_area(ring), 3.0);
+/// }
+/// ```
+#[must_use]
+pub fn pointline_symmetric_difference(a: &Pointline, b: &Pointline) -> Vec<Pointline> {
+    let a = ccw_ring(a);
+    let b = ccw_ring(b);
+    match (a.len() < 3, b.len() < 3) {
+        (true, true) => return Vec::new(),
+        (true, false) => return vec![b],
+        (false, true) => return vec![a],
+        _ => {}
+    }
+
+    let (splits_a, splits_b) = split_points(&a, &b);
+    let mut pieces = Vec::new();
+    add_pieces(&a, &splits_a, &b, &mut pieces);
+    add_pieces(&b, &splits_b, &a, &mut pieces);
+    link_rings(&pieces)
+}
+
+// Copy of the ring without repeated points, oriented CCW
+fn ccw_ring(poly: &Pointline) -> Pointline {
+    let mut ring: Pointline = Vec::with_capacity(poly.len());
+    for &p in poly {
+        if ring.last() != Some(&p) {
+            ring.push(p);
+        }
+    }
+    while ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    if pointline_area(&ring) < 0.0 {
+        ring.reverse();
+    }
+    ring
+}
+
+fn ring_edge(ring: &Pointline, i: usize) -> Segment {
+    segment(ring[i], ring[(i + 1) % ring.len()])
+}
+
+// Points splitting each edge of `a` and of `b`, shared exactly by both polygons
+fn split_points(a: &Pointline, b: &Pointline) -> (Vec<Vec<Point>>, Vec<Vec<Point>>) {
+    let mut splits_a = vec![Vec::new(); a.len()];
+    let mut splits_b = vec![Vec::new(); b.len()];
+    for (i, split_a) in splits_a.iter_mut().enumerate() {
+        let ea = ring_edge(a, i);
+        for (j, split_b) in splits_b.iter_mut().enumerate() {
+            let eb = ring_edge(b, j);
+            if let Some((p, _, _)) = int_segment_segment_params(&ea, &eb) {
+                // Reuse the vertex when the crossing is at an end
+                let p = [ea.a, ea.b, eb.a, eb.b]
+                    .into_iter()
+                    .find(|v| v.close_enough(p, GEOMETRIC_EPSILON))
+                    .unwrap_or(p);
+                split_a.push(p);
+                split_b.push(p);
+            }
+        }
+    }
+    // Vertices lying on the other polygon's edges, e.g. along overlapping edges
+    for (ring, other, splits) in [(a, b, &mut splits_b), (b, a, &mut splits_a)] {
+        for &v in ring {
+            for (j, split) in splits.iter_mut().enumerate() {
+                if dist_point_segment(&v, &ring_edge(other, j)).0 < GEOMETRIC_EPSILON {
+                    split.push(v);
+                }
+            }
+        }
+    }
+    (splits_a, splits_b)
+}
+
+// Pieces of the edges of `ring` bounding the symmetric difference with `other`
+fn add_pieces(ring: &Pointline, splits: &[Vec<Point>], other: &Pointline, pieces: &mut Vec<(Point, Point)>) {
+    for (i, split) in splits.iter().enumerate() {
+        let edge = ring_edge(ring, i);
+        let d = edge.b - edge.a;
+        let mut points: Vec<Point> = split
+            .iter()
+            .copied()
+            .filter(|p| !p.close_enough(edge.a, GEOMETRIC_EPSILON) && !p.close_enough(edge.b, GEOMETRIC_EPSILON))
+            .collect();
+        points.sort_by(|p, q| d.dot(*p - edge.a).total_cmp(&d.dot(*q - edge.a)));
+        points.dedup_by(|p, q| p.close_enough(*q, GEOMETRIC_EPSILON));
+        points.insert(0, edge.a);
+        points.push(edge.b);
+
+        for w in points.windows(2) {
+            let (p, q) = (w[0], w[1]);
+            let mid = (p + q) * 0.5;
+            if on_boundary(mid, other) {
+                continue;
+            }
+            if inside(mid, other) {
+                pieces.push((q, p));
+            } else {
+                pieces.push((p, q));
+            }
+        }
+    }
+}
+
+fn on_boundary(p: Point, ring: &Pointline) -> bool {
+    (0..ring.len()).any(|j| dist_point_segment(&p, &ring_edge(ring, j)).0 < GEOMETRIC_EPSILON)
+}
+
+// Crossing number test for a point not on the boundary
+fn inside(p: Point, ring: &Pointline) -> bool {
+    let mut result = false;
+    for j in 0..ring.len() {
+        let e = ring_edge(ring, j);
+        if (e.a.y > p.y) != (e.b.y > p.y)
+            && p.x < e.a.x + (p.y - e.a.y) * (e.b.x - e.a.x) / (e.b.y - e.a.y)
+        {
+            result = !result;
+        }
+    }
+    result
+}
+
+// Links directed pieces into closed rings, keeping the region on the left
+fn link_rings(pieces: &[(Point, Point)]) -> Vec<Pointline> {
+    let mut used = vec![false; pieces.len()];
+    let mut rings = Vec::new();
+    for start in 0..pieces.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let origin = pieces[start].0;
+        let mut ring = vec![origin];
+        let mut current = pieces[start];
+        let closed = loop {
+            if current.1.close_enough(origin, GEOMETRIC_EPSILON) {
+                break true;
+            }
+            ring.push(current.1);
+            // The next piece is the first one clockwise from the way back
+            let back = current.0 - current.1;
+            let next = (0..pieces.len())
+                .filter(|&k| !used[k] && pieces[k].0.close_enough(current.1, GEOMETRIC_EPSILON))
+                .min_by(|&k, &l| {
+                    let turn = |k: usize| {
+                        let d = pieces[k].1 - pieces[k].0;
+                        let angle = d.perp(back).atan2(d.dot(back));
+                        if angle <= 0.0 { angle + std::f64::consts::TAU } else { angle }
+                    };
+                    turn(k).total_cmp(&turn(l))
+                });
+            let Some(k) = next else {
+                break false;
+            };
+            used[k] = true;
+            current = pieces[k];
+        };
+        if closed {
+            remove_collinear(&mut ring);
+            if ring.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+    }
+    rings
+}
+
+// Removes vertices in the middle of straight runs
+fn remove_collinear(ring: &mut Pointline) {
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let n = ring.len();
+        let prev = ring[(i + n - 1) % n];
+        let next = ring[(i + 1) % n];
+        let p = ring[i];
+        if points_order(prev, p, next) == 0.0 && (p - prev).dot(next - p) > 0.0 {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_pointline_symmetric_difference {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Pointline {
+        vec![
+            point(x, y),
+            point(x + size, y),
+            point(x + size, y + size),
+            point(x, y + size),
+        ]
+    }
+
+    fn total_area(rings: &[Pointline]) -> f64 {
+        rings.iter().map(pointline_area).sum()
+    }
+
+    #[test]
+    fn test_overlapping_squares_give_two_l_shapes() {
+        let rings = pointline_symmetric_difference(&square(0.0, 0.0, 2.0), &square(1.0, 1.0, 2.0));
+        assert_eq!(rings.len(), 2);
+        for ring in &rings {
+            assert_eq!(ring.len(), 6);
+            assert_eq!(pointline_area(ring), 3.0);
+        }
+        // One L in each square
+        let l_a = rings.iter().find(|r| r.contains(&point(0.0, 0.0))).unwrap();
+        let l_b = rings.iter().find(|r| r.contains(&point(3.0, 3.0))).unwrap();
+        for p in [point(2.0, 0.0), point(2.0, 1.0), point(1.0, 1.0), point(1.0, 2.0), point(0.0, 2.0)] {
+            assert!(l_a.contains(&p));
+        }
+        for p in [point(2.0, 1.0), point(3.0, 1.0), point(1.0, 3.0), point(1.0, 2.0), point(2.0, 2.0)] {
+            assert!(l_b.contains(&p));
+        }
+    }
+
+    #[test]
+    fn test_disjoint_squares_returned_both() {
+        let a = square(0.0, 0.0, 1.0);
+        let b = square(3.0, 0.0, 2.0);
+        let rings = pointline_symmetric_difference(&a, &b);
+        assert_eq!(rings.len(), 2);
+        assert_eq!(total_area(&rings), 5.0);
+        assert!(rings.iter().all(|r| r.len() == 4 && pointline_area(r) > 0.0));
+    }
+
+    #[test]
+    fn test_cw_input_and_contained_square_is_a_hole() {
+        let mut outer = square(0.0, 0.0, 4.0);
+        outer.reverse();
+        let inner = square(1.0, 1.0, 1.0);
+        let rings = pointline_symmetric_difference(&outer, &inner);
+        assert_eq!(rings.len(), 2);
+        let areas: Vec<f64> = rings.iter().map(pointline_area).collect();
+        assert!(areas.contains(&16.0));
+        assert!(areas.contains(&-1.0));
+        assert_eq!(total_area(&rings), 15.0);
+    }
+
+    #[test]
+    fn test_shared_edge_and_identical() {
+        // Squares sharing a full edge merge into one rectangle
+        let rings = pointline_symmetric_difference(&square(0.0, 0.0, 1.0), &square(1.0, 0.0, 1.0));
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 4);
+        assert_eq!(pointline_area(&rings[0]), 2.0);
+        // Squares touching at a corner stay separate rings
+        let rings = pointline_symmetric_difference(&square(0.0, 0.0, 1.0), &square(1.0, 1.0, 1.0));
+        assert_eq!(rings.len(), 2);
+        assert_eq!(total_area(&rings), 2.0);
+        // Partially shared edge
+        let rings = pointline_symmetric_difference(&square(0.0, 0.0, 2.0), &square(2.0, 1.0, 2.0));
+        assert_eq!(total_area(&rings), 8.0);
+        assert!(rings.iter().all(|r| pointline_area(r) > 0.0));
+        // Identical polygons cancel out
+        assert!(pointline_symmetric_difference(&square(0.0, 0.0, 1.0), &square(0.0, 0.0, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn test_triangle_across_square() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = vec![point(1.0, -1.0), point(3.0, 1.0), point(1.0, 3.0)];
+        let rings = pointline_symmetric_difference(&a, &b);
+        // The common part is the square clipped by the convex triangle
+        let common = pointline_area(&crate::algo::pointline_clip_by_convex(&a, &b));
+        assert!((total_area(&rings) - (4.0 + 4.0 - 2.0 * common)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        let a = square(0.0, 0.0, 1.0);
+        assert_eq!(pointline_symmetric_difference(&a, &vec![]), vec![a.clone()]);
+        assert_eq!(pointline_symmetric_difference(&vec![point(0.0, 0.0)], &a), vec![a]);
+        assert!(pointline_symmetric_difference(&vec![], &vec![]).is_empty());
+    }
+}
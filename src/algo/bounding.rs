@@ -130,7 +130,7 @@ fn _point_in_circle_robust(p: Point, circle_points: &[Point]) -> bool {
 /// Uses robust geometric predicates from the `robust` crate for numerical stability.
 /// The implementation uses `orient2d` to check for collinearity and robust arithmetic
 /// for the circumcenter calculation.
-fn circumcircle(p1: Point, p2: Point, p3: Point) -> Option<Circle> {
+pub(crate) fn circumcircle(p1: Point, p2: Point, p3: Point) -> Option<Circle> {
     // Use robust orient2d to check for collinearity
     let orientation = orient2d(
         Coord { x: p1.x, y: p1.y },
@@ -900,51 +900,662 @@ pub fn arc_bounding_rect(arc: &Arc) -> Rect {
         }
     }
 
-    // For circular arcs, find the bounding rectangle
-    let center = arc.c;
-    let radius = arc.r;
-
     // Start with endpoints
     let mut min_x = arc.a.x.min(arc.b.x);
     let mut max_x = arc.a.x.max(arc.b.x);
     let mut min_y = arc.a.y.min(arc.b.y);
     let mut max_y = arc.a.y.max(arc.b.y);
 
-    // Calculate start and end angles
-    let start_angle = (arc.a - center).y.atan2((arc.a - center).x);
-    let end_angle = (arc.b - center).y.atan2((arc.b - center).x);
+    // Add extreme points of the circle that lie within the arc's angular span
+    for extreme_point in arc.cardinal_extrema() {
+        min_x = min_x.min(extreme_point.x);
+        max_x = max_x.max(extreme_point.x);
+        min_y = min_y.min(extreme_point.y);
+        max_y = max_y.max(extreme_point.y);
+    }
 
-    // Normalize angles to [0, 2π) and ensure CCW orientation
-    let start_norm = if start_angle < 0.0 {
-        start_angle + 2.0 * PI
-    } else {
-        start_angle
+    Rect::new(point(min_x, min_y), point(max_x, max_y))
+}
+
+/// Computes the smallest axis-aligned bounding rectangle around an arcline.
+///
+/// The result is the union of [`arc_bounding_rect`] of each element, so curved
+/// arcs contribute their extreme points, not just their endpoints.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to bound
+///
+/// # Returns
+///
+/// A `Rect` enclosing all elements (a degenerate rect at the origin for an empty arcline)
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::bounding::arcline_bounding_rect;
+///
+/// let half_disc = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+/// ];
+/// let bounding = arcline_bounding_rect(&half_disc);
+/// assert_eq!(bounding, rect(point(-1.0, 0.0), point(1.0, 1.0)));
+/// ```
+#[must_use]
+pub fn arcline_bounding_rect(arcs: &Arcline) -> Rect {
+    if arcs.is_empty() {
+        return Rect::new(point(0.0, 0.0), point(0.0, 0.0));
+    }
+
+    let mut min_x = f64::INFINITY;
+    let mut min_y = f64::INFINITY;
+    let mut max_x = f64::NEG_INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    for arc in arcs {
+        let r = arc_bounding_rect(arc);
+        min_x = min_x.min(r.p1.x);
+        min_y = min_y.min(r.p1.y);
+        max_x = max_x.max(r.p2.x);
+        max_y = max_y.max(r.p2.y);
+    }
+
+    Rect::new(point(min_x, min_y), point(max_x, max_y))
+}
+
+#[cfg(test)]
+mod test_arcline_bounding_rect {
+    use super::*;
+
+    #[test]
+    fn test_arcline_bounding_rect_empty() {
+        let empty: Arcline = vec![];
+        let bounding = arcline_bounding_rect(&empty);
+        assert_eq!(bounding, rect(point(0.0, 0.0), point(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_arcline_bounding_rect_quarter_arcs_circle() {
+        let c = point(2.0, 1.0);
+        let arcs = vec![
+            arc(point(3.0, 1.0), point(2.0, 2.0), c, 1.0),
+            arc(point(2.0, 2.0), point(1.0, 1.0), c, 1.0),
+            arc(point(1.0, 1.0), point(2.0, 0.0), c, 1.0),
+            arc(point(2.0, 0.0), point(3.0, 1.0), c, 1.0),
+        ];
+        let bounding = arcline_bounding_rect(&arcs);
+        assert_eq!(bounding, rect(point(1.0, 0.0), point(3.0, 2.0)));
+    }
+
+    #[test]
+    fn test_arcline_bounding_rect_l_shape() {
+        let l_shape = vec![
+            arcseg(point(0.0, 0.0), point(3.0, 0.0)),
+            arcseg(point(3.0, 0.0), point(3.0, 1.0)),
+            arcseg(point(3.0, 1.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(1.0, 4.0)),
+            arcseg(point(1.0, 4.0), point(0.0, 4.0)),
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        let bounding = arcline_bounding_rect(&l_shape);
+        assert_eq!(bounding, rect(point(0.0, 0.0), point(3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_arcline_bounding_rect_interior_extremum() {
+        // The arc ends are at 45 and 135 degrees, its top at 90 degrees bounds the arcline
+        let s = 0.5_f64.sqrt();
+        let arcs = vec![
+            arc(point(s, s), point(-s, s), point(0.0, 0.0), 1.0),
+            arcseg(point(-s, s), point(s, s)),
+        ];
+        let bounding = arcline_bounding_rect(&arcs);
+        assert_eq!(bounding.p1, point(-s, s));
+        assert!(bounding.p2.close_enough(point(s, 1.0), 1e-12));
+    }
+}
+
+/// Computes the support point of an arcline in a given direction.
+///
+/// The support point is the point of the arcline farthest in the direction `dir`,
+/// as used by rotating calipers and packing algorithms. Element endpoints are always
+/// candidates; for circular arcs the extreme circle point `c + r * dir` is also a candidate
+/// when it lies within the arc sweep.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline
+/// * `dir` - The direction (does not need to be normalized)
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The support point
+/// * Its projection onto the normalized direction
+///
+/// For an empty arcline returns the origin and `f64::NEG_INFINITY`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::bounding::arcline_support;
+///
+/// let half_disc = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+/// ];
+/// let (p, h) = arcline_support(&half_disc, point(0.0, 2.0));
+/// assert_eq!(p, point(0.0, 1.0));
+/// assert_eq!(h, 1.0);
+/// ```
+#[must_use]
+pub fn arcline_support(arcs: &Arcline, dir: Point) -> (Point, f64) {
+    let (dir, _) = dir.normalize(false);
+    let mut best = (point(0.0, 0.0), f64::NEG_INFINITY);
+    let mut update = |p: Point| {
+        let h = p.dot(dir);
+        if h > best.1 {
+            best = (p, h);
+        }
     };
-    let end_norm = if end_angle < 0.0 {
-        end_angle + 2.0 * PI
-    } else {
-        end_angle
+
+    for arc in arcs {
+        update(arc.a);
+        update(arc.b);
+        if arc.is_arc() {
+            let extreme = arc.c + dir * arc.r;
+            if arc.contains(extreme) {
+                update(extreme);
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test_arcline_support {
+    use super::*;
+
+    #[test]
+    fn test_arcline_support_empty() {
+        let empty: Arcline = vec![];
+        let (_, h) = arcline_support(&empty, point(1.0, 0.0));
+        assert_eq!(h, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_arcline_support_disc() {
+        let c = point(2.0, -1.0);
+        let disc = vec![
+            arc(point(3.0, -1.0), point(1.0, -1.0), c, 1.0),
+            arc(point(1.0, -1.0), point(3.0, -1.0), c, 1.0),
+        ];
+        for angle in [0.3_f64, 1.0, 2.5, 4.0, 5.5] {
+            let dir = point(angle.cos(), angle.sin()) * 3.0;
+            let (p, h) = arcline_support(&disc, dir);
+            let expected = c + point(angle.cos(), angle.sin());
+            assert!(p.close_enough(expected, 1e-12), "got {} expected {}", p, expected);
+            assert!((h - expected.dot(point(angle.cos(), angle.sin()))).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_arcline_support_segment() {
+        let seg = vec![arcseg(point(0.0, 0.0), point(2.0, 1.0))];
+        let (p, h) = arcline_support(&seg, point(1.0, 0.0));
+        assert_eq!(p, point(2.0, 1.0));
+        assert_eq!(h, 2.0);
+        let (p, h) = arcline_support(&seg, point(0.0, -1.0));
+        assert_eq!(p, point(0.0, 0.0));
+        assert_eq!(h, 0.0);
+    }
+
+    #[test]
+    fn test_arcline_support_extreme_outside_sweep() {
+        // Quarter arc in the first quadrant, direction pointing down-left
+        let quarter = vec![arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0)];
+        let (p, _) = arcline_support(&quarter, point(-1.0, -0.5));
+        assert_eq!(p, point(0.0, 1.0));
+    }
+}
+
+/// Computes the minimal enclosing circle of an arc in closed form.
+///
+/// The minimal enclosing circle of an arc is the minimal enclosing circle of its
+/// endpoints and its apex (the midpoint of the arc):
+/// - for a minor arc (sweep up to π) the apex lies inside the circle having the
+///   chord as diameter, so the result is centered on the chord midpoint,
+/// - for a major arc or a full circle the result is the arc's own circle.
+///
+/// Unlike [`arc_bounding_circle`], no candidate points are tested,
+/// only the sweep angle is needed.
+///
+/// # Arguments
+///
+/// * `arc` - The arc to bound (segments are bounded by their chord circle)
+///
+/// # Returns
+///
+/// The smallest circle enclosing the arc
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::bounding::arc_tight_bounding_circle;
+///
+/// // Shallow arc: the circle is centered on the chord
+/// let shallow = arc_from_bulge(point(0.0, 0.0), point(2.0, 0.0), 0.1);
+/// let c = arc_tight_bounding_circle(&shallow);
+/// assert!(c.c.close_enough(point(1.0, 0.0), 1e-12));
+/// assert!((c.r - 1.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn arc_tight_bounding_circle(arc: &Arc) -> Circle {
+    if arc.is_seg() || arc.sweep_angle() <= PI {
+        let chord_center = (arc.a + arc.b) * 0.5;
+        let chord_radius = (arc.b - arc.a).norm() * 0.5;
+        return Circle::new(chord_center, chord_radius);
+    }
+    Circle::new(arc.c, arc.r)
+}
+
+#[cfg(test)]
+mod test_arc_tight_bounding_circle {
+    use super::*;
+
+    fn encloses(c: &Circle, arc: &Arc) -> bool {
+        (0..=64).all(|i| (arc.point_at(i as f64 / 64.0) - c.c).norm() <= c.r + 1e-12)
+    }
+
+    #[test]
+    fn test_tight_bounding_circle_shallow_arc() {
+        let shallow = arc_from_bulge(point(1.0, 1.0), point(5.0, 1.0), 0.05);
+        let c = arc_tight_bounding_circle(&shallow);
+        assert!(c.c.close_enough(point(3.0, 1.0), 1e-12));
+        assert!((c.r - 2.0).abs() < 1e-12);
+        // Much smaller than the arc's own circle
+        assert!(c.r < shallow.r / 5.0);
+        assert!(encloses(&c, &shallow));
+    }
+
+    #[test]
+    fn test_tight_bounding_circle_semicircle() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        let c = arc_tight_bounding_circle(&half);
+        assert!(c.c.close_enough(point(1.0, 1.0), 1e-12));
+        assert!((c.r - 2.0).abs() < 1e-12);
+        assert!(encloses(&c, &half));
+    }
+
+    #[test]
+    fn test_tight_bounding_circle_major_arc() {
+        let major = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(arc_tight_bounding_circle(&major), circle(point(0.0, 0.0), 1.0));
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(arc_tight_bounding_circle(&full), circle(point(0.0, 0.0), 1.0));
+    }
+
+    #[test]
+    fn test_tight_bounding_circle_segment() {
+        let seg = arcseg(point(0.0, 0.0), point(4.0, 0.0));
+        assert_eq!(arc_tight_bounding_circle(&seg), circle(point(2.0, 0.0), 2.0));
+    }
+
+    #[test]
+    fn test_tight_bounding_circle_matches_arc_bounding_circle() {
+        let arcs = [
+            arc_from_bulge(point(0.0, 0.0), point(2.0, 1.0), 0.3),
+            arc_from_bulge(point(0.0, 0.0), point(2.0, 1.0), 0.9),
+            arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+        ];
+        for a in arcs {
+            let tight = arc_tight_bounding_circle(&a);
+            let other = arc_bounding_circle(&a);
+            assert!(tight.r <= other.r + 1e-12, "{} > {}", tight.r, other.r);
+            assert!(encloses(&tight, &a));
+        }
+    }
+}
+
+/// Computes the minimum enclosing circle of a set of points.
+///
+/// Uses Welzl's randomized incremental algorithm in its iterative form: the
+/// points are shuffled, then added one by one; a point outside the current
+/// circle must lie on the boundary of the new circle, which is rebuilt from
+/// the points seen so far with one, then two fixed boundary points. The
+/// expected running time is linear. The shuffle uses a fixed seed, so the
+/// result is deterministic.
+///
+/// # Arguments
+///
+/// * `points` - The points to enclose
+///
+/// # Returns
+///
+/// The smallest circle containing all points, a zero radius circle at the
+/// point for a single point, and at the origin for no points
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let points = vec![point(0.0, 0.0), point(4.0, 0.0), point(2.0, 1.0), point(1.0, -1.0)];
+/// let c = points_min_enclosing_circle(&points);
+/// assert_eq!(c, circle(point(2.0, 0.0), 2.0));
+/// ```
+#[must_use]
+pub fn points_min_enclosing_circle(points: &[Point]) -> Circle {
+    if points.is_empty() {
+        return Circle::new(point(0.0, 0.0), 0.0);
+    }
+
+    let mut pts = points.to_vec();
+    // Fisher-Yates shuffle with a xorshift generator
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    for i in (1..pts.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        pts.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    let inside = |c: &Circle, p: Point| (p - c.c).norm() <= c.r + GEOMETRIC_EPSILON;
+    let diameter = |p: Point, q: Point| Circle::new((p + q) * 0.5, (q - p).norm() * 0.5);
+    let mut c = Circle::new(pts[0], 0.0);
+    for i in 1..pts.len() {
+        if inside(&c, pts[i]) {
+            continue;
+        }
+        // pts[i] is on the boundary
+        c = Circle::new(pts[i], 0.0);
+        for j in 0..i {
+            if inside(&c, pts[j]) {
+                continue;
+            }
+            // pts[i] and pts[j] are on the boundary
+            c = diameter(pts[i], pts[j]);
+            for k in 0..j {
+                if inside(&c, pts[k]) {
+                    continue;
+                }
+                c = circumcircle(pts[i], pts[j], pts[k]).unwrap_or_else(|| {
+                    // Collinear: the farthest pair is a diameter
+                    [diameter(pts[i], pts[j]), diameter(pts[i], pts[k]), diameter(pts[j], pts[k])]
+                        .into_iter()
+                        .fold(Circle::new(pts[i], 0.0), |a, b| if b.r > a.r { b } else { a })
+                });
+            }
+        }
+    }
+    c
+}
+
+#[cfg(test)]
+mod test_points_min_enclosing_circle {
+    use super::*;
+
+    fn encloses_all(c: &Circle, points: &[Point]) -> bool {
+        points.iter().all(|p| (*p - c.c).norm() <= c.r + 1e-9)
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_degenerate() {
+        assert_eq!(points_min_enclosing_circle(&[]), circle(point(0.0, 0.0), 0.0));
+        assert_eq!(points_min_enclosing_circle(&[point(3.0, -1.0)]), circle(point(3.0, -1.0), 0.0));
+        let same = [point(1.0, 1.0); 4];
+        assert_eq!(points_min_enclosing_circle(&same), circle(point(1.0, 1.0), 0.0));
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_collinear() {
+        let points = [
+            point(1.0, 1.0),
+            point(0.0, 0.0),
+            point(5.0, 5.0),
+            point(2.0, 2.0),
+            point(3.5, 3.5),
+        ];
+        let c = points_min_enclosing_circle(&points);
+        assert!(c.c.close_enough(point(2.5, 2.5), 1e-12));
+        assert!((c.r - 12.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_points_on_circle() {
+        let center = point(1.0, -2.0);
+        let points: Vec<Point> = (0..12)
+            .map(|k| {
+                let t = 0.3 + k as f64 * 0.5;
+                center + point(t.cos(), t.sin()) * 3.0
+            })
+            .chain([center, center + point(1.0, 1.0)])
+            .collect();
+        let c = points_min_enclosing_circle(&points);
+        assert!(c.c.close_enough(center, 1e-9));
+        assert!((c.r - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_random_cloud() {
+        // Linear congruential generator for a reproducible cloud
+        let mut state: u64 = 12345;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let points: Vec<Point> = (0..500).map(|_| point(next() * 10.0 - 5.0, next() * 6.0)).collect();
+        let c = points_min_enclosing_circle(&points);
+        assert!(encloses_all(&c, &points));
+        // Minimal: two or three points are on the circle
+        let on_circle = points.iter().filter(|p| ((**p - c.c).norm() - c.r).abs() < 1e-9).count();
+        assert!(on_circle >= 2);
+        // Never larger than the circle of the bounding box diagonal
+        let bbox_circle = circle(point(0.0, 3.0), 34.0_f64.sqrt());
+        assert!(c.r <= bbox_circle.r);
+    }
+}
+
+/// Number of tangent directions sampled along each hull arc.
+const OBB_ARC_SAMPLES: usize = 16;
+/// Golden section iterations refining the best sampled arc direction.
+const OBB_REFINE_ITERATIONS: usize = 64;
+
+/// Computes the minimum-area oriented bounding box of an arcline.
+///
+/// The box is searched with rotating calipers on the convex hull of the arcline
+/// (see [`arclines_convex_hull`]). For every box orientation the extents are
+/// given by [`arcline_support`], so arcs contribute their extreme points. A
+/// minimum-area box has a side flush with a hull segment or tangent to a hull
+/// arc: every segment direction is tried, and the tangent directions along each
+/// arc are sampled and then refined with a golden section search.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to bound
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The area of the box
+/// * The box corners in CCW order
+///
+/// For an empty arcline the area is `0.0` and all corners are at the origin.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::bounding::arcline_min_area_obb;
+///
+/// // A 2 x 1 rectangle rotated by 45 degrees
+/// let s = 0.5_f64.sqrt();
+/// let p = [point(0.0, 0.0), point(2.0 * s, 2.0 * s), point(s, 3.0 * s), point(-s, s)];
+/// let arcs: Arcline = (0..4).map(|i| arcseg(p[i], p[(i + 1) % 4])).collect();
+/// let (area, corners) = arcline_min_area_obb(&arcs);
+/// assert!((area - 2.0).abs() < 1e-12);
+/// assert!(corners.iter().all(|c| p.iter().any(|q| c.close_enough(*q, 1e-12))));
+/// ```
+#[must_use]
+pub fn arcline_min_area_obb(arcs: &Arcline) -> (f64, [Point; 4]) {
+    let hull = arclines_convex_hull(core::slice::from_ref(arcs));
+    if hull.is_empty() {
+        return (0.0, [point(0.0, 0.0); 4]);
+    }
+
+    let mut best = obb_at(&hull, 0.0);
+    let mut consider = |theta: f64| {
+        let candidate = obb_at(&hull, theta);
+        if candidate.0 < best.0 {
+            best = candidate;
+        }
     };
+    for element in &hull {
+        if element.is_seg() {
+            let d = element.b - element.a;
+            if d.norm() > GEOMETRIC_EPSILON {
+                consider(d.y.atan2(d.x));
+            }
+            continue;
+        }
+        // Tangent directions of the arc, from `a` to `b`
+        let va = element.a - element.c;
+        let start = va.y.atan2(va.x) + PI / 2.0;
+        let end = start + element.sweep_angle();
+        let step = (end - start) / OBB_ARC_SAMPLES as f64;
+        let area = |theta: f64| obb_at(&hull, theta).0;
+        let mut best_theta = start;
+        let mut best_area = f64::INFINITY;
+        for k in 0..=OBB_ARC_SAMPLES {
+            let theta = start + step * k as f64;
+            let a = area(theta);
+            if a < best_area {
+                best_area = a;
+                best_theta = theta;
+            }
+        }
+        let (mut lo, mut hi) = ((best_theta - step).max(start), (best_theta + step).min(end));
+        let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+        for _ in 0..OBB_REFINE_ITERATIONS {
+            let m1 = hi - ratio * (hi - lo);
+            let m2 = lo + ratio * (hi - lo);
+            if area(m1) < area(m2) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        consider(best_theta);
+        consider((lo + hi) / 2.0);
+    }
+    best
+}
 
-    // Check the four extreme points of the full circle
-    let extreme_angles = [0.0, PI * 0.5, PI, PI * 1.5]; // Right, Top, Left, Bottom
-    let extreme_points = [
-        center + point(radius, 0.0),  // Right (0°): affects max_x
-        center + point(0.0, radius),  // Top (90°): affects max_y
-        center + point(-radius, 0.0), // Left (180°): affects min_x
-        center + point(0.0, -radius), // Bottom (270°): affects min_y
-    ];
+// Bounding box of the hull with sides along the angle `theta` and its normal
+fn obb_at(hull: &Arcline, theta: f64) -> (f64, [Point; 4]) {
+    let u = point(theta.cos(), theta.sin());
+    let v = point(-u.y, u.x);
+    let u_max = arcline_support(hull, u).1;
+    let u_min = -arcline_support(hull, -u).1;
+    let v_max = arcline_support(hull, v).1;
+    let v_min = -arcline_support(hull, -v).1;
+    let area = (u_max - u_min) * (v_max - v_min);
+    let corner = |s: f64, t: f64| u * s + v * t;
+    (
+        area,
+        [
+            corner(u_min, v_min),
+            corner(u_max, v_min),
+            corner(u_max, v_max),
+            corner(u_min, v_max),
+        ],
+    )
+}
 
-    // Add extreme points that lie within the arc's angular span
-    for (i, &angle) in extreme_angles.iter().enumerate() {
-        if angle_in_range(angle, start_norm, end_norm) {
-            let extreme_point = extreme_points[i];
-            min_x = min_x.min(extreme_point.x);
-            max_x = max_x.max(extreme_point.x);
-            min_y = min_y.min(extreme_point.y);
-            max_y = max_y.max(extreme_point.y);
+#[cfg(test)]
+mod test_arcline_min_area_obb {
+    use super::*;
+
+    fn rotate(p: Point, angle: f64) -> Point {
+        let (sin, cos) = angle.sin_cos();
+        point(p.x * cos - p.y * sin, p.x * sin + p.y * cos)
+    }
+
+    // 4 x 2 rectangle with corners rounded by 0.5, rotated around the origin
+    fn rotated_rounded_rectangle(angle: f64) -> Arcline {
+        let arcs = [
+            arcseg(point(0.5, 0.0), point(3.5, 0.0)),
+            arc(point(3.5, 0.0), point(4.0, 0.5), point(3.5, 0.5), 0.5),
+            arcseg(point(4.0, 0.5), point(4.0, 1.5)),
+            arc(point(4.0, 1.5), point(3.5, 2.0), point(3.5, 1.5), 0.5),
+            arcseg(point(3.5, 2.0), point(0.5, 2.0)),
+            arc(point(0.5, 2.0), point(0.0, 1.5), point(0.5, 1.5), 0.5),
+            arcseg(point(0.0, 1.5), point(0.0, 0.5)),
+            arc(point(0.0, 0.5), point(0.5, 0.0), point(0.5, 0.5), 0.5),
+        ];
+        arcs.iter()
+            .map(|e| {
+                if e.is_seg() {
+                    arcseg(rotate(e.a, angle), rotate(e.b, angle))
+                } else {
+                    arc(rotate(e.a, angle), rotate(e.b, angle), rotate(e.c, angle), e.r)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_obb_rotated_rounded_rectangle() {
+        let angle = 0.4;
+        let arcs = rotated_rounded_rectangle(angle);
+        let (area, corners) = arcline_min_area_obb(&arcs);
+        assert!((area - 8.0).abs() < 1e-9, "area = {}", area);
+        // Much tighter than the axis aligned box
+        let aabb = arcline_bounding_rect(&arcs);
+        assert!(area < (aabb.p2.x - aabb.p1.x) * (aabb.p2.y - aabb.p1.y) - 1.0);
+        let expected = [point(0.0, 0.0), point(4.0, 0.0), point(4.0, 2.0), point(0.0, 2.0)];
+        for c in corners {
+            assert!(expected.iter().any(|e| c.close_enough(rotate(*e, angle), 1e-9)), "corner {}", c);
         }
+        // CCW corners
+        assert!(pointline_area(&corners.to_vec()) > 0.0);
     }
 
-    Rect::new(point(min_x, min_y), point(max_x, max_y))
+    #[test]
+    fn test_obb_disc() {
+        let c = point(2.0, -1.0);
+        let r = 1.5;
+        let disc = vec![
+            arc(c + point(r, 0.0), c + point(0.0, r), c, r),
+            arc(c + point(0.0, r), c + point(-r, 0.0), c, r),
+            arc(c + point(-r, 0.0), c + point(0.0, -r), c, r),
+            arc(c + point(0.0, -r), c + point(r, 0.0), c, r),
+        ];
+        let (area, corners) = arcline_min_area_obb(&disc);
+        // Any orientation gives the square of side 2r
+        assert!((area - 4.0 * r * r).abs() < 1e-9);
+        for corner in corners {
+            assert!(((corner - c).norm() - r * 2.0_f64.sqrt()).abs() < 1e-9);
+        }
+        assert!(((corners[1] - corners[0]).norm() - 2.0 * r).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_obb_half_disc_flush_with_base() {
+        let half_disc = vec![
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+        ];
+        let (area, _) = arcline_min_area_obb(&half_disc);
+        assert!((area - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_obb_empty() {
+        assert_eq!(arcline_min_area_obb(&vec![]), (0.0, [point(0.0, 0.0); 4]));
+    }
 }
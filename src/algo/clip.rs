@@ -0,0 +1,153 @@
+//! Polygon clipping algorithms.
+//!
+//! This module provides clipping of polygons given as point sequences
+//! against convex windows.
+
+use crate::prelude::*;
+
+/// Clips a polygon by a convex window polygon.
+///
+/// Uses the Sutherland–Hodgman algorithm: the subject is clipped successively
+/// against the half-plane of each window edge. The subject may be concave;
+/// in that case parts of the result that are separated by the window can be
+/// connected by zero-width bridges along the window boundary.
+///
+/// Both polygons are closed implicitly (last point connects back to first).
+/// The window may be oriented CCW or CW.
+///
+/// # Arguments
+///
+/// * `subject` - The polygon to clip
+/// * `window` - The convex clipping polygon
+///
+/// # Returns
+///
+/// The vertices of the clipped polygon, in the order of the subject,
+/// or an empty pointline when the polygons do not overlap
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::clip::pointline_clip_by_convex;
+///
+/// // The overlap of the square and the triangle is the triangle (2, 2), (4, 4), (2, 4)
+/// let subject = vec![point(0.0, 0.0), point(4.0, 0.0), point(4.0, 4.0), point(0.0, 4.0)];
+/// let window = vec![point(2.0, 2.0), point(6.0, 6.0), point(2.0, 6.0)];
+/// let clipped = pointline_clip_by_convex(&subject, &window);
+/// assert_eq!(pointline_area(&clipped), 2.0);
+/// ```
+#[must_use]
+pub fn pointline_clip_by_convex(subject: &Pointline, window: &Pointline) -> Pointline {
+    if subject.len() < 3 || window.len() < 3 {
+        return Vec::new();
+    }
+
+    // Inside of a CCW window is left of its edges
+    let sign = if pointline_area(window) < 0.0 { -1.0 } else { 1.0 };
+
+    let mut output = subject.clone();
+    let n = window.len();
+    for i in 0..n {
+        if output.is_empty() {
+            break;
+        }
+        let w0 = window[i];
+        let w1 = window[(i + 1) % n];
+        if w0 == w1 {
+            continue;
+        }
+
+        let input = std::mem::take(&mut output);
+        let m = input.len();
+        for j in 0..m {
+            let a = input[j];
+            let b = input[(j + 1) % m];
+            let da = sign * points_order(w0, w1, a);
+            let db = sign * points_order(w0, w1, b);
+            if da >= 0.0 {
+                output.push(a);
+                if db < 0.0 && da > 0.0 {
+                    output.push(a + (b - a) * (da / (da - db)));
+                }
+            } else if db > 0.0 {
+                output.push(a + (b - a) * (da / (da - db)));
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test_pointline_clip_by_convex {
+    use super::*;
+
+    // L-shape: 4 x 4 square with the top right 2 x 2 square removed, area 12
+    fn l_shape() -> Pointline {
+        vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 2.0),
+            point(2.0, 2.0),
+            point(2.0, 4.0),
+            point(0.0, 4.0),
+        ]
+    }
+
+    // Square [-1, 3] x [-1, 3] with the corner x + y > 4 cut off
+    fn pentagon() -> Pointline {
+        vec![
+            point(-1.0, -1.0),
+            point(3.0, -1.0),
+            point(3.0, 1.0),
+            point(1.0, 3.0),
+            point(-1.0, 3.0),
+        ]
+    }
+
+    fn inside_convex(p: Point, window: &Pointline) -> bool {
+        let n = window.len();
+        (0..n).all(|i| points_order(window[i], window[(i + 1) % n], p) >= -1e-12)
+    }
+
+    #[test]
+    fn test_clip_concave_by_pentagon() {
+        let window = pentagon();
+        let clipped = pointline_clip_by_convex(&l_shape(), &window);
+        // L-shape inside [0, 3]^2 has area 8, the cut corner removes two triangles of 0.5
+        assert!((pointline_area(&clipped) - 7.0).abs() < 1e-12);
+        assert!(clipped.iter().all(|&p| inside_convex(p, &window)));
+    }
+
+    #[test]
+    fn test_clip_by_cw_window() {
+        let mut window = pentagon();
+        window.reverse();
+        let clipped = pointline_clip_by_convex(&l_shape(), &window);
+        assert!((pointline_area(&clipped) - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_clip_window_contains_subject() {
+        let window = vec![
+            point(-5.0, -5.0),
+            point(10.0, -5.0),
+            point(10.0, 10.0),
+            point(-5.0, 10.0),
+        ];
+        assert_eq!(pointline_clip_by_convex(&l_shape(), &window), l_shape());
+    }
+
+    #[test]
+    fn test_clip_disjoint() {
+        let window = vec![point(10.0, 10.0), point(12.0, 10.0), point(11.0, 12.0)];
+        assert!(pointline_clip_by_convex(&l_shape(), &window).is_empty());
+    }
+
+    #[test]
+    fn test_clip_degenerate_input() {
+        let line = vec![point(0.0, 0.0), point(1.0, 1.0)];
+        assert!(pointline_clip_by_convex(&line, &pentagon()).is_empty());
+        assert!(pointline_clip_by_convex(&l_shape(), &line).is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+//! Collision tests between convex polygons.
+//!
+//! This module provides overlap tests based on the separating axis theorem
+//! for convex polygons given as point sequences.
+
+use crate::prelude::*;
+
+/// Computes the minimum translation vector separating two convex polygons.
+///
+/// Uses the separating axis theorem: two convex polygons are disjoint exactly
+/// when their projections on the normal of one of their edges do not overlap.
+/// When all projections overlap, the axis with the smallest overlap gives the
+/// shortest translation separating the polygons.
+///
+/// Both polygons are closed implicitly and may be oriented CCW or CW. Polygons
+/// that only touch are not overlapping.
+///
+/// # Arguments
+///
+/// * `a` - The polygon to move
+/// * `b` - The other polygon
+///
+/// # Returns
+///
+/// The shortest translation of `a` after which it only touches `b`, or `None`
+/// if the polygons do not overlap or one has fewer than 3 vertices
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let a = vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)];
+/// let b = vec![point(1.5, 0.0), point(4.0, 0.0), point(4.0, 2.0), point(1.5, 2.0)];
+/// // Moving a left by 0.5 separates the squares
+/// assert_eq!(convex_polygons_penetration(&a, &b), Some(point(-0.5, 0.0)));
+/// ```
+#[must_use]
+pub fn convex_polygons_penetration(a: &Pointline, b: &Pointline) -> Option<Point> {
+    if a.len() < 3 || b.len() < 3 {
+        return None;
+    }
+
+    let mut best: Option<(f64, Point)> = None;
+    for poly in [a, b] {
+        let n = poly.len();
+        for i in 0..n {
+            let edge = poly[(i + 1) % n] - poly[i];
+            let (axis, length) = point(-edge.y, edge.x).normalize(false);
+            if length == 0.0 {
+                continue;
+            }
+            let (min_a, max_a) = project(a, axis);
+            let (min_b, max_b) = project(b, axis);
+            let push_back = max_b - min_a; // Moving a along the axis
+            let push_forward = max_a - min_b; // Moving a against the axis
+            let depth = push_back.min(push_forward);
+            if depth <= 0.0 {
+                return None;
+            }
+            if best.is_none_or(|(d, _)| depth < d) {
+                let dir = if push_back < push_forward { axis } else { -axis };
+                best = Some((depth, dir));
+            }
+        }
+    }
+    best.map(|(depth, dir)| dir * depth)
+}
+
+// Interval of the projections of the vertices on the axis
+fn project(poly: &Pointline, axis: Point) -> (f64, f64) {
+    poly.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), p| {
+        let t = p.dot(axis);
+        (min.min(t), max.max(t))
+    })
+}
+
+#[cfg(test)]
+mod test_convex_polygons_penetration {
+    use super::*;
+
+    fn square(min: Point, size: f64) -> Pointline {
+        vec![
+            min,
+            min + point(size, 0.0),
+            min + point(size, size),
+            min + point(0.0, size),
+        ]
+    }
+
+    fn translated(poly: &Pointline, v: Point) -> Pointline {
+        poly.iter().map(|p| *p + v).collect()
+    }
+
+    #[test]
+    fn test_overlapping_squares_axis_aligned_mtv() {
+        let a = square(point(0.0, 0.0), 2.0);
+        // Overlap of 1 along x and 1.5 along y
+        let b = square(point(1.0, 0.5), 2.0);
+        assert_eq!(convex_polygons_penetration(&a, &b), Some(point(-1.0, 0.0)));
+        assert_eq!(convex_polygons_penetration(&b, &a), Some(point(1.0, 0.0)));
+        // Overlap of 0.25 along y from above
+        let c = square(point(0.5, 1.75), 2.0);
+        assert_eq!(convex_polygons_penetration(&a, &c), Some(point(0.0, -0.25)));
+        // After the translation the squares only touch
+        let moved = translated(&a, point(-1.0, 0.0));
+        assert_eq!(convex_polygons_penetration(&moved, &b), None);
+    }
+
+    #[test]
+    fn test_overlapping_triangle_and_square() {
+        // Triangle corner pushed into the square from the upper right
+        let a = square(point(0.0, 0.0), 2.0);
+        let triangle = vec![point(1.5, 1.5), point(4.0, 2.0), point(2.0, 4.0)];
+        let mtv = convex_polygons_penetration(&triangle, &a).unwrap();
+        assert!(mtv.x >= 0.0 && mtv.y >= 0.0);
+        // The corner is 0.5 deep along both x and y, deeper along the triangle normals
+        assert!((mtv.norm() - 0.5).abs() < 1e-12);
+        let moved = translated(&triangle, mtv * (1.0 + 1e-9));
+        assert_eq!(convex_polygons_penetration(&moved, &a), None);
+        // Clockwise orientation gives the same result
+        let cw: Pointline = triangle.iter().rev().copied().collect();
+        assert_eq!(convex_polygons_penetration(&cw, &a), Some(mtv));
+    }
+
+    #[test]
+    fn test_non_overlapping_pairs() {
+        let a = square(point(0.0, 0.0), 2.0);
+        assert_eq!(convex_polygons_penetration(&a, &square(point(5.0, 0.0), 1.0)), None);
+        // Separated only along a diagonal axis
+        let triangle = vec![point(1.5, 2.6), point(2.6, 1.5), point(3.0, 3.0)];
+        assert_eq!(convex_polygons_penetration(&a, &triangle), None);
+        // Touching along an edge
+        assert_eq!(convex_polygons_penetration(&a, &square(point(2.0, 0.0), 2.0)), None);
+        assert_eq!(convex_polygons_penetration(&a, &vec![point(1.0, 1.0), point(3.0, 1.0)]), None);
+    }
+
+    #[test]
+    fn test_contained_polygon() {
+        let a = square(point(0.0, 0.0), 4.0);
+        let inner = square(point(0.5, 1.0), 1.0);
+        // Closest exit is through the left side of the outer square
+        assert_eq!(convex_polygons_penetration(&inner, &a), Some(point(-1.5, 0.0)));
+    }
+}
@@ -0,0 +1,479 @@
+//! Point containment tests for closed regions.
+//!
+//! This module provides inside/outside classification of points against
+//! regions bounded by arclines and polygons.
+
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+use robust::{Coord, orient2d};
+
+/// Clearance kept between a containment ray and element ends or tangent arcs.
+const RAY_CLEARANCE: f64 = 1e-8;
+
+/// Checks if a point lies inside the region enclosed by an arcline.
+///
+/// Casts a ray from the point and counts its crossings with the boundary: the
+/// point is inside when the count is odd. Segments are crossed at most once;
+/// arcs are intersected with the ray using [`int_line_arc`], which counts only
+/// the crossings within the arc span, so a ray passing through the bulge of an
+/// arc crosses it twice. The ray direction is chosen to keep clear of element
+/// ends and of circles it would only touch, where a crossing could be counted
+/// twice or not at all.
+///
+/// Points on the boundary (within `GEOMETRIC_EPSILON`) are treated as inside.
+///
+/// The arcline is assumed to be closed (last point connects back to first) and,
+/// as usual, to go CCW around its interior, with arcs bulging into the
+/// interior stored reversed. The crossing parity does not depend on the
+/// orientation, so a CW arcline gives the same result.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline defining the boundary of the region
+/// * `p` - The point to test
+///
+/// # Returns
+///
+/// `true` if the point is inside or on the boundary, `false` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::contains::arcline_contains;
+///
+/// let half_disc = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+/// ];
+/// assert!(arcline_contains(&half_disc, point(0.0, 0.5)));
+/// assert!(!arcline_contains(&half_disc, point(0.0, -0.5)));
+/// ```
+#[must_use]
+pub fn arcline_contains(arcs: &Arcline, p: Point) -> bool {
+    if arcs.is_empty() {
+        return false;
+    }
+
+    // Boundary points are inside
+    for arc in arcs {
+        let dist = if arc.is_seg() {
+            dist_point_segment(&p, &segment(arc.a, arc.b)).0
+        } else {
+            dist_point_arc_dist(&p, arc)
+        };
+        if dist <= GEOMETRIC_EPSILON {
+            return true;
+        }
+    }
+
+    // Rotate the ray by an irrational step until it misses all element ends and
+    // tangent points; the last candidate is used if none does
+    let mut carrier = line(p, point(1.0, 0.0));
+    for k in 0..16 {
+        let angle = k as f64 * 2.399_963_229_728_653;
+        carrier = line(p, point(angle.cos(), angle.sin()));
+        if arcs.iter().all(|element| ray_clears(&carrier, element)) {
+            break;
+        }
+    }
+
+    let crossings: usize = arcs.iter().map(|element| ray_crossings(&carrier, element)).sum();
+    crossings % 2 == 1
+}
+
+// Whether the ray along `carrier` keeps clear of the ends of the element and,
+// for an arc, does not touch its circle
+fn ray_clears(carrier: &Line, element: &Arc) -> bool {
+    let off_ray = |q: Point| {
+        let w = q - carrier.origin;
+        w.dot(carrier.dir) < 0.0 || carrier.dir.perp(w).abs() > RAY_CLEARANCE
+    };
+    if !off_ray(element.a) || !off_ray(element.b) {
+        return false;
+    }
+    element.is_seg() || (carrier.dir.perp(element.c - carrier.origin).abs() - element.r).abs() > RAY_CLEARANCE
+}
+
+#[cfg(test)]
+mod test_arcline_contains {
+    use super::*;
+
+    fn disc() -> Arcline {
+        vec![
+            arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+            arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arc(point(-1.0, 0.0), point(0.0, -1.0), point(0.0, 0.0), 1.0),
+            arc(point(0.0, -1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+        ]
+    }
+
+    #[test]
+    fn test_arcline_contains_empty() {
+        let empty: Arcline = vec![];
+        assert!(!arcline_contains(&empty, point(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_arcline_contains_disc() {
+        let arcs = disc();
+        assert!(arcline_contains(&arcs, point(0.0, 0.0)));
+        assert!(arcline_contains(&arcs, point(0.7, 0.7)));
+        assert!(!arcline_contains(&arcs, point(0.8, 0.8)));
+        assert!(!arcline_contains(&arcs, point(5.0, 0.0)));
+    }
+
+    #[test]
+    fn test_arcline_contains_boundary() {
+        let arcs = disc();
+        assert!(arcline_contains(&arcs, point(1.0, 0.0)));
+        assert!(arcline_contains(&arcs, point(0.0, -1.0)));
+    }
+
+    #[test]
+    fn test_arcline_contains_full_circle() {
+        let arcs = vec![arc(point(2.0, 0.0), point(2.0, 0.0), point(0.0, 0.0), 2.0)];
+        assert!(arcline_contains(&arcs, point(0.0, 1.5)));
+        assert!(!arcline_contains(&arcs, point(0.0, 2.5)));
+    }
+
+    #[test]
+    fn test_arcline_contains_concave_arc() {
+        // Square with the top edge replaced by an inward-bulging arc
+        let concave = arc_from_bulge(point(2.0, 2.0), point(0.0, 2.0), -0.5);
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            concave,
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert!(arcline_contains(&arcs, point(1.0, 1.0)));
+        // Inside the square but inside the bite of the concave arc
+        assert!(!arcline_contains(&arcs, point(1.0, 1.9)));
+        assert!(arcline_contains(&arcs, point(0.05, 1.5)));
+    }
+
+    // 4 x 2 rectangle with corners rounded by radius 0.5
+    fn rounded_rectangle() -> Arcline {
+        vec![
+            arcseg(point(0.5, 0.0), point(3.5, 0.0)),
+            arc(point(3.5, 0.0), point(4.0, 0.5), point(3.5, 0.5), 0.5),
+            arcseg(point(4.0, 0.5), point(4.0, 1.5)),
+            arc(point(4.0, 1.5), point(3.5, 2.0), point(3.5, 1.5), 0.5),
+            arcseg(point(3.5, 2.0), point(0.5, 2.0)),
+            arc(point(0.5, 2.0), point(0.0, 1.5), point(0.5, 1.5), 0.5),
+            arcseg(point(0.0, 1.5), point(0.0, 0.5)),
+            arc(point(0.0, 0.5), point(0.5, 0.0), point(0.5, 0.5), 0.5),
+        ]
+    }
+
+    #[test]
+    fn test_arcline_contains_rounded_rectangle() {
+        let arcs = rounded_rectangle();
+        assert!(arcline_contains(&arcs, point(2.0, 1.0)));
+        assert!(arcline_contains(&arcs, point(3.8, 1.0)));
+        // Inside the rounded corners, and in the cut off tips of the rectangle
+        assert!(arcline_contains(&arcs, point(0.2, 0.2)));
+        assert!(arcline_contains(&arcs, point(3.8, 1.8)));
+        assert!(!arcline_contains(&arcs, point(0.05, 0.05)));
+        assert!(!arcline_contains(&arcs, point(3.95, 1.95)));
+        assert!(!arcline_contains(&arcs, point(4.1, 1.0)));
+        // On a corner arc
+        let s = 0.5_f64.sqrt() * 0.5;
+        assert!(arcline_contains(&arcs, point(3.5 + s, 1.5 + s)));
+    }
+
+    #[test]
+    fn test_arcline_contains_ray_through_ends() {
+        // A ray along +x from the center of the diamond would pass through a vertex
+        let diamond = vec![
+            arcseg(point(1.0, 0.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(-1.0, 0.0)),
+            arcseg(point(-1.0, 0.0), point(0.0, -1.0)),
+            arcseg(point(0.0, -1.0), point(1.0, 0.0)),
+        ];
+        assert!(arcline_contains(&diamond, point(0.0, 0.0)));
+        assert!(!arcline_contains(&diamond, point(-2.0, 0.0)));
+        // A ray along +x from these points would touch the top and bottom of the disc
+        assert!(!arcline_contains(&disc(), point(-3.0, 1.0)));
+        assert!(!arcline_contains(&disc(), point(-3.0, -1.0)));
+    }
+
+    #[test]
+    fn test_arcline_contains_rounded_rectangle_clockwise() {
+        // Segments reversed, the corner arcs are now traversed from b to a
+        let cw: Arcline = rounded_rectangle()
+            .iter()
+            .rev()
+            .map(|e| if e.is_seg() { arcseg(e.b, e.a) } else { *e })
+            .collect();
+        assert!(arcline_contains(&cw, point(2.0, 1.0)));
+        assert!(arcline_contains(&cw, point(0.2, 0.2)));
+        assert!(!arcline_contains(&cw, point(0.05, 0.05)));
+        assert!(!arcline_contains(&cw, point(-1.0, 1.0)));
+    }
+}
+
+/// Counts how many times a ray crosses each element of an arcline.
+///
+/// This is a debugging aid for containment: the parity of the total count is
+/// the even-odd classification of the ray origin, which should agree with
+/// [`arcline_contains`] away from the boundary. Comparing the per element
+/// counts shows which element causes a misclassification, e.g. a ray passing
+/// through the bulge of an arc, which crosses it twice.
+///
+/// Only intersections at or beyond the ray origin are counted. A segment is
+/// crossed at most once, collinear overlaps are not counted. An arc is crossed
+/// at most twice, a tangent touch is counted as one crossing.
+///
+/// # Arguments
+///
+/// * `ray` - The ray to cast
+/// * `arcs` - The arcline to test against
+///
+/// # Returns
+///
+/// The number of crossings of each element, in arcline order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::contains::ray_arcline_crossing_counts;
+///
+/// let half_disc = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+/// ];
+/// let counts = ray_arcline_crossing_counts(&ray(point(0.0, 0.5), point(0.0, -1.0)), &half_disc);
+/// assert_eq!(counts, vec![0, 1]);
+/// ```
+#[must_use]
+pub fn ray_arcline_crossing_counts(ray: &Ray, arcs: &Arcline) -> Vec<usize> {
+    let carrier = line(ray.origin, ray.dir).unitdir();
+    arcs.iter()
+        .map(|element| ray_crossings(&carrier, element))
+        .collect()
+}
+
+// Number of crossings of the ray along the unit direction of `carrier` with an element
+fn ray_crossings(carrier: &Line, element: &Arc) -> usize {
+    if element.is_seg() {
+        return usize::from(ray_crosses_segment(carrier, element.a, element.b));
+    }
+    match int_line_arc(carrier, element) {
+        LineArcConfig::NoIntersection() => 0,
+        LineArcConfig::OnePoint(_, t) => usize::from(t >= 0.0),
+        LineArcConfig::TwoPoints(_, _, t0, t1) => usize::from(t0 >= 0.0) + usize::from(t1 >= 0.0),
+    }
+}
+
+// Whether the ray along the unit direction of `carrier` crosses the segment
+fn ray_crosses_segment(carrier: &Line, a: Point, b: Point) -> bool {
+    let e = b - a;
+    let denom = carrier.dir.perp(e);
+    if denom.abs() < GEOMETRIC_EPSILON * e.norm() {
+        return false;
+    }
+    let w = a - carrier.origin;
+    let t = w.perp(e) / denom;
+    let u = w.perp(carrier.dir) / denom;
+    t >= 0.0 && (0.0..=1.0).contains(&u)
+}
+
+#[cfg(test)]
+mod test_ray_arcline_crossing_counts {
+    use super::*;
+
+    fn half_disc() -> Arcline {
+        vec![
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_crossing_counts_clean_crossing() {
+        let arcs = half_disc();
+        // From inside, leaving through the arc or through the base
+        assert_eq!(ray_arcline_crossing_counts(&ray(point(0.0, 0.5), point(1.0, 0.0)), &arcs), vec![1, 0]);
+        assert_eq!(ray_arcline_crossing_counts(&ray(point(0.0, 0.5), point(0.0, -3.0)), &arcs), vec![0, 1]);
+        assert!(arcline_contains(&arcs, point(0.0, 0.5)));
+    }
+
+    #[test]
+    fn test_crossing_counts_through_arc_bulge() {
+        let arcs = half_disc();
+        // From outside, entering and leaving through the same arc
+        let counts = ray_arcline_crossing_counts(&ray(point(-3.0, 0.9), point(1.0, 0.0)), &arcs);
+        assert_eq!(counts, vec![2, 0]);
+        assert_eq!(counts.iter().sum::<usize>() % 2, 0);
+        assert!(!arcline_contains(&arcs, point(-3.0, 0.9)));
+        // Through the arc and the base
+        let counts = ray_arcline_crossing_counts(&ray(point(-2.0, 2.0), point(2.0, -3.0)), &arcs);
+        assert_eq!(counts, vec![1, 1]);
+    }
+
+    #[test]
+    fn test_crossing_counts_behind_origin_and_tangent() {
+        let arcs = half_disc();
+        assert_eq!(ray_arcline_crossing_counts(&ray(point(3.0, 0.9), point(1.0, 0.0)), &arcs), vec![0, 0]);
+        // Touching the top of the arc
+        assert_eq!(ray_arcline_crossing_counts(&ray(point(-3.0, 1.0), point(1.0, 0.0)), &arcs), vec![1, 0]);
+        // Along the base, collinear with the segment
+        assert_eq!(ray_arcline_crossing_counts(&ray(point(-3.0, 0.0), point(1.0, 0.0)), &arcs)[1], 0);
+    }
+
+    #[test]
+    fn test_crossing_counts_empty() {
+        assert!(ray_arcline_crossing_counts(&ray(point(0.0, 0.0), point(1.0, 0.0)), &vec![]).is_empty());
+    }
+}
+
+/// Checks if a point lies inside a polygon.
+///
+/// Uses the winding number of the polygon around the point: an edge crossing
+/// the horizontal line through the point upward with the point on its left, or
+/// downward with the point on its right, changes the winding by one. The side
+/// of the point is decided with the exact `orient2d` predicate, so the result
+/// is not affected by rounding.
+///
+/// Points exactly on an edge or at a vertex are treated as inside. Since the
+/// winding number is used, regions covered twice by a self-intersecting polygon
+/// are inside as well; the orientation of the polygon does not matter.
+///
+/// # Arguments
+///
+/// * `poly` - The polygon vertices in order, implicitly closed
+/// * `p` - The point to test
+///
+/// # Returns
+///
+/// `true` if the point is inside or on the boundary, `false` otherwise or for
+/// fewer than 3 vertices
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)];
+/// assert!(pointline_contains(&square, point(1.0, 1.0)));
+/// assert!(pointline_contains(&square, point(2.0, 1.0))); // On an edge
+/// assert!(!pointline_contains(&square, point(3.0, 1.0)));
+/// ```
+#[must_use]
+pub fn pointline_contains(poly: &Pointline, p: Point) -> bool {
+    let n = poly.len();
+    if n < 3 {
+        return false;
+    }
+
+    let pc = Coord { x: p.x, y: p.y };
+    let mut winding = 0i32;
+    for i in 0..n {
+        let a = poly[i];
+        let b = poly[(i + 1) % n];
+        let side = orient2d(Coord { x: a.x, y: a.y }, Coord { x: b.x, y: b.y }, pc);
+        // Boundary points are inside
+        if side == 0.0
+            && p.x >= a.x.min(b.x)
+            && p.x <= a.x.max(b.x)
+            && p.y >= a.y.min(b.y)
+            && p.y <= a.y.max(b.y)
+        {
+            return true;
+        }
+        if a.y <= p.y {
+            if b.y > p.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding != 0
+}
+
+#[cfg(test)]
+mod test_pointline_contains {
+    use super::*;
+
+    fn square() -> Pointline {
+        vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)]
+    }
+
+    #[test]
+    fn test_pointline_contains_square() {
+        let sq = square();
+        assert!(pointline_contains(&sq, point(1.0, 1.0)));
+        assert!(pointline_contains(&sq, point(0.1, 1.9)));
+        assert!(!pointline_contains(&sq, point(-0.1, 1.0)));
+        assert!(!pointline_contains(&sq, point(1.0, 2.5)));
+        // Clockwise orientation gives the same result
+        let cw: Pointline = sq.iter().rev().copied().collect();
+        assert!(pointline_contains(&cw, point(1.0, 1.0)));
+        assert!(!pointline_contains(&cw, point(3.0, 1.0)));
+    }
+
+    #[test]
+    fn test_pointline_contains_concave() {
+        // U shape opened at the top
+        let u_shape = vec![
+            point(0.0, 0.0),
+            point(3.0, 0.0),
+            point(3.0, 3.0),
+            point(2.0, 3.0),
+            point(2.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 3.0),
+            point(0.0, 3.0),
+        ];
+        assert!(pointline_contains(&u_shape, point(0.5, 2.0)));
+        assert!(pointline_contains(&u_shape, point(2.5, 2.0)));
+        assert!(pointline_contains(&u_shape, point(1.5, 0.5)));
+        // In the notch, the horizontal line passes through two reflex vertices
+        assert!(!pointline_contains(&u_shape, point(1.5, 2.0)));
+        assert!(!pointline_contains(&u_shape, point(1.5, 3.0)));
+        // Level with the reflex vertices, left of the polygon
+        assert!(!pointline_contains(&u_shape, point(-1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_pointline_contains_on_edge() {
+        let sq = square();
+        assert!(pointline_contains(&sq, point(1.0, 0.0)));
+        assert!(pointline_contains(&sq, point(0.0, 1.5)));
+        // On the line of an edge, beyond its end
+        assert!(!pointline_contains(&sq, point(3.0, 0.0)));
+        // On a diagonal edge, and just off it
+        let triangle = vec![point(0.0, 0.0), point(4.0, 0.0), point(0.0, 4.0)];
+        assert!(pointline_contains(&triangle, point(1.0, 3.0)));
+        assert!(!pointline_contains(&triangle, point(1.0, 3.0 + 1e-15)));
+    }
+
+    #[test]
+    fn test_pointline_contains_on_vertex() {
+        let sq = square();
+        for v in &sq {
+            assert!(pointline_contains(&sq, *v));
+        }
+        let u_reflex = vec![
+            point(0.0, 0.0),
+            point(3.0, 0.0),
+            point(3.0, 3.0),
+            point(2.0, 3.0),
+            point(2.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 3.0),
+            point(0.0, 3.0),
+        ];
+        assert!(pointline_contains(&u_reflex, point(2.0, 1.0)));
+    }
+
+    #[test]
+    fn test_pointline_contains_degenerate() {
+        assert!(!pointline_contains(&vec![], point(0.0, 0.0)));
+        assert!(!pointline_contains(&vec![point(0.0, 0.0), point(1.0, 0.0)], point(0.5, 0.0)));
+    }
+}
@@ -300,6 +300,110 @@ pub fn points_convex_hull(points: &Pointline) -> Pointline {
     hull
 }
 
+/// Computes the convex hull of a set of points, starting at a given input point.
+///
+/// Same as [`points_convex_hull`], but the returned hull begins at `points[start]`
+/// and proceeds counter-clockwise. This makes the output deterministic across
+/// related datasets where the input order differs.
+///
+/// If `points[start]` is not a hull vertex (interior, collinear on a hull edge,
+/// non-finite, or `start` is out of range), the hull starts at the extreme point
+/// used by [`points_convex_hull`] (leftmost, then bottommost).
+///
+/// # Arguments
+///
+/// * `points` - A slice of points for which to compute the convex hull
+/// * `start` - Index into `points` of the preferred first hull vertex
+///
+/// # Returns
+///
+/// A `Pointline` representing the vertices of the convex hull in counter-clockwise order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::convex_hull::points_convex_hull_from;
+///
+/// let points = vec![
+///     point(0.0, 0.0),
+///     point(1.0, 0.0),
+///     point(1.0, 1.0),
+///     point(0.0, 1.0),
+///     point(0.5, 0.5), // Interior point
+/// ];
+/// let hull = points_convex_hull_from(&points, 2);
+/// assert_eq!(hull[0], point(1.0, 1.0));
+/// assert_eq!(hull.len(), 4);
+/// ```
+#[must_use]
+pub fn points_convex_hull_from(points: &Pointline, start: usize) -> Pointline {
+    let mut hull = points_convex_hull(points);
+    if let Some(p) = points.get(start)
+        && let Some(idx) = hull.iter().position(|h| h == p)
+    {
+        hull.rotate_left(idx);
+    }
+    hull
+}
+
+/// Computes the convex hull of a set of points together with its perimeter and area.
+///
+/// The perimeter and the area are accumulated in a single pass over the hull
+/// edges, avoiding a separate walk for each measure.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points for which to compute the convex hull
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The hull vertices in counter-clockwise order, as from [`points_convex_hull`]
+/// * The hull perimeter
+/// * The hull area (positive)
+///
+/// Returns `None` when the hull has fewer than 3 vertices, i.e. for empty input,
+/// a single point or collinear points.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let points = vec![
+///     point(0.0, 0.0),
+///     point(2.0, 0.0),
+///     point(2.0, 1.0),
+///     point(0.0, 1.0),
+///     point(1.0, 0.5), // Interior point
+/// ];
+/// let (hull, perimeter, area) = points_convex_hull_metrics(&points).unwrap();
+/// assert_eq!(hull.len(), 4);
+/// assert_eq!(perimeter, 6.0);
+/// assert_eq!(area, 2.0);
+///
+/// // Collinear points have no area
+/// assert!(points_convex_hull_metrics(&vec![point(0.0, 0.0), point(1.0, 1.0), point(2.0, 2.0)]).is_none());
+/// ```
+#[must_use]
+pub fn points_convex_hull_metrics(points: &Pointline) -> Option<(Pointline, f64, f64)> {
+    let hull = points_convex_hull(points);
+    let n = hull.len();
+    if n < 3 {
+        return None;
+    }
+    let mut perimeter = 0.0;
+    let mut area = 0.0;
+    for i in 0..n {
+        let p = hull[i];
+        let q = hull[(i + 1) % n];
+        perimeter += (q - p).norm();
+        area += p.x * q.y - q.x * p.y;
+    }
+    Some((hull, perimeter, area / 2.0))
+}
+
 #[cfg(test)]
 mod test_akl_toussaint_filter {
     use super::*;
@@ -732,6 +836,113 @@ mod test_points_convex_hull {
     }
 }
 
+#[cfg(test)]
+mod test_points_convex_hull_from {
+    use super::*;
+
+    fn square_with_interior() -> Pointline {
+        vec![
+            point(0.0, 0.0),
+            point(2.0, 0.0),
+            point(2.0, 2.0),
+            point(0.0, 2.0),
+            point(1.0, 1.0), // interior
+            point(1.0, 0.0), // collinear on edge
+        ]
+    }
+
+    #[test]
+    fn test_points_convex_hull_from_empty() {
+        let points: Pointline = vec![];
+        assert!(points_convex_hull_from(&points, 0).is_empty());
+    }
+
+    #[test]
+    fn test_points_convex_hull_from_start_vertex() {
+        let points = square_with_interior();
+        for start in 0..4 {
+            let hull = points_convex_hull_from(&points, start);
+            assert_eq!(hull.len(), 4);
+            assert_eq!(hull[0], points[start]);
+            assert!(pointline_area(&hull) > 0.0, "hull should be CCW");
+        }
+    }
+
+    #[test]
+    fn test_points_convex_hull_from_snaps_to_extreme() {
+        let points = square_with_interior();
+        let expected = points_convex_hull(&points);
+        // Interior point, collinear edge point and out of range index
+        for start in [4, 5, 100] {
+            let hull = points_convex_hull_from(&points, start);
+            assert_eq!(hull, expected);
+            assert_eq!(hull[0], point(0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_points_convex_hull_from_rotated_input() {
+        // Same point set with rotated order, starting from the same point
+        let points = square_with_interior();
+        let mut rotated = points.clone();
+        rotated.rotate_left(3);
+        let target = point(2.0, 2.0);
+        let i0 = points.iter().position(|p| *p == target).unwrap();
+        let i1 = rotated.iter().position(|p| *p == target).unwrap();
+        let hull0 = points_convex_hull_from(&points, i0);
+        let hull1 = points_convex_hull_from(&rotated, i1);
+        assert_eq!(hull0, hull1);
+        assert_eq!(hull0[0], target);
+    }
+}
+
+#[cfg(test)]
+mod test_points_convex_hull_metrics {
+    use super::*;
+
+    fn perimeter(hull: &Pointline) -> f64 {
+        let n = hull.len();
+        (0..n).map(|i| (hull[(i + 1) % n] - hull[i]).norm()).sum()
+    }
+
+    #[test]
+    fn test_metrics_match_separate_calls() {
+        // Points on a wobbly spiral, many of them interior
+        let points: Pointline = (0..200)
+            .map(|k| {
+                let t = k as f64 * 0.37;
+                let r = 1.0 + 0.01 * k as f64 + 0.3 * (3.0 * t).sin();
+                point(r * t.cos(), r * t.sin())
+            })
+            .collect();
+        let (hull, perim, area) = points_convex_hull_metrics(&points).unwrap();
+        let expected = points_convex_hull(&points);
+        assert_eq!(hull, expected);
+        assert!((area - pointline_area(&expected)).abs() < 1e-12);
+        assert!((perim - perimeter(&expected)).abs() < 1e-12);
+        assert!(area > 0.0);
+    }
+
+    #[test]
+    fn test_metrics_triangle() {
+        let points = vec![point(0.0, 0.0), point(3.0, 0.0), point(0.0, 4.0), point(1.0, 1.0)];
+        let (hull, perim, area) = points_convex_hull_metrics(&points).unwrap();
+        assert_eq!(hull.len(), 3);
+        assert_eq!(perim, 12.0);
+        assert_eq!(area, 6.0);
+        assert_eq!(area, pointline_area(&points_convex_hull(&points)));
+    }
+
+    #[test]
+    fn test_metrics_degenerate() {
+        assert!(points_convex_hull_metrics(&vec![]).is_none());
+        assert!(points_convex_hull_metrics(&vec![point(1.0, 2.0)]).is_none());
+        assert!(points_convex_hull_metrics(&vec![point(0.0, 0.0), point(1.0, 0.0)]).is_none());
+        let collinear = vec![point(0.0, 0.0), point(1.0, 0.0), point(2.0, 0.0), point(3.0, 0.0)];
+        assert!(points_convex_hull_metrics(&collinear).is_none());
+    }
+}
+
 #[cfg(test)]
 mod test_pointline_convex_hull {
     use super::*;
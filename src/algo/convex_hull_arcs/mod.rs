@@ -3,8 +3,12 @@
 //! This module implements convex hull computation for polygons defined by arcs and line segments,
 //! preserving the arc structure where possible.
 
+#![allow(dead_code)]
+
 use crate::intersection::tangent::{external_tangents_between_circles, tangent_point_to_circle};
+use crate::constants::GEOMETRIC_EPSILON;
 use crate::prelude::*;
+use core::f64::consts::{FRAC_PI_2, TAU};
 
 #[cfg(test)]
 mod tests;
@@ -48,185 +52,21 @@ fn find_tangent_point_to_circle(from: Point, c: Circle) -> Option<Point> {
 fn find_tangent_circle_to_circle(c1: Circle, c2: Circle) -> Option<(Point, Point)> {
     match external_tangents_between_circles(c1, c2) {
         Some((t1_c1, t1_c2, t2_c1, t2_c2)) => {
-            // find the right side points
+            // find the right side points, right of the direction from c1 to c2
             let orient1 = (c2.c - c1.c).perp(t1_c1 - c1.c);
-            let rc1 = if orient1 > 0.0 { t1_c1 } else { t2_c1 };
+            let rc1 = if orient1 < 0.0 { t1_c1 } else { t2_c1 };
             let orient2 = (c2.c - c1.c).perp(t1_c2 - c2.c);
-            let rc2 = if orient2 > 0.0 { t1_c2 } else { t2_c2 };
+            let rc2 = if orient2 < 0.0 { t1_c2 } else { t2_c2 };
             Some((rc1, rc2))
         }
         None => None,
     }
 }
 
-/// Compare two connections and return true if conn1 is "better" (more to the right) than conn2
-/// 
-/// For convex hull, the rightmost connection is determined by:
-/// 1. Connection that starts closer to current_arc.a (beginning of current arc)
-/// 2. Among those, the one that ends further along the target arc (closer to its end)
-/// 
-/// This ensures we wrap tightly around the polygon for the convex hull.
-fn is_better_connection(conn1: &[Arc], conn2: &[Arc], current_arc: Arc) -> bool {
-    if conn1.is_empty() {
-        return false;
-    }
-    if conn2.is_empty() {
-        return true;
-    }
-    
-    // For gift-wrapping, we want the connection that turns most to the right
-    // from the current arc's direction. This means the smallest left turn.
-    
-    // Get the direction vector of current_arc
-    let current_dir = current_arc.b - current_arc.a;
-    
-    // Get the direction vector of each connection's first segment
-    // This represents the direction we'd be heading after leaving current_arc
-    let conn1_dir = conn1[0].b - conn1[0].a;
-    let conn2_dir = conn2[0].b - conn2[0].a;
-    
-    let epsilon = 1e-9;
-    
-    if current_dir.norm() < epsilon || conn1_dir.norm() < epsilon || conn2_dir.norm() < epsilon {
-        // Degenerate case
-        return conn1_dir.norm() > conn2_dir.norm();
-    }
-    
-    // Cross product: current_dir × conn_dir
-    // Negative cross means clockwise turn (right turn), positive means CCW turn (left turn)
-    // We want the MOST NEGATIVE (most right turn) or LEAST POSITIVE (least left turn)
-    let cross1 = current_dir.perp(conn1_dir);
-    let cross2 = current_dir.perp(conn2_dir);
-    
-    // Prefer the connection with smaller cross product (more right / less left)
-    cross1 < cross2
-}
-
-/// Returns true if dir1 is more to the right (more clockwise) than dir2
-/// when both directions start from the same point.
-/// 
-/// Uses cross product to determine relative orientation:
-/// - cross < 0: dir1 is clockwise from dir2 (dir1 is to the right)
-/// - cross > 0: dir1 is counter-clockwise from dir2 (dir1 is to the left)
-fn is_better_direction(dir1: Point, dir2: Point) -> bool {
-    let cross = dir1.perp(dir2);
-    
-    if cross.abs() < 1e-9 {
-        // Directions are collinear, prefer the one that reaches further
-        return dir1.norm() > dir2.norm();
-    }
-    
-    // dir1 is better if it's more clockwise (to the right), i.e., cross < 0
-    cross < 0.0
-}
-
-#[must_use]
-fn new_convex_hull(arcs: &Arcline) -> Arcline {
-    let mut segs = arcs.clone();
-    
-    if arcs.is_empty() {
-        return Arcline::new();
-    }
-
-    let n = arcs.len();
-    let mut convex = Vec::with_capacity(n);
-    convex.resize(n, false);
-    
-    // Convert concave to properly oriented arcseg
-    for i in 0..n {
-        if arcs[i].is_seg() {
-            convex[i] = false;
-            continue;
-        }
-        if is_arc_convex(arcs, i) {
-            convex[i] = true;
-        } else {
-            segs[i] = arcseg(segs[i].b, segs[i].a);
-            convex[i] = false;
-        }
-    }
-    
-    let start_idx = find_starting_arc(&segs);
-    let mut hull = Arcline::new();
-    let mut current_idx = start_idx;
-    let mut iterations = 0;
-    
-    // Gift wrapping: from current arc, find best connection to any other arc
-    loop {
-        let current_arc = segs[current_idx];
-        
-        let mut best_connection: Option<Vec<Arc>> = None;
-        let mut best_next_idx: Option<usize> = None;
-        
-        // Try connecting to all other arcs (including wrapping back to start)
-        for i in 1..n {
-            let next_idx = (current_idx + i) % n;
-            
-            let next_arc = segs[next_idx];
-            
-            // Compute hull connection based on types
-            let connection = if current_arc.is_seg() && next_arc.is_seg() {
-                vec![hull_seg_seg(current_arc, next_arc)]
-            } else if current_arc.is_seg() && !next_arc.is_seg() {
-                hull_seg_arc(current_arc, next_arc)
-            } else if !current_arc.is_seg() && next_arc.is_seg() {
-                hull_arc_seg(current_arc, next_arc)
-            } else {
-                hull_arc_arc(current_arc, next_arc)
-            };
-            
-            // Compare with current best
-            if best_connection.is_none() || is_better_connection(&connection, best_connection.as_ref().unwrap(), current_arc) {
-                best_connection = Some(connection);
-                best_next_idx = Some(next_idx);
-            }
-        }
-        
-        // Also check connection back to self (for wrapping, e.g., large arc containing everything)
-        let self_connection = if current_arc.is_seg() {
-            vec![hull_seg_seg(current_arc, current_arc)]
-        } else {
-            hull_arc_arc(current_arc, current_arc)
-        };
-        
-        if best_connection.is_none() || is_better_connection(&self_connection, best_connection.as_ref().unwrap(), current_arc) {
-            best_connection = Some(self_connection);
-            best_next_idx = Some(current_idx);
-        }
-        
-        // Add the best connection to hull
-        if let Some(connection) = best_connection {
-            for arc in connection {
-                hull.push(arc);
-            }
-        }
-        
-        // Move to next arc
-        if let Some(next_idx) = best_next_idx {
-            current_idx = next_idx;
-            iterations += 1;
-            
-            // Check if we've completed the loop back to start
-            if current_idx == start_idx && iterations > 1 {
-                // We've wrapped back to start after processing at least one arc
-                break;
-            }
-            
-            // Safety check to prevent infinite loops
-            if iterations > n {
-                break;
-            }
-        } else {
-            break; // No valid connection found
-        }
-    }
-    
-    hull
-}
-
 fn hull_seg_seg(seg1: Arc, seg2: Arc) -> Arc {
-    // If segments are already connected (seg1.b == seg2.a), return seg1
-    if seg1.b == seg2.a {
+    // If segments are already connected (seg1.b == seg2.a) and seg2 does not turn
+    // to the left of seg1, seg1 itself is the connection
+    if seg1.b == seg2.a && (seg1.b - seg1.a).perp(seg2.b - seg1.a) <= 1e-9 {
         return seg1;
     }
     
@@ -303,8 +143,9 @@ fn hull_arc_arc(arc1: Arc, arc2: Arc) -> Vec<Arc> {
     let c1 = Circle { c: arc1.c, r: arc1.r };
     let c2 = Circle { c: arc2.c, r: arc2.r };
     
-    // Find the external tangent between the two circles (right-side tangent)
-    let tangent_opt = find_tangent_circle_to_circle(c1, c2);
+    // Find the external tangent between the two circles on the left of c1 -> c2,
+    // which is the right-side tangent looking from c2 towards c1
+    let tangent_opt = find_tangent_circle_to_circle(c2, c1).map(|(t2, t1)| (t1, t2));
     
     if tangent_opt.is_none() {
         // No external tangent exists (concentric or nested circles)
@@ -660,17 +501,13 @@ fn hull_arc_seg(arc1: Arc, seg2: Arc) -> Vec<Arc> {
 
 /// Computes the convex hull of an arcline (arc-based polygon).
 ///
-/// This function computes the convex hull while attempting to preserve arc segments
-/// where they lie on the convex boundary. Concave arcs are replaced with line segments
-/// or tangent connections.
-///
-/// # Algorithm Overview
-///
-/// 1. **Extract all candidate points**: Arc endpoints + cardinal extrema for curved arcs
-/// 2. **Compute point-based hull**: Use gift wrapping on all candidates
-/// 3. **Convert to arcline**: Create line segments connecting hull vertices
-///
-/// Future enhancements will preserve original arc segments where they lie on the hull.
+/// The hull is the one of [`arclines_convex_hull`] for the single arcline: a
+/// supporting line is rotated around all elements. Curved arcs, or the parts of
+/// them, lying on the hull are kept as arcs, with their original center and
+/// radius, and are connected by tangent or bridging segments. A convex arc
+/// whose whole span is on the hull is kept intact rather than replaced by its
+/// chord, and arcs sharing a circle stay separate elements. Concave arcs only
+/// contribute their end points.
 ///
 /// # Arguments
 ///
@@ -678,13 +515,13 @@ fn hull_arc_seg(arc1: Arc, seg2: Arc) -> Vec<Arc> {
 ///
 /// # Returns
 ///
-/// A new `Arcline` representing the convex hull
+/// A new `Arcline` representing the convex hull, closed and CCW. Empty when the
+/// hull has no area, for example for an empty input or a single segment.
 ///
 /// # Examples
 ///
-/// ```ignore
+/// ```
 /// use togo::prelude::*;
-/// use togo::algo::convex_hull_arcs::arcline_convex_hull;
 ///
 /// // Circle made of 4 quarter-circle arcs
 /// let arcs = vec![
@@ -695,8 +532,450 @@ fn hull_arc_seg(arc1: Arc, seg2: Arc) -> Vec<Arc> {
 /// ];
 /// let hull = arcline_convex_hull(&arcs);
 /// assert_eq!(hull.len(), 4); // All arcs are convex
+/// assert!(hull.iter().all(|a| a.is_arc()));
 /// ```
 #[must_use]
 pub fn arcline_convex_hull(arcs: &Arcline) -> Arcline {
-    new_convex_hull(arcs)
+    single_hull(arcs, None)
+}
+
+/// Computes [`arcline_convex_hull`] and traces its gift-wrapping decisions.
+///
+/// Intended for debugging unexpected hull sizes, this is only available with
+/// the `hull-debug` feature. The supporting line rotates from site to
+/// site, a site being the start point, the end point or the curve of an
+/// element. Each step leaves the current site either because the current arc
+/// ends or because another site overtakes it as the support. The trace has one
+/// line per step, naming both sites by their element, the number of hull
+/// elements the step adds and the angle the supporting line turned.
+///
+/// # Arguments
+///
+/// * `arcs` - The input arcline (closed, non-self-intersecting, CCW)
+///
+/// # Returns
+///
+/// The hull, equal to [`arcline_convex_hull`], and the trace lines
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::convex_hull_arcs::arcline_convex_hull_debug;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let (hull, trace) = arcline_convex_hull_debug(&square);
+/// assert_eq!(hull, arcline_convex_hull(&square));
+/// assert!(trace[0].starts_with("step 1: from element"));
+/// ```
+#[cfg(feature = "hull-debug")]
+#[must_use]
+pub fn arcline_convex_hull_debug(arcs: &Arcline) -> (Arcline, Vec<String>) {
+    let mut trace = Vec::new();
+    let hull = single_hull(arcs, Some(&mut trace));
+    (hull, trace)
+}
+
+// Hull of a single arcline, empty when it has no area.
+fn single_hull(arcs: &Arcline, trace: Option<&mut Vec<String>>) -> Arcline {
+    let hull = support_hull(core::slice::from_ref(arcs), trace);
+    if hull.len() < 3 && hull.iter().all(Arc::is_seg) {
+        return Arcline::new();
+    }
+    hull
+}
+
+/// A candidate of the multi-arcline hull: a single point or a curved arc.
+#[derive(Debug, Clone, Copy)]
+enum HullSite {
+    Point(Point),
+    Arc {
+        c: Point,
+        r: f64,
+        // Angle of the arc start point around the center
+        start: f64,
+        sweep: f64,
+        b: Point,
+    },
+}
+
+const HULL_ANGLE_EPS: f64 = 1e-10;
+
+// Normalizes an angle into [0, 2pi), snapping values just below 2pi to 0.
+fn wrap_angle(angle: f64) -> f64 {
+    let t = angle.rem_euclid(TAU);
+    if t > TAU - HULL_ANGLE_EPS { 0.0 } else { t }
+}
+
+fn direction(angle: f64) -> Point {
+    point(angle.cos(), angle.sin())
+}
+
+impl HullSite {
+    // Point of the site touching the supporting line with outward normal at `angle`.
+    fn support(&self, angle: f64) -> Point {
+        match *self {
+            HullSite::Point(p) => p,
+            HullSite::Arc { c, r, .. } => c + direction(angle) * r,
+        }
+    }
+
+    // Arcs support the hull only for the normals between their end normals.
+    fn is_active(&self, angle: f64, closed_end: bool) -> bool {
+        match *self {
+            HullSite::Point(_) => true,
+            HullSite::Arc { start, sweep, .. } => {
+                let rel = wrap_angle(angle - start);
+                if closed_end {
+                    rel <= sweep + HULL_ANGLE_EPS
+                } else {
+                    rel < sweep - HULL_ANGLE_EPS
+                }
+            }
+        }
+    }
+
+    // Support function h(angle) = c . u + r, with r = 0 for points.
+    fn center_radius(&self) -> (Point, f64) {
+        match *self {
+            HullSite::Point(p) => (p, 0.0),
+            HullSite::Arc { c, r, .. } => (c, r),
+        }
+    }
+}
+
+// Angle advance from `theta` until `t` overtakes `s` as the supporting site.
+fn overtake_delta(s: &HullSite, t: &HullSite, theta: f64) -> Option<f64> {
+    let (cs, rs) = s.center_radius();
+    let (ct, rt) = t.center_radius();
+    let d = ct - cs;
+    let len = d.norm();
+    if len < GEOMETRIC_EPSILON {
+        return None;
+    }
+    // h_t - h_s = |d| cos(angle - psi) + e, crossing upwards at psi - acos(-e / |d|)
+    let k = (rs - rt) / len;
+    if !(-1.0 - HULL_ANGLE_EPS..1.0 - HULL_ANGLE_EPS).contains(&k) {
+        // Never strictly ahead, or ahead everywhere it is active (entered via its endpoint)
+        return None;
+    }
+    // Snap tangential contact (a point on the circle) to avoid acos round-off
+    let k = if k <= -1.0 + HULL_ANGLE_EPS { -1.0 } else { k };
+    let phi = d.y.atan2(d.x) - k.acos();
+    if !t.is_active(phi, false) {
+        return None;
+    }
+    Some(wrap_angle(phi - theta))
+}
+
+/// Computes the convex hull enclosing several arclines together.
+///
+/// The hull is traced by rotating a supporting line around all elements
+/// (gift wrapping on the support function). Hull parts touching curved arcs
+/// follow the arcs, including their extrema, and consecutive parts are
+/// connected by tangent segments. The parts do not need to be closed,
+/// connected or oriented in any particular way.
+///
+/// # Arguments
+///
+/// * `parts` - The arclines to enclose
+///
+/// # Returns
+///
+/// A closed CCW `Arcline` bounding all parts, or an empty arcline when the
+/// input has no elements or all of them collapse to a single point
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::convex_hull_arcs::arclines_convex_hull;
+///
+/// // Two unit discs, each made of two half-circle arcs
+/// let disc = |x: f64| {
+///     vec![
+///         arc(point(x, -1.0), point(x, 1.0), point(x, 0.0), 1.0),
+///         arc(point(x, 1.0), point(x, -1.0), point(x, 0.0), 1.0),
+///     ]
+/// };
+/// let hull = arclines_convex_hull(&[disc(0.0), disc(4.0)]);
+/// // Two half circles connected by two tangent segments
+/// assert_eq!(hull.len(), 4);
+/// assert_eq!(hull.iter().filter(|a| a.is_seg()).count(), 2);
+/// ```
+#[must_use]
+pub fn arclines_convex_hull(parts: &[Arcline]) -> Arcline {
+    support_hull(parts, None)
+}
+
+// Rotating supporting line around all elements; each step is described in
+// `trace` when given.
+fn support_hull(parts: &[Arcline], mut trace: Option<&mut Vec<String>>) -> Arcline {
+    // Every site with its element and what part of the element it is
+    let mut sites = Vec::new();
+    let mut labels: Vec<(usize, &str)> = Vec::new();
+    for (i, a) in parts.iter().flatten().enumerate() {
+        sites.push(HullSite::Point(a.a));
+        labels.push((i, "start point"));
+        sites.push(HullSite::Point(a.b));
+        labels.push((i, "end point"));
+        if !a.is_seg() && a.r > GEOMETRIC_EPSILON {
+            let va = a.a - a.c;
+            sites.push(HullSite::Arc {
+                c: a.c,
+                r: a.r,
+                start: va.y.atan2(va.x),
+                sweep: a.sweep_angle(),
+                b: a.b,
+            });
+            labels.push((i, "arc"));
+        }
+    }
+    if sites.is_empty() {
+        return Arcline::new();
+    }
+
+    // Start with the lowest site, rightmost among ties
+    let theta0 = -FRAC_PI_2;
+    let u0 = direction(theta0);
+    let du0 = direction(theta0 + FRAC_PI_2);
+    let mut current = sites[0];
+    let mut label = labels[0];
+    let mut best_h = f64::NEG_INFINITY;
+    let mut best_dh = f64::NEG_INFINITY;
+    for (site, site_label) in sites.iter().zip(&labels) {
+        if !site.is_active(theta0, true) {
+            continue;
+        }
+        let p = site.support(theta0);
+        let h = p.dot(u0);
+        let dh = p.dot(du0);
+        if h > best_h + GEOMETRIC_EPSILON
+            || (h > best_h - GEOMETRIC_EPSILON && dh > best_dh + GEOMETRIC_EPSILON)
+        {
+            best_h = best_h.max(h);
+            best_dh = dh;
+            current = *site;
+            label = *site_label;
+        }
+    }
+
+    let start_point = current.support(theta0);
+    let start_label = label;
+    // Only a start inside an arc splits it between the first and last element
+    let split_start = match current {
+        HullSite::Arc { c, r, start, .. } => {
+            !start_point.close_enough(c + direction(start) * r, GEOMETRIC_EPSILON)
+                && current.is_active(theta0, false)
+        }
+        HullSite::Point(_) => false,
+    };
+    let mut enter = start_point;
+    let mut theta = theta0;
+    let mut travelled = 0.0;
+    let mut hull = Arcline::new();
+    // Every site and every arc end is visited at most once per turn
+    for step in 0..(2 * sites.len() + 4) {
+        // Next event: the current arc ends or another site overtakes the current one
+        let mut next: Option<(f64, HullSite, bool, (usize, &str))> = None;
+        if let HullSite::Arc { start, sweep, b, .. } = current
+            && sweep < TAU - HULL_ANGLE_EPS
+        {
+            next = Some((wrap_angle(start + sweep - theta), HullSite::Point(b), true, (label.0, "end point")));
+        }
+        for (site, site_label) in sites.iter().zip(&labels) {
+            let Some(delta) = overtake_delta(&current, site, theta) else {
+                continue;
+            };
+            let better = match next {
+                None => true,
+                Some((best, best_site, _, _)) => {
+                    if delta < best - HULL_ANGLE_EPS {
+                        true
+                    } else if delta <= best + HULL_ANGLE_EPS {
+                        // Collinear candidates: jump to the farthest one
+                        let from = current.support(theta + delta);
+                        let dist = (site.support(theta + delta) - from).norm();
+                        dist > (best_site.support(theta + best) - from).norm() + GEOMETRIC_EPSILON
+                    } else {
+                        false
+                    }
+                }
+            };
+            if better {
+                next = Some((delta, *site, false, *site_label));
+            }
+        }
+
+        let remaining = TAU - travelled;
+        let closing = next.is_none_or(|(delta, ..)| delta >= remaining - HULL_ANGLE_EPS);
+        let (delta, site, arc_end, site_label) = match next {
+            Some((delta, site, arc_end, site_label)) if !closing => (delta, site, arc_end, site_label),
+            // Back at the start angle, only the end of the current arc still counts
+            Some((delta, site, true, site_label)) if delta <= remaining + HULL_ANGLE_EPS => {
+                (remaining, site, true, site_label)
+            }
+            _ => (remaining, current, false, start_label),
+        };
+        let phi = theta + delta;
+        let exit = match current {
+            HullSite::Arc { b, .. } if arc_end => b,
+            _ => current.support(phi),
+        };
+        let count = hull.len();
+        if let HullSite::Arc { c, r, .. } = current
+            && (!enter.close_enough(exit, GEOMETRIC_EPSILON) || (closing && hull.is_empty()))
+        {
+            hull.push(arc(enter, exit, c, r));
+        }
+        // Keep the exact point when the next site starts where the current one ends
+        let q = if closing { start_point } else { site.support(phi) };
+        let q = if exit.close_enough(q, GEOMETRIC_EPSILON) {
+            exit
+        } else {
+            hull.push(arcseg(exit, q));
+            q
+        };
+        if let Some(trace) = trace.as_deref_mut() {
+            let reason = if closing {
+                "closing the hull".to_string()
+            } else if arc_end {
+                format!("the arc ends after turning {:.6e} rad", delta)
+            } else {
+                format!("overtaken after turning {:.6e} rad among {} sites", delta, sites.len())
+            };
+            trace.push(format!(
+                "step {}: from element {} ({}) to element {} ({}), adding {} hull element(s): {}",
+                step + 1,
+                label.0,
+                label.1,
+                site_label.0,
+                site_label.1,
+                hull.len() - count,
+                reason
+            ));
+        }
+        if closing {
+            break;
+        }
+        enter = q;
+        current = site;
+        label = site_label;
+        theta = phi;
+        travelled += delta;
+    }
+
+    if split_start && hull.len() >= 2 {
+        let first = hull[0];
+        let last = hull[hull.len() - 1];
+        if !first.is_seg()
+            && !last.is_seg()
+            && first.c.close_enough(last.c, GEOMETRIC_EPSILON)
+            && (first.r - last.r).abs() < GEOMETRIC_EPSILON
+            && first.sweep_angle() + last.sweep_angle() < TAU - HULL_ANGLE_EPS
+        {
+            hull[0] = arc(last.a, first.b, first.c, first.r);
+            hull.pop();
+        }
+    }
+    hull
+}
+
+/// Origin of an element of the hull returned by [`arcline_convex_hull_tagged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HullEdgeKind {
+    /// Part of the input element at this index (a curved arc or a line segment).
+    OriginalArc(usize),
+    /// Inserted segment tangent to a neighboring hull arc.
+    TangentSegment,
+    /// Inserted segment bridging two corners of the input.
+    ConnectingSegment,
+}
+
+/// Computes the convex hull of an arcline and tags where each hull element came from.
+///
+/// The hull is the one of [`arclines_convex_hull`] for the single arcline.
+/// Hull arcs and segments lying on an input element are tagged with the index
+/// of that element; inserted segments are tagged as tangent when they leave or
+/// reach a hull arc tangentially, and as connecting segments otherwise.
+///
+/// # Arguments
+///
+/// * `arcs` - The input arcline
+///
+/// # Returns
+///
+/// The hull elements in CCW order, each paired with its [`HullEdgeKind`]
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::convex_hull_arcs::{HullEdgeKind, arcline_convex_hull_tagged};
+///
+/// // L-shape: the hull bridges the inner corner
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(2.0, 1.0)),
+///     arcseg(point(2.0, 1.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(1.0, 2.0)),
+///     arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+///     arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+/// ];
+/// let hull = arcline_convex_hull_tagged(&arcs);
+/// assert_eq!(hull.len(), 5);
+/// let bridges = hull.iter().filter(|(_, k)| *k == HullEdgeKind::ConnectingSegment).count();
+/// assert_eq!(bridges, 1);
+/// ```
+#[must_use]
+pub fn arcline_convex_hull_tagged(arcs: &Arcline) -> Vec<(Arc, HullEdgeKind)> {
+    let hull = arclines_convex_hull(core::slice::from_ref(arcs));
+    let n = hull.len();
+    let mut result = Vec::with_capacity(n);
+    for (i, h) in hull.iter().enumerate() {
+        let original = if h.is_seg() {
+            arcs.iter().position(|e| {
+                e.is_seg()
+                    && dist_point_segment(&h.a, &segment(e.a, e.b)).0 < GEOMETRIC_EPSILON
+                    && dist_point_segment(&h.b, &segment(e.a, e.b)).0 < GEOMETRIC_EPSILON
+            })
+        } else {
+            let middle = h.point_at(0.5);
+            arcs.iter().position(|e| {
+                !e.is_seg()
+                    && e.c.close_enough(h.c, GEOMETRIC_EPSILON)
+                    && (e.r - h.r).abs() < GEOMETRIC_EPSILON
+                    && (e.is_full_circle() || e.contains(middle))
+            })
+        };
+        let kind = match original {
+            Some(index) => HullEdgeKind::OriginalArc(index),
+            None => {
+                let prev = hull[(i + n - 1) % n];
+                let next = hull[(i + 1) % n];
+                if is_tangent_at(h, &prev, h.a) || is_tangent_at(h, &next, h.b) {
+                    HullEdgeKind::TangentSegment
+                } else {
+                    HullEdgeKind::ConnectingSegment
+                }
+            }
+        };
+        result.push((*h, kind));
+    }
+    result
+}
+
+/// Checks if segment `seg` touches the curved arc `neighbor` tangentially at `p`.
+fn is_tangent_at(seg: &Arc, neighbor: &Arc, p: Point) -> bool {
+    if neighbor.is_seg() {
+        return false;
+    }
+    let d = seg.b - seg.a;
+    let radial = p - neighbor.c;
+    d.dot(radial).abs() <= GEOMETRIC_EPSILON * d.norm().max(1.0) * radial.norm().max(1.0)
 }
@@ -88,6 +88,29 @@ fn test_arcline_convex_hull_circle() {
     svg.write_stroke_width(0.1);
 }
 
+#[test]
+fn test_arcline_convex_hull_circle_keeps_arcs() {
+    let arcs = vec![
+        arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+        arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+        arc(point(-1.0, 0.0), point(0.0, -1.0), point(0.0, 0.0), 1.0),
+        arc(point(0.0, -1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+    ];
+    let hull = arcline_convex_hull(&arcs);
+    // The quarter arcs themselves, not their chords
+    assert_eq!(hull.len(), 4);
+    assert!(hull.iter().all(|a| a.is_arc() && a.c == point(0.0, 0.0) && a.r == 1.0));
+    for a in &arcs {
+        assert!(hull.contains(a));
+    }
+    let sweep: f64 = hull.iter().map(Arc::sweep_angle).sum();
+    assert!((sweep - std::f64::consts::TAU).abs() < 1e-12);
+    // Closed and connected
+    for i in 0..hull.len() {
+        assert_eq!(hull[i].b, hull[(i + 1) % hull.len()].a);
+    }
+}
+
 #[test]
 fn test_arcline_convex_hull_semicircle() {
     // Semicircle (top half) with a line segment closing it
@@ -2338,3 +2361,256 @@ fn test_hull_arc_seg_segment_inside_arc_circle() {
     // Connection
     assert_eq!(result.len(), 2);
 }
+
+fn half_disc_pair(c: Point, r: f64) -> Arcline {
+    let lo = point(c.x, c.y - r);
+    let hi = point(c.x, c.y + r);
+    vec![arc(lo, hi, c, r), arc(hi, lo, c, r)]
+}
+
+fn assert_closed(hull: &Arcline) {
+    for i in 0..hull.len() {
+        let next = hull[(i + 1) % hull.len()];
+        assert!(
+            hull[i].b.close_enough(next.a, 1e-9),
+            "gap between {} and {}",
+            hull[i],
+            next
+        );
+    }
+}
+
+#[test]
+fn test_arclines_convex_hull_two_discs() {
+    let parts = vec![
+        half_disc_pair(point(0.0, 0.0), 1.0),
+        half_disc_pair(point(5.0, 0.0), 1.0),
+    ];
+    let hull = arclines_convex_hull(&parts);
+    assert_eq!(hull.len(), 4);
+    assert_closed(&hull);
+
+    let segs: Vec<&Arc> = hull.iter().filter(|a| a.is_seg()).collect();
+    assert_eq!(segs.len(), 2);
+    // Tangent segments along y = -1 and y = 1
+    for s in segs {
+        assert!((s.a.y - s.b.y).abs() < 1e-9);
+        assert!((s.a.y.abs() - 1.0).abs() < 1e-9);
+        assert!(((s.a.x - s.b.x).abs() - 5.0).abs() < 1e-9);
+    }
+    // Stadium: rectangle 5 x 2 plus a unit disc
+    assert!((arcline_area(&hull) - (10.0 + std::f64::consts::PI)).abs() < 1e-9);
+    for part in &parts {
+        for a in part {
+            assert!(arcline_contains(&hull, a.a));
+        }
+    }
+}
+
+#[test]
+fn test_arclines_convex_hull_discs_of_different_size() {
+    // Disc of radius 2 at the origin and a quarter-arc disc of radius 1 at (6, 0)
+    let c = point(6.0, 0.0);
+    let small = vec![
+        arc(point(7.0, 0.0), point(6.0, 1.0), c, 1.0),
+        arc(point(6.0, 1.0), point(5.0, 0.0), c, 1.0),
+        arc(point(5.0, 0.0), point(6.0, -1.0), c, 1.0),
+        arc(point(6.0, -1.0), point(7.0, 0.0), c, 1.0),
+    ];
+    let parts = vec![half_disc_pair(point(0.0, 0.0), 2.0), small];
+    let hull = arclines_convex_hull(&parts);
+    assert_closed(&hull);
+    assert_eq!(hull.iter().filter(|a| a.is_seg()).count(), 2);
+    // Tangent segments touch both circles
+    for s in hull.iter().filter(|a| a.is_seg()) {
+        let on_big = (s.a.norm() - 2.0).abs() < 1e-9 || (s.b.norm() - 2.0).abs() < 1e-9;
+        let on_small =
+            ((s.a - c).norm() - 1.0).abs() < 1e-9 || ((s.b - c).norm() - 1.0).abs() < 1e-9;
+        assert!(on_big && on_small);
+    }
+    // The rightmost extreme of the small disc is on the hull
+    assert!(crate::algo::arcline_bounding_rect(&hull).p2.x > 7.0 - 1e-9);
+}
+
+#[test]
+fn test_arclines_convex_hull_segments_match_points_hull() {
+    let parts = vec![
+        vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(0.5, 0.5)),
+        ],
+        vec![arcseg(point(3.0, 2.0), point(0.0, 3.0))],
+    ];
+    let hull = arclines_convex_hull(&parts);
+    assert_closed(&hull);
+    assert!(hull.iter().all(|a| a.is_seg()));
+    let points = vec![
+        point(0.0, 0.0),
+        point(1.0, 0.0),
+        point(0.5, 0.5),
+        point(3.0, 2.0),
+        point(0.0, 3.0),
+    ];
+    let expected = pointline_area(&points_convex_hull(&points));
+    assert!((arcline_area(&hull) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_arclines_convex_hull_single_circle() {
+    let c = circle(point(1.0, 1.0), 2.0);
+    let full = arc(point(3.0, 1.0), point(3.0, 1.0), c.c, c.r);
+    let hull = arclines_convex_hull(&[vec![full]]);
+    assert_eq!(hull.len(), 1);
+    assert!((arcline_area(&hull) - 4.0 * std::f64::consts::PI).abs() < 1e-9);
+}
+
+#[test]
+fn test_arclines_convex_hull_empty() {
+    assert!(arclines_convex_hull(&[]).is_empty());
+    assert!(arclines_convex_hull(&[vec![], vec![]]).is_empty());
+}
+
+
+// Two unit discs at (0, 0) and (4, 0) joined by a neck of width 1
+fn dumbbell() -> Arcline {
+    let s = 0.75_f64.sqrt();
+    vec![
+        arcseg(point(s, -0.5), point(4.0 - s, -0.5)),
+        arc(point(4.0 - s, -0.5), point(4.0 - s, 0.5), point(4.0, 0.0), 1.0),
+        arcseg(point(4.0 - s, 0.5), point(s, 0.5)),
+        arc(point(s, 0.5), point(s, -0.5), point(0.0, 0.0), 1.0),
+    ]
+}
+
+#[test]
+fn test_arcline_convex_hull_tagged_two_discs() {
+    let tagged = arcline_convex_hull_tagged(&dumbbell());
+    assert_eq!(tagged.len(), 4);
+    let hull: Arcline = tagged.iter().map(|(a, _)| *a).collect();
+    assert_closed(&hull);
+
+    for (a, kind) in &tagged {
+        if a.is_seg() {
+            assert_eq!(*kind, HullEdgeKind::TangentSegment);
+            assert!((a.a.y.abs() - 1.0).abs() < 1e-9);
+        } else if a.c.x > 2.0 {
+            assert_eq!(*kind, HullEdgeKind::OriginalArc(1));
+        } else {
+            assert_eq!(*kind, HullEdgeKind::OriginalArc(3));
+        }
+    }
+    assert!((arcline_area(&hull) - (8.0 + std::f64::consts::PI)).abs() < 1e-9);
+}
+
+#[test]
+fn test_arcline_convex_hull_tagged_segments() {
+    // Square: every hull element is an original segment
+    let square = vec![
+        arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+        arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+        arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+        arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+    ];
+    let tagged = arcline_convex_hull_tagged(&square);
+    assert_eq!(tagged.len(), 4);
+    let mut indices: Vec<usize> = tagged
+        .iter()
+        .map(|(_, kind)| match kind {
+            HullEdgeKind::OriginalArc(i) => *i,
+            _ => panic!("unexpected {:?}", kind),
+        })
+        .collect();
+    indices.sort_unstable();
+    assert_eq!(indices, vec![0, 1, 2, 3]);
+
+    // Notched square: the top side, notch included, becomes one connecting segment
+    let notched = vec![
+        arcseg(point(0.0, 0.0), point(3.0, 0.0)),
+        arcseg(point(3.0, 0.0), point(3.0, 3.0)),
+        arcseg(point(3.0, 3.0), point(2.0, 3.0)),
+        arcseg(point(2.0, 3.0), point(1.5, 2.0)),
+        arcseg(point(1.5, 2.0), point(1.0, 3.0)),
+        arcseg(point(1.0, 3.0), point(0.0, 3.0)),
+        arcseg(point(0.0, 3.0), point(0.0, 0.0)),
+    ];
+    let tagged = arcline_convex_hull_tagged(&notched);
+    let bridges: Vec<&Arc> = tagged
+        .iter()
+        .filter(|(_, kind)| *kind == HullEdgeKind::ConnectingSegment)
+        .map(|(a, _)| a)
+        .collect();
+    assert_eq!(bridges.len(), 1);
+    assert!(bridges[0].a.close_enough(point(3.0, 3.0), 1e-9));
+    assert!(bridges[0].b.close_enough(point(0.0, 3.0), 1e-9));
+}
+
+#[test]
+fn test_arcline_convex_hull_tagged_empty() {
+    assert!(arcline_convex_hull_tagged(&vec![]).is_empty());
+}
+
+// Number of hull elements added by each step of the trace
+#[cfg(feature = "hull-debug")]
+fn added_per_step(trace: &[String]) -> Vec<usize> {
+    trace
+        .iter()
+        .map(|line| {
+            let count = line.split("adding ").nth(1).unwrap();
+            count.split(' ').next().unwrap().parse().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "hull-debug")]
+fn test_arcline_convex_hull_debug_trace_steps() {
+    let notched = vec![
+        arcseg(point(0.0, 0.0), point(3.0, 0.0)),
+        arcseg(point(3.0, 0.0), point(3.0, 3.0)),
+        arcseg(point(3.0, 3.0), point(2.0, 3.0)),
+        arcseg(point(2.0, 3.0), point(1.5, 2.0)),
+        arcseg(point(1.5, 2.0), point(1.0, 3.0)),
+        arcseg(point(1.0, 3.0), point(0.0, 3.0)),
+        arcseg(point(0.0, 3.0), point(0.0, 0.0)),
+    ];
+    let circle = vec![
+        arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+        arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+        arc(point(-1.0, 0.0), point(0.0, -1.0), point(0.0, 0.0), 1.0),
+        arc(point(0.0, -1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+    ];
+    for arcs in [notched, circle, arcline200()] {
+        let (hull, trace) = arcline_convex_hull_debug(&arcs);
+        assert_eq!(hull, arcline_convex_hull(&arcs));
+        // One line per step, numbered in order, together adding every hull element
+        for (i, line) in trace.iter().enumerate() {
+            assert!(line.starts_with(&format!("step {}: ", i + 1)));
+        }
+        // Arcs are entered and left in separate steps
+        assert!(!trace.is_empty() && trace.len() <= 2 * arcs.len() + 1);
+        // An arc split by the start point is added twice and merged at the end
+        let added = added_per_step(&trace).iter().sum::<usize>();
+        assert!(added == hull.len() || added == hull.len() + 1);
+    }
+}
+
+#[test]
+#[cfg(feature = "hull-debug")]
+fn test_arcline_convex_hull_debug_names_skipped_elements() {
+    // The wrap jumps over the notch, from the top right corner to the top left one
+    let notched = vec![
+        arcseg(point(0.0, 0.0), point(3.0, 0.0)),
+        arcseg(point(3.0, 0.0), point(3.0, 3.0)),
+        arcseg(point(3.0, 3.0), point(2.0, 3.0)),
+        arcseg(point(2.0, 3.0), point(1.5, 2.0)),
+        arcseg(point(1.5, 2.0), point(1.0, 3.0)),
+        arcseg(point(1.0, 3.0), point(0.0, 3.0)),
+        arcseg(point(0.0, 3.0), point(0.0, 0.0)),
+    ];
+    let (_, trace) = arcline_convex_hull_debug(&notched);
+    assert_eq!(trace.len(), 4);
+    assert!(trace[1].starts_with("step 2: from element 1 (end point) to element 5 (end point)"));
+    assert!(trace.iter().all(|line| !line.contains("to element 3 ") && !line.contains("to element 4 ")));
+    assert!(trace[3].ends_with("closing the hull"));
+    assert!(arcline_convex_hull_debug(&vec![]).1.is_empty());
+}
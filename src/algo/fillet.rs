@@ -0,0 +1,231 @@
+//! Fillets between arcline elements.
+//!
+//! This module rounds the corner where two elements meet with a tangent arc,
+//! for segments as well as curved arcs.
+
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+
+/// Rounds the corner between two arcs with a tangent arc of the given radius.
+///
+/// The path runs along `a1` from `a` to `b` and continues along `a2` from `a`
+/// to `b`, so `a1.b` must meet `a2.a` at the corner. Either element may be a
+/// line segment. The fillet is tangent to both elements on the inner side of the
+/// corner: its center is where the curves offset by `radius` toward that side
+/// intersect, the offset of a segment being a parallel line and the offset of an
+/// arc a concentric circle. Both elements are trimmed at the tangent points.
+///
+/// The fillet is CCW like every arc. At a left (CCW) turn it goes from the end of
+/// the trimmed `a1` to the start of the trimmed `a2`; at a right turn it is stored
+/// reversed, from the start of `a2` back to the end of `a1`, like the concave
+/// arcs of a closed shape.
+///
+/// # Arguments
+///
+/// * `a1` - The element ending at the corner
+/// * `a2` - The element starting at the corner
+/// * `radius` - The fillet radius
+///
+/// # Returns
+///
+/// The trimmed `a1`, the fillet and the trimmed `a2`, or `None` if the elements
+/// do not meet, meet without a corner (tangent continuous), or the fillet does
+/// not fit: its tangent points would fall outside the elements
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let a1 = arcseg(point(0.0, 0.0), point(4.0, 0.0));
+/// let a2 = arcseg(point(4.0, 0.0), point(4.0, 4.0));
+/// let (t1, fillet, t2) = fillet_arcs(&a1, &a2, 1.0).unwrap();
+/// assert_eq!(t1, arcseg(point(0.0, 0.0), point(3.0, 0.0)));
+/// assert_eq!(fillet, arc(point(3.0, 0.0), point(4.0, 1.0), point(3.0, 1.0), 1.0));
+/// assert_eq!(t2, arcseg(point(4.0, 1.0), point(4.0, 4.0)));
+/// ```
+#[must_use]
+pub fn fillet_arcs(a1: &Arc, a2: &Arc, radius: f64) -> Option<(Arc, Arc, Arc)> {
+    if radius <= 0.0 || !radius.is_finite() || !a1.b.close_enough(a2.a, GEOMETRIC_EPSILON) {
+        return None;
+    }
+    let corner = a1.b;
+    let turn = direction(a1, corner).perp(direction(a2, corner));
+    if turn.abs() <= GEOMETRIC_EPSILON {
+        return None;
+    }
+    // Offset toward the inner side of the corner, the left of the path at a left turn
+    let side = if turn > 0.0 { 1.0 } else { -1.0 };
+    let off1 = offset(a1, side * radius)?;
+    let off2 = offset(a2, side * radius)?;
+
+    let candidates: Vec<Point> = match (off1, off2) {
+        (Offset::Line(l1), Offset::Line(l2)) => match int_line_line(&l1, &l2) {
+            LineLineConfig::OnePoint(p, _, _) => vec![p],
+            _ => Vec::new(),
+        },
+        (Offset::Line(l), Offset::Circle(c)) | (Offset::Circle(c), Offset::Line(l)) => {
+            match int_line_circle(&l, &c) {
+                LineCircleConfig::NoIntersection() => Vec::new(),
+                LineCircleConfig::OnePoint(p, _) => vec![p],
+                LineCircleConfig::TwoPoints(p0, p1, _, _) => vec![p0, p1],
+            }
+        }
+        (Offset::Circle(c1), Offset::Circle(c2)) => match int_circle_circle(c1, c2) {
+            CircleCircleConfig::NoncocircularOnePoint(p) => vec![p],
+            CircleCircleConfig::NoncocircularTwoPoints(p0, p1) => vec![p0, p1],
+            _ => Vec::new(),
+        },
+    };
+
+    // The fillet nearest to the corner with both tangent points on the elements
+    let (center, p1, p2) = candidates
+        .into_iter()
+        .filter_map(|c| Some((c, tangent_point(a1, c)?, tangent_point(a2, c)?)))
+        .min_by(|x, y| (x.0 - corner).norm().total_cmp(&(y.0 - corner).norm()))?;
+
+    let trimmed1 = if a1.is_seg() { arcseg(a1.a, p1) } else { arc(a1.a, p1, a1.c, a1.r) };
+    let trimmed2 = if a2.is_seg() { arcseg(p2, a2.b) } else { arc(p2, a2.b, a2.c, a2.r) };
+    let fillet = if side > 0.0 { arc(p1, p2, center, radius) } else { arc(p2, p1, center, radius) };
+    Some((trimmed1, fillet, trimmed2))
+}
+
+// Curve at a constant distance from an element
+enum Offset {
+    Line(Line),
+    Circle(Circle),
+}
+
+// Unit direction of travel from a to b at a point of the element
+fn direction(a: &Arc, p: Point) -> Point {
+    let t = if a.is_seg() {
+        a.b - a.a
+    } else {
+        let v = p - a.c;
+        point(-v.y, v.x)
+    };
+    t.normalize(false).0
+}
+
+// Offset by `dist` to the left of the direction of travel, to the right if negative
+fn offset(a: &Arc, dist: f64) -> Option<Offset> {
+    if a.is_seg() {
+        let (dir, length) = (a.b - a.a).normalize(false);
+        if length == 0.0 {
+            return None;
+        }
+        let normal = point(-dir.y, dir.x);
+        return Some(Offset::Line(line(a.a + normal * dist, dir)));
+    }
+    // The left of a CCW arc is toward its center
+    let r = a.r - dist;
+    (r > 0.0).then(|| Offset::Circle(circle(a.c, r)))
+}
+
+// Point where a circle centered at `c` touches the element, if on the element
+fn tangent_point(a: &Arc, c: Point) -> Option<Point> {
+    let p = if a.is_seg() {
+        let d = a.b - a.a;
+        let t = (c - a.a).dot(d) / d.dot(d);
+        if !(-GEOMETRIC_EPSILON..=1.0 + GEOMETRIC_EPSILON).contains(&t) {
+            return None;
+        }
+        a.a + d * t.clamp(0.0, 1.0)
+    } else {
+        let (dir, _) = (c - a.c).normalize(false);
+        a.c + dir * a.r
+    };
+    if p.close_enough(a.a, GEOMETRIC_EPSILON) {
+        Some(a.a)
+    } else if p.close_enough(a.b, GEOMETRIC_EPSILON) {
+        Some(a.b)
+    } else if a.is_seg() || a.contains(p) {
+        Some(p)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test_fillet_arcs {
+    use super::*;
+
+    fn assert_tangent(element: &Arc, fillet: &Arc, p: Point) {
+        assert!(fillet.a == p || fillet.b == p);
+        if element.is_seg() {
+            let (dir, _) = (element.b - element.a).normalize(false);
+            assert!((fillet.c - p).dot(dir).abs() < 1e-12);
+        } else {
+            // Internally or externally tangent circles
+            let d = (fillet.c - element.c).norm();
+            assert!((d - (element.r - fillet.r)).abs() < 1e-12 || (d - (element.r + fillet.r)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_segments_left_and_right_turn() {
+        let a1 = arcseg(point(0.0, 0.0), point(4.0, 0.0));
+        let a2 = arcseg(point(4.0, 0.0), point(4.0, 4.0));
+        let (t1, fillet, t2) = fillet_arcs(&a1, &a2, 1.0).unwrap();
+        assert_eq!((t1.b, t2.a), (point(3.0, 0.0), point(4.0, 1.0)));
+        assert_eq!(fillet, arc(point(3.0, 0.0), point(4.0, 1.0), point(3.0, 1.0), 1.0));
+
+        // Right turn: the fillet is stored reversed
+        let a2 = arcseg(point(4.0, 0.0), point(4.0, -4.0));
+        let (t1, fillet, t2) = fillet_arcs(&a1, &a2, 1.0).unwrap();
+        assert_eq!((t1.b, t2.a), (point(3.0, 0.0), point(4.0, -1.0)));
+        assert_eq!(fillet, arc(point(4.0, -1.0), point(3.0, 0.0), point(3.0, -1.0), 1.0));
+    }
+
+    #[test]
+    fn test_segments_at_an_angle() {
+        // 60 degree turn: tangent points at radius * tan(30 degrees) from the corner
+        let dir = point(0.5, 0.75_f64.sqrt());
+        let a1 = arcseg(point(-5.0, 0.0), point(0.0, 0.0));
+        let a2 = arcseg(point(0.0, 0.0), dir * 5.0);
+        let (t1, fillet, t2) = fillet_arcs(&a1, &a2, 2.0).unwrap();
+        let d = 2.0 * (std::f64::consts::PI / 6.0).tan();
+        assert!(t1.b.close_enough(point(-d, 0.0), 1e-12));
+        assert!(t2.a.close_enough(dir * d, 1e-12));
+        assert!(fillet.c.close_enough(point(-d, 2.0), 1e-12));
+        assert_tangent(&a1, &fillet, t1.b);
+        assert_tangent(&a2, &fillet, t2.a);
+        assert!(fillet.is_ccw_consistent());
+    }
+
+    #[test]
+    fn test_two_arcs_at_right_angle() {
+        // Going up at (5, 0) on a circle at the origin, then left on a circle at (5, -5)
+        let a1 = arc(point(5.0 * 0.75_f64.sqrt(), -2.5), point(5.0, 0.0), point(0.0, 0.0), 5.0);
+        let a2 = arc(point(5.0, 0.0), point(0.0, -5.0), point(5.0, -5.0), 5.0);
+        let (t1, fillet, t2) = fillet_arcs(&a1, &a2, 1.0).unwrap();
+        assert_eq!(fillet.r, 1.0);
+        assert_eq!((fillet.a, fillet.b), (t1.b, t2.a));
+        assert_tangent(&a1, &fillet, t1.b);
+        assert_tangent(&a2, &fillet, t2.a);
+        // The trimmed arcs keep their circles and shrink
+        assert_eq!((t1.a, t1.c, t1.r), (a1.a, a1.c, a1.r));
+        assert_eq!((t2.b, t2.c, t2.r), (a2.b, a2.c, a2.r));
+        assert!(t1.sweep_angle() < a1.sweep_angle() && t2.sweep_angle() < a2.sweep_angle());
+        // The fillet turns by the corner angle and the turn of the trimmed parts
+        let removed = a1.sweep_angle() - t1.sweep_angle() + a2.sweep_angle() - t2.sweep_angle();
+        assert!((fillet.sweep_angle() - std::f64::consts::FRAC_PI_2 - removed).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fillet_does_not_fit() {
+        let a1 = arcseg(point(0.0, 0.0), point(1.0, 0.0));
+        let a2 = arcseg(point(1.0, 0.0), point(1.0, 4.0));
+        // Tangent point beyond the start of the short segment
+        assert!(fillet_arcs(&a1, &a2, 2.0).is_none());
+        // Offset circle of an arc collapses
+        let quarter = arc(point(5.0, 0.0), point(4.0, 1.0), point(4.0, 0.0), 1.0);
+        let before = arcseg(point(2.0, 0.0), point(5.0, 0.0));
+        assert!(fillet_arcs(&before, &quarter, 0.25).is_some());
+        assert!(fillet_arcs(&before, &quarter, 1.5).is_none());
+        // Not meeting, or meeting without a corner
+        assert!(fillet_arcs(&a1, &arcseg(point(2.0, 0.0), point(2.0, 4.0)), 0.5).is_none());
+        assert!(fillet_arcs(&a1, &arcseg(point(1.0, 0.0), point(3.0, 0.0)), 0.5).is_none());
+        assert!(fillet_arcs(&a1, &a2, 0.0).is_none());
+    }
+}
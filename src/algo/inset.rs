@@ -0,0 +1,397 @@
+//! Inward offsets of polygons.
+//!
+//! This module insets simple polygons given as point sequences by propagating
+//! the wavefront of their straight skeleton, so that the result splits where
+//! parts of the polygon pinch off and loses the parts that collapse instead of
+//! self-intersecting.
+
+use crate::prelude::*;
+
+/// Tolerance for coincident wavefront vertices and parallel edges.
+const INSET_TOLERANCE: f64 = 1e-9;
+
+// Vertex of the wavefront with the line of the edge starting at it. The line is
+// `n · p = c`, `n` being the unit inward normal; it moves inward at unit speed.
+#[derive(Debug, Clone, Copy)]
+struct WaveVertex {
+    p: Point,
+    n: Point,
+    c: f64,
+}
+
+/// Insets a simple polygon without self-intersections.
+///
+/// Every edge moves inward by `distance` along its normal, corners staying
+/// sharp (mitered), which is the wavefront of the straight skeleton of the
+/// polygon at time `distance`. The wavefront is propagated event by event:
+///
+/// - edge event: an edge shrinks to a point and is removed, its neighbors
+///   meeting at the point
+/// - split event: a reflex vertex reaches an edge that is not adjacent to it,
+///   and the wavefront splits there into two polygons
+///
+/// Polygons that collapse to zero area vanish. So unlike the naive offset,
+/// which moves the edge lines and intersects consecutive ones, the result never
+/// has loops from shrunk edges or parts crossing each other across a neck.
+///
+/// # Arguments
+///
+/// * `poly` - The vertices of a simple polygon (closed implicitly, any orientation)
+/// * `distance` - The inset distance
+///
+/// # Returns
+///
+/// The pieces of the inset polygon, each CCW. Empty when the polygon vanishes
+/// at this distance or has fewer than 3 vertices; the polygon itself (CCW) for a
+/// non-positive distance.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Two 4 x 4 squares joined by a neck of width 1
+/// let dumbbell = vec![
+///     point(0.0, 0.0), point(4.0, 0.0), point(4.0, 1.5), point(6.0, 1.5),
+///     point(6.0, 0.0), point(10.0, 0.0), point(10.0, 4.0), point(6.0, 4.0),
+///     point(6.0, 2.5), point(4.0, 2.5), point(4.0, 4.0), point(0.0, 4.0),
+/// ];
+/// assert_eq!(pointline_inset_safe(&dumbbell, 0.25).len(), 1);
+/// // The neck vanishes, leaving two squares of side 2
+/// let pieces = pointline_inset_safe(&dumbbell, 1.0);
+/// assert_eq!(pieces.len(), 2);
+/// assert!((pointline_area(&pieces[0]) - 4.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn pointline_inset_safe(poly: &Pointline, distance: f64) -> Vec<Pointline> {
+    let mut ring: Pointline = Vec::with_capacity(poly.len());
+    for &p in poly {
+        if ring.last() != Some(&p) {
+            ring.push(p);
+        }
+    }
+    while ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    if ring.len() < 3 {
+        return Vec::new();
+    }
+    if pointline_area(&ring) < 0.0 {
+        ring.reverse();
+    }
+    if distance <= 0.0 {
+        return vec![ring];
+    }
+
+    let n = ring.len();
+    let front: Vec<WaveVertex> = (0..n)
+        .map(|i| {
+            let (d, _) = (ring[(i + 1) % n] - ring[i]).normalize(false);
+            let normal = point(-d.y, d.x);
+            WaveVertex {
+                p: ring[i],
+                n: normal,
+                c: normal.dot(ring[i]),
+            }
+        })
+        .collect();
+
+    // Every event removes a vertex or splits off a polygon, the bound only
+    // guards against events repeating on round-off
+    let mut budget = 8 * n + 16;
+    let mut fronts = vec![front];
+    let mut time = 0.0;
+    loop {
+        fronts = fronts.into_iter().flat_map(resolve).collect();
+        if fronts.is_empty() || time >= distance || budget == 0 {
+            break;
+        }
+        budget -= 1;
+        let remaining = distance - time;
+        let step = fronts
+            .iter()
+            .filter_map(|f| next_event(f))
+            .fold(remaining, f64::min);
+        for f in &mut fronts {
+            advance(f, step);
+        }
+        time = if step < remaining { time + step } else { distance };
+    }
+    fronts
+        .into_iter()
+        .map(|f| f.iter().map(|v| v.p).collect())
+        .collect()
+}
+
+// Velocity of a vertex keeping it on both its edge lines.
+fn velocity(prev: &WaveVertex, v: &WaveVertex) -> Point {
+    (prev.n + v.n) / (1.0 + prev.n.dot(v.n))
+}
+
+fn velocities(front: &[WaveVertex]) -> Vec<Point> {
+    let n = front.len();
+    (0..n)
+        .map(|i| velocity(&front[(i + n - 1) % n], &front[i]))
+        .collect()
+}
+
+// Moves the wavefront forward by `step`.
+fn advance(front: &mut [WaveVertex], step: f64) {
+    let vel = velocities(front);
+    for (v, dv) in front.iter_mut().zip(vel) {
+        v.p = v.p + dv * step;
+        v.c += step;
+    }
+}
+
+// Time until the next edge or split event of the wavefront.
+fn next_event(front: &[WaveVertex]) -> Option<f64> {
+    let n = front.len();
+    let vel = velocities(front);
+    let mut next: Option<f64> = None;
+    let mut add = |s: f64| next = Some(next.map_or(s, |t: f64| t.min(s)));
+
+    for k in 0..n {
+        // Edge event, the edge length shrinking to zero
+        let l = (k + 1) % n;
+        let dir = point(front[k].n.y, -front[k].n.x);
+        let length = (front[l].p - front[k].p).dot(dir);
+        let rate = (vel[l] - vel[k]).dot(dir);
+        if rate < 0.0 {
+            add((-length / rate).max(0.0));
+        }
+    }
+
+    for j in 0..n {
+        let prev = (j + n - 1) % n;
+        if front[prev].n.perp(front[j].n) >= -INSET_TOLERANCE {
+            continue;
+        }
+        // Split event, the reflex vertex reaching a non-adjacent edge
+        for k in (0..n).filter(|&k| k != j && k != prev) {
+            let l = (k + 1) % n;
+            let line = &front[k];
+            let approach = 1.0 - vel[j].dot(line.n);
+            if approach <= INSET_TOLERANCE {
+                continue;
+            }
+            let s = (line.n.dot(front[j].p) - line.c) / approach;
+            if s < -INSET_TOLERANCE {
+                continue;
+            }
+            let s = s.max(0.0);
+            let q = front[j].p + vel[j] * s;
+            let a = front[k].p + vel[k] * s;
+            let b = front[l].p + vel[l] * s;
+            let (dist, _) = dist_point_segment(&q, &segment(a, b));
+            if dist <= INSET_TOLERANCE * (1.0 + s) {
+                add(s);
+            }
+        }
+    }
+    next
+}
+
+// Resolves the events at the current time: merges coincident vertices, removes
+// collinear vertices and zero-width spikes, splits the wavefront where it touches
+// itself and drops the pieces without area.
+fn resolve(front: Vec<WaveVertex>) -> Vec<Vec<WaveVertex>> {
+    let mut pending = vec![front];
+    let mut result = Vec::new();
+    while let Some(mut f) = pending.pop() {
+        simplify(&mut f);
+        if f.len() < 3 {
+            continue;
+        }
+        if let Some((j, k)) = touching(&mut f) {
+            let (j, k) = (j.min(k), j.max(k));
+            let mut other: Vec<WaveVertex> = f.drain(k..).collect();
+            other.extend(f.drain(..j));
+            pending.push(f);
+            pending.push(other);
+            continue;
+        }
+        let points: Pointline = f.iter().map(|v| v.p).collect();
+        if pointline_area(&points) > INSET_TOLERANCE {
+            result.push(f);
+        }
+    }
+    result
+}
+
+fn simplify(f: &mut Vec<WaveVertex>) {
+    let mut i = 0;
+    while f.len() >= 3 && i < f.len() {
+        let n = f.len();
+        let prev = (i + n - 1) % n;
+        let next = (i + 1) % n;
+        let (n1, n2) = (f[prev].n, f[i].n);
+        if f[i].p.close_enough(f[next].p, INSET_TOLERANCE) {
+            // Collapsed edge, the next vertex takes over the previous edge
+            f.remove(i);
+        } else if n1.dot(n2) >= 1.0 - INSET_TOLERANCE && (f[prev].c - f[i].c).abs() <= INSET_TOLERANCE {
+            // Straight continuation of the previous edge
+            f.remove(i);
+        } else if n1.dot(n2) <= INSET_TOLERANCE - 1.0 {
+            // Spike of two overlapping edges; the previous vertex continues
+            // toward the next one on the line of whichever edge points there
+            let dir = point(n1.y, -n1.x);
+            if (f[next].p - f[prev].p).dot(dir) < 0.0 {
+                f[prev].n = f[i].n;
+                f[prev].c = f[i].c;
+            }
+            f.remove(i);
+        } else {
+            i += 1;
+            continue;
+        }
+        i = i.saturating_sub(1);
+    }
+}
+
+// Finds two non-adjacent vertices at the same position, inserting a vertex
+// where a vertex touches the inside of an edge.
+fn touching(f: &mut Vec<WaveVertex>) -> Option<(usize, usize)> {
+    let n = f.len();
+    for j in 0..n {
+        for k in 0..n {
+            let l = (k + 1) % n;
+            if k == j || l == j {
+                continue;
+            }
+            if f[j].p.close_enough(f[k].p, INSET_TOLERANCE) {
+                return Some((j, k));
+            }
+            let (dist, _) = dist_point_segment(&f[j].p, &segment(f[k].p, f[l].p));
+            if dist <= INSET_TOLERANCE && !f[j].p.close_enough(f[l].p, INSET_TOLERANCE) {
+                let split = WaveVertex { p: f[j].p, ..f[k] };
+                f.insert(k + 1, split);
+                let j = if j > k { j + 1 } else { j };
+                return Some((j, k + 1));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test_pointline_inset_safe {
+    use super::*;
+
+    // Plus of half width 1 with arms reaching 5 from the center, optionally
+    // with square pads of side 6 at the arm ends
+    fn plus(pads: bool) -> Pointline {
+        let mut arm = vec![point(5.0, -1.0)];
+        if pads {
+            arm.extend([point(5.0, -3.0), point(11.0, -3.0), point(11.0, 3.0), point(5.0, 3.0)]);
+        }
+        arm.extend([point(5.0, 1.0), point(1.0, 1.0)]);
+        // The four arms rotated by quarter turns
+        (0..4)
+            .flat_map(|q| {
+                arm.iter().map(move |p| match q {
+                    0 => *p,
+                    1 => point(-p.y, p.x),
+                    2 => point(-p.x, -p.y),
+                    _ => point(p.y, -p.x),
+                })
+            })
+            .collect()
+    }
+
+    fn assert_inset(poly: &Pointline, pieces: &[Pointline], distance: f64) {
+        for piece in pieces {
+            assert!(pointline_area(piece) > 0.0);
+            for p in piece {
+                assert!(pointline_contains(poly, *p));
+                // Mitered corners are at least the distance away from the boundary
+                let n = poly.len();
+                let dist = (0..n)
+                    .map(|i| dist_point_segment(p, &segment(poly[i], poly[(i + 1) % n])).0)
+                    .fold(f64::INFINITY, f64::min);
+                assert!(dist >= distance - 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plus_shrinks_then_vanishes() {
+        let poly = plus(false);
+        assert!((pointline_area(&poly) - 36.0).abs() < 1e-12);
+        let pieces = pointline_inset_safe(&poly, 0.5);
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].len(), 12);
+        // Half width 0.5 and arms reaching 4.5
+        assert!((pointline_area(&pieces[0]) - 17.0).abs() < 1e-9);
+        assert!(pieces[0].iter().any(|p| p.close_enough(point(4.5, 0.5), 1e-9)));
+        assert_inset(&poly, &pieces, 0.5);
+        // The arms collapse together with the center
+        assert!(pointline_inset_safe(&poly, 1.0).is_empty());
+        assert!(pointline_inset_safe(&poly, 1.5).is_empty());
+    }
+
+    #[test]
+    fn test_plus_with_pads_splits() {
+        let poly = plus(true);
+        // Still one piece while the arms have width
+        let pieces = pointline_inset_safe(&poly, 0.75);
+        assert_eq!(pieces.len(), 1);
+        assert_inset(&poly, &pieces, 0.75);
+        // The arms vanish at half their width, leaving the four pads
+        let pieces = pointline_inset_safe(&poly, 1.5);
+        assert_eq!(pieces.len(), 4);
+        assert_inset(&poly, &pieces, 1.5);
+        for piece in &pieces {
+            assert_eq!(piece.len(), 4);
+            assert!((pointline_area(piece) - 9.0).abs() < 1e-9);
+        }
+        assert!(pieces.iter().any(|piece| piece.contains(&point(6.5, -1.5))));
+        assert!(pointline_inset_safe(&poly, 3.0).is_empty());
+    }
+
+    #[test]
+    fn test_slanted_neck_splits() {
+        // Rectangle pinched by two notches, the neck between them of width 2
+        let poly = vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(5.0, 1.0),
+            point(6.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 4.0),
+            point(6.0, 4.0),
+            point(5.0, 3.0),
+            point(4.0, 4.0),
+            point(0.0, 4.0),
+        ];
+        // The notch tips move toward each other at sqrt(2) and meet at 1 / sqrt(2)
+        let pieces = pointline_inset_safe(&poly, 0.7);
+        assert_eq!(pieces.len(), 1);
+        assert_inset(&poly, &pieces, 0.7);
+        let pieces = pointline_inset_safe(&poly, 1.0);
+        assert_eq!(pieces.len(), 2);
+        assert_inset(&poly, &pieces, 1.0);
+        let areas: Vec<f64> = pieces.iter().map(pointline_area).collect();
+        assert!((areas[0] - areas[1]).abs() < 1e-9);
+        // Clockwise input gives the same pieces
+        let cw: Pointline = poly.iter().rev().copied().collect();
+        assert_eq!(pointline_inset_safe(&cw, 1.0).len(), 2);
+    }
+
+    #[test]
+    fn test_convex_and_degenerate() {
+        let square = vec![point(0.0, 0.0), point(4.0, 0.0), point(4.0, 4.0), point(0.0, 4.0)];
+        let pieces = pointline_inset_safe(&square, 1.0);
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0], vec![point(1.0, 1.0), point(3.0, 1.0), point(3.0, 3.0), point(1.0, 3.0)]);
+        assert!(pointline_inset_safe(&square, 2.0).is_empty());
+        assert_eq!(pointline_inset_safe(&square, 0.0), vec![square.clone()]);
+        assert!(pointline_inset_safe(&vec![point(0.0, 0.0), point(1.0, 0.0)], 0.1).is_empty());
+        // A triangle shrinks toward its incenter
+        let triangle = vec![point(0.0, 0.0), point(4.0, 0.0), point(0.0, 3.0)];
+        let pieces = pointline_inset_safe(&triangle, 0.5);
+        assert_eq!(pieces.len(), 1);
+        assert!((pointline_area(&pieces[0]) - 6.0 * 0.25).abs() < 1e-9);
+        assert!(pointline_inset_safe(&triangle, 1.0).is_empty());
+    }
+}
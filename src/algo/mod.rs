@@ -5,29 +5,67 @@
 
 #![allow(dead_code)]
 
+use crate::constants::GEOMETRIC_EPSILON;
+use core::f64::consts::TAU;
 use crate::prelude::*;
 use robust::{orient2d, Coord};
 
 // Re-export algorithm submodules here when they are added
 // pub mod triangulation;
 pub mod area;
+pub mod boolean;
 pub mod bounding;
+pub mod clip;
+pub mod collision;
+pub mod contains;
 pub mod convex_hull;
 pub mod convex_hull_arcs;
+pub mod fillet;
+pub mod inset;
+pub mod offset;
 pub mod tangent;
+pub mod trapezoid;
 pub mod self_intersect;
+pub mod simplify;
+pub mod spatial;
+pub mod subdivision;
+pub mod visibility;
+pub mod voronoi;
 // pub mod closest_pair;
 
 // Re-export all public types and functions for easy access
-pub use area::{arcline_area, pointline_area};
-pub use bounding::{arc_bounding_circle, arc_bounding_rect};
-pub use convex_hull::{points_convex_hull, pointline_convex_hull};
-pub use convex_hull_arcs::arcline_convex_hull;
+pub use area::{
+    AreaError, arcline_area, arcline_area_checked, arcline_area_contributions, arcline_area_green, arcline_centroid,
+    circle_circle_intersection_area, circle_circle_intersection_region, circle_rect_intersection, normalize_ring_orientations,
+    pointline_area, pointline_area_under,
+};
+pub use boolean::pointline_symmetric_difference;
+pub use bounding::{
+    arc_bounding_circle, arc_bounding_rect, arc_tight_bounding_circle, arcline_bounding_rect,
+    arcline_min_area_obb, arcline_support, points_min_enclosing_circle,
+};
+pub use clip::pointline_clip_by_convex;
+pub use collision::convex_polygons_penetration;
+pub use contains::{arcline_contains, pointline_contains, ray_arcline_crossing_counts};
+pub use convex_hull::{points_convex_hull, points_convex_hull_from, points_convex_hull_metrics, pointline_convex_hull};
+pub use convex_hull_arcs::{HullEdgeKind, arcline_convex_hull, arcline_convex_hull_tagged, arclines_convex_hull};
+#[cfg(feature = "hull-debug")]
+pub use convex_hull_arcs::arcline_convex_hull_debug;
+pub use fillet::fillet_arcs;
+pub use inset::pointline_inset_safe;
+pub use offset::{arcline_buffer, arcline_offset_band_area, pointline_max_inscribed_radius};
 pub use self_intersect::{
     arcline_has_self_intersection, arcline_self_intersections, arcline_self_intersection_status,
     arcline_has_self_intersection_aabb, arcline_self_intersections_aabb,
     SelfIntersectionStatus,
 };
+pub use simplify::arcline_simplify;
+pub use spatial::{HilbertRTreeExt, arcline_arcline_min_distance, hilbert_k_nearest, hilbert_nearest, hilbert_query_point, hilbert_query_rect, hilbert_within_radius};
+pub use subdivision::{HalfEdge, PlanarSubdivision, arclines_overlay};
+pub use tangent::tangent_path_around;
+pub use trapezoid::pointline_trapezoidal_decomposition;
+pub use visibility::pointline_visibility;
+pub use voronoi::{pointline_medial_axis, points_largest_empty_circle, points_voronoi};
 //pub use tangent::{tangent_arc_arc, TangentArcArc};
 
 /// Checks if a polygon defined by points is convex.
@@ -74,9 +112,145 @@ pub fn is_convex_pointline(points: &Pointline) -> bool {
     true
 }
 
+/// Checks if a polygon is monotone with respect to a direction.
+///
+/// A simple polygon is monotone with respect to `axis` when its boundary splits
+/// into two chains whose vertices are ordered along `axis`, so that every line
+/// perpendicular to `axis` meets the polygon in at most one segment. Such polygons
+/// can be triangulated in linear time.
+///
+/// The vertices are projected on `axis` and the polygon is monotone when, going
+/// around the boundary, the projections change direction at most twice (once
+/// at the minimum and once at the maximum). Edges perpendicular to `axis` do
+/// not change direction. The polygon is assumed to be simple.
+///
+/// # Arguments
+///
+/// * `poly` - The polygon vertices in order, implicitly closed
+/// * `axis` - The direction (does not need to be normalized)
+///
+/// # Returns
+///
+/// `true` if the polygon is monotone, `false` otherwise, for fewer than 3 vertices
+/// or a zero `axis`
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // A notch from the top keeps the polygon x-monotone, but not y-monotone
+/// let notched = vec![
+///     point(0.0, 0.0),
+///     point(4.0, 0.0),
+///     point(4.0, 3.0),
+///     point(2.0, 1.0),
+///     point(0.0, 3.0),
+/// ];
+/// assert!(pointline_is_monotone(&notched, point(1.0, 0.0)));
+/// assert!(!pointline_is_monotone(&notched, point(0.0, 1.0)));
+/// ```
+#[must_use]
+pub fn pointline_is_monotone(poly: &Pointline, axis: Point) -> bool {
+    let n = poly.len();
+    if n < 3 || axis.dot(axis) == 0.0 {
+        return false;
+    }
+
+    // Directions of the edges along the axis, perpendicular edges skipped
+    let signs: Vec<bool> = (0..n)
+        .filter_map(|i| {
+            let step = (poly[(i + 1) % n] - poly[i]).dot(axis);
+            (step != 0.0).then_some(step > 0.0)
+        })
+        .collect();
+    let m = signs.len();
+    let changes = (0..m).filter(|&i| signs[i] != signs[(i + 1) % m]).count();
+    changes <= 2
+}
+
+/// Checks if a closed arcline bounds a convex region.
+///
+/// The arcline is convex when every arc is convex relative to the path
+/// (traversed forward, see [`is_arc_convex`]) and all joints between consecutive
+/// elements turn in the same direction. Tangential joins (e.g. rounded corners) are allowed,
+/// while cusps (the path reversing at a joint) are not. The total turning (joint turns
+/// plus arc sweeps) must also be one full turn, which rejects self-intersecting loops
+/// such as a pentagram whose turns all have the same sign.
+///
+/// # Arguments
+///
+/// * `arcs` - A closed arcline (last point connects back to first)
+///
+/// # Returns
+///
+/// `true` if the arcline is convex, `false` otherwise or for an empty arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::arcline_is_convex;
+///
+/// let half_disc = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+/// ];
+/// assert!(arcline_is_convex(&half_disc));
+/// ```
+#[must_use]
+pub fn arcline_is_convex(arcs: &Arcline) -> bool {
+    if arcs.is_empty() {
+        return false;
+    }
+
+    let n = arcs.len();
+    // Arcs turn left (CCW) when traversed forward
+    let mut sign = 0;
+    // Signed total turning of the path
+    let mut turning = 0.0;
+
+    for i in 0..n {
+        let arc = arcs[i];
+        if arc.is_arc() {
+            if !is_arc_convex(arcs, i) {
+                return false;
+            }
+            if sign < 0 {
+                return false;
+            }
+            sign = 1;
+            turning += arc.sweep_angle();
+        }
+
+        // Turn at the joint between this element and the next one
+        let next = arcs[(i + 1) % n];
+        let dir_out = arc.tangents()[1];
+        let dir_in = -next.tangents()[0];
+        let cross = dir_out.perp(dir_in);
+        turning += cross.atan2(dir_out.dot(dir_in));
+        if cross.abs() < GEOMETRIC_EPSILON {
+            if dir_out.dot(dir_in) < 0.0 {
+                // Path reverses at the joint
+                return false;
+            }
+            continue;
+        }
+        let current_sign = if cross > 0.0 { 1 } else { -1 };
+        if sign == 0 {
+            sign = current_sign;
+        } else if sign != current_sign {
+            return false;
+        }
+    }
+
+    (turning.abs() - TAU).abs() < 1e-9
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::f64::consts::PI;
 
     #[test]
     fn test_is_convex_pointline_square() {
@@ -136,4 +310,163 @@ mod tests {
         ];
         assert!(is_convex_pointline(&polygon));
     }
+
+    #[test]
+    fn test_pointline_is_monotone_x_monotone() {
+        // Zigzag top and bottom chains, both going steadily along x
+        let poly = vec![
+            point(0.0, 0.0),
+            point(1.0, -1.0),
+            point(2.0, 0.5),
+            point(3.0, -0.5),
+            point(4.0, 0.0),
+            point(3.0, 2.0),
+            point(2.0, 1.0),
+            point(1.0, 3.0),
+        ];
+        assert!(pointline_is_monotone(&poly, point(1.0, 0.0)));
+        // Orientation and axis sign do not matter
+        let reversed: Pointline = poly.iter().rev().copied().collect();
+        assert!(pointline_is_monotone(&reversed, point(-2.0, 0.0)));
+        assert!(!pointline_is_monotone(&poly, point(0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_pointline_is_monotone_non_monotone() {
+        // U shape: a vertical line through both arms meets it twice
+        let u_shape = vec![
+            point(0.0, 0.0),
+            point(3.0, 0.0),
+            point(3.0, 3.0),
+            point(2.0, 3.0),
+            point(2.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 3.0),
+            point(0.0, 3.0),
+        ];
+        assert!(!pointline_is_monotone(&u_shape, point(0.0, 1.0)));
+        // Spiral-like polygon, not monotone along any axis
+        let spiral = vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 4.0),
+            point(1.0, 4.0),
+            point(1.0, 2.0),
+            point(2.0, 2.0),
+            point(2.0, 3.0),
+            point(3.0, 3.0),
+            point(3.0, 1.0),
+            point(0.0, 1.0),
+        ];
+        assert!(!pointline_is_monotone(&spiral, point(1.0, 0.0)));
+        assert!(!pointline_is_monotone(&spiral, point(0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_pointline_is_monotone_degenerate() {
+        let square = vec![point(0.0, 0.0), point(1.0, 0.0), point(1.0, 1.0), point(0.0, 1.0)];
+        assert!(pointline_is_monotone(&square, point(1.0, 0.0)));
+        assert!(pointline_is_monotone(&square, point(1.0, 1.0)));
+        assert!(!pointline_is_monotone(&square, point(0.0, 0.0)));
+        assert!(!pointline_is_monotone(&vec![point(0.0, 0.0), point(1.0, 0.0)], point(1.0, 0.0)));
+    }
+
+    fn rounded_rectangle() -> Arcline {
+        vec![
+            arcseg(point(0.5, 0.0), point(3.5, 0.0)),
+            arc(point(3.5, 0.0), point(4.0, 0.5), point(3.5, 0.5), 0.5),
+            arcseg(point(4.0, 0.5), point(4.0, 1.5)),
+            arc(point(4.0, 1.5), point(3.5, 2.0), point(3.5, 1.5), 0.5),
+            arcseg(point(3.5, 2.0), point(0.5, 2.0)),
+            arc(point(0.5, 2.0), point(0.0, 1.5), point(0.5, 1.5), 0.5),
+            arcseg(point(0.0, 1.5), point(0.0, 0.5)),
+            arc(point(0.0, 0.5), point(0.5, 0.0), point(0.5, 0.5), 0.5),
+        ]
+    }
+
+    #[test]
+    fn test_arcline_is_convex_empty() {
+        let arcs: Arcline = vec![];
+        assert!(!arcline_is_convex(&arcs));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_disc_of_arcs() {
+        let c = point(0.0, 0.0);
+        let disc = vec![
+            arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0),
+            arc(point(0.0, 1.0), point(-1.0, 0.0), c, 1.0),
+            arc(point(-1.0, 0.0), point(0.0, -1.0), c, 1.0),
+            arc(point(0.0, -1.0), point(1.0, 0.0), c, 1.0),
+        ];
+        assert!(arcline_is_convex(&disc));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_full_circle() {
+        let full = vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)];
+        assert!(arcline_is_convex(&full));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_rounded_rectangle() {
+        assert!(arcline_is_convex(&rounded_rectangle()));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_square_segments() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+        ];
+        assert!(arcline_is_convex(&square));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_concave_arc() {
+        // Square with the top edge replaced by an inward-bulging arc
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arc_from_bulge(point(2.0, 2.0), point(0.0, 2.0), -0.5),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert!(!arcline_is_convex(&arcs));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_reflex_vertex() {
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert!(!arcline_is_convex(&arcs));
+    }
+
+    #[test]
+    fn test_arcline_is_convex_pentagram() {
+        // Every joint turns left, but the loop winds twice
+        let v: Vec<Point> = (0..5)
+            .map(|k| {
+                let t = PI / 2.0 + 4.0 * PI * k as f64 / 5.0;
+                point(t.cos(), t.sin())
+            })
+            .collect();
+        let star: Arcline = (0..5).map(|k| arcseg(v[k], v[(k + 1) % 5])).collect();
+        assert!(!arcline_is_convex(&star));
+        // Same vertices in pentagon order
+        let v: Vec<Point> = (0..5)
+            .map(|k| {
+                let t = PI / 2.0 + 2.0 * PI * k as f64 / 5.0;
+                point(t.cos(), t.sin())
+            })
+            .collect();
+        let pentagon: Arcline = (0..5).map(|k| arcseg(v[k], v[(k + 1) % 5])).collect();
+        assert!(arcline_is_convex(&pentagon));
+    }
 }
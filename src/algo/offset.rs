@@ -0,0 +1,1045 @@
+//! Offset related algorithms for polygons.
+//!
+//! This module provides functions describing how polygons behave under
+//! offsetting, such as the inward offset distance at which a polygon
+//! collapses or the area swept by an outward offset.
+
+use crate::algo::subdivision::{element_intersections, element_param};
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::f64::consts::SQRT_2;
+
+/// Relative precision (to the shorter bounding box side) of the coarse inscribed radius search.
+const INSCRIBED_PRECISION: f64 = 1e-3;
+
+/// Number of nearest edges used to polish the inscribed circle center.
+const INSCRIBED_POLISH_EDGES: usize = 6;
+
+/// Square cell of the inscribed radius search.
+#[derive(Debug)]
+struct Cell {
+    /// Center of the cell
+    c: Point,
+    /// Half of the cell size
+    h: f64,
+    /// Signed distance from the center to the polygon boundary
+    d: f64,
+    /// Upper bound of the distance to the boundary inside the cell
+    max: f64,
+}
+
+impl Cell {
+    fn new(c: Point, h: f64, poly: &Pointline) -> Self {
+        let d = signed_dist_to_pointline(c, poly);
+        Cell {
+            c,
+            h,
+            d,
+            max: d + h * SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max == other.max
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max.total_cmp(&other.max)
+    }
+}
+
+/// Signed distance from a point to the polygon boundary (positive inside).
+fn signed_dist_to_pointline(p: Point, poly: &Pointline) -> f64 {
+    let n = poly.len();
+    let mut inside = false;
+    let mut min_dist = f64::INFINITY;
+    for i in 0..n {
+        let a = poly[i];
+        let b = poly[(i + 1) % n];
+        // Crossing number with a ray in +x direction
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+        let (dist, _) = dist_point_segment(&p, &segment(a, b));
+        min_dist = min_dist.min(dist);
+    }
+    if inside { min_dist } else { -min_dist }
+}
+
+/// Computes the inward offset distance at which a polygon collapses.
+///
+/// Offsetting a polygon inward by more than this distance leaves nothing,
+/// so the value equals the radius of the largest circle inscribed in the polygon.
+/// It is useful for checking the minimum wall thickness of thin features.
+///
+/// # Arguments
+///
+/// * `poly` - The vertices of a simple polygon (closed implicitly, any orientation)
+///
+/// # Returns
+///
+/// The radius of the largest inscribed circle, or 0.0 for degenerate input
+///
+/// # Algorithm
+///
+/// Pole of inaccessibility search: the bounding box is covered with square cells,
+/// kept in a priority queue ordered by the upper bound of the distance to the boundary
+/// reachable inside the cell. The most promising cell is split into four until
+/// no cell can improve the best distance by more than `1e-3` of the shorter
+/// bounding box side. The found center is then polished by computing, in closed form,
+/// the points equidistant to triples of features (edge lines and vertices)
+/// of the nearest edges.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::offset::pointline_max_inscribed_radius;
+///
+/// // 10 x 2 rectangle collapses at half of the short side
+/// let rect = vec![
+///     point(0.0, 0.0),
+///     point(10.0, 0.0),
+///     point(10.0, 2.0),
+///     point(0.0, 2.0),
+/// ];
+/// let r = pointline_max_inscribed_radius(&rect);
+/// assert!((r - 1.0).abs() < 1e-10);
+/// ```
+#[must_use]
+pub fn pointline_max_inscribed_radius(poly: &Pointline) -> f64 {
+    if poly.len() < 3 {
+        return 0.0;
+    }
+
+    let mut min_x = f64::INFINITY;
+    let mut min_y = f64::INFINITY;
+    let mut max_x = f64::NEG_INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    for p in poly {
+        min_x = min_x.min(p.x);
+        min_y = min_y.min(p.y);
+        max_x = max_x.max(p.x);
+        max_y = max_y.max(p.y);
+    }
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    let cell_size = width.min(height);
+    if cell_size <= 0.0 || !cell_size.is_finite() {
+        return 0.0;
+    }
+    let precision = cell_size * INSCRIBED_PRECISION;
+
+    // Cover the bounding box with initial cells
+    let h = cell_size / 2.0;
+    let mut queue = BinaryHeap::new();
+    let mut x = min_x;
+    while x < max_x {
+        let mut y = min_y;
+        while y < max_y {
+            queue.push(Cell::new(point(x + h, y + h), h, poly));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    // Start with the centroid of the bounding box as first guess
+    let mut best = Cell::new(point(min_x + width / 2.0, min_y + height / 2.0), 0.0, poly);
+
+    while let Some(cell) = queue.pop() {
+        if cell.d > best.d {
+            best = Cell::new(cell.c, 0.0, poly);
+        }
+        // Cell cannot contain a better point
+        if cell.max - best.d <= precision {
+            continue;
+        }
+        let h = cell.h / 2.0;
+        queue.push(Cell::new(cell.c + point(-h, -h), h, poly));
+        queue.push(Cell::new(cell.c + point(h, -h), h, poly));
+        queue.push(Cell::new(cell.c + point(-h, h), h, poly));
+        queue.push(Cell::new(cell.c + point(h, h), h, poly));
+    }
+
+    // Polish the coarse result by solving for the exact equidistant center
+    let polished = polish_inscribed_center(poly, best.c);
+    best.d.max(polished).max(0.0)
+}
+
+/// Boundary feature the inscribed circle can be tangent to.
+#[derive(Debug, Clone, Copy)]
+enum Feature {
+    /// Supporting line of an edge, inward signed distance is `n.dot(p) + c`
+    Line(Point, f64),
+    /// Polygon vertex
+    Vertex(Point),
+}
+
+/// Refines an approximate inscribed circle center.
+///
+/// Candidate centers equidistant to the features (edge lines and vertices) of the
+/// nearest edges are computed in closed form, and the best signed distance
+/// among them is returned.
+fn polish_inscribed_center(poly: &Pointline, p0: Point) -> f64 {
+    let n = poly.len();
+    let orientation = if pointline_area(poly) < 0.0 { -1.0 } else { 1.0 };
+
+    let mut edges: Vec<(f64, usize)> = (0..n)
+        .map(|i| (dist_point_segment(&p0, &segment(poly[i], poly[(i + 1) % n])).0, i))
+        .collect();
+    edges.sort_by(|e0, e1| e0.0.total_cmp(&e1.0));
+    edges.truncate(INSCRIBED_POLISH_EDGES);
+
+    // Features in coordinates relative to p0
+    let mut features = Vec::with_capacity(3 * edges.len());
+    let mut vertices: Vec<Point> = Vec::with_capacity(2 * edges.len());
+    for &(_, i) in &edges {
+        let a = poly[i] - p0;
+        let b = poly[(i + 1) % n] - p0;
+        let (dir, len) = (b - a).normalize(false);
+        if len > 0.0 {
+            let normal = point(-dir.y, dir.x) * orientation;
+            features.push(Feature::Line(normal, -normal.dot(a)));
+        }
+        for v in [a, b] {
+            if !vertices.contains(&v) {
+                vertices.push(v);
+                features.push(Feature::Vertex(v));
+            }
+        }
+    }
+
+    let mut candidates = Vec::new();
+    let m = features.len();
+    for i in 0..m {
+        for j in (i + 1)..m {
+            // Center line between two opposite parallel edges
+            if let (Feature::Line(n0, c0), Feature::Line(n1, c1)) = (features[i], features[j])
+                && (n0 + n1).norm() < 1e-12
+            {
+                candidates.push(n0 * ((c0 + c1) / 2.0 - c0));
+            }
+            for k in (j + 1)..m {
+                candidates.extend(equidistant_centers([features[i], features[j], features[k]]));
+            }
+        }
+    }
+
+    candidates
+        .iter()
+        .map(|c| signed_dist_to_pointline(p0 + *c, poly))
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// Computes the centers equidistant (at positive distance) to three features.
+fn equidistant_centers(features: [Feature; 3]) -> Vec<Point> {
+    let mut lines = Vec::with_capacity(3);
+    let mut vertices = Vec::with_capacity(3);
+    for f in features {
+        match f {
+            Feature::Line(n, c) => lines.push((n, c)),
+            Feature::Vertex(v) => vertices.push(v),
+        }
+    }
+
+    match lines.len() {
+        3 => {
+            // (n1 - n0).p = c0 - c1, (n2 - n0).p = c0 - c2
+            let (n0, c0) = lines[0];
+            let d1 = lines[1].0 - n0;
+            let d2 = lines[2].0 - n0;
+            let det = d1.perp(d2);
+            if det.abs() < 1e-12 {
+                return Vec::new();
+            }
+            let r1 = c0 - lines[1].1;
+            let r2 = c0 - lines[2].1;
+            let p = point(r1 * d2.y - r2 * d1.y, d1.x * r2 - d2.x * r1) / det;
+            if n0.dot(p) + c0 > 0.0 { vec![p] } else { Vec::new() }
+        }
+        2 => {
+            // Bisector of the two lines: (n0 - n1).p = c1 - c0
+            let (n0, c0) = lines[0];
+            let (n1, c1) = lines[1];
+            let (m, len) = (n0 - n1).normalize(false);
+            if len < 1e-12 {
+                return Vec::new();
+            }
+            let q0 = m * ((c1 - c0) / len);
+            centers_on_line(q0, point(-m.y, m.x), n0, c0, vertices[0])
+        }
+        1 => {
+            // Bisector of the two vertices: (v1 - v0).p = (|v1|^2 - |v0|^2) / 2
+            let (n0, c0) = lines[0];
+            let v0 = vertices[0];
+            let v1 = vertices[1];
+            let (m, len) = (v1 - v0).normalize(false);
+            if len < 1e-12 {
+                return Vec::new();
+            }
+            let q0 = m * ((v1.dot(v1) - v0.dot(v0)) / (2.0 * len));
+            centers_on_line(q0, point(-m.y, m.x), n0, c0, v0)
+        }
+        _ => {
+            let b = vertices[1] - vertices[0];
+            let c = vertices[2] - vertices[0];
+            let d = 2.0 * b.perp(c);
+            if d.abs() < 1e-12 {
+                return Vec::new();
+            }
+            let bb = b.dot(b);
+            let cc = c.dot(c);
+            vec![vertices[0] + point(c.y * bb - b.y * cc, b.x * cc - c.x * bb) / d]
+        }
+    }
+}
+
+/// Computes points `p = q0 + s * u` (`u` unit) where the distance to the line
+/// `n.dot(p) + c` equals the distance to the vertex `v`.
+fn centers_on_line(q0: Point, u: Point, n: Point, c: f64, v: Point) -> Vec<Point> {
+    // n.p + c = alpha + beta * s == |q0 + s * u - v|
+    let alpha = n.dot(q0) + c;
+    let beta = n.dot(u);
+    let w = q0 - v;
+    let qa = 1.0 - beta * beta;
+    let qb = 2.0 * (u.dot(w) - alpha * beta);
+    let qc = w.dot(w) - alpha * alpha;
+
+    let mut roots = Vec::with_capacity(2);
+    if qa.abs() < 1e-12 {
+        if qb.abs() > 1e-12 {
+            roots.push(-qc / qb);
+        }
+    } else {
+        let disc = qb * qb - 4.0 * qa * qc;
+        if disc >= 0.0 {
+            let sq = disc.sqrt();
+            roots.push((-qb + sq) / (2.0 * qa));
+            roots.push((-qb - sq) / (2.0 * qa));
+        }
+    }
+
+    roots
+        .into_iter()
+        .filter(|s| alpha + beta * s > 0.0)
+        .map(|s| q0 + u * s)
+        .collect()
+}
+
+#[cfg(test)]
+mod test_pointline_max_inscribed_radius {
+    use super::*;
+
+    #[test]
+    fn test_max_inscribed_radius_degenerate() {
+        assert_eq!(pointline_max_inscribed_radius(&vec![]), 0.0);
+        let two = vec![point(0.0, 0.0), point(1.0, 0.0)];
+        assert_eq!(pointline_max_inscribed_radius(&two), 0.0);
+        let collinear = vec![point(0.0, 0.0), point(1.0, 0.0), point(2.0, 0.0)];
+        assert_eq!(pointline_max_inscribed_radius(&collinear), 0.0);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_square() {
+        let square = vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 4.0),
+            point(0.0, 4.0),
+        ];
+        let r = pointline_max_inscribed_radius(&square);
+        assert!((r - 2.0).abs() < 1e-10, "got {}", r);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_thin_rectangle() {
+        let thin = vec![
+            point(0.0, 0.0),
+            point(20.0, 0.0),
+            point(20.0, 0.5),
+            point(0.0, 0.5),
+        ];
+        let r = pointline_max_inscribed_radius(&thin);
+        assert!((r - 0.25).abs() < 1e-10, "got {}", r);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_cw_orientation() {
+        let square_cw = vec![
+            point(0.0, 0.0),
+            point(0.0, 4.0),
+            point(4.0, 4.0),
+            point(4.0, 0.0),
+        ];
+        let r = pointline_max_inscribed_radius(&square_cw);
+        assert!((r - 2.0).abs() < 1e-10, "got {}", r);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_triangle() {
+        // Right triangle with legs 3 and 4: inradius = (3 + 4 - 5) / 2 = 1
+        let triangle = vec![point(0.0, 0.0), point(4.0, 0.0), point(0.0, 3.0)];
+        let r = pointline_max_inscribed_radius(&triangle);
+        assert!((r - 1.0).abs() < 1e-10, "got {}", r);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_l_shape() {
+        // L-shape with 2-wide arms: the largest circle sits in the corner square
+        let l_shape = vec![
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 2.0),
+            point(2.0, 2.0),
+            point(2.0, 10.0),
+            point(0.0, 10.0),
+        ];
+        let r = pointline_max_inscribed_radius(&l_shape);
+        // Circle tangent to both outer sides and the reflex vertex (2, 2)
+        let expected = 2.0 * (2.0 - SQRT_2);
+        assert!((r - expected).abs() < 1e-10, "got {}", r);
+    }
+
+    #[test]
+    fn test_max_inscribed_radius_regular_polygon() {
+        let n = 64;
+        let poly: Pointline = (0..n)
+            .map(|i| {
+                let a = 2.0 * std::f64::consts::PI * (i as f64) / (n as f64);
+                point(3.0 + 2.0 * a.cos(), -1.0 + 2.0 * a.sin())
+            })
+            .collect();
+        let r = pointline_max_inscribed_radius(&poly);
+        let expected = 2.0 * (std::f64::consts::PI / n as f64).cos();
+        assert!((r - expected).abs() < 1e-10, "got {}", r);
+    }
+}
+
+/// Computes the area of the band swept by offsetting a closed arcline outward.
+///
+/// The band lies between the original boundary and its outward offset by `distance`.
+/// It is computed analytically from the elements and the joints between them:
+///
+/// - each element contributes `length * distance`,
+/// - each arc additionally contributes `±sweep * distance² / 2`
+///   (positive for convex arcs, negative for concave arcs, see [`is_arc_convex`]),
+/// - each convex corner with turning angle `θ` adds the round wedge `θ * distance² / 2`,
+/// - each reflex corner with turning angle `-θ` removes the overlap `distance² * tan(θ / 2)`.
+///
+/// For a convex region this reduces to `perimeter * distance + π * distance²`.
+/// The result is exact as long as the offset does not self-intersect, i.e. `distance`
+/// is smaller than the radius of every concave arc and than the local feature size.
+///
+/// # Arguments
+///
+/// * `arcs` - A closed CCW arcline (last point connects back to first)
+/// * `distance` - The outward offset distance (non-negative)
+///
+/// # Returns
+///
+/// The area of the offset band, or 0.0 for an empty arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::offset::arcline_offset_band_area;
+///
+/// // Unit square offset by 1: 4 side rectangles plus 4 quarter discs
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let area = arcline_offset_band_area(&square, 1.0);
+/// assert!((area - (4.0 + std::f64::consts::PI)).abs() < 1e-10);
+/// ```
+#[must_use]
+pub fn arcline_offset_band_area(arcs: &Arcline, distance: f64) -> f64 {
+    let n = arcs.len();
+    if n == 0 {
+        return 0.0;
+    }
+
+    let d2 = distance * distance;
+    let mut area = 0.0;
+    // Travel directions at the start and at the end of each element
+    let mut start_dirs = Vec::with_capacity(n);
+    let mut end_dirs = Vec::with_capacity(n);
+
+    for (i, arc) in arcs.iter().enumerate() {
+        let tangents = arc.tangents();
+        if arc.is_seg() {
+            area += (arc.b - arc.a).norm() * distance;
+            start_dirs.push(-tangents[0]);
+            end_dirs.push(tangents[1]);
+            continue;
+        }
+
+        let sweep = arc.sweep_angle();
+        area += sweep * arc.r * distance;
+        if is_arc_convex(arcs, i) {
+            area += sweep * d2 / 2.0;
+            start_dirs.push(-tangents[0]);
+            end_dirs.push(tangents[1]);
+        } else {
+            // Arc is traversed from b to a
+            area -= sweep * d2 / 2.0;
+            start_dirs.push(-tangents[1]);
+            end_dirs.push(tangents[0]);
+        }
+    }
+
+    for i in 0..n {
+        let dir_out = end_dirs[i];
+        let dir_in = start_dirs[(i + 1) % n];
+        let turn = dir_out.perp(dir_in).atan2(dir_out.dot(dir_in));
+        if turn > 0.0 {
+            area += turn * d2 / 2.0;
+        } else {
+            area -= d2 * (-turn / 2.0).tan();
+        }
+    }
+
+    area
+}
+
+#[cfg(test)]
+mod test_arcline_offset_band_area {
+    use super::*;
+    use std::f64::consts::PI;
+
+    // Circle of radius r at the origin made of four quarter arcs
+    fn circle_of_arcs(r: f64) -> Arcline {
+        let c = point(0.0, 0.0);
+        vec![
+            arc(point(r, 0.0), point(0.0, r), c, r),
+            arc(point(0.0, r), point(-r, 0.0), c, r),
+            arc(point(-r, 0.0), point(0.0, -r), c, r),
+            arc(point(0.0, -r), point(r, 0.0), c, r),
+        ]
+    }
+
+    #[test]
+    fn test_offset_band_area_empty() {
+        let empty: Arcline = vec![];
+        assert_eq!(arcline_offset_band_area(&empty, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_offset_band_area_circle() {
+        let r = 3.0;
+        for d in [0.1, 0.5, 2.0] {
+            let area = arcline_offset_band_area(&circle_of_arcs(r), d);
+            let annulus = 2.0 * PI * r * d + PI * d * d;
+            assert!((area - annulus).abs() < 1e-10, "d = {}: {} vs {}", d, area, annulus);
+        }
+    }
+
+    #[test]
+    fn test_offset_band_area_full_circle() {
+        let arcs = vec![arc(point(2.0, 0.0), point(2.0, 0.0), point(0.0, 0.0), 2.0)];
+        let area = arcline_offset_band_area(&arcs, 0.5);
+        assert!((area - (2.0 * PI * 2.0 * 0.5 + PI * 0.25)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_offset_band_area_rounded_rectangle() {
+        // 4 x 2 rectangle with corners rounded by radius 0.5
+        let r = 0.5;
+        let arcs = vec![
+            arcseg(point(r, 0.0), point(4.0 - r, 0.0)),
+            arc(point(4.0 - r, 0.0), point(4.0, r), point(4.0 - r, r), r),
+            arcseg(point(4.0, r), point(4.0, 2.0 - r)),
+            arc(point(4.0, 2.0 - r), point(4.0 - r, 2.0), point(4.0 - r, 2.0 - r), r),
+            arcseg(point(4.0 - r, 2.0), point(r, 2.0)),
+            arc(point(r, 2.0), point(0.0, 2.0 - r), point(r, 2.0 - r), r),
+            arcseg(point(0.0, 2.0 - r), point(0.0, r)),
+            arc(point(0.0, r), point(r, 0.0), point(r, r), r),
+        ];
+        let d = 0.25;
+        let perimeter = 2.0 * (3.0 + 1.0) + 2.0 * PI * r;
+        let area = arcline_offset_band_area(&arcs, d);
+        assert!((area - (perimeter * d + PI * d * d)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_offset_band_area_l_shape() {
+        // L-shape: 2 x 2 square with the top right unit square removed
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 1.0)),
+            arcseg(point(2.0, 1.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(1.0, 2.0)),
+            arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let d = 0.25;
+        // Five convex corners add quarter discs, the reflex corner removes a d x d square
+        let expected = 8.0 * d + 5.0 * PI / 4.0 * d * d - d * d;
+        let area = arcline_offset_band_area(&arcs, d);
+        assert!((area - expected).abs() < 1e-10, "{} vs {}", area, expected);
+    }
+
+    #[test]
+    fn test_offset_band_area_concave_arc() {
+        // Square with the top edge replaced by an inward-bulging half circle
+        let concave = arc(point(0.5, 2.0), point(1.5, 2.0), point(1.0, 2.0), 0.5);
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(1.5, 2.0)),
+            concave,
+            arcseg(point(0.5, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let d = 0.2;
+        // Straight parts, the annular half ring (radius 0.5 → 0.3) and quarter discs
+        // at the four square corners and at the two joints with the half circle
+        let straight = 2.0 + 2.0 + 0.5 + 0.5 + 2.0;
+        let ring = PI / 2.0 * (0.5 * 0.5 - 0.3 * 0.3);
+        let expected = straight * d + ring + 6.0 * PI / 4.0 * d * d;
+        let area = arcline_offset_band_area(&arcs, d);
+        assert!((area - expected).abs() < 1e-10, "{} vs {}", area, expected);
+    }
+}
+
+/// Relative tolerance (to the buffer distance) used when trimming a raw offset.
+const BUFFER_TOLERANCE: f64 = 1e-7;
+
+/// Tolerance for merging nearby split points of a raw offset.
+const BUFFER_VERTEX_TOLERANCE: f64 = 1e-9;
+
+/// Element of a raw offset or a piece of one, with its traversal direction.
+#[derive(Debug, Clone, Copy)]
+struct OffsetPiece {
+    /// Geometry, arcs stored CCW as usual
+    arc: Arc,
+    /// True if traversed from `a` to `b`
+    forward: bool,
+    /// Index of the element of the original arcline the piece comes from
+    source: usize,
+}
+
+impl OffsetPiece {
+    fn start(&self) -> Point {
+        if self.forward { self.arc.a } else { self.arc.b }
+    }
+
+    fn end(&self) -> Point {
+        if self.forward { self.arc.b } else { self.arc.a }
+    }
+}
+
+/// Buffers a closed arcline, offsetting it and cleaning up the result.
+///
+/// This is a one-call pipeline of four steps:
+///
+/// 1. Raw offset: every segment is moved by `distance` along its outward normal
+///    and every arc keeps its center, its radius grown or shrunk by `distance`.
+///    An arc shrunk past its center is turned inside out. Where the
+///    offsets of neighbouring elements move apart, at corners turning away from
+///    the offset side, they are joined by an arc of radius `|distance|` around the
+///    corner; other gaps are closed by two segments through the corner.
+/// 2. Splitting: the raw offset is split at all its self-intersections.
+/// 3. Trimming: pieces closer than `|distance|` to the original arcline, or on the
+///    wrong side of it, are dropped. This removes the loops formed at reflex
+///    corners and across narrow parts.
+/// 4. Merging: the remaining pieces are chained into a closed loop, and runs of
+///    collinear or co-circular pieces are merged by [`arcline_simplify`] with
+///    `max_sagitta` as the tolerance.
+///
+/// If trimming leaves several loops, e.g. when shrinking splits the region in
+/// two, the one with the largest area is returned; holes closed off when growing
+/// a region are dropped in the same way.
+///
+/// # Arguments
+///
+/// * `arcs` - A closed CCW arcline (last point connects back to first)
+/// * `distance` - The offset distance, positive outward and negative inward
+/// * `max_sagitta` - The maximum deviation allowed when merging elements
+///
+/// # Returns
+///
+/// The buffered closed CCW arcline, empty for an empty arcline or when the
+/// region vanishes
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::offset::arcline_buffer;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+///     arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+///     arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+/// ];
+/// // Four sides and four rounded corners
+/// let grown = arcline_buffer(&square, 0.5, 1e-9);
+/// assert_eq!(grown.len(), 8);
+/// // A smaller square
+/// let shrunk = arcline_buffer(&square, -0.5, 1e-9);
+/// assert_eq!(shrunk.len(), 4);
+/// assert!((arcline_area(&shrunk) - 1.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn arcline_buffer(arcs: &Arcline, distance: f64, max_sagitta: f64) -> Arcline {
+    if arcs.is_empty() {
+        return Arcline::new();
+    }
+    if distance == 0.0 {
+        return arcline_simplify(arcs, max_sagitta);
+    }
+
+    let raw = raw_offset(arcs, distance);
+    let pieces = split_offset(&raw);
+
+    let tolerance = BUFFER_TOLERANCE * distance.abs();
+    let kept: Vec<OffsetPiece> = pieces
+        .into_iter()
+        .filter(|piece| {
+            let mid = piece.arc.point_at(0.5);
+            let (dist, _, _) = dist_point_arcline_hinted(&mid, arcs, piece.source);
+            dist >= distance.abs() - tolerance && arcline_contains(arcs, mid) == (distance < 0.0)
+        })
+        .collect();
+
+    // Chain the pieces into loops, preferring the pieces that follow in the raw offset
+    let mut used = vec![false; kept.len()];
+    let mut best = (0.0, Arcline::new());
+    for first in 0..kept.len() {
+        if used[first] {
+            continue;
+        }
+        let mut ring = Arcline::new();
+        let mut i = first;
+        loop {
+            used[i] = true;
+            ring.push(kept[i].arc);
+            let end = kept[i].end();
+            if end.close_enough(kept[first].start(), BUFFER_VERTEX_TOLERANCE) {
+                break;
+            }
+            match (1..kept.len())
+                .map(|k| (i + k) % kept.len())
+                .find(|&j| !used[j] && kept[j].start().close_enough(end, BUFFER_VERTEX_TOLERANCE))
+            {
+                Some(j) => i = j,
+                None => {
+                    // Open chain, not part of the boundary
+                    ring.clear();
+                    break;
+                }
+            }
+        }
+        let area = arcline_area_green(&ring);
+        if area > best.0 {
+            best = (area, ring);
+        }
+    }
+
+    arcline_simplify(&best.1, max_sagitta)
+}
+
+// Raw offset of a closed arcline, with joins at the corners, in traversal order.
+fn raw_offset(arcs: &Arcline, distance: f64) -> Vec<OffsetPiece> {
+    let n = arcs.len();
+    // Offset of every element, None when an arc shrinks to its center
+    let mut offsets: Vec<Option<Arc>> = Vec::with_capacity(n);
+    // Traversal direction, start and end points of the offsets and original corners
+    let mut forward = Vec::with_capacity(n);
+    let mut starts = Vec::with_capacity(n);
+    let mut ends = Vec::with_capacity(n);
+    let mut corners = Vec::with_capacity(n);
+    // Travel directions at the start and at the end of each element
+    let mut start_dirs = Vec::with_capacity(n);
+    let mut end_dirs = Vec::with_capacity(n);
+
+    for (i, e) in arcs.iter().enumerate() {
+        let tangents = e.tangents();
+        let convex = e.is_seg() || is_arc_convex(arcs, i);
+        let offset = if e.is_seg() {
+            let (u, _) = (e.b - e.a).normalize(false);
+            let shift = point(u.y, -u.x) * distance;
+            Some(arcseg(e.a + shift, e.b + shift))
+        } else {
+            // A negative radius turns the arc inside out around its center
+            let r = if convex { e.r + distance } else { e.r - distance };
+            let scale = r / e.r;
+            (r.abs() > BUFFER_TOLERANCE * distance.abs())
+                .then(|| arc(e.c + (e.a - e.c) * scale, e.c + (e.b - e.c) * scale, e.c, r.abs()))
+        };
+        let (start, end) = match offset {
+            Some(o) if convex => (o.a, o.b),
+            Some(o) => (o.b, o.a),
+            None => (e.c, e.c),
+        };
+        offsets.push(offset);
+        forward.push(convex);
+        starts.push(start);
+        ends.push(end);
+        if convex {
+            corners.push(e.b);
+            start_dirs.push(-tangents[0]);
+            end_dirs.push(tangents[1]);
+        } else {
+            // Arc is traversed from b to a
+            corners.push(e.a);
+            start_dirs.push(-tangents[1]);
+            end_dirs.push(tangents[0]);
+        }
+    }
+
+    let mut raw = Vec::new();
+    for i in 0..n {
+        if let Some(o) = offsets[i] {
+            raw.push(OffsetPiece {
+                arc: o,
+                forward: forward[i],
+                source: i,
+            });
+        }
+
+        let j = (i + 1) % n;
+        let (from, to, v) = (ends[i], starts[j], corners[i]);
+        if from.close_enough(to, BUFFER_VERTEX_TOLERANCE) {
+            continue;
+        }
+        let turn = end_dirs[i].perp(start_dirs[j]);
+        let on_circle = |p: Point| ((p - v).norm() - distance.abs()).abs() <= BUFFER_TOLERANCE * distance.abs();
+        if turn * distance > 0.0 && on_circle(from) && on_circle(to) {
+            // Round join, CCW around the corner when growing
+            let join = if distance > 0.0 {
+                arc(from, to, v, distance)
+            } else {
+                arc(to, from, v, -distance)
+            };
+            raw.push(OffsetPiece {
+                arc: join,
+                forward: distance > 0.0,
+                source: i,
+            });
+        } else {
+            for (a, b) in [(from, v), (v, to)] {
+                if !a.close_enough(b, BUFFER_VERTEX_TOLERANCE) {
+                    raw.push(OffsetPiece {
+                        arc: arcseg(a, b),
+                        forward: true,
+                        source: i,
+                    });
+                }
+            }
+        }
+    }
+    raw
+}
+
+// Splits the raw offset at all its self-intersections, keeping the traversal order.
+fn split_offset(raw: &[OffsetPiece]) -> Vec<OffsetPiece> {
+    let mut vertices: Vec<Point> = Vec::new();
+    let mut snap = |p: Point| match vertices
+        .iter()
+        .find(|v| v.close_enough(p, BUFFER_VERTEX_TOLERANCE))
+    {
+        Some(&v) => v,
+        None => {
+            vertices.push(p);
+            p
+        }
+    };
+
+    let mut splits: Vec<Vec<(f64, Point)>> = raw
+        .iter()
+        .map(|piece| vec![(0.0, snap(piece.arc.a)), (1.0, snap(piece.arc.b))])
+        .collect();
+    for i in 0..raw.len() {
+        for j in (i + 1)..raw.len() {
+            for p in element_intersections(&raw[i].arc, &raw[j].arc) {
+                let p = snap(p);
+                splits[i].push((element_param(&raw[i].arc, p), p));
+                splits[j].push((element_param(&raw[j].arc, p), p));
+            }
+        }
+    }
+
+    let mut pieces = Vec::new();
+    for (piece, split) in raw.iter().zip(&mut splits) {
+        split.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut parts: Vec<OffsetPiece> = split
+            .windows(2)
+            .filter(|w| {
+                let ((t0, p0), (t1, p1)) = (w[0], w[1]);
+                if piece.arc.is_seg() {
+                    !p0.close_enough(p1, BUFFER_VERTEX_TOLERANCE)
+                } else {
+                    (t1 - t0) * piece.arc.sweep_angle() * piece.arc.r > BUFFER_VERTEX_TOLERANCE
+                }
+            })
+            .map(|w| {
+                let ((_, p0), (_, p1)) = (w[0], w[1]);
+                let arc = if piece.arc.is_seg() {
+                    arcseg(p0, p1)
+                } else {
+                    arc(p0, p1, piece.arc.c, piece.arc.r)
+                };
+                OffsetPiece { arc, ..*piece }
+            })
+            .collect();
+        if !piece.forward {
+            parts.reverse();
+        }
+        pieces.extend(parts);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test_arcline_buffer {
+    use super::*;
+    use std::f64::consts::PI;
+
+    // 4 x 2 rectangle with corners rounded by radius 0.5
+    fn rounded_rectangle() -> Arcline {
+        let r = 0.5;
+        vec![
+            arcseg(point(r, 0.0), point(4.0 - r, 0.0)),
+            arc(point(4.0 - r, 0.0), point(4.0, r), point(4.0 - r, r), r),
+            arcseg(point(4.0, r), point(4.0, 2.0 - r)),
+            arc(point(4.0, 2.0 - r), point(4.0 - r, 2.0), point(4.0 - r, 2.0 - r), r),
+            arcseg(point(4.0 - r, 2.0), point(r, 2.0)),
+            arc(point(r, 2.0), point(0.0, 2.0 - r), point(r, 2.0 - r), r),
+            arcseg(point(0.0, 2.0 - r), point(0.0, r)),
+            arc(point(0.0, r), point(r, 0.0), point(r, r), r),
+        ]
+    }
+
+    // L-shape: 2 x 2 square with the top right unit square removed
+    fn l_shape() -> Arcline {
+        vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 1.0)),
+            arcseg(point(2.0, 1.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(1.0, 2.0)),
+            arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_buffer_empty() {
+        assert!(arcline_buffer(&vec![], 1.0, 1e-9).is_empty());
+    }
+
+    #[test]
+    fn test_buffer_rounded_rectangle_outward() {
+        let arcs = rounded_rectangle();
+        let d = 0.25;
+        let buffered = arcline_buffer(&arcs, d, 1e-9);
+        assert_eq!(arcline_is_valid(&buffered), ArclineValidation::Valid);
+        assert!(!arcline_has_self_intersection(&buffered));
+        // The corner arcs grow to radius 0.75, no joins are needed
+        assert_eq!(buffered.len(), 8);
+        let area = arcline_area_green(&buffered);
+        let expected = arcline_area_green(&arcs) + arcline_offset_band_area(&arcs, d);
+        assert!(area > arcline_area_green(&arcs));
+        assert!((area - expected).abs() < 1e-9, "{} vs {}", area, expected);
+        for e in &buffered {
+            let (dist, _, _) = dist_point_arcline_hinted(&e.point_at(0.5), &arcs, 0);
+            assert!((dist - d).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_buffer_rounded_rectangle_inward() {
+        // The corner arcs collapse to their centers, leaving a 3 x 1 rectangle
+        let buffered = arcline_buffer(&rounded_rectangle(), -0.5, 1e-9);
+        assert_eq!(buffered.len(), 4);
+        assert!(buffered.iter().all(|e| e.is_seg()));
+        assert!((arcline_area_green(&buffered) - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_buffer_reflex_corner() {
+        // The loop formed at the reflex corner is trimmed away
+        let arcs = l_shape();
+        let d = 0.25;
+        let buffered = arcline_buffer(&arcs, d, 1e-9);
+        assert!(!arcline_has_self_intersection(&buffered));
+        let expected = 3.0 + arcline_offset_band_area(&arcs, d);
+        assert!((arcline_area_green(&buffered) - expected).abs() < 1e-9);
+
+        // Shrinking: the reflex corner becomes a quarter circle of radius 0.25
+        let shrunk = arcline_buffer(&arcs, -d, 1e-9);
+        assert!(!arcline_has_self_intersection(&shrunk));
+        let expected = 1.25 + (1.0 - PI / 4.0) * d * d;
+        assert!((arcline_area_green(&shrunk) - expected).abs() < 1e-9, "{}", arcline_area_green(&shrunk));
+    }
+
+    #[test]
+    fn test_buffer_concave_arc() {
+        // Square with the top edge replaced by an inward-bulging half circle
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(1.5, 2.0)),
+            arc(point(0.5, 2.0), point(1.5, 2.0), point(1.0, 2.0), 0.5),
+            arcseg(point(0.5, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let buffered = arcline_buffer(&arcs, 0.2, 1e-9);
+        assert!(!arcline_has_self_intersection(&buffered));
+        let expected = 4.0 - PI / 8.0 + arcline_offset_band_area(&arcs, 0.2);
+        assert!((arcline_area_green(&buffered) - expected).abs() < 1e-9);
+
+        // The bite is filled when the half circle is shrunk past its center. Above it,
+        // the 1 x 0.6 notch between the discs around the bite corners stays uncovered.
+        let filled = arcline_buffer(&arcs, 0.6, 1e-9);
+        assert!(!arcline_has_self_intersection(&filled));
+        let (r, half) = (0.6_f64, 0.5_f64);
+        // Upper half of the lens where the two discs overlap
+        let lens = r * r * (half / r).acos() - half * (r * r - half * half).sqrt();
+        let notch = r - (PI * r * r / 2.0 - lens);
+        let expected = 4.0 + 8.0 * r + PI * r * r - notch;
+        assert!((arcline_area_green(&filled) - expected).abs() < 1e-9, "{}", arcline_area_green(&filled));
+    }
+
+    #[test]
+    fn test_buffer_merges_collinear_pieces() {
+        // Collinear bottom edge in two pieces
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let shrunk = arcline_buffer(&square, -0.5, 1e-9);
+        assert_eq!(shrunk.len(), 4);
+        assert!((arcline_area_green(&shrunk) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_buffer_vanishing_region() {
+        let disc = vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)];
+        assert!(arcline_buffer(&disc, -1.5, 1e-9).is_empty());
+        let grown = arcline_buffer(&disc, 0.5, 1e-9);
+        assert_eq!(grown.len(), 1);
+        assert!((arcline_area_green(&grown) - PI * 2.25).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,275 @@
+//! Simplification of arclines.
+//!
+//! This module reduces the number of elements of an arcline while keeping it
+//! made of arcs and segments.
+
+use std::f64::consts::PI;
+
+use crate::algo::bounding::circumcircle;
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+
+/// Parameters sampled along each element when measuring deviations.
+const RUN_SAMPLES: usize = 4;
+/// Parameters sampled along a merged candidate when measuring deviations.
+const CANDIDATE_SAMPLES: usize = 8;
+
+/// Simplifies an arcline, merging runs of elements into single segments or arcs.
+///
+/// The arcline is walked from the first element, growing a run of consecutive
+/// elements while it can be replaced by one element from the start of the run
+/// to its end:
+/// - a segment, when the run is nearly collinear,
+/// - otherwise an arc through the run start, end and middle joint, when the
+///   run is nearly co-circular and the arc spans at most a half circle.
+///
+/// A replacement is accepted when the deviation, measured both from sample
+/// points of the run to the replacement and from sample points of the
+/// replacement to the run, is less than `tolerance`. Elements that cannot be
+/// merged with their neighbours are kept unchanged, so genuine curvature is
+/// preserved. Arcs traversed clockwise are stored reversed, as in the input.
+///
+/// The arcline is treated as open: the last and first elements of a closed
+/// arcline are never merged.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to simplify
+/// * `tolerance` - The maximum allowed deviation of the result
+///
+/// # Returns
+///
+/// The simplified arcline, connecting the same start and end points
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::simplify::arcline_simplify;
+///
+/// // Three collinear segments and a quarter circle split in two
+/// let c = point(3.0, 1.0);
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(3.0, 0.0)),
+///     arc(point(3.0, 0.0), c + point(0.5_f64.sqrt(), -0.5_f64.sqrt()), c, 1.0),
+///     arc(c + point(0.5_f64.sqrt(), -0.5_f64.sqrt()), point(4.0, 1.0), c, 1.0),
+/// ];
+/// let simple = arcline_simplify(&arcs, 1e-6);
+/// assert_eq!(simple.len(), 2);
+/// assert!(simple[0].is_seg());
+/// assert!(simple[1].is_arc());
+/// ```
+#[must_use]
+pub fn arcline_simplify(arcs: &Arcline, tolerance: f64) -> Arcline {
+    let n = arcs.len();
+    let mut result = Vec::with_capacity(n);
+    let mut start = 0;
+    while start < n {
+        let mut merged = arcs[start];
+        let mut end = start + 1;
+        while end < n {
+            match merge_run(&arcs[start..=end], tolerance) {
+                Some(candidate) => {
+                    merged = candidate;
+                    end += 1;
+                }
+                None => break,
+            }
+        }
+        result.push(merged);
+        start = end;
+    }
+    result
+}
+
+// Single element replacing the run within tolerance, if any
+fn merge_run(run: &[Arc], tolerance: f64) -> Option<Arc> {
+    let ends = traversal_ends(run);
+    let from = ends[0].0;
+    let to = ends[ends.len() - 1].1;
+    if from.close_enough(to, GEOMETRIC_EPSILON) {
+        return None;
+    }
+
+    let segment = arcseg(from, to);
+    if deviation(run, &segment) < tolerance {
+        return Some(segment);
+    }
+
+    let middle = ends[ends.len() / 2].0;
+    let circle = circumcircle(from, middle, to)?;
+    let ccw = points_order(from, middle, to) > 0.0;
+    let candidate = if ccw {
+        arc(from, to, circle.c, circle.r)
+    } else {
+        arc(to, from, circle.c, circle.r)
+    };
+    if candidate.sweep_angle() > PI + GEOMETRIC_EPSILON {
+        return None;
+    }
+    (deviation(run, &candidate) < tolerance).then_some(candidate)
+}
+
+// Start and end points of the run elements in traversal order
+fn traversal_ends(run: &[Arc]) -> Vec<(Point, Point)> {
+    let first = run[0];
+    let next = run[1];
+    let gap = |p: Point| (p - next.a).norm().min((p - next.b).norm());
+    let mut ends = if gap(first.b) <= gap(first.a) {
+        vec![(first.a, first.b)]
+    } else {
+        vec![(first.b, first.a)]
+    };
+    for element in &run[1..] {
+        let prev = ends[ends.len() - 1].1;
+        if (element.a - prev).norm() <= (element.b - prev).norm() {
+            ends.push((element.a, element.b));
+        } else {
+            ends.push((element.b, element.a));
+        }
+    }
+    ends
+}
+
+// Largest distance between sample points of the run and the candidate, both ways
+fn deviation(run: &[Arc], candidate: &Arc) -> f64 {
+    let mut max: f64 = 0.0;
+    for element in run {
+        for k in 0..=RUN_SAMPLES {
+            let p = element.point_at(k as f64 / RUN_SAMPLES as f64);
+            max = max.max(dist_to_element(&p, candidate));
+        }
+    }
+    for k in 1..CANDIDATE_SAMPLES {
+        let p = candidate.point_at(k as f64 / CANDIDATE_SAMPLES as f64);
+        let nearest = run
+            .iter()
+            .map(|element| dist_to_element(&p, element))
+            .fold(f64::INFINITY, f64::min);
+        max = max.max(nearest);
+    }
+    max
+}
+
+fn dist_to_element(p: &Point, element: &Arc) -> f64 {
+    if element.is_seg() {
+        dist_point_segment(p, &segment(element.a, element.b)).0
+    } else {
+        match dist_point_arc(p, element) {
+            DistPointArcConfig::OnePoint(dist, _) | DistPointArcConfig::Equidistant(dist, _) => dist,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_simplify {
+    use super::*;
+
+    // Closed polygon approximating a circle with `count` chords
+    fn polygon_circle(c: Point, r: f64, count: usize) -> Arcline {
+        let vertex = |k: usize| {
+            let t = 2.0 * PI * k as f64 / count as f64;
+            c + point(t.cos(), t.sin()) * r
+        };
+        (0..count).map(|k| arcseg(vertex(k), vertex((k + 1) % count))).collect()
+    }
+
+    fn max_deviation_from_circle(arcs: &Arcline, c: Point, r: f64) -> f64 {
+        let mut max: f64 = 0.0;
+        for element in arcs {
+            for k in 0..=16 {
+                let p = element.point_at(k as f64 / 16.0);
+                max = max.max(((p - c).norm() - r).abs());
+            }
+        }
+        max
+    }
+
+    #[test]
+    fn test_simplify_over_segmented_circle() {
+        let c = point(1.0, -2.0);
+        let polygon = polygon_circle(c, 10.0, 64);
+        // Chords deviate from the circle by r * (1 - cos(π / 64)) ≈ 0.012
+        let simple = arcline_simplify(&polygon, 0.02);
+        assert!(simple.len() <= 4, "len = {}", simple.len());
+        assert!(simple.len() < polygon.len());
+        for element in &simple {
+            assert!(element.is_arc());
+            assert!(element.c.close_enough(c, 0.02));
+        }
+        assert!(max_deviation_from_circle(&simple, c, 10.0) < 0.02);
+        assert_eq!(arcline_is_valid(&simple), ArclineValidation::Valid);
+        // Same closed path, start to end
+        assert_eq!(simple[0].a, polygon[0].a);
+    }
+
+    #[test]
+    fn test_simplify_tight_tolerance_keeps_chords() {
+        let polygon = polygon_circle(point(0.0, 0.0), 10.0, 64);
+        assert_eq!(arcline_simplify(&polygon, 1e-3), polygon);
+    }
+
+    #[test]
+    fn test_simplify_co_circular_arcs() {
+        // A disc split into 12 arcs becomes two half circles
+        let c = point(0.0, 0.0);
+        let disc = arc(point(2.0, 0.0), point(2.0, 0.0), c, 2.0).split_equal(12);
+        let simple = arcline_simplify(&disc, 1e-9);
+        assert_eq!(simple.len(), 2);
+        for element in &simple {
+            assert!((element.r - 2.0).abs() < 1e-9);
+            assert!((element.sweep_angle() - PI).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_simplify_keeps_genuine_corners() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arc(point(2.0, 2.0), point(0.0, 2.0), point(1.0, 2.0), 1.0),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let simple = arcline_simplify(&square, 1e-6);
+        assert_eq!(
+            simple,
+            vec![
+                arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+                square[2],
+                square[3],
+                square[4],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simplify_reversed_concave_arcs() {
+        // An inward bulge stored as two reversed arcs is merged into one reversed arc
+        let c = point(1.0, 3.0);
+        let r = 2.0_f64.sqrt();
+        let bottom = point(1.0, 3.0 - r);
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arc(bottom, point(2.0, 2.0), c, r),
+            arc(point(0.0, 2.0), bottom, c, r),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let simple = arcline_simplify(&arcs, 1e-9);
+        assert_eq!(simple.len(), 4);
+        let merged = simple[2];
+        assert_eq!((merged.a, merged.b), (point(0.0, 2.0), point(2.0, 2.0)));
+        assert!(merged.c.close_enough(c, 1e-9));
+        assert!((arcline_area(&simple) - arcline_area(&arcs)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simplify_small_inputs() {
+        assert!(arcline_simplify(&vec![], 0.1).is_empty());
+        let one = vec![arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0)];
+        assert_eq!(arcline_simplify(&one, 0.1), one);
+    }
+}
@@ -0,0 +1,751 @@
+//! Spatial queries on arclines indexed by a Hilbert R-tree.
+//!
+//! The queries take a [`HilbertRTree`] built from the bounding boxes of the
+//! arcline elements, added in arcline order, so that tree item ids are element
+//! indices.
+
+use crate::algo::subdivision::element_intersections;
+use crate::prelude::*;
+
+/// Finds the elements of an arcline within a distance of a point.
+///
+/// The tree is queried with the square enclosing the circle of `radius` around
+/// `p` to prune elements by bounding box. The remaining candidates are confirmed
+/// with the exact distance from [`dist_point_arc`] or [`dist_point_segment`].
+///
+/// The tree must index the bounding boxes of `arcs` in arcline order, e.g. from
+/// [`arc_bounding_rect`]; boxes that do not enclose their element can miss
+/// results.
+///
+/// # Arguments
+///
+/// * `tree` - The built tree of the element bounding boxes
+/// * `arcs` - The indexed arcline
+/// * `p` - The query point
+/// * `radius` - The maximum distance, inclusive
+///
+/// # Returns
+///
+/// The indices of the elements whose minimum distance to `p` is at most
+/// `radius`, in increasing order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::spatial::hilbert_within_radius;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+///     arc(point(4.0, 0.0), point(4.0, 2.0), point(4.0, 1.0), 1.0),
+///     arcseg(point(4.0, 2.0), point(0.0, 2.0)),
+/// ];
+/// let mut tree = HilbertRTree::with_capacity(arcs.len());
+/// for a in &arcs {
+///     let r = arc_bounding_rect(a);
+///     tree.add(r.p1.x, r.p1.y, r.p2.x, r.p2.y);
+/// }
+/// tree.build();
+/// assert_eq!(hilbert_within_radius(&tree, &arcs, &point(5.5, 1.0), 0.5), vec![1]);
+/// assert_eq!(hilbert_within_radius(&tree, &arcs, &point(2.0, 1.0), 1.0), vec![0, 2]);
+/// ```
+#[must_use]
+pub fn hilbert_within_radius(tree: &HilbertRTree, arcs: &Arcline, p: &Point, radius: f64) -> Vec<usize> {
+    let mut candidates = Vec::new();
+    tree.query_intersecting(p.x - radius, p.y - radius, p.x + radius, p.y + radius, &mut candidates);
+    let mut result: Vec<usize> = candidates
+        .into_iter()
+        .filter(|&i| {
+            let element = &arcs[i];
+            let dist = if element.is_seg() {
+                dist_point_segment(p, &segment(element.a, element.b)).0
+            } else {
+                dist_point_arc_dist(p, element)
+            };
+            dist <= radius
+        })
+        .collect();
+    result.sort_unstable();
+    result
+}
+
+/// Finds the stored boxes intersecting a rectangle.
+///
+/// Subtrees whose bounding box does not overlap the rectangle are pruned. The
+/// boundaries are inclusive: boxes touching the rectangle along an edge or at a
+/// corner intersect it.
+///
+/// # Arguments
+///
+/// * `tree` - The built tree
+/// * `r` - The query rectangle, from its left-bottom to its right-top corner
+///
+/// # Returns
+///
+/// The ids of the intersecting boxes, in increasing order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::spatial::hilbert_query_rect;
+///
+/// let mut tree = HilbertRTree::with_capacity(3);
+/// tree.add(0.0, 0.0, 2.0, 2.0);
+/// tree.add(1.0, 1.0, 3.0, 3.0);
+/// tree.add(4.0, 4.0, 5.0, 5.0);
+/// tree.build();
+/// assert_eq!(hilbert_query_rect(&tree, &rect(point(2.5, 2.5), point(4.0, 6.0))), vec![1, 2]);
+/// ```
+#[must_use]
+pub fn hilbert_query_rect(tree: &HilbertRTree, r: &Rect) -> Vec<usize> {
+    let mut result = Vec::new();
+    tree.query_intersecting(r.p1.x, r.p1.y, r.p2.x, r.p2.y, &mut result);
+    result.sort_unstable();
+    result
+}
+
+/// Finds the stored boxes containing a point.
+///
+/// Like [`hilbert_query_rect`], boundaries are inclusive: a point on the edge
+/// of a box is contained in it.
+///
+/// # Arguments
+///
+/// * `tree` - The built tree
+/// * `p` - The query point
+///
+/// # Returns
+///
+/// The ids of the boxes containing `p`, in increasing order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::spatial::hilbert_query_point;
+///
+/// let mut tree = HilbertRTree::with_capacity(2);
+/// tree.add(0.0, 0.0, 2.0, 2.0);
+/// tree.add(1.0, 1.0, 3.0, 3.0);
+/// tree.build();
+/// assert_eq!(hilbert_query_point(&tree, &point(1.5, 1.5)), vec![0, 1]);
+/// assert_eq!(hilbert_query_point(&tree, &point(2.5, 1.0)), vec![1]);
+/// ```
+#[must_use]
+pub fn hilbert_query_point(tree: &HilbertRTree, p: &Point) -> Vec<usize> {
+    let mut result = Vec::new();
+    tree.query_point(p.x, p.y, &mut result);
+    result.sort_unstable();
+    result
+}
+
+/// Finds the stored box nearest to a point.
+///
+/// See [`hilbert_k_nearest`] for the search and the tie-breaking.
+///
+/// # Arguments
+///
+/// * `tree` - The built tree
+/// * `p` - The query point
+///
+/// # Returns
+///
+/// The id of the nearest box, or `None` for an empty tree
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::spatial::hilbert_nearest;
+///
+/// let mut tree = HilbertRTree::with_capacity(2);
+/// tree.add(0.0, 0.0, 1.0, 1.0);
+/// tree.add(3.0, 0.0, 4.0, 1.0);
+/// tree.build();
+/// assert_eq!(hilbert_nearest(&tree, &point(2.6, 2.0)), Some(1));
+/// ```
+#[must_use]
+pub fn hilbert_nearest(tree: &HilbertRTree, p: &Point) -> Option<usize> {
+    hilbert_k_nearest(tree, p, 1).first().copied()
+}
+
+/// Finds the `k` stored boxes nearest to a point.
+///
+/// The distance of a box is the distance from `p` to its nearest point, zero for
+/// boxes containing `p`. The search is the best-first traversal of aabb's
+/// `query_nearest_k`: nodes are visited in order of the distance to their
+/// bounding box, a lower bound of the distance of every box below them, and
+/// nodes farther than the current `k`-th result are pruned.
+///
+/// Boxes at equal distance, e.g. several boxes containing `p`, come in an
+/// unspecified order, and when they tie for the `k`-th place it is unspecified
+/// which of them are returned.
+///
+/// # Arguments
+///
+/// * `tree` - The built tree
+/// * `p` - The query point
+/// * `k` - The number of boxes to find
+///
+/// # Returns
+///
+/// The ids of the `k` nearest boxes, or of all boxes if the tree has fewer,
+/// nearest first
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::spatial::hilbert_k_nearest;
+///
+/// let mut tree = HilbertRTree::with_capacity(3);
+/// tree.add(0.0, 0.0, 1.0, 1.0);
+/// tree.add(3.0, 0.0, 4.0, 1.0);
+/// tree.add(10.0, 0.0, 11.0, 1.0);
+/// tree.build();
+/// assert_eq!(hilbert_k_nearest(&tree, &point(2.5, 0.5), 2), vec![1, 0]);
+/// ```
+#[must_use]
+pub fn hilbert_k_nearest(tree: &HilbertRTree, p: &Point, k: usize) -> Vec<usize> {
+    let mut result = Vec::new();
+    tree.query_nearest_k(p.x, p.y, k, &mut result);
+    result
+}
+
+/// Geometry queries as methods of [`HilbertRTree`].
+///
+/// The methods take togo points and return the ids of the stored boxes, like
+/// the `hilbert_*` functions of this module they forward to. The traversals
+/// are the ones of the aabb crate.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let mut tree = HilbertRTree::with_capacity(2);
+/// tree.add(0.0, 0.0, 1.0, 1.0);
+/// tree.add(3.0, 0.0, 4.0, 1.0);
+/// tree.build();
+/// assert_eq!(tree.nearest(point(2.6, 2.0)), Some(1));
+/// assert_eq!(tree.query_rect(rect(point(0.5, 0.5), point(3.0, 0.5))), vec![0, 1]);
+/// assert_eq!(tree.query_at_point(point(3.5, 1.0)), vec![1]);
+/// ```
+pub trait HilbertRTreeExt {
+    /// Finds the stored box nearest to a point, see [`hilbert_nearest`].
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The query point
+    ///
+    /// # Returns
+    ///
+    /// The id of the nearest box, or `None` for an empty tree
+    fn nearest(&self, p: Point) -> Option<usize>;
+
+    /// Finds the `k` stored boxes nearest to a point, see [`hilbert_k_nearest`].
+    ///
+    /// Boxes at equal distance come in an unspecified order, and when they tie
+    /// for the `k`-th place it is unspecified which of them are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The query point
+    /// * `k` - The number of boxes to find
+    ///
+    /// # Returns
+    ///
+    /// The ids of the `k` nearest boxes, or of all boxes if the tree has fewer,
+    /// nearest first
+    fn k_nearest(&self, p: Point, k: usize) -> Vec<usize>;
+
+    /// Finds the stored boxes intersecting a rectangle, see [`hilbert_query_rect`].
+    ///
+    /// Boundaries are inclusive: boxes touching the rectangle intersect it.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The query rectangle, from its left-bottom to its right-top corner
+    ///
+    /// # Returns
+    ///
+    /// The ids of the intersecting boxes, in increasing order
+    fn query_rect(&self, r: Rect) -> Vec<usize>;
+
+    /// Finds the stored boxes containing a point, see [`hilbert_query_point`].
+    ///
+    /// Boundaries are inclusive. The method is not named `query_point` because
+    /// the inherent `HilbertRTree::query_point(x, y, out)` of aabb would take
+    /// precedence over it.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The query point
+    ///
+    /// # Returns
+    ///
+    /// The ids of the boxes containing `p`, in increasing order
+    fn query_at_point(&self, p: Point) -> Vec<usize>;
+}
+
+impl HilbertRTreeExt for HilbertRTree {
+    fn nearest(&self, p: Point) -> Option<usize> {
+        hilbert_nearest(self, &p)
+    }
+
+    fn k_nearest(&self, p: Point, k: usize) -> Vec<usize> {
+        hilbert_k_nearest(self, &p, k)
+    }
+
+    fn query_rect(&self, r: Rect) -> Vec<usize> {
+        hilbert_query_rect(self, &r)
+    }
+
+    fn query_at_point(&self, p: Point) -> Vec<usize> {
+        hilbert_query_point(self, &p)
+    }
+}
+
+/// Computes the minimum distance between two arclines.
+///
+/// Intended for clearance checks between parts. The elements of `b` are indexed
+/// in a [`HilbertRTree`] by their bounding boxes. A first upper bound comes from
+/// the element of `b` whose box is nearest to the start of `a`; then each
+/// element of `a` queries the tree with its bounding box grown by the best
+/// distance found so far, and only the elements of `b` in that box are measured
+/// with [`dist_segment_segment`], [`dist_segment_arc`] or [`dist_arc_arc`]. The
+/// closest points are computed for the nearest pair only.
+///
+/// The arclines do not need to be closed. Elements crossing or touching each
+/// other give distance 0 at a common point.
+///
+/// # Arguments
+///
+/// * `a` - The first arcline
+/// * `b` - The second arcline
+///
+/// # Returns
+///
+/// A tuple `(distance, p, q)` with the closest points `p` on `a` and `q` on `b`.
+/// When either arcline is empty the distance is `f64::INFINITY` and both points
+/// are the origin.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+///     arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+///     arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+/// ];
+/// // Unit circle of two half arcs, centered 3 to the right of the square side
+/// let disc = vec![
+///     arc(point(5.0, -1.0), point(5.0, 1.0), point(5.0, 0.0), 1.0),
+///     arc(point(5.0, 1.0), point(5.0, -1.0), point(5.0, 0.0), 1.0),
+/// ];
+/// let (dist, p, q) = arcline_arcline_min_distance(&square, &disc);
+/// assert_eq!(dist, 2.0);
+/// assert_eq!((p, q), (point(2.0, 0.0), point(4.0, 0.0)));
+/// ```
+#[must_use]
+pub fn arcline_arcline_min_distance(a: &Arcline, b: &Arcline) -> (f64, Point, Point) {
+    if a.is_empty() || b.is_empty() {
+        return (f64::INFINITY, Point::default(), Point::default());
+    }
+    let mut tree = HilbertRTree::with_capacity(b.len());
+    for e in b {
+        let r = arc_bounding_rect(e);
+        tree.add(r.p1.x, r.p1.y, r.p2.x, r.p2.y);
+    }
+    tree.build();
+
+    // Upper bound from the element whose box is nearest to the start of a
+    let j0 = hilbert_nearest(&tree, &a[0].a).unwrap_or(0);
+    let mut best = (element_distance(&a[0], &b[j0]), 0, j0);
+    let mut candidates = Vec::new();
+    for (i, e) in a.iter().enumerate() {
+        if best.0 == 0.0 {
+            break;
+        }
+        let r = arc_bounding_rect(e);
+        let d = best.0;
+        candidates.clear();
+        tree.query_intersecting(r.p1.x - d, r.p1.y - d, r.p2.x + d, r.p2.y + d, &mut candidates);
+        for &j in &candidates {
+            let dist = element_distance(e, &b[j]);
+            if dist < best.0 {
+                best = (dist, i, j);
+            }
+        }
+    }
+    let (dist, i, j) = best;
+    let (p, q) = element_closest_points(&a[i], &b[j]);
+    (dist, p, q)
+}
+
+// Minimum distance between two elements, curved arcs or segments.
+fn element_distance(e0: &Arc, e1: &Arc) -> f64 {
+    match (e0.is_seg(), e1.is_seg()) {
+        (true, true) => dist_segment_segment(&segment(e0.a, e0.b), &segment(e1.a, e1.b)),
+        (true, false) => dist_segment_arc(&segment(e0.a, e0.b), e1),
+        (false, true) => dist_segment_arc(&segment(e1.a, e1.b), e0),
+        (false, false) => dist_arc_arc(e0, e1),
+    }
+}
+
+// Closest point of an element to `p`.
+fn element_closest_point(e: &Arc, p: Point) -> Point {
+    if e.is_seg() {
+        dist_point_segment(&p, &segment(e.a, e.b)).1
+    } else {
+        match dist_point_arc(&p, e) {
+            DistPointArcConfig::OnePoint(_, closest) | DistPointArcConfig::Equidistant(_, closest) => closest,
+        }
+    }
+}
+
+// Closest points of two elements: a common point, the end of one element and
+// its closest point on the other, or interior points facing each other.
+fn element_closest_points(e0: &Arc, e1: &Arc) -> (Point, Point) {
+    if let Some(&p) = element_intersections(e0, e1).first() {
+        return (p, p);
+    }
+    let mut pairs = vec![
+        (e0.a, element_closest_point(e1, e0.a)),
+        (e0.b, element_closest_point(e1, e0.b)),
+        (element_closest_point(e0, e1.a), e1.a),
+        (element_closest_point(e0, e1.b), e1.b),
+    ];
+    match (e0.is_seg(), e1.is_seg()) {
+        (true, true) => {}
+        (true, false) | (false, true) => {
+            // Points of the arc where it is parallel to the segment
+            let (seg, arc) = if e0.is_seg() { (e0, e1) } else { (e1, e0) };
+            let (dir, length) = (seg.b - seg.a).normalize(false);
+            if length > 0.0 {
+                let normal = point(-dir.y, dir.x);
+                for q in [arc.c + normal * arc.r, arc.c - normal * arc.r] {
+                    let t = (q - seg.a).dot(dir);
+                    if (0.0..=length).contains(&t) && arc.contains(q) {
+                        let p = seg.a + dir * t;
+                        pairs.push(if e0.is_seg() { (p, q) } else { (q, p) });
+                    }
+                }
+            }
+        }
+        (false, false) => {
+            // Points on the line through the centers
+            let (u, length) = (e1.c - e0.c).normalize(false);
+            if length > 0.0 {
+                for p in [e0.c + u * e0.r, e0.c - u * e0.r] {
+                    for q in [e1.c + u * e1.r, e1.c - u * e1.r] {
+                        if e0.contains(p) && e1.contains(q) {
+                            pairs.push((p, q));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    pairs
+        .into_iter()
+        .min_by(|x, y| (x.1 - x.0).norm().total_cmp(&(y.1 - y.0).norm()))
+        .unwrap_or((e0.a, e1.a))
+}
+
+#[cfg(test)]
+mod test_hilbert_within_radius {
+    use super::*;
+    use crate::algo::bounding::arcline_bounding_rect;
+    use crate::poly::data::arcline200;
+
+    fn build_tree(arcs: &Arcline) -> HilbertRTree {
+        let mut tree = HilbertRTree::with_capacity(arcs.len());
+        for a in arcs {
+            let r = arc_bounding_rect(a);
+            tree.add(r.p1.x, r.p1.y, r.p2.x, r.p2.y);
+        }
+        tree.build();
+        tree
+    }
+
+    fn brute_force(arcs: &Arcline, p: &Point, radius: f64) -> Vec<usize> {
+        (0..arcs.len())
+            .filter(|&i| {
+                let element = &arcs[i];
+                let dist = if element.is_seg() {
+                    dist_point_segment(p, &segment(element.a, element.b)).0
+                } else {
+                    match dist_point_arc(p, element) {
+                        DistPointArcConfig::OnePoint(d, _) | DistPointArcConfig::Equidistant(d, _) => d,
+                    }
+                };
+                dist <= radius
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_within_radius_matches_brute_force_arcline200() {
+        let arcs = arcline200();
+        let tree = build_tree(&arcs);
+        let bounds = arcline_bounding_rect(&arcs);
+        let size = (bounds.p2 - bounds.p1).norm();
+        let mut found = 0;
+        for k in 0..50 {
+            let t = k as f64 / 50.0;
+            let p = point(
+                bounds.p1.x + (bounds.p2.x - bounds.p1.x) * t,
+                bounds.p1.y + (bounds.p2.y - bounds.p1.y) * (1.0 - t * t),
+            );
+            for radius in [0.0, size * 0.01, size * 0.05, size * 0.2] {
+                let result = hilbert_within_radius(&tree, &arcs, &p, radius);
+                assert_eq!(result, brute_force(&arcs, &p, radius), "k = {}, radius = {}", k, radius);
+                found += result.len();
+            }
+        }
+        assert!(found > 0);
+    }
+
+    #[test]
+    fn test_within_radius_boundary_inclusive() {
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arc(point(2.0, 0.0), point(0.0, 0.0), point(1.0, 0.0), 1.0),
+        ];
+        let tree = build_tree(&arcs);
+        // Exactly 1 from the segment and 2 from the top of the arc
+        assert_eq!(hilbert_within_radius(&tree, &arcs, &point(1.0, -1.0), 1.0), vec![0]);
+        assert_eq!(hilbert_within_radius(&tree, &arcs, &point(1.0, 3.0), 2.0), vec![1]);
+        assert!(hilbert_within_radius(&tree, &arcs, &point(1.0, 3.0), 1.9).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_hilbert_nearest {
+    use super::*;
+
+    // Unit boxes with their lower left corners on a grid with spacing 3
+    fn grid_tree(n: usize) -> HilbertRTree {
+        let mut tree = HilbertRTree::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let (x, y) = (3.0 * i as f64, 3.0 * j as f64);
+                tree.add(x, y, x + 1.0, y + 1.0);
+            }
+        }
+        tree.build();
+        tree
+    }
+
+    fn box_distance(tree: &HilbertRTree, id: usize, p: &Point) -> f64 {
+        let (x0, y0, x1, y1) = tree.get(id).unwrap();
+        let dx = (x0 - p.x).max(p.x - x1).max(0.0);
+        let dy = (y0 - p.y).max(p.y - y1).max(0.0);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    #[test]
+    fn test_nearest_grid_entries() {
+        let n = 10;
+        let tree = grid_tree(n);
+        // Box (i, j) has id i * n + j
+        assert_eq!(hilbert_nearest(&tree, &point(0.5, 0.5)), Some(0));
+        assert_eq!(hilbert_nearest(&tree, &point(13.2, 6.4)), Some(4 * n + 2));
+        assert_eq!(hilbert_nearest(&tree, &point(27.9, 28.1)), Some(9 * n + 9));
+        assert_eq!(hilbert_nearest(&tree, &point(-5.0, 15.3)), Some(5));
+        assert_eq!(hilbert_nearest(&tree, &point(100.0, 1.0)), Some(9 * n));
+    }
+
+    #[test]
+    fn test_k_nearest_matches_brute_force() {
+        let n = 12;
+        let tree = grid_tree(n);
+        for (q, k) in [(point(7.3, 11.9), 5), (point(-3.0, 40.0), 3), (point(17.5, 17.5), 12)] {
+            let result = hilbert_k_nearest(&tree, &q, k);
+            assert_eq!(result.len(), k);
+            let dists: Vec<f64> = result.iter().map(|&id| box_distance(&tree, id, &q)).collect();
+            assert!(dists.windows(2).all(|w| w[0] <= w[1]));
+            let mut all: Vec<f64> = (0..n * n).map(|id| box_distance(&tree, id, &q)).collect();
+            all.sort_by(f64::total_cmp);
+            assert_eq!(dists, all[..k]);
+        }
+        // Nearest first around a point between boxes (3, 3) and (4, 3)
+        let result = hilbert_k_nearest(&tree, &point(11.5, 9.5), 2);
+        assert_eq!(result, vec![4 * n + 3, 3 * n + 3]);
+    }
+
+    #[test]
+    fn test_nearest_degenerate() {
+        let mut empty = HilbertRTree::new();
+        empty.build();
+        assert_eq!(hilbert_nearest(&empty, &point(0.0, 0.0)), None);
+        let tree = grid_tree(2);
+        assert!(hilbert_k_nearest(&tree, &point(0.0, 0.0), 0).is_empty());
+        let mut all = hilbert_k_nearest(&tree, &point(0.0, 0.0), 10);
+        all.sort_unstable();
+        assert_eq!(all, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_nearest_methods() {
+        let n = 10;
+        let tree = grid_tree(n);
+        assert_eq!(tree.nearest(point(13.2, 6.4)), Some(4 * n + 2));
+        assert_eq!(tree.k_nearest(point(11.5, 9.5), 2), vec![4 * n + 3, 3 * n + 3]);
+        for q in [point(7.3, 11.9), point(-3.0, 40.0), point(17.5, 17.5)] {
+            assert_eq!(tree.nearest(q), hilbert_nearest(&tree, &q));
+            assert_eq!(tree.k_nearest(q, 4), hilbert_k_nearest(&tree, &q, 4));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_hilbert_query_rect {
+    use super::*;
+
+    fn build(boxes: &[(f64, f64, f64, f64)]) -> HilbertRTree {
+        let mut tree = HilbertRTree::with_capacity(boxes.len());
+        for &(x0, y0, x1, y1) in boxes {
+            tree.add(x0, y0, x1, y1);
+        }
+        tree.build();
+        tree
+    }
+
+    #[test]
+    fn test_query_rect_overlapping_and_disjoint() {
+        let tree = build(&[
+            (0.0, 0.0, 2.0, 2.0),
+            (1.0, 1.0, 3.0, 3.0),
+            (10.0, 10.0, 11.0, 11.0),
+            (1.5, -5.0, 1.6, 5.0),
+        ]);
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(1.2, 1.2), point(1.8, 1.8))), vec![0, 1, 3]);
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(9.0, 9.0), point(10.5, 12.0))), vec![2]);
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(-100.0, -100.0), point(100.0, 100.0))), vec![0, 1, 2, 3]);
+        // Between the boxes
+        assert!(hilbert_query_rect(&tree, &rect(point(4.0, 4.0), point(9.0, 9.0))).is_empty());
+        assert!(hilbert_query_rect(&tree, &rect(point(2.5, -4.0), point(8.0, 0.5))).is_empty());
+    }
+
+    #[test]
+    fn test_query_rect_touching_edges_is_inclusive() {
+        let tree = build(&[(0.0, 0.0, 1.0, 1.0), (2.0, 0.0, 3.0, 1.0)]);
+        // Touching the right edge of box 0 and the left edge of box 1
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(1.0, 0.5), point(2.0, 2.0))), vec![0, 1]);
+        // Touching a corner only
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(3.0, 1.0), point(4.0, 2.0))), vec![1]);
+        // Degenerate rectangle on an edge
+        assert_eq!(hilbert_query_rect(&tree, &rect(point(0.5, 1.0), point(0.5, 1.0))), vec![0]);
+        assert!(hilbert_query_rect(&tree, &rect(point(1.0 + 1e-9, 0.0), point(2.0 - 1e-9, 1.0))).is_empty());
+    }
+
+    #[test]
+    fn test_query_point() {
+        let tree = build(&[(0.0, 0.0, 2.0, 2.0), (1.0, 1.0, 3.0, 3.0), (5.0, 5.0, 6.0, 6.0)]);
+        assert_eq!(hilbert_query_point(&tree, &point(1.5, 1.5)), vec![0, 1]);
+        assert_eq!(hilbert_query_point(&tree, &point(0.5, 0.5)), vec![0]);
+        // On the boundary of both boxes
+        assert_eq!(hilbert_query_point(&tree, &point(2.0, 1.0)), vec![0, 1]);
+        assert_eq!(hilbert_query_point(&tree, &point(6.0, 6.0)), vec![2]);
+        assert!(hilbert_query_point(&tree, &point(4.0, 4.0)).is_empty());
+        let mut empty = HilbertRTree::new();
+        empty.build();
+        assert!(hilbert_query_point(&empty, &point(0.0, 0.0)).is_empty());
+        assert!(hilbert_query_rect(&empty, &rect(point(0.0, 0.0), point(1.0, 1.0))).is_empty());
+    }
+
+    #[test]
+    fn test_query_methods() {
+        let tree = build(&[(0.0, 0.0, 2.0, 2.0), (1.0, 1.0, 3.0, 3.0), (5.0, 5.0, 6.0, 6.0)]);
+        assert_eq!(tree.query_rect(rect(point(2.5, 2.5), point(5.0, 5.0))), vec![1, 2]);
+        assert!(tree.query_rect(rect(point(3.5, 3.5), point(4.5, 4.5))).is_empty());
+        assert_eq!(tree.query_at_point(point(2.0, 1.0)), vec![0, 1]);
+        assert!(tree.query_at_point(point(4.0, 4.0)).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_arcline_min_distance {
+    use super::*;
+    use crate::poly::data::arcline200;
+
+    fn disc(c: Point, r: f64, n: usize) -> Arcline {
+        let at = |k: usize| {
+            let t = std::f64::consts::TAU * k as f64 / n as f64;
+            c + point(t.cos(), t.sin()) * r
+        };
+        (0..n).map(|k| arc(at(k), at((k + 1) % n), c, r)).collect()
+    }
+
+    fn brute_force(a: &Arcline, b: &Arcline) -> f64 {
+        a.iter()
+            .flat_map(|e0| b.iter().map(move |e1| element_distance(e0, e1)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn check(a: &Arcline, b: &Arcline) -> (f64, Point, Point) {
+        let (dist, p, q) = arcline_arcline_min_distance(a, b);
+        assert!((dist - brute_force(a, b)).abs() < 1e-12);
+        assert!(((q - p).norm() - dist).abs() < 1e-9);
+        // The witnesses lie on the arclines
+        assert!(a.iter().any(|e| (element_closest_point(e, p) - p).norm() < 1e-9));
+        assert!(b.iter().any(|e| (element_closest_point(e, q) - q).norm() < 1e-9));
+        (dist, p, q)
+    }
+
+    #[test]
+    fn test_separated_discs() {
+        let (c1, r1) = (point(0.0, 0.0), 1.0);
+        let (c2, r2) = (point(4.0, 3.0), 1.5);
+        let a = disc(c1, r1, 4);
+        let b = disc(c2, r2, 7);
+        let (dist, p, q) = check(&a, &b);
+        assert!((dist - (5.0 - r1 - r2)).abs() < 1e-12);
+        // The closest points face each other on the line through the centers
+        assert!(p.close_enough(point(0.8, 0.6), 1e-12));
+        assert!(q.close_enough(c2 - point(0.8, 0.6) * r2, 1e-12));
+        // Symmetric
+        let (dist_ba, p_ba, q_ba) = arcline_arcline_min_distance(&b, &a);
+        assert!((dist_ba - dist).abs() < 1e-12);
+        assert!(p_ba.close_enough(q, 1e-12) && q_ba.close_enough(p, 1e-12));
+    }
+
+    #[test]
+    fn test_segments_and_arcs() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let (dist, p, q) = check(&square, &disc(point(1.0, 4.5), 1.0, 3));
+        assert!((dist - 1.5).abs() < 1e-12);
+        assert!(p.close_enough(point(1.0, 2.0), 1e-12) && q.close_enough(point(1.0, 3.5), 1e-12));
+        // Corner against a disc on the diagonal
+        let (dist, p, _) = check(&square, &disc(point(4.0, 4.0), 1.0, 5));
+        assert!((dist - (8.0_f64.sqrt() - 1.0)).abs() < 1e-12);
+        assert_eq!(p, point(2.0, 2.0));
+        // Far from each other, many elements
+        let arcs = arcline200();
+        let moved = arcline_translate(&disc(point(0.0, 0.0), 3.0, 6), point(500.0, 40.0));
+        check(&arcs, &moved);
+    }
+
+    #[test]
+    fn test_crossing_and_empty() {
+        let a = disc(point(0.0, 0.0), 1.0, 4);
+        let b = disc(point(1.5, 0.0), 1.0, 4);
+        let (dist, p, q) = arcline_arcline_min_distance(&a, &b);
+        assert_eq!(dist, 0.0);
+        assert_eq!(p, q);
+        assert!((p.x - 0.75).abs() < 1e-12);
+        assert_eq!(arcline_arcline_min_distance(&a, &vec![]).0, f64::INFINITY);
+        assert_eq!(arcline_arcline_min_distance(&vec![], &b).0, f64::INFINITY);
+    }
+}
@@ -0,0 +1,636 @@
+//! Planar subdivision of a set of arclines.
+//!
+//! This module provides a half-edge data structure built from arclines that
+//! are split at all their mutual intersections, for topology operations such
+//! as map overlay and boolean operations.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+
+use crate::prelude::*;
+
+/// Tolerance for merging nearby split points into a single vertex.
+const VERTEX_TOLERANCE: f64 = 1e-9;
+
+/// A directed edge of a [`PlanarSubdivision`].
+///
+/// Every edge of the subdivision is stored as two half-edges with opposite
+/// directions (twins). The face of a half-edge is the one on its left side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HalfEdge {
+    /// Index of the start vertex.
+    pub origin: usize,
+    /// Index of the end vertex.
+    pub dest: usize,
+    /// Index of the oppositely directed twin half-edge.
+    pub twin: usize,
+    /// Index of the next half-edge around the same face.
+    pub next: usize,
+    /// Index of the face (boundary cycle) on the left side.
+    pub face: usize,
+    /// Geometry of the edge, shared by both twins (arcs are always CCW).
+    pub arc: Arc,
+    /// True if the half-edge runs along `arc` from `a` to `b`.
+    pub forward: bool,
+}
+
+/// A half-edge subdivision of the plane induced by a set of arclines.
+///
+/// The elements of all rings are split at their mutual intersections.
+/// Coincident split points are merged into vertices and overlapping pieces
+/// into single edges. The boundary cycles of the resulting half-edges are the
+/// faces: bounded faces are traversed CCW (positive area), the unbounded side
+/// of each connected component is traversed CW.
+///
+/// Components nested inside a face of another component are not linked to it,
+/// so such faces are returned without holes.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = |x: f64, y: f64| {
+///     vec![
+///         arcseg(point(x, y), point(x + 2.0, y)),
+///         arcseg(point(x + 2.0, y), point(x + 2.0, y + 2.0)),
+///         arcseg(point(x + 2.0, y + 2.0), point(x, y + 2.0)),
+///         arcseg(point(x, y + 2.0), point(x, y)),
+///     ]
+/// };
+/// let sub = PlanarSubdivision::from_arclines(&[square(0.0, 0.0), square(1.0, 1.0)]);
+/// // A-only, B-only and the overlap
+/// assert_eq!(sub.faces().len(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PlanarSubdivision {
+    /// Vertex positions.
+    pub vertices: Vec<Point>,
+    /// Half-edges, twins are stored next to each other.
+    pub half_edges: Vec<HalfEdge>,
+    // First half-edge and signed area of each boundary cycle
+    cycles: Vec<(usize, f64)>,
+}
+
+impl PlanarSubdivision {
+    /// Builds the subdivision induced by a set of arclines.
+    ///
+    /// The arclines are usually closed rings, but open chains are accepted too;
+    /// their dangling edges border a single face on both sides.
+    ///
+    /// # Arguments
+    ///
+    /// * `rings` - The arclines to overlay
+    ///
+    /// # Returns
+    ///
+    /// The planar subdivision with vertices, half-edges and faces
+    #[must_use]
+    pub fn from_arclines(rings: &[Arcline]) -> Self {
+        let elements: Vec<Arc> = rings
+            .iter()
+            .flatten()
+            .filter(|e| e.is_arc() || !e.a.close_enough(e.b, VERTEX_TOLERANCE))
+            .copied()
+            .collect();
+
+        let mut sub = PlanarSubdivision::default();
+        let splits = sub.split(&elements);
+
+        // Pieces between consecutive split points, with overlapping pieces merged
+        let mut edges: Vec<(usize, usize, Arc)> = Vec::new();
+        let mut by_ends: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (e, split) in elements.iter().zip(&splits) {
+            for w in split.windows(2) {
+                let ((_, u), (_, v)) = (w[0], w[1]);
+                if is_empty_piece(e, w[0], w[1]) {
+                    continue;
+                }
+                let piece = if e.is_seg() {
+                    arcseg(sub.vertices[u], sub.vertices[v])
+                } else {
+                    arc(sub.vertices[u], sub.vertices[v], e.c, e.r)
+                };
+                let key = (u.min(v), u.max(v));
+                let mid = piece.point_at(0.5);
+                let known = by_ends.entry(key).or_default();
+                if known.iter().any(|&k| {
+                    let other = edges[k].2;
+                    other.is_seg() == piece.is_seg()
+                        && other.point_at(0.5).close_enough(mid, VERTEX_TOLERANCE)
+                }) {
+                    continue;
+                }
+                known.push(edges.len());
+                edges.push((u, v, piece));
+            }
+        }
+
+        for &(u, v, piece) in &edges {
+            let h = sub.half_edges.len();
+            for (origin, dest, forward, twin) in [(u, v, true, h + 1), (v, u, false, h)] {
+                sub.half_edges.push(HalfEdge {
+                    origin,
+                    dest,
+                    twin,
+                    next: usize::MAX,
+                    face: usize::MAX,
+                    arc: piece,
+                    forward,
+                });
+            }
+        }
+
+        // Outgoing half-edges of every vertex in CCW order
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); sub.vertices.len()];
+        for (h, he) in sub.half_edges.iter().enumerate() {
+            outgoing[he.origin].push(h);
+        }
+        let keys: Vec<(f64, f64)> = (0..sub.half_edges.len())
+            .map(|h| sub.departure(h))
+            .collect();
+        for out in &mut outgoing {
+            out.sort_by(|&x, &y| compare_departure(keys[x], keys[y]));
+        }
+
+        // The face continues with the edge just before the twin in CCW order
+        let mut position = vec![0; sub.half_edges.len()];
+        for out in &outgoing {
+            for (k, &h) in out.iter().enumerate() {
+                position[h] = k;
+            }
+        }
+        for h in 0..sub.half_edges.len() {
+            let twin = sub.half_edges[h].twin;
+            let out = &outgoing[sub.half_edges[twin].origin];
+            sub.half_edges[h].next = out[(position[twin] + out.len() - 1) % out.len()];
+        }
+
+        for start in 0..sub.half_edges.len() {
+            if sub.half_edges[start].face != usize::MAX {
+                continue;
+            }
+            let face = sub.cycles.len();
+            let mut area = 0.0;
+            let mut h = start;
+            while sub.half_edges[h].face == usize::MAX {
+                sub.half_edges[h].face = face;
+                area += sub.half_edge_area(h);
+                h = sub.half_edges[h].next;
+            }
+            sub.cycles.push((start, area));
+        }
+        sub
+    }
+
+    /// Returns the boundaries of the bounded faces, as closed CCW arclines.
+    ///
+    /// Edges follow the arcline convention: arcs traversed from `b` to `a`
+    /// are concave and segments are oriented along the boundary.
+    #[must_use]
+    pub fn faces(&self) -> Vec<Arcline> {
+        self.cycles
+            .iter()
+            .filter(|(_, area)| *area > VERTEX_TOLERANCE)
+            .map(|&(start, _)| self.cycle(start))
+            .collect()
+    }
+
+    /// Returns the number of boundary cycles, including the unbounded side of
+    /// every connected component.
+    #[must_use]
+    pub fn cycle_count(&self) -> usize {
+        self.cycles.len()
+    }
+
+    // Split points of every element at all mutual intersections, as (parameter,
+    // vertex) sorted from `a` to `b`.
+    fn split(&mut self, elements: &[Arc]) -> Vec<Vec<(f64, usize)>> {
+        let mut splits: Vec<Vec<(f64, usize)>> = elements
+            .iter()
+            .map(|e| {
+                let a = self.vertex(e.a);
+                let b = self.vertex(e.b);
+                vec![(0.0, a), (1.0, b)]
+            })
+            .collect();
+        for i in 0..elements.len() {
+            for j in (i + 1)..elements.len() {
+                for p in element_intersections(&elements[i], &elements[j]) {
+                    let v = self.vertex(p);
+                    let p = self.vertices[v];
+                    splits[i].push((element_param(&elements[i], p), v));
+                    splits[j].push((element_param(&elements[j], p), v));
+                }
+            }
+        }
+        for split in &mut splits {
+            split.sort_by(|x, y| x.0.total_cmp(&y.0));
+        }
+        splits
+    }
+
+    // Returns the vertex at `p`, adding a new one if there is none nearby.
+    fn vertex(&mut self, p: Point) -> usize {
+        match self
+            .vertices
+            .iter()
+            .position(|v| v.close_enough(p, VERTEX_TOLERANCE))
+        {
+            Some(i) => i,
+            None => {
+                self.vertices.push(p);
+                self.vertices.len() - 1
+            }
+        }
+    }
+
+    fn cycle(&self, start: usize) -> Arcline {
+        let mut arcs = Arcline::new();
+        let mut h = start;
+        loop {
+            let he = &self.half_edges[h];
+            if he.arc.is_seg() {
+                arcs.push(arcseg(self.vertices[he.origin], self.vertices[he.dest]));
+            } else {
+                arcs.push(he.arc);
+            }
+            h = he.next;
+            if h == start {
+                break;
+            }
+        }
+        arcs
+    }
+
+    // Direction angle and signed curvature of a half-edge at its origin.
+    fn departure(&self, h: usize) -> (f64, f64) {
+        let he = &self.half_edges[h];
+        let p = self.vertices[he.origin];
+        let (dir, curvature) = if he.arc.is_seg() {
+            (self.vertices[he.dest] - p, 0.0)
+        } else {
+            let radial = p - he.arc.c;
+            let tangent = point(-radial.y, radial.x);
+            if he.forward {
+                (tangent, 1.0 / he.arc.r)
+            } else {
+                (-tangent, -1.0 / he.arc.r)
+            }
+        };
+        (dir.y.atan2(dir.x), curvature)
+    }
+
+    // Signed area contribution of a half-edge (Green's theorem).
+    fn half_edge_area(&self, h: usize) -> f64 {
+        let he = &self.half_edges[h];
+        let chord = self.vertices[he.origin].perp(self.vertices[he.dest]) / 2.0;
+        if he.arc.is_seg() {
+            return chord;
+        }
+        // The circular segment between chord and arc lies right of a CCW arc
+        let sweep = he.arc.sweep_angle();
+        let segment = he.arc.r * he.arc.r * (sweep - sweep.sin()) / 2.0;
+        if he.forward {
+            chord + segment
+        } else {
+            chord - segment
+        }
+    }
+}
+
+/// Splits arclines at all their mutual intersections into a vertex-edge graph.
+///
+/// This is the raw overlay that [`PlanarSubdivision::from_arclines`] builds its
+/// faces from: the element end points and the intersection points become
+/// vertices, coincident points merged into one, and every element is cut into
+/// edges between consecutive vertices along it. Unlike the subdivision, the
+/// edges are not linked into faces and pieces shared by overlapping elements
+/// are kept once for each element.
+///
+/// # Arguments
+///
+/// * `rings` - The arclines to overlay, usually closed rings
+///
+/// # Returns
+///
+/// The vertices and the edges as pairs of vertex indices, listed ring by ring
+/// and element by element, each running along its element from `a` to `b`.
+/// A full circle without intersections is a single edge from its vertex to itself.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let cross = vec![
+///     vec![arcseg(point(0.0, 0.0), point(2.0, 2.0))],
+///     vec![arcseg(point(0.0, 2.0), point(2.0, 0.0))],
+/// ];
+/// let (vertices, edges) = arclines_overlay(&cross);
+/// assert_eq!(vertices.len(), 5);
+/// assert_eq!(vertices[4], point(1.0, 1.0));
+/// assert_eq!(edges, vec![(0, 4), (4, 1), (2, 4), (4, 3)]);
+/// ```
+#[must_use]
+pub fn arclines_overlay(rings: &[Arcline]) -> (Vec<Point>, Vec<(usize, usize)>) {
+    let elements: Vec<Arc> = rings
+        .iter()
+        .flatten()
+        .filter(|e| e.is_arc() || !e.a.close_enough(e.b, VERTEX_TOLERANCE))
+        .copied()
+        .collect();
+
+    let mut sub = PlanarSubdivision::default();
+    let splits = sub.split(&elements);
+    let edges = elements
+        .iter()
+        .zip(&splits)
+        .flat_map(|(e, split)| {
+            split
+                .windows(2)
+                .filter(|w| !is_empty_piece(e, w[0], w[1]))
+                .map(|w| (w[0].1, w[1].1))
+        })
+        .collect();
+    (sub.vertices, edges)
+}
+
+// True if the piece of an element between two split points has no length.
+fn is_empty_piece(e: &Arc, (t0, u): (f64, usize), (t1, v): (f64, usize)) -> bool {
+    if e.is_seg() {
+        u == v
+    } else {
+        (t1 - t0) * e.sweep_angle() * e.r < VERTEX_TOLERANCE
+    }
+}
+
+// Orders half-edges leaving a vertex CCW; equal directions are ordered by curvature,
+// the edge turning left more strongly comes later.
+fn compare_departure(x: (f64, f64), y: (f64, f64)) -> Ordering {
+    if (x.0 - y.0).abs() > VERTEX_TOLERANCE {
+        x.0.total_cmp(&y.0)
+    } else {
+        x.1.total_cmp(&y.1)
+    }
+}
+
+// Position of a point along an element, 0 at `a` and 1 at `b`.
+pub(crate) fn element_param(e: &Arc, p: Point) -> f64 {
+    if e.is_seg() {
+        let d = e.b - e.a;
+        return ((p - e.a).dot(d) / d.dot(d)).clamp(0.0, 1.0);
+    }
+    let sweep = e.sweep_angle();
+    let va = e.a - e.c;
+    let vp = p - e.c;
+    let mut angle = va.perp(vp).atan2(va.dot(vp));
+    if angle < 0.0 {
+        angle += TAU;
+    }
+    if angle > sweep {
+        // Only round-off puts a point of the arc past `b`; the gap before `a` is nearer
+        if angle - sweep > TAU - angle {
+            return 0.0;
+        }
+        return 1.0;
+    }
+    angle / sweep
+}
+
+// Intersection points of two elements, including the ends of overlaps.
+pub(crate) fn element_intersections(e0: &Arc, e1: &Arc) -> Vec<Point> {
+    match (e0.is_seg(), e1.is_seg()) {
+        (true, true) => match int_segment_segment(&segment(e0.a, e0.b), &segment(e1.a, e1.b)) {
+            SegmentSegmentConfig::NoIntersection() => vec![],
+            SegmentSegmentConfig::OnePoint(p, _, _)
+            | SegmentSegmentConfig::OnePointTouching(p, _, _) => vec![p],
+            SegmentSegmentConfig::TwoPoints(p0, p1, _, _)
+            | SegmentSegmentConfig::TwoPointsTouching(p0, p1, _, _) => vec![p0, p1],
+        },
+        (true, false) | (false, true) => {
+            let (seg, arc) = if e0.is_seg() { (e0, e1) } else { (e1, e0) };
+            match int_segment_arc(&segment(seg.a, seg.b), arc) {
+                SegmentArcConfig::NoIntersection() => vec![],
+                SegmentArcConfig::OnePoint(p, _) | SegmentArcConfig::OnePointTouching(p, _) => {
+                    vec![p]
+                }
+                SegmentArcConfig::TwoPoints(p0, p1, _, _)
+                | SegmentArcConfig::TwoPointsTouching(p0, p1, _, _) => vec![p0, p1],
+            }
+        }
+        (false, false) => match int_arc_arc(e0, e1) {
+            ArcArcConfig::NoIntersection() => vec![],
+            ArcArcConfig::NonCocircularOnePoint(p)
+            | ArcArcConfig::NonCocircularOnePointTouching(p)
+            | ArcArcConfig::CocircularOnePoint0(p)
+            | ArcArcConfig::CocircularOnePoint1(p) => vec![p],
+            ArcArcConfig::NonCocircularTwoPoints(p0, p1)
+            | ArcArcConfig::NonCocircularTwoPointsTouching(p0, p1)
+            | ArcArcConfig::CocircularTwoPoints(p0, p1) => vec![p0, p1],
+            ArcArcConfig::CocircularOnePointOneArc0(p, a)
+            | ArcArcConfig::CocircularOnePointOneArc1(p, a) => vec![p, a.a, a.b],
+            ArcArcConfig::CocircularOneArc0(a)
+            | ArcArcConfig::CocircularOneArc1(a)
+            | ArcArcConfig::CocircularOneArc2(a)
+            | ArcArcConfig::CocircularOneArc3(a)
+            | ArcArcConfig::CocircularOneArc4(a) => vec![a.a, a.b],
+            ArcArcConfig::CocircularTwoArcs(a0, a1) => vec![a0.a, a0.b, a1.a, a1.b],
+        },
+    }
+}
+
+#[cfg(test)]
+mod test_planar_subdivision {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn square(x: f64, y: f64, size: f64) -> Arcline {
+        vec![
+            arcseg(point(x, y), point(x + size, y)),
+            arcseg(point(x + size, y), point(x + size, y + size)),
+            arcseg(point(x + size, y + size), point(x, y + size)),
+            arcseg(point(x, y + size), point(x, y)),
+        ]
+    }
+
+    fn disc(c: Point, r: f64) -> Arcline {
+        let lo = point(c.x, c.y - r);
+        let hi = point(c.x, c.y + r);
+        vec![arc(lo, hi, c, r), arc(hi, lo, c, r)]
+    }
+
+    fn sorted_areas(sub: &PlanarSubdivision) -> Vec<f64> {
+        let mut areas: Vec<f64> = sub.faces().iter().map(face_area).collect();
+        areas.sort_by(f64::total_cmp);
+        areas
+    }
+
+    // Area of a face boundary, concave arcs taken as traversed from b to a
+    fn face_area(face: &Arcline) -> f64 {
+        (0..face.len())
+            .map(|i| {
+                let e = face[i];
+                if e.is_seg() {
+                    return e.a.perp(e.b) / 2.0;
+                }
+                let sweep = e.sweep_angle();
+                let segment = e.r * e.r * (sweep - sweep.sin()) / 2.0;
+                if is_arc_convex(face, i) {
+                    e.a.perp(e.b) / 2.0 + segment
+                } else {
+                    e.b.perp(e.a) / 2.0 - segment
+                }
+            })
+            .sum()
+    }
+
+    fn assert_closed(face: &Arcline) {
+        for i in 0..face.len() {
+            let e = face[i];
+            let next = face[(i + 1) % face.len()];
+            let end = if is_arc_convex(face, i) { e.b } else { e.a };
+            let start = if is_arc_convex(face, (i + 1) % face.len()) {
+                next.a
+            } else {
+                next.b
+            };
+            assert!(end.close_enough(start, 1e-9), "gap after element {}", i);
+        }
+    }
+
+    #[test]
+    fn test_two_overlapping_squares() {
+        let sub = PlanarSubdivision::from_arclines(&[square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)]);
+        assert_eq!(sub.vertices.len(), 10);
+        assert_eq!(sub.half_edges.len(), 2 * 12);
+        let faces = sub.faces();
+        assert_eq!(faces.len(), 3);
+        for face in &faces {
+            assert_closed(face);
+        }
+        let areas = sorted_areas(&sub);
+        assert!((areas[0] - 1.0).abs() < 1e-12);
+        assert!((areas[1] - 3.0).abs() < 1e-12);
+        assert!((areas[2] - 3.0).abs() < 1e-12);
+        // The unbounded side of the single component
+        assert_eq!(sub.cycle_count(), 4);
+    }
+
+    #[test]
+    fn test_two_overlapping_discs() {
+        let sub = PlanarSubdivision::from_arclines(&[
+            disc(point(0.0, 0.0), 1.0),
+            disc(point(1.0, 0.0), 1.0),
+        ]);
+        let faces = sub.faces();
+        assert_eq!(faces.len(), 3);
+        for face in &faces {
+            assert_closed(face);
+        }
+        // Lens of two unit circles at distance 1
+        let lens = 2.0 * PI / 3.0 - 3.0_f64.sqrt() / 2.0;
+        let areas = sorted_areas(&sub);
+        assert!((areas[0] - lens).abs() < 1e-9);
+        assert!((areas[1] - (PI - lens)).abs() < 1e-9);
+        assert!((areas[2] - (PI - lens)).abs() < 1e-9);
+        // The lens is bounded by two convex arcs
+        let lens_face = faces
+            .iter()
+            .find(|f| (face_area(f) - lens).abs() < 1e-9)
+            .unwrap();
+        assert!((0..lens_face.len()).all(|i| is_arc_convex(lens_face, i)));
+    }
+
+    #[test]
+    fn test_shared_edge() {
+        // Two squares sharing the edge x = 1
+        let sub = PlanarSubdivision::from_arclines(&[square(0.0, 0.0, 1.0), square(1.0, 0.0, 1.0)]);
+        assert_eq!(sub.half_edges.len(), 2 * 7);
+        let areas = sorted_areas(&sub);
+        assert_eq!(areas.len(), 2);
+        assert!((areas[0] - 1.0).abs() < 1e-12);
+        assert!((areas[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_disjoint_and_full_circle() {
+        let full = vec![arc(point(6.0, 0.0), point(6.0, 0.0), point(5.0, 0.0), 1.0)];
+        let sub = PlanarSubdivision::from_arclines(&[square(0.0, 0.0, 1.0), full]);
+        let areas = sorted_areas(&sub);
+        assert_eq!(areas.len(), 2);
+        assert!((areas[0] - 1.0).abs() < 1e-12);
+        assert!((areas[1] - PI).abs() < 1e-12);
+        assert_eq!(sub.cycle_count(), 4);
+    }
+
+    #[test]
+    fn test_empty() {
+        let sub = PlanarSubdivision::from_arclines(&[]);
+        assert!(sub.vertices.is_empty());
+        assert!(sub.faces().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_arclines_overlay {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Arcline {
+        vec![
+            arcseg(point(x, y), point(x + size, y)),
+            arcseg(point(x + size, y), point(x + size, y + size)),
+            arcseg(point(x + size, y + size), point(x, y + size)),
+            arcseg(point(x, y + size), point(x, y)),
+        ]
+    }
+
+    #[test]
+    fn test_two_crossing_squares() {
+        let (vertices, edges) = arclines_overlay(&[square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)]);
+        // Eight corners and the two crossings at (2, 1) and (1, 2)
+        assert_eq!(vertices.len(), 10);
+        assert!(vertices.contains(&point(2.0, 1.0)) && vertices.contains(&point(1.0, 2.0)));
+        // Each square has two of its sides split in two
+        assert_eq!(edges.len(), 12);
+        // Every vertex is on two edges, crossings on four
+        let mut degree = vec![0; vertices.len()];
+        for &(u, v) in &edges {
+            assert_ne!(u, v);
+            degree[u] += 1;
+            degree[v] += 1;
+        }
+        for (p, d) in vertices.iter().zip(&degree) {
+            let crossing = *p == point(2.0, 1.0) || *p == point(1.0, 2.0);
+            assert_eq!(*d, if crossing { 4 } else { 2 });
+        }
+        // Edges of a ring are consecutive: each ends where the next starts
+        for ring in edges.chunks(6) {
+            for i in 0..ring.len() {
+                assert_eq!(ring[i].1, ring[(i + 1) % ring.len()].0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_circle_crossing_square() {
+        let lo = point(2.0, 1.0);
+        let hi = point(2.0, 3.0);
+        let disc = vec![arc(lo, hi, point(2.0, 2.0), 1.0), arc(hi, lo, point(2.0, 2.0), 1.0)];
+        let (vertices, edges) = arclines_overlay(&[square(0.0, 0.0, 2.0), disc]);
+        // Square corners, the circle ends and the crossing at (1, 2); the circle
+        // end (2, 1) is on the right side of the square
+        assert_eq!(vertices.len(), 7);
+        assert!(vertices.contains(&point(1.0, 2.0)));
+        // Right and top sides split once, the left half circle split at (1, 2)
+        assert_eq!(edges.len(), 6 + 3);
+    }
+
+    #[test]
+    fn test_full_circle_and_empty() {
+        let full = vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)];
+        assert_eq!(arclines_overlay(&[full]), (vec![point(1.0, 0.0)], vec![(0, 0)]));
+        assert_eq!(arclines_overlay(&[]), (vec![], vec![]));
+    }
+}
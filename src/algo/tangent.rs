@@ -1,3 +1,4 @@
+use crate::intersection::tangent::tangent_point_to_circle;
 use crate::prelude::*;
 
 #[doc(hidden)]
@@ -239,3 +240,145 @@ mod test_tangent_arc_arc {
     //     assert!((end_angle - expected_angle).abs() < 1e-10);
     // }
 }
+
+/// Computes the shortest path between two points around a circular obstacle.
+///
+/// When the straight segment from `from` to `to` does not enter the interior of
+/// the obstacle, the path is that segment. Otherwise the path leaves `from` along
+/// a tangent to the circle, follows the circle and reaches `to` along a tangent;
+/// the shorter of the two ways around is chosen.
+///
+/// The arc element is CCW as all arcs; when the path goes around clockwise
+/// it is traversed from `b` to `a`.
+///
+/// # Arguments
+///
+/// * `from` - The start point
+/// * `to` - The end point
+/// * `obstacle` - The circle to go around
+///
+/// # Returns
+///
+/// The path as an open arcline, or `None` when the straight line is blocked and
+/// `from` or `to` is not strictly outside the circle
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::tangent::tangent_path_around;
+///
+/// let obstacle = circle(point(0.0, 0.0), 1.0);
+/// let path = tangent_path_around(point(-3.0, 0.0), point(3.0, 0.0), &obstacle).unwrap();
+/// // Tangent segment, arc, tangent segment
+/// assert_eq!(path.len(), 3);
+/// assert!(path[1].is_arc());
+///
+/// let clear = tangent_path_around(point(-3.0, 2.0), point(3.0, 2.0), &obstacle).unwrap();
+/// assert_eq!(clear, vec![arcseg(point(-3.0, 2.0), point(3.0, 2.0))]);
+/// ```
+#[must_use]
+pub fn tangent_path_around(from: Point, to: Point, obstacle: &Circle) -> Option<Arcline> {
+    let (dist, _) = dist_point_segment(&obstacle.c, &segment(from, to));
+    if dist >= obstacle.r {
+        return Some(vec![arcseg(from, to)]);
+    }
+
+    let (f1, f2) = tangent_point_to_circle(from, *obstacle)?;
+    let (t1, t2) = tangent_point_to_circle(to, *obstacle)?;
+    let c = obstacle.c;
+    // CCW direction of travel along the circle at p
+    let ccw = |p: Point| point(c.y - p.y, p.x - c.x);
+
+    // Going around CCW, the path leaves `from` and arrives at `to` along the CCW tangent
+    let f_ccw = if (f1 - from).dot(ccw(f1)) > 0.0 { f1 } else { f2 };
+    let t_ccw = if (to - t1).dot(ccw(t1)) > 0.0 { t1 } else { t2 };
+    let f_cw = if f_ccw == f1 { f2 } else { f1 };
+    let t_cw = if t_ccw == t1 { t2 } else { t1 };
+
+    let around_ccw = arc(f_ccw, t_ccw, c, obstacle.r);
+    let around_cw = arc(t_cw, f_cw, c, obstacle.r);
+    let len_ccw = (f_ccw - from).norm() + around_ccw.sweep_angle() * obstacle.r + (to - t_ccw).norm();
+    let len_cw = (f_cw - from).norm() + around_cw.sweep_angle() * obstacle.r + (to - t_cw).norm();
+
+    let (enter, around, exit) = if len_ccw <= len_cw {
+        (f_ccw, around_ccw, t_ccw)
+    } else {
+        (f_cw, around_cw, t_cw)
+    };
+    Some(vec![arcseg(from, enter), around, arcseg(exit, to)])
+}
+
+#[cfg(test)]
+mod test_tangent_path_around {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn path_length(path: &Arcline) -> f64 {
+        path.iter()
+            .map(|a| {
+                if a.is_seg() {
+                    (a.b - a.a).norm()
+                } else {
+                    a.sweep_angle() * a.r
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_blocking_circle() {
+        let obstacle = circle(point(0.0, 0.0), 1.0);
+        let from = point(-2.0, 0.0);
+        let to = point(2.0, 0.0);
+        let path = tangent_path_around(from, to, &obstacle).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path[0].a, from);
+        assert_eq!(path[2].b, to);
+        assert!(path[0].is_seg() && path[1].is_arc() && path[2].is_seg());
+
+        // Tangent points at 60 degrees from the x axis, tangent length sqrt(3)
+        let expected = 2.0 * 3.0_f64.sqrt() + PI / 3.0;
+        assert!((path_length(&path) - expected).abs() < 1e-12);
+
+        // Segments touch the circle tangentially
+        for seg in [path[0], path[2]] {
+            let (d, _) = dist_point_segment(&obstacle.c, &segment(seg.a, seg.b));
+            assert!((d - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_shorter_side_is_chosen() {
+        // The obstacle is slightly above the straight line, going below is shorter
+        let obstacle = circle(point(0.0, 0.5), 1.0);
+        let path = tangent_path_around(point(-3.0, 0.0), point(3.0, 0.0), &obstacle).unwrap();
+        assert_eq!(path.len(), 3);
+        let arc = path[1];
+        assert!(arc.point_at(0.5).y < 0.0);
+        // Traversed CCW from left to right below the center
+        assert!(path[0].b.close_enough(arc.a, 1e-12));
+        assert!(path[2].a.close_enough(arc.b, 1e-12));
+
+        let obstacle = circle(point(0.0, -0.5), 1.0);
+        let path = tangent_path_around(point(-3.0, 0.0), point(3.0, 0.0), &obstacle).unwrap();
+        let arc = path[1];
+        assert!(arc.point_at(0.5).y > 0.0);
+        // Traversed CW, from b to a
+        assert!(path[0].b.close_enough(arc.b, 1e-12));
+        assert!(path[2].a.close_enough(arc.a, 1e-12));
+    }
+
+    #[test]
+    fn test_non_blocking_circle() {
+        let obstacle = circle(point(0.0, 3.0), 1.0);
+        let path = tangent_path_around(point(-2.0, 0.0), point(2.0, 0.0), &obstacle).unwrap();
+        assert_eq!(path, vec![arcseg(point(-2.0, 0.0), point(2.0, 0.0))]);
+    }
+
+    #[test]
+    fn test_endpoint_inside() {
+        let obstacle = circle(point(0.0, 0.0), 1.0);
+        assert_eq!(tangent_path_around(point(0.5, 0.0), point(3.0, 0.0), &obstacle), None);
+    }
+}
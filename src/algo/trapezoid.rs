@@ -0,0 +1,214 @@
+//! Trapezoidal decomposition of polygons.
+//!
+//! This module splits simple polygons given as point sequences into
+//! trapezoids with horizontal parallel sides, as used for point location
+//! and monotone triangulation.
+
+use crate::prelude::*;
+
+/// Decomposes a simple polygon into trapezoids.
+///
+/// Horizontal lines are extended from each vertex into the interior until they
+/// hit an edge, cutting the polygon into trapezoids whose parallel sides are
+/// horizontal. A trapezoid with a side collapsed to a point is a triangle.
+///
+/// The polygon is swept between consecutive vertex levels. In each horizontal
+/// slab the edges crossing it are ordered along x and paired left to right;
+/// a pair of edges bounds the interior between them. A trapezoid grows over the
+/// next slab while it is bounded by the same pair of edges, that is while no
+/// vertex touches it.
+///
+/// # Arguments
+///
+/// * `poly` - The polygon vertices in order (CCW or CW), implicitly closed
+///
+/// # Returns
+///
+/// The trapezoids, each with CCW vertices starting at its bottom-left corner,
+/// ordered by their bottom side. Empty for fewer than 3 vertices.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // A square with a triangular roof: a square and a triangle
+/// let house = vec![
+///     point(0.0, 0.0),
+///     point(2.0, 0.0),
+///     point(2.0, 2.0),
+///     point(1.0, 3.0),
+///     point(0.0, 2.0),
+/// ];
+/// let trapezoids = pointline_trapezoidal_decomposition(&house);
+/// assert_eq!(trapezoids.len(), 2);
+/// assert_eq!(trapezoids[1], vec![point(0.0, 2.0), point(2.0, 2.0), point(1.0, 3.0)]);
+/// ```
+#[must_use]
+pub fn pointline_trapezoidal_decomposition(poly: &Pointline) -> Vec<Pointline> {
+    let n = poly.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    // Non-horizontal edges, from their lower to their upper end
+    let edges: Vec<(Point, Point)> = (0..n)
+        .filter_map(|i| {
+            let (a, b) = (poly[i], poly[(i + 1) % n]);
+            if a.y < b.y {
+                Some((a, b))
+            } else if a.y > b.y {
+                Some((b, a))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let x_at = |e: usize, y: f64| {
+        let (lo, hi) = edges[e];
+        if y == lo.y {
+            lo.x
+        } else if y == hi.y {
+            hi.x
+        } else {
+            lo.x + (hi.x - lo.x) * (y - lo.y) / (hi.y - lo.y)
+        }
+    };
+    let trapezoid = |left: usize, right: usize, bottom: f64, top: f64| {
+        let mut corners = vec![
+            point(x_at(left, bottom), bottom),
+            point(x_at(right, bottom), bottom),
+            point(x_at(right, top), top),
+            point(x_at(left, top), top),
+        ];
+        corners.dedup();
+        if corners.len() > 1 && corners[0] == corners[corners.len() - 1] {
+            corners.pop();
+        }
+        corners
+    };
+
+    let mut levels: Vec<f64> = poly.iter().map(|p| p.y).collect();
+    levels.sort_by(f64::total_cmp);
+    levels.dedup();
+
+    let mut result = Vec::new();
+    // Trapezoids still growing upward: left edge, right edge, bottom level
+    let mut open: Vec<(usize, usize, f64)> = Vec::new();
+    for slab in levels.windows(2) {
+        let (y0, y1) = (slab[0], slab[1]);
+        let middle = 0.5 * (y0 + y1);
+        let mut crossing: Vec<usize> = (0..edges.len())
+            .filter(|&e| edges[e].0.y <= y0 && edges[e].1.y >= y1)
+            .collect();
+        crossing.sort_by(|&e, &f| x_at(e, middle).total_cmp(&x_at(f, middle)));
+
+        let mut next_open = Vec::with_capacity(crossing.len() / 2);
+        for pair in crossing.chunks_exact(2) {
+            let (left, right) = (pair[0], pair[1]);
+            match open.iter().position(|&(l, r, _)| l == left && r == right) {
+                Some(k) => next_open.push(open.swap_remove(k)),
+                None => next_open.push((left, right, y0)),
+            }
+        }
+        for (left, right, bottom) in open {
+            result.push(trapezoid(left, right, bottom, y0));
+        }
+        open = next_open;
+    }
+    if let Some(&top) = levels.last() {
+        for (left, right, bottom) in open {
+            result.push(trapezoid(left, right, bottom, top));
+        }
+    }
+
+    result.sort_by(|a, b| a[0].y.total_cmp(&b[0].y).then(a[0].x.total_cmp(&b[0].x)));
+    result
+}
+
+#[cfg(test)]
+mod test_pointline_trapezoidal_decomposition {
+    use super::*;
+
+    fn total_area(trapezoids: &[Pointline]) -> f64 {
+        trapezoids.iter().map(pointline_area).sum()
+    }
+
+    #[test]
+    fn test_trapezoids_tile_l_shape() {
+        let l_shape = vec![
+            point(0.0, 0.0),
+            point(3.0, 0.0),
+            point(3.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 3.0),
+            point(0.0, 3.0),
+        ];
+        let trapezoids = pointline_trapezoidal_decomposition(&l_shape);
+        assert_eq!(
+            trapezoids,
+            vec![
+                vec![point(0.0, 0.0), point(3.0, 0.0), point(3.0, 1.0), point(0.0, 1.0)],
+                vec![point(0.0, 1.0), point(1.0, 1.0), point(1.0, 3.0), point(0.0, 3.0)],
+            ]
+        );
+        assert_eq!(total_area(&trapezoids), pointline_area(&l_shape));
+        // Same tiling for the clockwise polygon
+        let cw: Pointline = l_shape.iter().rev().copied().collect();
+        assert_eq!(pointline_trapezoidal_decomposition(&cw), trapezoids);
+    }
+
+    #[test]
+    fn test_trapezoids_grow_past_distant_vertices() {
+        // Two arms of different heights: the vertex at the top of the short arm
+        // does not cut the tall arm
+        let arms = vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 2.0),
+            point(3.0, 2.0),
+            point(3.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 3.0),
+            point(0.0, 3.0),
+        ];
+        let trapezoids = pointline_trapezoidal_decomposition(&arms);
+        assert_eq!(trapezoids.len(), 3);
+        assert!(trapezoids.contains(&vec![point(0.0, 1.0), point(1.0, 1.0), point(1.0, 3.0), point(0.0, 3.0)]));
+        assert_eq!(total_area(&trapezoids), pointline_area(&arms));
+    }
+
+    #[test]
+    fn test_trapezoids_slanted_edges() {
+        // Hexagon with slanted sides and a reflex vertex at the bottom
+        let poly = vec![
+            point(0.0, 0.0),
+            point(2.0, 1.0),
+            point(4.0, 0.0),
+            point(5.0, 2.0),
+            point(3.0, 4.0),
+            point(-1.0, 3.0),
+        ];
+        let trapezoids = pointline_trapezoidal_decomposition(&poly);
+        assert!((total_area(&trapezoids) - pointline_area(&poly)).abs() < 1e-12);
+        for t in &trapezoids {
+            assert!(t.len() == 3 || t.len() == 4);
+            assert!(pointline_area(t) > 0.0);
+            // Every corner is on the bottom or the top side
+            let bottom = t[0].y;
+            let top = t.iter().map(|p| p.y).fold(bottom, f64::max);
+            assert!(t.iter().all(|p| p.y == bottom || p.y == top));
+            // Each trapezoid is inside the polygon
+            let centroid = t.iter().fold(point(0.0, 0.0), |acc, p| acc + *p) / t.len() as f64;
+            assert!(pointline_contains(&poly, centroid));
+        }
+    }
+
+    #[test]
+    fn test_trapezoids_degenerate() {
+        assert!(pointline_trapezoidal_decomposition(&vec![]).is_empty());
+        assert!(pointline_trapezoidal_decomposition(&vec![point(0.0, 0.0), point(1.0, 1.0)]).is_empty());
+        let triangle = vec![point(0.0, 0.0), point(2.0, 0.0), point(1.0, 1.0)];
+        assert_eq!(pointline_trapezoidal_decomposition(&triangle), vec![triangle]);
+    }
+}
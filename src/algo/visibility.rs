@@ -0,0 +1,223 @@
+//! Visibility computations inside polygons.
+//!
+//! This module computes the region visible from a point inside a simple
+//! polygon given as a point sequence.
+
+use crate::prelude::*;
+
+/// Relative tolerance on ray parameters, where `1.0` is the vertex distance.
+const RAY_EPSILON: f64 = 1e-12;
+
+/// Computes the visibility polygon of a point inside a simple polygon.
+///
+/// The region visible from `viewpoint` is found by an angular sweep over the
+/// polygon vertices. A ray is cast towards every vertex and intersected with
+/// all edges: vertices hidden behind an edge are skipped. When the ray only
+/// grazes a visible vertex, with both of its edges on the same side of the ray,
+/// the ray is extended past the vertex to the next edge it hits, adding the
+/// shadow point there. The points are ordered by their angle around the
+/// viewpoint.
+///
+/// The polygon is closed implicitly (last point connects back to first), may
+/// be oriented CCW or CW and has no holes. The viewpoint is assumed to be
+/// strictly inside the polygon and not collinear with two vertices.
+///
+/// # Arguments
+///
+/// * `poly` - The simple polygon
+/// * `viewpoint` - The point to look from
+///
+/// # Returns
+///
+/// The visibility polygon in CCW order, or an empty pointline for polygons
+/// with fewer than 3 points
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::visibility::pointline_visibility;
+///
+/// // From the lower arm of an L-shape only a sliver of the upper arm is visible
+/// let l_shape = vec![
+///     point(0.0, 0.0),
+///     point(4.0, 0.0),
+///     point(4.0, 1.0),
+///     point(1.0, 1.0),
+///     point(1.0, 4.0),
+///     point(0.0, 4.0),
+/// ];
+/// let visible = pointline_visibility(&l_shape, point(3.0, 0.5));
+/// assert!((pointline_area(&visible) - 4.125).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn pointline_visibility(poly: &Pointline, viewpoint: Point) -> Pointline {
+    let n = poly.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let edge = |i: usize| (poly[i], poly[(i + 1) % n]);
+
+    // (angle, point), sorted stably so a vertex and its shadow keep their order
+    let mut hits: Vec<(f64, Point)> = Vec::new();
+    for i in 0..n {
+        let v = poly[i];
+        let d = v - viewpoint;
+        let prev_edge = (i + n - 1) % n;
+        let next_edge = i;
+        let nearest = (0..n)
+            .filter(|&j| j != prev_edge && j != next_edge)
+            .filter_map(|j| ray_edge_param(viewpoint, d, edge(j)))
+            .fold(f64::INFINITY, f64::min);
+        if nearest < 1.0 - RAY_EPSILON {
+            continue;
+        }
+
+        let angle = d.y.atan2(d.x);
+        let side_prev = points_order(viewpoint, v, poly[(i + n - 1) % n]);
+        let side_next = points_order(viewpoint, v, poly[(i + 1) % n]);
+        if side_prev * side_next <= 0.0 {
+            hits.push((angle, v));
+            continue;
+        }
+        // The ray grazes the vertex and continues to the edge behind it
+        let far = (0..n)
+            .filter(|&j| j != prev_edge && j != next_edge)
+            .filter_map(|j| ray_edge_param(viewpoint, d, edge(j)))
+            .filter(|&t| t > 1.0 + RAY_EPSILON)
+            .fold(f64::INFINITY, f64::min);
+        if !far.is_finite() {
+            hits.push((angle, v));
+            continue;
+        }
+        let shadow = viewpoint + d * far;
+        if side_prev > 0.0 {
+            // The vertex edges are on the CCW side, the shadow comes first
+            hits.push((angle, shadow));
+            hits.push((angle, v));
+        } else {
+            hits.push((angle, v));
+            hits.push((angle, shadow));
+        }
+    }
+    hits.sort_by(|x, y| x.0.total_cmp(&y.0));
+    hits.into_iter().map(|(_, p)| p).collect()
+}
+
+// Parameter `t > 0` where the ray `origin + t * d` meets the edge, if it does
+fn ray_edge_param(origin: Point, d: Point, (a, b): (Point, Point)) -> Option<f64> {
+    let e = b - a;
+    let denom = d.perp(e);
+    if denom == 0.0 {
+        return None;
+    }
+    let w = a - origin;
+    let t = w.perp(e) / denom;
+    let u = w.perp(d) / denom;
+    if t > 0.0 && (0.0..=1.0).contains(&u) {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test_pointline_visibility {
+    use super::*;
+
+    fn l_shape() -> Pointline {
+        vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 4.0),
+            point(0.0, 4.0),
+        ]
+    }
+
+    #[test]
+    fn test_visibility_convex_sees_everything() {
+        let hexagon: Pointline = (0..6)
+            .map(|k| {
+                let t = std::f64::consts::PI * k as f64 / 3.0;
+                point(2.0 * t.cos(), 2.0 * t.sin())
+            })
+            .collect();
+        let visible = pointline_visibility(&hexagon, point(0.5, -0.3));
+        assert_eq!(visible.len(), 6);
+        assert!((pointline_area(&visible) - pointline_area(&hexagon)).abs() < 1e-12);
+        for p in &hexagon {
+            assert!(visible.contains(p));
+        }
+
+        // A CW square is returned CCW
+        let square = vec![point(0.0, 0.0), point(0.0, 1.0), point(1.0, 1.0), point(1.0, 0.0)];
+        let visible = pointline_visibility(&square, point(0.3, 0.6));
+        assert_eq!(pointline_area(&visible), 1.0);
+    }
+
+    #[test]
+    fn test_visibility_l_shape_occluded() {
+        let visible = pointline_visibility(&l_shape(), point(3.0, 0.5));
+        assert_eq!(
+            visible,
+            vec![
+                point(0.0, 0.0),
+                point(4.0, 0.0),
+                point(4.0, 1.0),
+                point(1.0, 1.0),
+                point(0.0, 1.25),
+            ]
+        );
+        // Lower arm plus a triangle of the upper arm
+        assert!((pointline_area(&visible) - 4.125).abs() < 1e-12);
+        assert!(pointline_area(&visible) < pointline_area(&l_shape()));
+    }
+
+    #[test]
+    fn test_visibility_l_shape_from_corner_sees_all() {
+        // From the square where the arms meet, both arms are fully visible
+        let visible = pointline_visibility(&l_shape(), point(0.5, 0.5));
+        assert!((pointline_area(&visible) - pointline_area(&l_shape())).abs() < 1e-12);
+    }
+
+    // Rectangle with a notch cut down from the top between x = 2 and x = 3
+    fn notched() -> Pointline {
+        vec![
+            point(0.0, 0.0),
+            point(6.0, 0.0),
+            point(6.0, 4.0),
+            point(3.0, 4.0),
+            point(3.0, 2.0),
+            point(2.0, 2.0),
+            point(2.0, 4.0),
+            point(0.0, 4.0),
+        ]
+    }
+
+    #[test]
+    fn test_visibility_shadow_after_vertex() {
+        // The ray through (2, 2) continues to the left wall
+        let visible = pointline_visibility(&notched(), point(5.0, 1.0));
+        let i = visible.iter().position(|v| *v == point(2.0, 2.0)).unwrap();
+        assert!(visible[i + 1].close_enough(point(0.0, 8.0 / 3.0), 1e-12));
+        assert!(pointline_area(&visible) < pointline_area(&notched()));
+    }
+
+    #[test]
+    fn test_visibility_shadow_before_vertex() {
+        // The ray through (3, 2) continues to the right wall
+        let visible = pointline_visibility(&notched(), point(1.0, 1.0));
+        let i = visible.iter().position(|v| *v == point(3.0, 2.0)).unwrap();
+        assert!(visible[i - 1].close_enough(point(6.0, 3.5), 1e-12));
+        // Hidden: the corner behind the notch wall
+        let hidden = pointline_area(&vec![point(3.0, 2.0), point(6.0, 3.5), point(6.0, 4.0), point(3.0, 4.0)]);
+        assert!((pointline_area(&visible) - (pointline_area(&notched()) - hidden)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_visibility_degenerate() {
+        assert!(pointline_visibility(&vec![point(0.0, 0.0), point(1.0, 0.0)], point(0.5, 0.0)).is_empty());
+    }
+}
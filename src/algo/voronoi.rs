@@ -0,0 +1,516 @@
+//! Delaunay triangulation and Voronoi based constructions.
+//!
+//! This module triangulates point sets with the Bowyer-Watson algorithm and
+//! derives Voronoi structures from the triangulation: the Voronoi vertices are
+//! the circumcenters of the Delaunay triangles, and two triangles sharing an
+//! edge are joined by a Voronoi edge.
+
+use std::collections::HashMap;
+
+use robust::{Coord, incircle};
+
+use crate::algo::bounding::circumcircle;
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+
+/// Computes the Delaunay triangulation of a point set.
+///
+/// Incremental Bowyer-Watson insertion inside a large enclosing triangle, with
+/// the robust in-circle predicate. Cocircular points are triangulated in an
+/// arbitrary but valid way. Duplicate points are skipped.
+///
+/// Returns the triangles as CCW index triples into `points`.
+pub(crate) fn delaunay_triangles(points: &[Point]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let mut min = points[0];
+    let mut max = points[0];
+    for p in points {
+        min = point(min.x.min(p.x), min.y.min(p.y));
+        max = point(max.x.max(p.x), max.y.max(p.y));
+    }
+    let center = (min + max) * 0.5;
+    let size = (max.x - min.x).max(max.y - min.y).max(1.0) * 64.0;
+
+    // The enclosing triangle vertices follow the input points
+    let mut vertices = points.to_vec();
+    vertices.push(center + point(-size, -size));
+    vertices.push(center + point(size, -size));
+    vertices.push(center + point(0.0, size));
+    let coord = |i: usize| Coord { x: vertices[i].x, y: vertices[i].y };
+
+    let mut triangles: Vec<[usize; 3]> = vec![[n, n + 1, n + 2]];
+    for i in 0..n {
+        let p = coord(i);
+        let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) = triangles
+            .into_iter()
+            .partition(|t| incircle(coord(t[0]), coord(t[1]), coord(t[2]), p) > 0.0);
+        triangles = good;
+        if bad.is_empty() {
+            // Duplicate of an inserted point
+            continue;
+        }
+        // Directed edges of the cavity that are not shared by two bad triangles
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        for t in &bad {
+            for k in 0..3 {
+                *edges.entry((t[k], t[(k + 1) % 3])).or_insert(0) += 1;
+            }
+        }
+        for t in &bad {
+            for k in 0..3 {
+                let (u, v) = (t[k], t[(k + 1) % 3]);
+                if !edges.contains_key(&(v, u)) {
+                    triangles.push([u, v, i]);
+                }
+            }
+        }
+    }
+    triangles.retain(|t| t.iter().all(|&v| v < n));
+    triangles
+}
+
+/// Approximates the medial axis of a simple polygon.
+///
+/// The polygon boundary is densified with `sample_density` points per unit
+/// length and the Voronoi diagram of the samples is computed. Voronoi edges
+/// with both ends inside the polygon approximate the medial axis, except those
+/// separating two consecutive boundary samples, which cross the boundary
+/// perpendicularly and are dropped.
+///
+/// This is an approximation: the axis is made of many short segments, and its
+/// accuracy and the size of the spurious branches near the boundary improve
+/// with `sample_density`, at the cost of more samples (quadratic time in
+/// their number). Branches reach into every convex corner, as for the exact
+/// medial axis.
+///
+/// # Arguments
+///
+/// * `poly` - The simple polygon, closed implicitly (last point connects to first)
+/// * `sample_density` - The number of boundary samples per unit length
+///
+/// # Returns
+///
+/// The medial axis segments, or an empty vector for polygons with fewer than
+/// 3 points or a non-positive density
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::voronoi::pointline_medial_axis;
+///
+/// let rect = vec![point(0.0, 0.0), point(10.0, 0.0), point(10.0, 2.0), point(0.0, 2.0)];
+/// let axis = pointline_medial_axis(&rect, 4.0);
+/// // Most of the axis runs along the centerline y = 1
+/// let on_centerline: f64 = axis
+///     .iter()
+///     .filter(|s| (s.a.y - 1.0).abs() < 0.1 && (s.b.y - 1.0).abs() < 0.1)
+///     .map(|s| (s.b - s.a).norm())
+///     .sum();
+/// assert!(on_centerline > 7.0);
+/// ```
+#[must_use]
+pub fn pointline_medial_axis(poly: &Pointline, sample_density: f64) -> Vec<Segment> {
+    let n = poly.len();
+    if n < 3 || sample_density <= 0.0 {
+        return Vec::new();
+    }
+    let mut samples = Vec::new();
+    for i in 0..n {
+        let a = poly[i];
+        let b = poly[(i + 1) % n];
+        let count = ((b - a).norm() * sample_density).ceil().max(1.0) as usize;
+        for k in 0..count {
+            samples.push(a + (b - a) * (k as f64 / count as f64));
+        }
+    }
+    let m = samples.len();
+    let boundary: Arcline = (0..n).map(|i| arcseg(poly[i], poly[(i + 1) % n])).collect();
+
+    let triangles = delaunay_triangles(&samples);
+    let centers: Vec<Option<Point>> = triangles
+        .iter()
+        .map(|t| circumcircle(samples[t[0]], samples[t[1]], samples[t[2]]).map(|c| c.c))
+        .collect();
+    // Triangle on each side of every Delaunay edge
+    let mut sides: HashMap<(usize, usize), usize> = HashMap::new();
+    for (index, t) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            sides.insert((t[k], t[(k + 1) % 3]), index);
+        }
+    }
+
+    let mut axis = Vec::new();
+    for (left, t) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let (u, v) = (t[k], t[(k + 1) % 3]);
+            if u > v || (u + 1) % m == v || (v + 1) % m == u {
+                continue;
+            }
+            let Some(&right) = sides.get(&(v, u)) else {
+                continue;
+            };
+            let (Some(p), Some(q)) = (centers[left], centers[right]) else {
+                continue;
+            };
+            if (q - p).norm() >= GEOMETRIC_EPSILON && arcline_contains(&boundary, p) && arcline_contains(&boundary, q) {
+                axis.push(segment(p, q));
+            }
+        }
+    }
+    axis
+}
+
+/// Computes the Voronoi cells of a point set, clipped to a bounding box.
+///
+/// The cell of a point is the region closer to it than to any other point.
+/// It is built by clipping the box with the bisectors between the point and
+/// its Delaunay neighbours; when the point is in no Delaunay triangle (fewer
+/// than 3 points, all collinear, or a duplicate) all other points are used.
+/// Cells of points on the convex hull are unbounded and end at the box.
+///
+/// The box is the bounding box of the points enlarged on every side by half
+/// of its larger dimension (at least `1.0`).
+///
+/// # Arguments
+///
+/// * `points` - The sites
+///
+/// # Returns
+///
+/// One closed CCW polyline (all bulges zero) per input point, in input order.
+/// Duplicate points share the same cell.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::voronoi::points_voronoi;
+///
+/// let sites = vec![point(0.0, 0.0), point(2.0, 0.0), point(1.0, 2.0)];
+/// let cells = points_voronoi(&sites);
+/// assert_eq!(cells.len(), 3);
+/// // The three cells meet at the circumcenter of the sites
+/// for cell in &cells {
+///     assert!(cell.iter().any(|v| v.p.close_enough(point(1.0, 0.75), 1e-12)));
+/// }
+/// ```
+#[must_use]
+pub fn points_voronoi(points: &Pointline) -> Vec<Polyline> {
+    let n = points.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut min = points[0];
+    let mut max = points[0];
+    for p in points {
+        min = point(min.x.min(p.x), min.y.min(p.y));
+        max = point(max.x.max(p.x), max.y.max(p.y));
+    }
+    let margin = ((max.x - min.x).max(max.y - min.y) * 0.5).max(1.0);
+    let (min, max) = (min - point(margin, margin), max + point(margin, margin));
+    let bbox = vec![min, point(max.x, min.y), max, point(min.x, max.y)];
+    voronoi_cells(points, &bbox)
+        .into_iter()
+        .map(|cell| cell.into_iter().map(|v| pvertex(v, 0.0)).collect())
+        .collect()
+}
+
+// Voronoi cells of the points, clipped to a convex CCW polygon
+fn voronoi_cells(points: &Pointline, bbox: &Pointline) -> Vec<Pointline> {
+    let n = points.len();
+    let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for t in delaunay_triangles(points) {
+        for k in 0..3 {
+            let (u, v) = (t[k], t[(k + 1) % 3]);
+            if !neighbours[u].contains(&v) {
+                neighbours[u].push(v);
+                neighbours[v].push(u);
+            }
+        }
+    }
+
+    (0..n)
+        .map(|i| {
+            let p = points[i];
+            let others: Vec<usize> = if neighbours[i].is_empty() {
+                (0..n).filter(|&j| points[j] != p).collect()
+            } else {
+                neighbours[i].clone()
+            };
+            let mut cell = bbox.clone();
+            for j in others {
+                cell = clip_to_closer_half(&cell, p, points[j]);
+            }
+            cell
+        })
+        .collect()
+}
+
+/// Finds the largest circle centered in a rectangle that contains no input point.
+///
+/// The center of the largest empty circle is a vertex of the Voronoi diagram
+/// clipped to `bounds`: a Voronoi vertex inside the rectangle, a crossing of a
+/// Voronoi edge with the rectangle boundary, or a rectangle corner. All vertices
+/// of the clipped Voronoi cells are evaluated, the radius at a vertex being its
+/// distance to the site of its cell. Input points may lie on the circle.
+///
+/// # Arguments
+///
+/// * `points` - The points to avoid
+/// * `bounds` - The rectangle containing the circle center
+///
+/// # Returns
+///
+/// The largest empty circle, or `None` when there are no points or `bounds`
+/// is inverted (`p1` not below and left of `p2`)
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::algo::voronoi::points_largest_empty_circle;
+///
+/// // Three corners of a square: the circle is centered at the free corner
+/// let points = vec![point(0.0, 0.0), point(4.0, 0.0), point(0.0, 4.0)];
+/// let bounds = rect(point(0.0, 0.0), point(4.0, 4.0));
+/// let empty = points_largest_empty_circle(&points, &bounds).unwrap();
+/// assert_eq!(empty.c, point(4.0, 4.0));
+/// assert_eq!(empty.r, 4.0);
+/// ```
+#[must_use]
+pub fn points_largest_empty_circle(points: &Pointline, bounds: &Rect) -> Option<Circle> {
+    let (min, max) = (bounds.p1, bounds.p2);
+    if points.is_empty() || min.x > max.x || min.y > max.y {
+        return None;
+    }
+    let corners = vec![min, point(max.x, min.y), max, point(min.x, max.y)];
+    let cells = voronoi_cells(points, &corners);
+    let mut best: Option<Circle> = None;
+    for (site, cell) in points.iter().zip(&cells) {
+        for v in cell {
+            let r = (*v - *site).norm();
+            if best.is_none_or(|b| r > b.r) {
+                best = Some(circle(*v, r));
+            }
+        }
+    }
+    best
+}
+
+// Part of a convex polygon closer to `p` than to `q`
+fn clip_to_closer_half(poly: &Pointline, p: Point, q: Point) -> Pointline {
+    let mid = (p + q) * 0.5;
+    let d = q - p;
+    let side = |v: Point| (v - mid).dot(d);
+    let n = poly.len();
+    let mut result = Vec::with_capacity(n + 1);
+    for i in 0..n {
+        let a = poly[i];
+        let b = poly[(i + 1) % n];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= 0.0 {
+            result.push(a);
+        }
+        if (sa < 0.0 && sb > 0.0) || (sa > 0.0 && sb < 0.0) {
+            result.push(a + (b - a) * (sa / (sa - sb)));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test_pointline_medial_axis {
+    use super::*;
+
+    fn total_length(segments: &[Segment]) -> f64 {
+        segments.iter().map(|s| (s.b - s.a).norm()).sum()
+    }
+
+    #[test]
+    fn test_delaunay_square_with_center() {
+        let points = vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0), point(1.0, 1.0)];
+        let triangles = delaunay_triangles(&points);
+        assert_eq!(triangles.len(), 4);
+        for t in &triangles {
+            assert!(t.contains(&4));
+            assert!(points_order(points[t[0]], points[t[1]], points[t[2]]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_medial_axis_rectangle_centerline() {
+        let rect = vec![point(0.0, 0.0), point(10.0, 0.0), point(10.0, 2.0), point(0.0, 2.0)];
+        let axis = pointline_medial_axis(&rect, 4.0);
+        assert!(!axis.is_empty());
+        let centerline: Vec<Segment> = axis
+            .iter()
+            .copied()
+            .filter(|s| (s.a.y - 1.0).abs() < 0.05 && (s.b.y - 1.0).abs() < 0.05)
+            .collect();
+        // The exact axis has a centerline from x = 1 to x = 9 and four corner branches
+        let length = total_length(&centerline);
+        assert!(length > 7.5 && length < 8.5, "centerline length {}", length);
+        let min_x = centerline.iter().map(|s| s.a.x.min(s.b.x)).fold(f64::INFINITY, f64::min);
+        let max_x = centerline.iter().map(|s| s.a.x.max(s.b.x)).fold(f64::NEG_INFINITY, f64::max);
+        assert!(min_x > 0.5 && min_x < 1.5, "min x {}", min_x);
+        assert!(max_x > 8.5 && max_x < 9.5, "max x {}", max_x);
+        // Strictly inside, the corner branches stop short of the corners
+        for s in &axis {
+            for p in [s.a, s.b] {
+                assert!(p.x > 0.0 && p.x < 10.0 && p.y > 0.0 && p.y < 2.0);
+            }
+        }
+        assert!(total_length(&axis) < 8.0 + 4.0 * 2.0_f64.sqrt() + 1.0);
+    }
+
+    #[test]
+    fn test_medial_axis_l_shape_stays_inside() {
+        let l_shape = vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 1.0),
+            point(1.0, 1.0),
+            point(1.0, 4.0),
+            point(0.0, 4.0),
+        ];
+        let axis = pointline_medial_axis(&l_shape, 8.0);
+        let boundary: Arcline = (0..6).map(|i| arcseg(l_shape[i], l_shape[(i + 1) % 6])).collect();
+        assert!(!axis.is_empty());
+        for s in &axis {
+            assert!(arcline_contains(&boundary, s.a) && arcline_contains(&boundary, s.b));
+        }
+        // Both arms have a centerline
+        assert!(axis.iter().any(|s| (s.a.y - 0.5).abs() < 0.05 && s.a.x > 2.5));
+        assert!(axis.iter().any(|s| (s.a.x - 0.5).abs() < 0.05 && s.a.y > 2.5));
+    }
+
+    #[test]
+    fn test_medial_axis_degenerate() {
+        assert!(pointline_medial_axis(&vec![point(0.0, 0.0), point(1.0, 0.0)], 4.0).is_empty());
+        let square = vec![point(0.0, 0.0), point(1.0, 0.0), point(1.0, 1.0), point(0.0, 1.0)];
+        assert!(pointline_medial_axis(&square, 0.0).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_points_voronoi {
+    use super::*;
+
+    fn cell_points(cell: &Polyline) -> Pointline {
+        cell.iter().map(|v| v.p).collect()
+    }
+
+    #[test]
+    fn test_voronoi_square_four_cells_meet_at_center() {
+        let sites = vec![point(-1.0, -1.0), point(1.0, -1.0), point(1.0, 1.0), point(-1.0, 1.0)];
+        let cells = points_voronoi(&sites);
+        assert_eq!(cells.len(), 4);
+        for (site, cell) in sites.iter().zip(&cells) {
+            let poly = cell_points(cell);
+            // A quadrant of the box [-2, 2] x [-2, 2]
+            assert_eq!(poly.len(), 4);
+            assert!(poly.iter().any(|p| p.close_enough(point(0.0, 0.0), 1e-12)));
+            assert!(poly.iter().any(|p| p.close_enough(*site * 2.0, 1e-12)));
+            assert!((pointline_area(&poly) - 4.0).abs() < 1e-12);
+            assert!(cell.iter().all(|v| v.b == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_voronoi_cells_partition_box() {
+        let sites: Pointline = (0..40)
+            .map(|k| {
+                let t = k as f64 * 2.399;
+                let r = (k as f64).sqrt();
+                point(r * t.cos(), r * t.sin())
+            })
+            .collect();
+        let cells = points_voronoi(&sites);
+        let total: f64 = cells.iter().map(|c| pointline_area(&cell_points(c))).sum();
+        let mut min = sites[0];
+        let mut max = sites[0];
+        for p in &sites {
+            min = point(min.x.min(p.x), min.y.min(p.y));
+            max = point(max.x.max(p.x), max.y.max(p.y));
+        }
+        let margin = (max.x - min.x).max(max.y - min.y) * 0.5;
+        let box_area = (max.x - min.x + 2.0 * margin) * (max.y - min.y + 2.0 * margin);
+        assert!((total - box_area).abs() < 1e-9 * box_area);
+        // Every cell vertex is at least as close to its site as to any other
+        for (i, cell) in cells.iter().enumerate() {
+            for v in cell {
+                let own = (v.p - sites[i]).norm();
+                for other in &sites {
+                    assert!(own <= (v.p - *other).norm() + 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_voronoi_degenerate_inputs() {
+        assert!(points_voronoi(&vec![]).is_empty());
+        // A single site owns the whole box
+        let cells = points_voronoi(&vec![point(1.0, 1.0)]);
+        assert_eq!(pointline_area(&cell_points(&cells[0])), 4.0);
+        // Collinear sites get strips
+        let cells = points_voronoi(&vec![point(0.0, 0.0), point(1.0, 0.0), point(2.0, 0.0)]);
+        assert_eq!(cells.len(), 3);
+        let middle = cell_points(&cells[1]);
+        assert!(middle.iter().all(|p| (p.x - 0.5).abs() < 1e-12 || (p.x - 1.5).abs() < 1e-12));
+    }
+}
+
+#[cfg(test)]
+mod test_points_largest_empty_circle {
+    use super::*;
+
+    #[test]
+    fn test_empty_circle_grid_between_cells() {
+        let grid: Pointline = (0..25).map(|k| point((k % 5) as f64, (k / 5) as f64)).collect();
+        let bounds = rect(point(0.0, 0.0), point(4.0, 4.0));
+        let empty = points_largest_empty_circle(&grid, &bounds).unwrap();
+        // Centered in a grid cell, touching its four corners
+        assert!((empty.r - 0.5_f64.sqrt()).abs() < 1e-12);
+        assert!((empty.c.x.fract() - 0.5).abs() < 1e-12);
+        assert!((empty.c.y.fract() - 0.5).abs() < 1e-12);
+        for p in &grid {
+            assert!((*p - empty.c).norm() >= empty.r - 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_empty_circle_gap_in_grid() {
+        // Grid with the point (2, 2) missing: the circle fills the gap
+        let points: Pointline = (0..25)
+            .filter(|&k| k != 12)
+            .map(|k| point((k % 5) as f64, (k / 5) as f64))
+            .collect();
+        let bounds = rect(point(0.0, 0.0), point(4.0, 4.0));
+        let empty = points_largest_empty_circle(&points, &bounds).unwrap();
+        assert!(empty.c.close_enough(point(2.0, 2.0), 1e-12));
+        assert!((empty.r - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empty_circle_on_bounds_edge() {
+        // Two points at the top corners: the bisector meets the bottom edge at the center
+        let points = vec![point(0.0, 1.0), point(4.0, 1.0)];
+        let bounds = rect(point(0.0, 0.0), point(4.0, 1.0));
+        let empty = points_largest_empty_circle(&points, &bounds).unwrap();
+        assert!(empty.c.close_enough(point(2.0, 0.0), 1e-12));
+        assert!((empty.r - 5.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empty_circle_degenerate() {
+        let bounds = rect(point(0.0, 0.0), point(1.0, 1.0));
+        assert!(points_largest_empty_circle(&vec![], &bounds).is_none());
+        let inverted = rect(point(1.0, 1.0), point(0.0, 0.0));
+        assert!(points_largest_empty_circle(&vec![point(0.5, 0.5)], &inverted).is_none());
+    }
+}
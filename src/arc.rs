@@ -3,12 +3,16 @@
 use robust::{Coord, orient2d};
 
 use crate::constants::{DIVISION_EPSILON, GEOMETRIC_EPSILON};
+use crate::intersection::tangent::tangent_point_to_circle;
 use crate::prelude::*;
 
-use std::ops::Div;
-use std::{fmt::Display, sync::atomic::AtomicUsize};
+use core::f64::consts::PI;
+use core::{fmt::Display, sync::atomic::AtomicUsize};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 /// A Arcline is a sequence of connected Arc-s forming a path.
+#[cfg(feature = "std")]
 pub type Arcline = Vec<Arc>;
 
 static ID_COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -45,20 +49,58 @@ const ARC_COLLAPSED_TOLERANCE: f64 = GEOMETRIC_EPSILON; // 1e-10
 ///
 // #00001
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Arc {
     /// Start point of the arc.
     pub a: Point,
     /// End point of the arc.
     pub b: Point,
     /// Center point of the arc.
+    #[cfg_attr(feature = "serde", serde(with = "serde_infinite::center"))]
     pub c: Point,
     /// Radius of the arc.
+    #[cfg_attr(feature = "serde", serde(with = "serde_infinite::radius"))]
     pub r: f64,
     /// non-unique id, used for debugging and
     /// checking parts coming from the same segment
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub id: usize,
 }
 
+// Line segments have an infinite center and radius, which formats like JSON
+// cannot represent: they are written as missing values and read back as infinite.
+#[cfg(feature = "serde")]
+mod serde_infinite {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub mod radius {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(r: &f64, s: S) -> Result<S::Ok, S::Error> {
+            let r = if r.is_infinite() { None } else { Some(*r) };
+            r.serialize(s)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+            Ok(Option::<f64>::deserialize(d)?.unwrap_or(f64::INFINITY))
+        }
+    }
+
+    pub mod center {
+        use super::*;
+        use crate::point::{Point, point};
+
+        pub fn serialize<S: Serializer>(c: &Point, s: S) -> Result<S::Ok, S::Error> {
+            let c = if c.x.is_infinite() || c.y.is_infinite() { None } else { Some(*c) };
+            c.serialize(s)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Point, D::Error> {
+            Ok(Option::<Point>::deserialize(d)?.unwrap_or(point(f64::INFINITY, f64::INFINITY)))
+        }
+    }
+}
+
 // Implemented because id is different in tests
 impl PartialEq for Arc {
     fn eq(&self, other: &Self) -> bool {
@@ -68,7 +110,7 @@ impl PartialEq for Arc {
 }
 
 impl Display for Arc {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}, {}, {:.20}]", self.a, self.b, self.c, self.r)
     }
 }
@@ -112,7 +154,7 @@ impl Arc {
     /// ```
     #[inline]
     pub fn new(a: Point, b: Point, c: Point, r: f64) -> Self {
-        let id = ID_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let id = ID_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
         Arc { a, b, c, r, id }
     }
 
@@ -166,6 +208,33 @@ impl Arc {
         self.r == f64::INFINITY
     }
 
+    /// Returns true if this arc is a full circle.
+    ///
+    /// A curved arc whose end points are exactly equal (`a == b`) represents the whole
+    /// circle, starting and ending at `a`. Arcs with distinct but nearby ends are tiny
+    /// arcs, not full circles, as produced e.g. by offsetting. Full circles sweep 2π
+    /// (see [`Arc::sweep_angle`]), contain every point of their circle and are split
+    /// into two semicircles by [`Arc::split_at_point`]. Note that the validity
+    /// checks ([`Arc::is_collapsed_ends`], [`Arc::is_valid`]) still report them as
+    /// collapsed, since most arcline algorithms expect distinct end points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// assert!(full.is_full_circle());
+    ///
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// assert!(!half.is_full_circle());
+    /// assert!(!arcseg(point(0.0, 0.0), point(0.0, 0.0)).is_full_circle());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_full_circle(&self) -> bool {
+        self.is_arc() && self.a == self.b
+    }
+
     /// Translates this arc by the given vector.
     ///
     /// # Arguments
@@ -202,9 +271,44 @@ impl Arc {
         self.r *= factor;
     }
 
+    /// Rotates this arc about a point by the given angle.
+    ///
+    /// A positive angle rotates counter-clockwise. The radius is unchanged, and
+    /// since a rotation preserves orientation the arc stays CCW. For a line
+    /// segment only the end points are rotated, the center stays at infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of rotation
+    /// * `angle_rad` - The rotation angle in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let mut quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// quarter.rotate(point(0.0, 0.0), std::f64::consts::FRAC_PI_2);
+    /// assert!(quarter.a.close_enough(point(0.0, 1.0), 1e-12));
+    /// assert!(quarter.b.close_enough(point(-1.0, 0.0), 1e-12));
+    /// ```
+    pub fn rotate(&mut self, center: Point, angle_rad: f64) {
+        let (sin, cos) = (angle_rad.sin(), angle_rad.cos());
+        let rotate = |p: Point| {
+            let d = p - center;
+            center + point(d.x * cos - d.y * sin, d.x * sin + d.y * cos)
+        };
+        self.a = rotate(self.a);
+        self.b = rotate(self.b);
+        if self.is_arc() {
+            self.c = rotate(self.c);
+        }
+    }
+
     /// Returns a reversed copy of this Arc.
     ///
     /// The reversed arc (all arcs are CCW) is not the same as original arc, but complement of the circle.
+    /// A full circle has no complement and reverses to itself.
     ///
     /// # Returns
     ///
@@ -226,6 +330,7 @@ impl Arc {
     #[inline]
     /// Checks if the arc contains the given point,
     /// where the point is a result of intersection.
+    /// A full circle (`a == b`) contains every point of its circle.
     /// <div class="warning">This does not work for points not on the circle!</div>
     ///
     /// # Arguments
@@ -326,6 +431,7 @@ pub fn arcseg(a: Point, b: Point) -> Arc {
 
 /// Translates an arcline by a given translation vector, returning a new arcline.
 #[must_use]
+#[cfg(feature = "std")]
 pub fn arcline_translate(arcline: &Arcline, translation: Point) -> Arcline {
     let mut result: Arcline = Vec::with_capacity(arcline.len());
     for arc in arcline {
@@ -338,6 +444,7 @@ pub fn arcline_translate(arcline: &Arcline, translation: Point) -> Arcline {
 
 /// Scales an arcline by a given scale factor, returning a new arcline.
 #[must_use]
+#[cfg(feature = "std")]
 pub fn arcline_scale(arcline: &Arcline, scale: f64) -> Arcline {
     let mut result: Arcline = Vec::with_capacity(arcline.len());
     for arc in arcline {
@@ -358,6 +465,21 @@ pub fn arcline_scale(arcline: &Arcline, scale: f64) -> Arcline {
     result
 }
 
+/// Rotates an arcline about a point by the given angle, returning a new arcline.
+///
+/// A positive angle rotates counter-clockwise, see [`Arc::rotate`].
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_rotate(arcline: &Arcline, center: Point, angle: f64) -> Arcline {
+    let mut result: Arcline = Vec::with_capacity(arcline.len());
+    for arc in arcline {
+        let mut rotated_arc = *arc;
+        rotated_arc.rotate(center, angle);
+        result.push(rotated_arc);
+    }
+    result
+}
+
 #[cfg(test)]
 mod test_arc {
     use super::*;
@@ -565,6 +687,53 @@ mod test_arc {
         assert_eq!(result[0].c, point(0.25, 0.0));
         assert_eq!(result[0].r, 0.25);
     }
+
+    #[test]
+    fn test_rotate_quarter_arc_stays_ccw() {
+        let mut quarter = arc(point(2.0, 1.0), point(1.0, 2.0), point(1.0, 1.0), 1.0);
+        let sweep = quarter.sweep_angle();
+        quarter.rotate(point(1.0, 1.0), std::f64::consts::FRAC_PI_2);
+        assert!(quarter.a.close_enough(point(1.0, 2.0), 1e-12));
+        assert!(quarter.b.close_enough(point(0.0, 1.0), 1e-12));
+        assert_eq!(quarter.c, point(1.0, 1.0));
+        assert_eq!(quarter.r, 1.0);
+        // Same sweep, still the quarter from a to b CCW
+        assert!((quarter.sweep_angle() - sweep).abs() < 1e-12);
+        assert!(quarter.contains(point(1.0 - 0.5_f64.sqrt(), 1.0 + 0.5_f64.sqrt())));
+        assert!(quarter.is_consistent(1e-12));
+    }
+
+    #[test]
+    fn test_rotate_segment_keeps_infinite_radius() {
+        let mut seg = arcseg(point(1.0, 0.0), point(2.0, 0.0));
+        seg.rotate(point(0.0, 0.0), std::f64::consts::PI);
+        assert!(seg.is_seg());
+        assert_eq!(seg.r, f64::INFINITY);
+        assert_eq!(seg.c, point(f64::INFINITY, f64::INFINITY));
+        assert!(seg.a.close_enough(point(-1.0, 0.0), 1e-12));
+        assert!(seg.b.close_enough(point(-2.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_arcline_rotate_full_turns() {
+        let arcline = vec![
+            arc(point(0.0, 0.0), point(1.0, 0.0), point(0.5, 0.0), 0.5),
+            arcseg(point(1.0, 0.0), point(2.0, 1.0)),
+            arc(point(2.0, 1.0), point(2.0, 2.0), point(1.0, 1.5), 1.25_f64.sqrt()),
+        ];
+        let pivot = point(-3.0, 2.0);
+        for turns in [1.0, -2.0, 5.0] {
+            let result = arcline_rotate(&arcline, pivot, turns * std::f64::consts::TAU);
+            assert_eq!(result.len(), arcline.len());
+            for (rotated, original) in result.iter().zip(&arcline) {
+                assert!(rotated.a.close_enough(original.a, GEOMETRIC_EPSILON));
+                assert!(rotated.b.close_enough(original.b, GEOMETRIC_EPSILON));
+                assert_eq!(rotated.r, original.r);
+                assert!(rotated.is_seg() || rotated.c.close_enough(original.c, GEOMETRIC_EPSILON));
+            }
+        }
+        assert!(arcline_rotate(&vec![], pivot, 1.0).is_empty());
+    }
 }
 
 // #00003 #00004
@@ -1821,6 +1990,7 @@ mod test_arc_g_from_points {
 /// # Returns
 /// A new arcline with reversed direction
 #[must_use]
+#[cfg(feature = "std")]
 pub fn arcline_reverse(arcs: &Arcline) -> Arcline {
     let mut reversed: Vec<Arc> = Vec::with_capacity(arcs.len());
     for arc in arcs.iter().rev() {
@@ -2287,6 +2457,7 @@ pub enum ArclineValidation {
 /// Time complexity: O(n²) where n is the number of elements in the arcline,
 /// due to intersection checking between all non-adjacent pairs.
 #[must_use]
+#[cfg(feature = "std")]
 pub fn arcline_is_valid(arcs: &Arcline) -> ArclineValidation {
     let size = arcs.len();
     if size < 2 {
@@ -2394,6 +2565,7 @@ fn arc_have_two_connected_ends(arc1: &Arc, arc2: &Arc, arc3: &Arc) -> bool {
 /// # Returns
 ///
 /// `true` if the arc is convex (forward traversal), `false` if concave (backward traversal)
+#[cfg(feature = "std")]
 pub fn is_arc_convex(arcs: &Arcline, mut index: usize) -> bool {
     if arcs.is_empty() {
         return true; // Default to convex
@@ -2409,8 +2581,8 @@ pub fn is_arc_convex(arcs: &Arcline, mut index: usize) -> bool {
 // Check that each arc have 2 connected ends
 #[must_use]
 fn arc_tangents_are_collinear(arc1: &Arc, arc2: &Arc) -> bool {
-    let x = vec![arc1.a, arc1.b];
-    let y = vec![arc2.a, arc2.b];
+    let x = [arc1.a, arc1.b];
+    let y = [arc2.a, arc2.b];
 
     for i in 0..2 {
         for j in 0..2 {
@@ -3456,3 +3628,3415 @@ mod test_is_valid_arcline {
         assert!(is_arc_convex(&arcs, 2)); // The line segment
     }
 }
+
+impl Arc {
+    /// Splits the arc at a point into two parts.
+    ///
+    /// The first part goes from `a` to `p` and the second part from `p` to `b`.
+    /// Both parts keep the `id` of the original arc. Works for line segments too.
+    ///
+    /// A full circle has no distinct start and end, it is split into two
+    /// semicircles, from `p` to the opposite point and back to `p`.
+    ///
+    /// <div class="warning">The point is assumed to lie on the arc!</div>
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The split point on the arc
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the two parts `(a -> p, p -> b)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// let (first, second) = half.split_at_point(point(0.0, 1.0));
+    /// assert_eq!(first, arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0));
+    /// assert_eq!(second, arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0));
+    /// ```
+    #[must_use]
+    pub fn split_at_point(&self, p: Point) -> (Arc, Arc) {
+        if self.is_full_circle() {
+            let q = self.c * 2.0 - p;
+            let mut first = arc(p, q, self.c, self.r);
+            let mut second = arc(q, p, self.c, self.r);
+            first.id = self.id;
+            second.id = self.id;
+            return (first, second);
+        }
+        let first = Arc {
+            a: self.a,
+            b: p,
+            c: self.c,
+            r: self.r,
+            id: self.id,
+        };
+        let second = Arc {
+            a: p,
+            b: self.b,
+            c: self.c,
+            r: self.r,
+            id: self.id,
+        };
+        (first, second)
+    }
+}
+
+/// Splits the element of an arcline that contains the point `p`.
+///
+/// Finds the first element within `eps` of `p` and splits it there, so that
+/// the returned arcline has the split point as an element boundary. The split
+/// point is the closest point on the element, so arcs stay on their circle.
+/// If `p` is already an element boundary the arcline is returned unchanged.
+///
+/// Concave arcs (traversed from `b` to `a`, see [`is_arc_convex`]) are replaced
+/// by their parts in reverse order, so the traversal order is preserved.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to split
+/// * `p` - The split point on the boundary
+/// * `eps` - Maximum distance from `p` to the boundary
+///
+/// # Returns
+///
+/// The split arcline, or `None` if `p` is not on the boundary
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(0.0, 2.0)),
+///     arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+/// ];
+/// let split = arcline_split_at_point(&arcs, point(1.0, 0.0), 1e-10).unwrap();
+/// assert_eq!(split.len(), 4);
+/// assert_eq!(split[0].b, point(1.0, 0.0));
+/// assert!(arcline_split_at_point(&arcs, point(1.0, 1.5), 1e-10).is_none());
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_split_at_point(arcs: &Arcline, p: Point, eps: f64) -> Option<Arcline> {
+    for (i, element) in arcs.iter().enumerate() {
+        let (dist, closest) = if element.is_seg() {
+            dist_point_segment(&p, &segment(element.a, element.b))
+        } else {
+            match dist_point_arc(&p, element) {
+                DistPointArcConfig::OnePoint(dist, closest)
+                | DistPointArcConfig::Equidistant(dist, closest) => (dist, closest),
+            }
+        };
+        if dist > eps {
+            continue;
+        }
+        if closest.close_enough(element.a, eps) || closest.close_enough(element.b, eps) {
+            return Some(arcs.clone());
+        }
+
+        let (first, second) = element.split_at_point(closest);
+        let mut result: Arcline = Vec::with_capacity(arcs.len() + 1);
+        result.extend_from_slice(&arcs[..i]);
+        if is_arc_convex(arcs, i) {
+            result.push(first);
+            result.push(second);
+        } else {
+            result.push(second);
+            result.push(first);
+        }
+        result.extend_from_slice(&arcs[i + 1..]);
+        return Some(result);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test_split_at_point {
+    use super::*;
+
+    // Disc of radius 2 centered at (1, 1), made of four quarter arcs
+    fn disc_of_arcs() -> Arcline {
+        let c = point(1.0, 1.0);
+        vec![
+            arc(point(3.0, 1.0), point(1.0, 3.0), c, 2.0),
+            arc(point(1.0, 3.0), point(-1.0, 1.0), c, 2.0),
+            arc(point(-1.0, 1.0), point(1.0, -1.0), c, 2.0),
+            arc(point(1.0, -1.0), point(3.0, 1.0), c, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_arc_split_at_point_segment() {
+        let mut seg = arcseg(point(0.0, 0.0), point(4.0, 0.0));
+        seg.id(7);
+        let (first, second) = seg.split_at_point(point(1.0, 0.0));
+        assert_eq!(first, arcseg(point(0.0, 0.0), point(1.0, 0.0)));
+        assert_eq!(second, arcseg(point(1.0, 0.0), point(4.0, 0.0)));
+        assert_eq!(first.id, 7);
+        assert_eq!(second.id, 7);
+    }
+
+    #[test]
+    fn test_arcline_split_at_point_disc() {
+        let arcs = disc_of_arcs();
+        let s = 2.0_f64.sqrt();
+        let mid = point(1.0 + s, 1.0 + s); // middle of the first quarter arc
+        let split = arcline_split_at_point(&arcs, mid, 1e-10).unwrap();
+        assert_eq!(split.len(), 5);
+        assert_eq!(split[0].a, point(3.0, 1.0));
+        assert!(split[0].b.close_enough(mid, 1e-12));
+        assert!(split[1].a.close_enough(mid, 1e-12));
+        assert_eq!(split[1].b, point(1.0, 3.0));
+        assert_eq!(split[0].c, point(1.0, 1.0));
+        assert_eq!(split[0].r, 2.0);
+        assert_eq!(&split[2..], &arcs[1..]);
+        assert_eq!(arcline_is_valid(&split), ArclineValidation::Valid);
+        assert!((crate::algo::arcline_area(&split) - crate::algo::arcline_area(&arcs)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_arcline_split_at_point_not_on_boundary() {
+        let arcs = disc_of_arcs();
+        assert!(arcline_split_at_point(&arcs, point(1.0, 1.0), 1e-10).is_none());
+        assert!(arcline_split_at_point(&arcs, point(5.0, 1.0), 1e-10).is_none());
+    }
+
+    #[test]
+    fn test_arcline_split_at_point_vertex() {
+        let arcs = disc_of_arcs();
+        let split = arcline_split_at_point(&arcs, point(1.0, 3.0), 1e-10).unwrap();
+        assert_eq!(split, arcs);
+    }
+
+    #[test]
+    fn test_arcline_split_at_point_concave() {
+        // Square with the top edge replaced by an inward-bulging arc
+        let concave = arc_from_bulge(point(2.0, 2.0), point(0.0, 2.0), -0.5);
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            concave,
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let lowest = concave.c - point(0.0, concave.r);
+        let split = arcline_split_at_point(&arcs, lowest, 1e-10).unwrap();
+        assert_eq!(split.len(), 5);
+        assert!(split[2].b.close_enough(point(2.0, 2.0), 1e-12));
+        assert!(split[2].a.close_enough(lowest, 1e-12));
+        assert!(split[3].b.close_enough(lowest, 1e-12));
+        assert!(split[3].a.close_enough(point(0.0, 2.0), 1e-12));
+        assert!(!is_arc_convex(&split, 2));
+        assert!(!is_arc_convex(&split, 3));
+    }
+}
+
+impl Arc {
+    /// Returns the axis-extreme points of the circle that lie inside the arc.
+    ///
+    /// The candidates are the rightmost, topmost, leftmost and bottommost points
+    /// of the supporting circle, in this order. Only those strictly inside the
+    /// arc's sweep are returned; extrema at the arc endpoints are skipped. A full
+    /// circle (`a == b`) returns all four points, a line segment returns none.
+    ///
+    /// # Returns
+    ///
+    /// 0 to 4 extreme points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// // Upper half of the unit circle
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// assert_eq!(half.cardinal_extrema(), vec![point(0.0, 1.0)]);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn cardinal_extrema(&self) -> Vec<Point> {
+        if self.is_seg() {
+            return Vec::new();
+        }
+        let extrema = [
+            self.c + point(self.r, 0.0),
+            self.c + point(0.0, self.r),
+            self.c + point(-self.r, 0.0),
+            self.c + point(0.0, -self.r),
+        ];
+        if self.a.close_enough(self.b, GEOMETRIC_EPSILON) {
+            return extrema.to_vec();
+        }
+        extrema
+            .into_iter()
+            .filter(|&p| {
+                self.contains(p)
+                    && !p.close_enough(self.a, GEOMETRIC_EPSILON)
+                    && !p.close_enough(self.b, GEOMETRIC_EPSILON)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_cardinal_extrema {
+    use super::*;
+
+    #[test]
+    fn test_cardinal_extrema_semicircle() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        assert_eq!(half.cardinal_extrema(), vec![point(1.0, 3.0)]);
+        let lower = half.reverse();
+        assert_eq!(lower.cardinal_extrema(), vec![point(1.0, -1.0)]);
+    }
+
+    #[test]
+    fn test_cardinal_extrema_quarter() {
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        assert!(quarter.cardinal_extrema().is_empty());
+        let small = arc_from_bulge(point(0.9, 0.1), point(0.1, 0.9), 0.2);
+        assert!(small.cardinal_extrema().is_empty());
+    }
+
+    #[test]
+    fn test_cardinal_extrema_large_arc() {
+        // Three quarters of the unit circle from the top, going CCW
+        let large = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(
+            large.cardinal_extrema(),
+            vec![point(-1.0, 0.0), point(0.0, -1.0)]
+        );
+    }
+
+    #[test]
+    fn test_cardinal_extrema_full_circle_and_segment() {
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(full.cardinal_extrema().len(), 4);
+        let seg = arcseg(point(0.0, 0.0), point(1.0, 1.0));
+        assert!(seg.cardinal_extrema().is_empty());
+    }
+}
+
+impl Arc {
+    /// Returns the CCW sweep angle of the arc from `a` to `b`.
+    ///
+    /// The result is in (0, 2π]; a full circle (`a == b`) sweeps 2π.
+    /// Line segments return 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// assert!((quarter.sweep_angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn sweep_angle(&self) -> f64 {
+        if self.is_seg() {
+            return 0.0;
+        }
+        if self.is_full_circle() {
+            return 2.0 * PI;
+        }
+        let va = self.a - self.c;
+        let vb = self.b - self.c;
+        let angle = va.perp(vb).atan2(va.dot(vb));
+        if angle <= 0.0 { angle + 2.0 * PI } else { angle }
+    }
+
+    /// Returns the length of the arc.
+    ///
+    /// For line segments this is the distance between the end points, for arcs
+    /// `r * sweep_angle()`; a full circle (`a == b`) has length `2πr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// assert!((quarter.length() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// assert_eq!(arcseg(point(0.0, 0.0), point(3.0, 4.0)).length(), 5.0);
+    /// ```
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        if self.is_seg() {
+            (self.b - self.a).norm()
+        } else {
+            self.r * self.sweep_angle()
+        }
+    }
+
+    /// Returns the point at parameter `t` along the arc.
+    ///
+    /// `t = 0` gives `a` and `t = 1` gives `b`. Curved arcs are parametrized
+    /// by angle (uniform in arc length) in CCW direction, segments linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The parameter, in [0, 1] for points on the arc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// assert!(half.point_at(0.5).close_enough(point(0.0, 1.0), 1e-12));
+    /// ```
+    #[must_use]
+    pub fn point_at(&self, t: f64) -> Point {
+        if t == 0.0 {
+            return self.a;
+        }
+        if t == 1.0 {
+            return self.b;
+        }
+        if self.is_seg() {
+            return self.a + (self.b - self.a) * t;
+        }
+        let va = self.a - self.c;
+        let angle = va.y.atan2(va.x) + t * self.sweep_angle();
+        self.c + point(angle.cos(), angle.sin()) * self.r
+    }
+
+    /// Returns `n` points evenly spaced along the arc, including both end points.
+    ///
+    /// The points are [`Arc::point_at`] at parameters `k / (n - 1)`, so they are
+    /// equally spaced in arc length, from `a` to `b` in CCW direction. Points of
+    /// a segment are collinear. For `n < 2` only the two end points are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// let points = half.sample(5);
+    /// assert_eq!(points.len(), 5);
+    /// assert!(points[2].close_enough(point(0.0, 1.0), 1e-12));
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn sample(&self, n: usize) -> Vec<Point> {
+        if n < 2 {
+            return vec![self.a, self.b];
+        }
+        let last = (n - 1) as f64;
+        (0..n).map(|k| self.point_at(k as f64 / last)).collect()
+    }
+
+    /// Returns the parameter `t` of a point on the arc, the inverse of [`Arc::point_at`].
+    ///
+    /// For line segments this is the segment parameter. The point must lie on the
+    /// arc within `GEOMETRIC_EPSILON`, otherwise `None` is returned. For a full circle
+    /// the start point `a` has parameter 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The point on the arc
+    ///
+    /// # Returns
+    ///
+    /// The parameter `t` in [0, 1], or `None` if `p` is not on the arc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// let t = half.param_of_point(point(0.0, 1.0)).unwrap();
+    /// assert!((t - 0.5).abs() < 1e-12);
+    /// assert_eq!(half.param_of_point(point(0.0, -1.0)), None);
+    /// ```
+    #[must_use]
+    pub fn param_of_point(&self, p: Point) -> Option<f64> {
+        if self.is_seg() {
+            let ab = self.b - self.a;
+            let len2 = ab.dot(ab);
+            if len2 < DIVISION_EPSILON {
+                return p.close_enough(self.a, GEOMETRIC_EPSILON).then_some(0.0);
+            }
+            let t = (p - self.a).dot(ab) / len2;
+            let closest = self.a + ab * t;
+            if (p - closest).norm() > GEOMETRIC_EPSILON {
+                return None;
+            }
+            let tol = GEOMETRIC_EPSILON / len2.sqrt();
+            if t < -tol || t > 1.0 + tol {
+                return None;
+            }
+            return Some(t.clamp(0.0, 1.0));
+        }
+
+        if ((p - self.c).norm() - self.r).abs() > GEOMETRIC_EPSILON {
+            return None;
+        }
+        let va = self.a - self.c;
+        let vp = p - self.c;
+        let mut angle = va.perp(vp).atan2(va.dot(vp));
+        if angle < 0.0 {
+            angle += 2.0 * PI;
+        }
+        let sweep = self.sweep_angle();
+        // Tolerance along the arc, converted to angle
+        let tol = GEOMETRIC_EPSILON / self.r;
+        if angle <= sweep + tol {
+            Some((angle / sweep).min(1.0))
+        } else if 2.0 * PI - angle <= tol {
+            // Just before a, on the other side of the angle wrap
+            Some(0.0)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the total length of an arcline, the sum of its element lengths.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arc(point(2.0, 0.0), point(0.0, 0.0), point(1.0, 0.0), 1.0),
+/// ];
+/// assert!((arcline_length(&arcs) - (2.0 + std::f64::consts::PI)).abs() < 1e-12);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_length(arcs: &Arcline) -> f64 {
+    arcs.iter().map(Arc::length).sum()
+}
+
+#[cfg(test)]
+mod test_param_of_point {
+    use super::*;
+
+    #[test]
+    fn test_sweep_angle() {
+        let c = point(0.0, 0.0);
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0);
+        assert!((quarter.sweep_angle() - PI / 2.0).abs() < 1e-12);
+        let large = arc(point(0.0, 1.0), point(1.0, 0.0), c, 1.0);
+        assert!((large.sweep_angle() - 1.5 * PI).abs() < 1e-12);
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), c, 1.0);
+        assert_eq!(full.sweep_angle(), 2.0 * PI);
+        assert_eq!(arcseg(point(0.0, 0.0), point(1.0, 0.0)).sweep_angle(), 0.0);
+    }
+
+    #[test]
+    fn test_point_at_endpoints() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        assert_eq!(half.point_at(0.0), half.a);
+        assert_eq!(half.point_at(1.0), half.b);
+        assert!(half.point_at(0.5).close_enough(point(1.0, 3.0), 1e-12));
+        let seg = arcseg(point(0.0, 0.0), point(4.0, 2.0));
+        assert_eq!(seg.point_at(0.25), point(1.0, 0.5));
+    }
+
+    #[test]
+    fn test_param_of_point_roundtrip_semicircle() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        for t in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            let p = half.point_at(t);
+            let back = half.param_of_point(p).unwrap();
+            assert!((back - t).abs() < 1e-12, "t = {}: got {}", t, back);
+        }
+    }
+
+    #[test]
+    fn test_param_of_point_segment() {
+        let seg = arcseg(point(0.0, 0.0), point(4.0, 2.0));
+        assert_eq!(seg.param_of_point(point(2.0, 1.0)), Some(0.5));
+        assert_eq!(seg.param_of_point(point(0.0, 0.0)), Some(0.0));
+        assert_eq!(seg.param_of_point(point(4.0, 2.0)), Some(1.0));
+        assert_eq!(seg.param_of_point(point(2.0, 1.5)), None);
+        assert_eq!(seg.param_of_point(point(6.0, 3.0)), None);
+    }
+
+    #[test]
+    fn test_param_of_point_not_on_arc() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        assert_eq!(half.param_of_point(point(1.0, -1.0)), None); // other half
+        assert_eq!(half.param_of_point(point(1.0, 2.0)), None); // inside the circle
+    }
+
+    #[test]
+    fn test_param_of_point_full_circle() {
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(full.param_of_point(point(1.0, 0.0)), Some(0.0));
+        let t = full.param_of_point(point(-1.0, 0.0)).unwrap();
+        assert!((t - 0.5).abs() < 1e-12);
+        let t = full.param_of_point(point(0.0, -1.0)).unwrap();
+        assert!((t - 0.75).abs() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod test_arc_length {
+    use super::*;
+
+    #[test]
+    fn test_length_quarter_and_semicircle() {
+        let c = point(0.0, 0.0);
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0);
+        assert!((quarter.length() - PI / 2.0).abs() < 1e-12);
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        assert!((half.length() - 2.0 * PI).abs() < 1e-12);
+        // The complement of the quarter
+        assert!((quarter.reverse().length() - 1.5 * PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_length_full_circle_and_segment() {
+        let full = arc(point(3.0, 0.0), point(3.0, 0.0), point(0.0, 0.0), 3.0);
+        assert_eq!(full.length(), 6.0 * PI);
+        assert_eq!(arcseg(point(1.0, 1.0), point(4.0, 5.0)).length(), 5.0);
+        assert_eq!(arcseg(point(1.0, 1.0), point(1.0, 1.0)).length(), 0.0);
+    }
+
+    #[test]
+    fn test_arcline_length() {
+        let slot = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arc(point(4.0, 0.0), point(4.0, 2.0), point(4.0, 1.0), 1.0),
+            arcseg(point(4.0, 2.0), point(0.0, 2.0)),
+            arc(point(0.0, 2.0), point(0.0, 0.0), point(0.0, 1.0), 1.0),
+        ];
+        assert!((arcline_length(&slot) - (8.0 + 2.0 * PI)).abs() < 1e-12);
+        assert_eq!(arcline_length(&vec![]), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod test_arc_sample {
+    use super::*;
+
+    #[test]
+    fn test_sample_semicircle_midpoint() {
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        let points = half.sample(3);
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0], half.a);
+        assert!(points[1].close_enough(point(1.0, 3.0), 1e-12));
+        assert_eq!(points[2], half.b);
+    }
+
+    #[test]
+    fn test_sample_evenly_spaced() {
+        let large = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        let points = large.sample(7);
+        assert_eq!(points.len(), 7);
+        // Equal chords between consecutive points, on the arc
+        let chord = (points[1] - points[0]).norm();
+        for pair in points.windows(2) {
+            assert!(((pair[1] - pair[0]).norm() - chord).abs() < 1e-12);
+        }
+        for p in &points {
+            assert!(large.contains(*p));
+        }
+    }
+
+    #[test]
+    fn test_sample_segment_and_small_n() {
+        let seg = arcseg(point(0.0, 0.0), point(4.0, 2.0));
+        let points = seg.sample(5);
+        assert_eq!(points[1], point(1.0, 0.5));
+        for p in &points {
+            assert_eq!(points_order(seg.a, seg.b, *p), 0.0);
+        }
+        assert_eq!(seg.sample(0), vec![seg.a, seg.b]);
+        assert_eq!(seg.sample(1), vec![seg.a, seg.b]);
+        assert_eq!(seg.sample(2), vec![seg.a, seg.b]);
+    }
+}
+
+/// Returns the region between an arc and its chord as a closed arcline.
+///
+/// The region (a circular segment) is the intersection of the arc's disc with
+/// the half-plane on the arc side of the chord, so it is convex. It is returned
+/// CCW: the arc from `a` to `b`, then the chord segment back from `b` to `a`.
+/// An arc has no orientation of its own, so for an arc that is concave in some
+/// arcline (traversed from `b` to `a`) the region is the same circular segment,
+/// lying on the other side of the chord than that arcline's interior.
+///
+/// A full circle gives its disc as a single element arcline, a line segment
+/// encloses no region and gives an empty arcline.
+///
+/// # Arguments
+///
+/// * `a` - The arc
+///
+/// # Returns
+///
+/// The closed arcline bounding the circular segment
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+/// let region = arc_region_hull(&half);
+/// assert_eq!(region, vec![half, arcseg(point(-1.0, 0.0), point(1.0, 0.0))]);
+/// assert!((arcline_area(&region) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arc_region_hull(a: &Arc) -> Arcline {
+    if a.is_seg() {
+        return Vec::new();
+    }
+    if a.is_full_circle() {
+        return vec![*a];
+    }
+    vec![*a, arcseg(a.b, a.a)]
+}
+
+#[cfg(test)]
+mod test_arc_region_hull {
+    use super::*;
+
+    // Area of the circular segment of radius r and sweep angle theta
+    fn segment_area(r: f64, theta: f64) -> f64 {
+        0.5 * r * r * (theta - theta.sin())
+    }
+
+    #[test]
+    fn test_region_hull_area_minor_and_major() {
+        let c = point(1.0, -2.0);
+        let at = |angle: f64| c + point(angle.cos(), angle.sin()) * 3.0;
+        for (start, end) in [(0.2, 1.1), (0.5, 3.0), (1.0, 5.5), (4.0, 0.5)] {
+            let a = arc(at(start), at(end), c, 3.0);
+            let region = arc_region_hull(&a);
+            assert_eq!(region.len(), 2);
+            assert_eq!(arcline_is_valid(&region), ArclineValidation::Valid);
+            let expected = segment_area(3.0, a.sweep_angle());
+            assert!((arcline_area(&region) - expected).abs() < 1e-9, "{} {}", start, end);
+        }
+    }
+
+    #[test]
+    fn test_region_hull_concave_arc() {
+        // The top of the square bulges inward, the region is the bite outside it
+        let concave = arc_from_bulge(point(2.0, 2.0), point(0.0, 2.0), -0.5);
+        let region = arc_region_hull(&concave);
+        assert!((arcline_area(&region) - segment_area(concave.r, concave.sweep_angle())).abs() < 1e-12);
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            concave,
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        // The bite is inside the region and outside the square's arcline
+        let bite = point(1.0, 1.8);
+        assert!(arcline_contains(&region, bite));
+        assert!(!arcline_contains(&square, bite));
+    }
+
+    #[test]
+    fn test_region_hull_full_circle_and_segment() {
+        let full = arc(point(2.0, 0.0), point(2.0, 0.0), point(0.0, 0.0), 2.0);
+        assert_eq!(arc_region_hull(&full), vec![full]);
+        assert!(arc_region_hull(&arcseg(point(0.0, 0.0), point(1.0, 0.0))).is_empty());
+    }
+}
+
+/// Merges two adjacent arcs of the same circle into one arc.
+///
+/// The arcs are merged when both are curved, their centers and radii agree
+/// within `eps`, and the end of one is the start of the other (within `eps`)
+/// in CCW order, in either argument order. The merged arc runs CCW from the
+/// start of the leading arc to the end of the trailing one, on the circle of
+/// `a1`. When each arc ends where the other starts the result is a full circle
+/// starting at `a1.a`. Arcs whose sweeps would overlap are not merged.
+///
+/// # Arguments
+///
+/// * `a1` - The first arc
+/// * `a2` - The second arc
+/// * `eps` - Tolerance for the center, radius and endpoint comparisons
+///
+/// # Returns
+///
+/// The arc covering both arcs, or `None` if they cannot be merged
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let c = point(0.0, 0.0);
+/// let q1 = arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0);
+/// let q2 = arc(point(0.0, 1.0), point(-1.0, 0.0), c, 1.0);
+/// let half = merge_cocircular_arcs(&q2, &q1, 1e-9).unwrap();
+/// assert_eq!((half.a, half.b), (point(1.0, 0.0), point(-1.0, 0.0)));
+/// ```
+#[must_use]
+pub fn merge_cocircular_arcs(a1: &Arc, a2: &Arc, eps: f64) -> Option<Arc> {
+    if a1.is_seg() || a2.is_seg() || !a1.c.close_enough(a2.c, eps) || (a1.r - a2.r).abs() > eps {
+        return None;
+    }
+    if a1.sweep_angle() + a2.sweep_angle() > 2.0 * PI + eps {
+        return None;
+    }
+    let forward = a1.b.close_enough(a2.a, eps);
+    let backward = a2.b.close_enough(a1.a, eps);
+    match (forward, backward) {
+        (true, true) => Some(arc(a1.a, a1.a, a1.c, a1.r)),
+        (true, false) => Some(arc(a1.a, a2.b, a1.c, a1.r)),
+        (false, true) => Some(arc(a2.a, a1.b, a1.c, a1.r)),
+        (false, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod test_merge_cocircular_arcs {
+    use super::*;
+
+    fn quarters() -> [Arc; 4] {
+        let c = point(1.0, 1.0);
+        [
+            arc(point(3.0, 1.0), point(1.0, 3.0), c, 2.0),
+            arc(point(1.0, 3.0), point(-1.0, 1.0), c, 2.0),
+            arc(point(-1.0, 1.0), point(1.0, -1.0), c, 2.0),
+            arc(point(1.0, -1.0), point(3.0, 1.0), c, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_merge_quarters_into_semicircle() {
+        let q = quarters();
+        let half = merge_cocircular_arcs(&q[0], &q[1], 1e-9).unwrap();
+        assert_eq!((half.a, half.b, half.c, half.r), (point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0));
+        assert!((half.sweep_angle() - PI).abs() < 1e-12);
+        // Argument order does not matter
+        let same = merge_cocircular_arcs(&q[1], &q[0], 1e-9).unwrap();
+        assert_eq!((same.a, same.b), (half.a, half.b));
+        // Across the angle wrap
+        let lower_right = merge_cocircular_arcs(&q[3], &q[0], 1e-9).unwrap();
+        assert_eq!((lower_right.a, lower_right.b), (point(1.0, -1.0), point(1.0, 3.0)));
+    }
+
+    #[test]
+    fn test_merge_within_tolerance_and_full_circle() {
+        let q = quarters();
+        let nudged = arc(point(1.0, 3.0 + 1e-10), point(-1.0, 1.0), point(1.0 + 1e-10, 1.0), 2.0);
+        assert!(merge_cocircular_arcs(&q[0], &nudged, 1e-9).is_some());
+        assert!(merge_cocircular_arcs(&q[0], &nudged, 1e-12).is_none());
+        let half0 = merge_cocircular_arcs(&q[0], &q[1], 1e-9).unwrap();
+        let half1 = merge_cocircular_arcs(&q[2], &q[3], 1e-9).unwrap();
+        let full = merge_cocircular_arcs(&half0, &half1, 1e-9).unwrap();
+        assert!(full.is_full_circle());
+        assert_eq!(full.a, point(3.0, 1.0));
+    }
+
+    #[test]
+    fn test_merge_rejects_different_circles() {
+        let q = quarters();
+        // Same end points, other circle
+        let other = arc(point(1.0, 3.0), point(-1.0, 1.0), point(-1.0, 3.0), 2.0);
+        assert!(merge_cocircular_arcs(&q[0], &other, 1e-9).is_none());
+        // Concentric, other radius
+        let inner = arc(point(1.0, 2.0), point(0.0, 1.0), point(1.0, 1.0), 1.0);
+        assert!(merge_cocircular_arcs(&q[0], &inner, 1e-9).is_none());
+        // Same circle, not adjacent
+        assert!(merge_cocircular_arcs(&q[0], &q[2], 1e-9).is_none());
+        // Segments are not merged
+        let seg = arcseg(point(1.0, 3.0), point(-1.0, 1.0));
+        assert!(merge_cocircular_arcs(&q[0], &seg, 1e-9).is_none());
+    }
+
+    #[test]
+    fn test_merge_rejects_overlapping_sweeps() {
+        let c = point(0.0, 0.0);
+        let at = |angle: f64| point(angle.cos(), angle.sin());
+        let long = arc(at(0.0), at(5.0), c, 1.0);
+        let wrap = arc(at(5.0), at(2.0), c, 1.0);
+        assert!(merge_cocircular_arcs(&long, &wrap, 1e-9).is_none());
+    }
+}
+
+/// Degenerate elements and vertices found in an arcline by [`arcline_degenerate_report`].
+///
+/// All fields hold element indices. An empty report means no defects were found.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg(feature = "std")]
+pub struct DegenerateReport {
+    /// Arcs with radius below tolerance or NaN, see [`Arc::is_collapsed_radius`].
+    pub collapsed_radius: Vec<usize>,
+    /// Elements with (nearly) coinciding endpoints, see [`Arc::is_collapsed_ends`].
+    /// Full circles are reported here too.
+    pub collapsed_ends: Vec<usize>,
+    /// Arcs whose endpoints are not on their circle, see [`Arc::is_consistent`].
+    pub inconsistent: Vec<usize>,
+    /// Pairs of elements `(i, j)`, `i < j`, having endpoints within tolerance
+    /// of each other that are not exactly equal.
+    pub near_duplicate_vertices: Vec<(usize, usize)>,
+}
+
+#[cfg(feature = "std")]
+impl DegenerateReport {
+    /// Returns the total number of defects found.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.collapsed_radius.len()
+            + self.collapsed_ends.len()
+            + self.inconsistent.len()
+            + self.near_duplicate_vertices.len()
+    }
+
+    /// Returns true if no defects were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+}
+
+/// Collects the degenerate elements and vertices of an arcline.
+///
+/// Where [`arcline_is_valid`] stops at the first problem, this function reports
+/// every occurrence of the element defects checked by [`Arc::is_valid`], and the
+/// endpoints that almost, but not exactly, coincide. Such near-duplicate vertices
+/// break the exact endpoint matching used for connectivity.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to inspect
+/// * `eps` - Tolerance for the checks
+///
+/// # Returns
+///
+/// A [`DegenerateReport`] listing the indices of the problematic elements
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 1e-12), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let report = arcline_degenerate_report(&arcs, 1e-8);
+/// assert_eq!(report.near_duplicate_vertices, vec![(0, 1)]);
+/// assert_eq!(report.count(), 1);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_degenerate_report(arcs: &Arcline, eps: f64) -> DegenerateReport {
+    let mut report = DegenerateReport::default();
+
+    for (i, arc) in arcs.iter().enumerate() {
+        if arc.is_arc() && arc.is_collapsed_radius(eps) {
+            report.collapsed_radius.push(i);
+        }
+        if arc.is_collapsed_ends(eps) {
+            report.collapsed_ends.push(i);
+        }
+        if !arc.is_consistent(eps) {
+            report.inconsistent.push(i);
+        }
+    }
+
+    for i in 0..arcs.len() {
+        for j in (i + 1)..arcs.len() {
+            let near_duplicate = [arcs[i].a, arcs[i].b].iter().any(|p| {
+                [arcs[j].a, arcs[j].b]
+                    .iter()
+                    .any(|q| p != q && p.close_enough(*q, eps))
+            });
+            if near_duplicate {
+                report.near_duplicate_vertices.push((i, j));
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod test_arcline_degenerate_report {
+    use super::*;
+
+    #[test]
+    fn test_degenerate_report_clean() {
+        let arcs = vec![
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+        ];
+        let report = arcline_degenerate_report(&arcs, 1e-8);
+        assert!(report.is_empty());
+        assert_eq!(report, DegenerateReport::default());
+    }
+
+    #[test]
+    fn test_degenerate_report_all_defects() {
+        let arcs = vec![
+            // 0: fine
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            // 1: start nearly equal to the end of 0
+            arcseg(point(4.0 + 1e-11, 0.0), point(4.0, 2.0)),
+            // 2: collapsed ends
+            arcseg(point(4.0, 2.0), point(4.0, 2.0)),
+            // 3: endpoints not on the circle
+            arc(point(4.0, 2.0), point(2.0, 4.0), point(2.0, 2.0), 2.5),
+            // 4: collapsed radius (and inconsistent)
+            arc(point(2.0, 4.0), point(0.0, 4.0), point(1.0, 4.0), 1e-12),
+            // 5: fine
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        let report = arcline_degenerate_report(&arcs, 1e-8);
+        assert_eq!(report.collapsed_radius, vec![4]);
+        assert_eq!(report.collapsed_ends, vec![2]);
+        assert_eq!(report.inconsistent, vec![3, 4]);
+        assert_eq!(report.near_duplicate_vertices, vec![(0, 1)]);
+        assert_eq!(report.count(), 5);
+        assert!(!report.is_empty());
+    }
+
+    #[test]
+    fn test_degenerate_report_empty_arcline() {
+        let arcs: Arcline = vec![];
+        assert!(arcline_degenerate_report(&arcs, 1e-8).is_empty());
+    }
+}
+
+impl Arc {
+    /// Splits the arc into `n` sub-arcs of equal sweep.
+    ///
+    /// The sub-arcs are CCW, share the center and radius of the original arc,
+    /// keep its `id` and are connected end to end from `a` to `b`.
+    /// Line segments are split into `n` equal pieces.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of sub-arcs
+    ///
+    /// # Returns
+    ///
+    /// The sub-arcs in order from `a` to `b`, empty for `n == 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// let quarters = full.split_equal(4);
+    /// assert_eq!(quarters.len(), 4);
+    /// assert!(quarters[0].b.close_enough(point(0.0, 1.0), 1e-12));
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn split_equal(&self, n: usize) -> Arcline {
+        let mut result: Arcline = Vec::with_capacity(n);
+        let mut start = self.a;
+        for i in 1..=n {
+            let end = if i == n {
+                self.b
+            } else {
+                self.point_at(i as f64 / n as f64)
+            };
+            result.push(Arc {
+                a: start,
+                b: end,
+                c: self.c,
+                r: self.r,
+                id: self.id,
+            });
+            start = end;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_split_equal {
+    use super::*;
+
+    #[test]
+    fn test_split_equal_reassembles() {
+        let a = arc(point(3.0, 1.0), point(1.0, 3.0), point(1.0, 1.0), 2.0);
+        let parts = a.split_equal(5);
+        assert_eq!(parts.len(), 5);
+        assert_eq!(parts[0].a, a.a);
+        assert_eq!(parts[4].b, a.b);
+        for w in parts.windows(2) {
+            assert_eq!(w[0].b, w[1].a);
+        }
+        for p in &parts {
+            assert_eq!(p.c, a.c);
+            assert_eq!(p.r, a.r);
+            assert!(p.is_consistent(1e-12));
+        }
+        let total: f64 = parts.iter().map(Arc::sweep_angle).sum();
+        assert!((total - a.sweep_angle()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_split_equal_equal_sweeps() {
+        let a = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        let parts = a.split_equal(3);
+        for p in &parts {
+            assert!((p.sweep_angle() - PI / 2.0).abs() < 1e-12);
+        }
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        for p in &full.split_equal(6) {
+            assert!((p.sweep_angle() - PI / 3.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_split_equal_segment() {
+        let seg = arcseg(point(0.0, 0.0), point(3.0, 6.0));
+        let parts = seg.split_equal(3);
+        assert_eq!(
+            parts,
+            vec![
+                arcseg(point(0.0, 0.0), point(1.0, 2.0)),
+                arcseg(point(1.0, 2.0), point(2.0, 4.0)),
+                arcseg(point(2.0, 4.0), point(3.0, 6.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_equal_trivial() {
+        let a = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        assert!(a.split_equal(0).is_empty());
+        assert_eq!(a.split_equal(1), vec![a]);
+    }
+}
+
+/// Number of `f64` values per element in the buffer layout of [`arcline_to_buffer`].
+pub const ARCLINE_RECORD_LEN: usize = 8;
+
+/// Serializes an arcline into a flat `f64` buffer, e.g. for FFI or GPU upload.
+///
+/// Each element is written as a fixed-width record of [`ARCLINE_RECORD_LEN`] values:
+///
+/// | index | value                                 |
+/// |-------|---------------------------------------|
+/// | 0     | type flag: `0.0` segment, `1.0` arc   |
+/// | 1, 2  | start point `a.x`, `a.y`              |
+/// | 3, 4  | end point `b.x`, `b.y`                |
+/// | 5, 6  | center `c.x`, `c.y` (`0.0` for segments) |
+/// | 7     | radius `r` (`0.0` for segments)       |
+///
+/// Segments are written with zero center and radius so the buffer stays finite.
+/// Element ids are not stored.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to serialize
+///
+/// # Returns
+///
+/// A buffer of `arcs.len() * ARCLINE_RECORD_LEN` values
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))];
+/// let buf = arcline_to_buffer(&arcs);
+/// assert_eq!(buf, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_to_buffer(arcs: &Arcline) -> Vec<f64> {
+    let mut buf = Vec::with_capacity(arcs.len() * ARCLINE_RECORD_LEN);
+    for arc in arcs {
+        if arc.is_seg() {
+            buf.extend_from_slice(&[0.0, arc.a.x, arc.a.y, arc.b.x, arc.b.y, 0.0, 0.0, 0.0]);
+        } else {
+            buf.extend_from_slice(&[
+                1.0, arc.a.x, arc.a.y, arc.b.x, arc.b.y, arc.c.x, arc.c.y, arc.r,
+            ]);
+        }
+    }
+    buf
+}
+
+/// Reconstructs an arcline from a flat `f64` buffer written by [`arcline_to_buffer`].
+///
+/// # Arguments
+///
+/// * `buf` - The buffer, a sequence of records of [`ARCLINE_RECORD_LEN`] values
+///
+/// # Returns
+///
+/// The arcline, or an error message when the buffer length is not a multiple
+/// of the record length, a record has an unknown type flag, or a value is not finite
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arc(point(2.0, 0.0), point(0.0, 0.0), point(1.0, 0.0), 1.0),
+/// ];
+/// let restored = arcline_from_buffer(&arcline_to_buffer(&arcs)).unwrap();
+/// assert_eq!(restored, arcs);
+///
+/// assert!(arcline_from_buffer(&[1.0, 2.0]).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn arcline_from_buffer(buf: &[f64]) -> Result<Arcline, String> {
+    if !buf.len().is_multiple_of(ARCLINE_RECORD_LEN) {
+        return Err(format!(
+            "buffer length {} is not a multiple of {}",
+            buf.len(),
+            ARCLINE_RECORD_LEN
+        ));
+    }
+    let mut arcs = Vec::with_capacity(buf.len() / ARCLINE_RECORD_LEN);
+    for (i, rec) in buf.chunks_exact(ARCLINE_RECORD_LEN).enumerate() {
+        if let Some(v) = rec.iter().find(|v| !v.is_finite()) {
+            return Err(format!("record {}: value {} is not finite", i, v));
+        }
+        let a = point(rec[1], rec[2]);
+        let b = point(rec[3], rec[4]);
+        if rec[0] == 0.0 {
+            arcs.push(arcseg(a, b));
+        } else if rec[0] == 1.0 {
+            arcs.push(arc(a, b, point(rec[5], rec[6]), rec[7]));
+        } else {
+            return Err(format!("record {}: unknown type flag {}", i, rec[0]));
+        }
+    }
+    Ok(arcs)
+}
+
+#[cfg(test)]
+mod test_arcline_buffer {
+    use super::*;
+
+    #[test]
+    fn test_buffer_round_trip() {
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arc(point(4.0, 0.0), point(4.0, 2.0), point(4.0, 1.0), 1.0),
+            arcseg(point(4.0, 2.0), point(0.0, 2.0)),
+            arc(point(0.0, 2.0), point(0.0, 0.0), point(0.0, 1.0), 1.0),
+        ];
+        let buf = arcline_to_buffer(&arcs);
+        assert_eq!(buf.len(), 4 * ARCLINE_RECORD_LEN);
+        assert!(buf.iter().all(|v| v.is_finite()));
+        let restored = arcline_from_buffer(&buf).unwrap();
+        assert_eq!(restored.len(), arcs.len());
+        for (r, a) in restored.iter().zip(&arcs) {
+            assert_eq!(r.a, a.a);
+            assert_eq!(r.b, a.b);
+            assert_eq!(r.is_seg(), a.is_seg());
+            if a.is_arc() {
+                assert_eq!(r.c, a.c);
+                assert_eq!(r.r, a.r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_buffer_empty() {
+        assert!(arcline_to_buffer(&vec![]).is_empty());
+        assert_eq!(arcline_from_buffer(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_buffer_errors() {
+        assert!(arcline_from_buffer(&[0.0; 7]).is_err());
+        let mut buf = arcline_to_buffer(&vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))]);
+        buf[0] = 2.0;
+        assert!(arcline_from_buffer(&buf).is_err());
+        buf[0] = 1.0;
+        buf[7] = f64::NAN;
+        assert!(arcline_from_buffer(&buf).is_err());
+    }
+}
+
+/// Samples outward unit normals along a closed CCW arcline.
+///
+/// Each element is sampled at `samples_per_arc` parameters `(k + 0.5) / samples_per_arc`,
+/// so vertices, where the normal may be undefined, are skipped. The samples follow
+/// the travel direction of the loop: concave arcs are traversed from `b` to `a`.
+/// The outward normal is the right side of the travel direction, which for a CCW
+/// loop points away from the enclosed region.
+///
+/// # Arguments
+///
+/// * `arcs` - The closed CCW arcline
+/// * `samples_per_arc` - Number of samples for each element
+///
+/// # Returns
+///
+/// The `(point, normal)` pairs in traversal order
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let normals = arcline_outward_normals(&square, 1);
+/// assert_eq!(normals[0], (point(0.5, 0.0), point(0.0, -1.0)));
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_outward_normals(arcs: &Arcline, samples_per_arc: usize) -> Vec<(Point, Point)> {
+    let mut result = Vec::with_capacity(arcs.len() * samples_per_arc);
+    for (i, arc) in arcs.iter().enumerate() {
+        if arc.is_seg() {
+            let (dir, _) = (arc.b - arc.a).normalize(false);
+            let normal = point(dir.y, -dir.x);
+            for k in 0..samples_per_arc {
+                let t = (k as f64 + 0.5) / samples_per_arc as f64;
+                result.push((arc.point_at(t), normal));
+            }
+        } else {
+            let convex = is_arc_convex(arcs, i);
+            for k in 0..samples_per_arc {
+                let t = (k as f64 + 0.5) / samples_per_arc as f64;
+                let p = arc.point_at(if convex { t } else { 1.0 - t });
+                // Right of the CCW tangent is away from the center
+                let radial = (p - arc.c) / arc.r;
+                result.push((p, if convex { radial } else { -radial }));
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test_arcline_outward_normals {
+    use super::*;
+
+    #[test]
+    fn test_outward_normals_disc() {
+        let c = point(1.0, 2.0);
+        let arcs = vec![
+            arc(point(3.0, 2.0), point(1.0, 4.0), c, 2.0),
+            arc(point(1.0, 4.0), point(-1.0, 2.0), c, 2.0),
+            arc(point(-1.0, 2.0), point(1.0, 0.0), c, 2.0),
+            arc(point(1.0, 0.0), point(3.0, 2.0), c, 2.0),
+        ];
+        let normals = arcline_outward_normals(&arcs, 5);
+        assert_eq!(normals.len(), 20);
+        for (p, n) in normals {
+            assert!(((p - c).norm() - 2.0).abs() < 1e-12);
+            assert!((n.norm() - 1.0).abs() < 1e-12);
+            // Points away from the center
+            assert!((p - c).dot(n) > 0.0);
+            assert!((p + n - c).norm() > 2.0);
+        }
+    }
+
+    #[test]
+    fn test_outward_normals_concave_arc() {
+        // Square with a concave bite taken from the top edge
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arcseg(point(4.0, 0.0), point(4.0, 4.0)),
+            arcseg(point(4.0, 4.0), point(3.0, 4.0)),
+            arc(point(1.0, 4.0), point(3.0, 4.0), point(2.0, 4.0), 1.0),
+            arcseg(point(1.0, 4.0), point(0.0, 4.0)),
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        let normals = arcline_outward_normals(&arcs, 4);
+        assert_eq!(normals.len(), 24);
+        let bite = &normals[12..16];
+        // Traversed from (3, 4) to (1, 4), normals point toward the bite center
+        assert!(bite[0].0.x > bite[3].0.x);
+        for (p, n) in bite {
+            assert!((p + *n * 0.5 - point(2.0, 4.0)).norm() < 1.0);
+        }
+        assert_eq!(normals[0].1, point(0.0, -1.0));
+        assert_eq!(normals[4].1, point(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_outward_normals_no_samples() {
+        let arcs = vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))];
+        assert!(arcline_outward_normals(&arcs, 0).is_empty());
+    }
+}
+
+/// Samples the signed curvature along an arcline, e.g. for path fairness checks.
+///
+/// Every element is sampled at `samples_per_arc` evenly spaced points including
+/// both of its ends, so each join appears twice at the same arc length, with
+/// the curvature of the element before and after it. Curvature is `0` on
+/// segments and `1 / r` on arcs, positive where the path turns left (arcs
+/// traversed CCW) and negative where it turns right (concave arcs, traversed
+/// backward, see [`is_arc_convex`]).
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to sample
+/// * `samples_per_arc` - The number of samples per element, at least 2 are taken
+///
+/// # Returns
+///
+/// The `(arc length, signed curvature)` pairs along the path, arc length
+/// starting at `0` on the first element
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arc(point(2.0, 0.0), point(2.0, 2.0), point(2.0, 1.0), 1.0),
+///     arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+/// ];
+/// let samples = arcline_curvature_samples(&arcs, 2);
+/// let pi = std::f64::consts::PI;
+/// assert_eq!(samples[1..5], [(2.0, 0.0), (2.0, 1.0), (2.0 + pi, 1.0), (2.0 + pi, 0.0)]);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_curvature_samples(arcs: &Arcline, samples_per_arc: usize) -> Vec<(f64, f64)> {
+    let samples = samples_per_arc.max(2);
+    let mut result = Vec::with_capacity(arcs.len() * samples);
+    let mut start = 0.0;
+    for (i, arc) in arcs.iter().enumerate() {
+        let (length, curvature) = if arc.is_seg() {
+            ((arc.b - arc.a).norm(), 0.0)
+        } else if is_arc_convex(arcs, i) {
+            (arc.r * arc.sweep_angle(), 1.0 / arc.r)
+        } else {
+            (arc.r * arc.sweep_angle(), -1.0 / arc.r)
+        };
+        for k in 0..samples {
+            let t = k as f64 / (samples - 1) as f64;
+            result.push((start + length * t, curvature));
+        }
+        start += length;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test_arcline_curvature_samples {
+    use super::*;
+
+    // 4 x 2 rectangle with corners rounded by 0.5
+    fn rounded_rectangle() -> Arcline {
+        vec![
+            arcseg(point(0.5, 0.0), point(3.5, 0.0)),
+            arc(point(3.5, 0.0), point(4.0, 0.5), point(3.5, 0.5), 0.5),
+            arcseg(point(4.0, 0.5), point(4.0, 1.5)),
+            arc(point(4.0, 1.5), point(3.5, 2.0), point(3.5, 1.5), 0.5),
+            arcseg(point(3.5, 2.0), point(0.5, 2.0)),
+            arc(point(0.5, 2.0), point(0.0, 1.5), point(0.5, 1.5), 0.5),
+            arcseg(point(0.0, 1.5), point(0.0, 0.5)),
+            arc(point(0.0, 0.5), point(0.5, 0.0), point(0.5, 0.5), 0.5),
+        ]
+    }
+
+    #[test]
+    fn test_curvature_rounded_rectangle() {
+        let arcs = rounded_rectangle();
+        let samples = arcline_curvature_samples(&arcs, 5);
+        assert_eq!(samples.len(), 40);
+        for (i, chunk) in samples.chunks(5).enumerate() {
+            let expected = if i % 2 == 0 { 0.0 } else { 2.0 };
+            assert!(chunk.iter().all(|(_, k)| *k == expected), "element {}", i);
+        }
+        // Joins appear twice, with the jump in curvature
+        assert_eq!(samples[4].0, samples[5].0);
+        assert_eq!((samples[4].1, samples[5].1), (0.0, 2.0));
+        // Perimeter: straight edges plus one full circle
+        let perimeter = 6.0 + 2.0 + PI;
+        assert!((samples[39].0 - perimeter).abs() < 1e-12);
+        assert!(samples.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn test_curvature_concave_arc_negative() {
+        // Rectangle with an inward bite on top, the arc is traversed backward
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arcseg(point(4.0, 0.0), point(4.0, 2.0)),
+            arcseg(point(4.0, 2.0), point(3.0, 2.0)),
+            arc(point(1.0, 2.0), point(3.0, 2.0), point(2.0, 2.0), 1.0),
+            arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let samples = arcline_curvature_samples(&arcs, 3);
+        assert_eq!(&samples[9..12], &[(7.0, -1.0), (7.0 + PI / 2.0, -1.0), (7.0 + PI, -1.0)]);
+    }
+
+    #[test]
+    fn test_curvature_minimum_samples() {
+        let arcs = vec![arcseg(point(0.0, 0.0), point(3.0, 4.0))];
+        assert_eq!(arcline_curvature_samples(&arcs, 0), vec![(0.0, 0.0), (5.0, 0.0)]);
+        assert!(arcline_curvature_samples(&vec![], 4).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_full_circle {
+    use super::*;
+
+    fn full() -> Arc {
+        arc(point(3.0, 1.0), point(3.0, 1.0), point(1.0, 1.0), 2.0)
+    }
+
+    #[test]
+    fn test_is_full_circle() {
+        assert!(full().is_full_circle());
+        let tiny = arc(point(3.0, 1.0), point(3.0, 1.0 + 1e-12), point(1.0, 1.0), 2.0);
+        assert!(!tiny.is_full_circle());
+        let half = arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0);
+        assert!(!half.is_full_circle());
+    }
+
+    #[test]
+    fn test_full_circle_sweep_and_reverse() {
+        assert_eq!(full().sweep_angle(), 2.0 * PI);
+        assert_eq!(full().reverse(), full());
+        assert_eq!(full().reverse().sweep_angle(), 2.0 * PI);
+    }
+
+    #[test]
+    fn test_full_circle_contains() {
+        let f = full();
+        for k in 0..16 {
+            let t = k as f64 * PI / 8.0;
+            assert!(f.contains(f.c + point(t.cos(), t.sin()) * f.r));
+        }
+    }
+
+    #[test]
+    fn test_tiny_arc_is_not_full_circle() {
+        // Sweeps only 1e-11 rad, the opposite point of its circle is not on it
+        let t: f64 = 1e-11;
+        let tiny = arc(point(1.0, 0.0), point(t.cos(), t.sin()), point(0.0, 0.0), 1.0);
+        assert!(!tiny.is_full_circle());
+        assert!(tiny.sweep_angle() < 1e-10);
+        assert!(!tiny.contains(point(-1.0, 0.0)));
+        assert_eq!(
+            int_segment_arc(&segment(point(-2.0, 0.0), point(0.0, 0.0)), &tiny),
+            SegmentArcConfig::NoIntersection()
+        );
+        let other = arc(point(-1.0, -1.0), point(-1.0, 1.0), point(-1.0, 0.0), 1.0);
+        assert_eq!(int_arc_arc(&tiny, &other), ArcArcConfig::NoIntersection());
+    }
+
+    #[test]
+    fn test_full_circle_split_at_point() {
+        let f = full();
+        let (first, second) = f.split_at_point(point(1.0, 3.0));
+        assert_eq!(first, arc(point(1.0, 3.0), point(1.0, -1.0), f.c, f.r));
+        assert_eq!(second, arc(point(1.0, -1.0), point(1.0, 3.0), f.c, f.r));
+        assert_eq!(first.id, f.id);
+        assert!((first.sweep_angle() - PI).abs() < 1e-12);
+        assert!((second.sweep_angle() - PI).abs() < 1e-12);
+
+        // Splitting at the start point also gives two semicircles
+        let (first, second) = f.split_at_point(f.a);
+        assert!((first.sweep_angle() - PI).abs() < 1e-12);
+        assert!((second.sweep_angle() - PI).abs() < 1e-12);
+        assert_eq!(first.a, f.a);
+        assert_eq!(second.b, f.a);
+    }
+
+    #[test]
+    fn test_full_circle_split_equal() {
+        let parts = full().split_equal(2);
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].b.close_enough(point(-1.0, 1.0), 1e-12));
+        assert!((parts[0].sweep_angle() - PI).abs() < 1e-12);
+        assert!((parts[1].sweep_angle() - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_full_circle_parametrization() {
+        let f = full();
+        assert!(f.point_at(0.25).close_enough(point(1.0, 3.0), 1e-12));
+        assert!(f.point_at(0.5).close_enough(point(-1.0, 1.0), 1e-12));
+        assert_eq!(f.point_at(1.0), f.a);
+        assert_eq!(f.param_of_point(f.a), Some(0.0));
+        assert!((f.param_of_point(point(1.0, -1.0)).unwrap() - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_full_circle_extrema_and_tangents() {
+        let f = full();
+        assert_eq!(f.cardinal_extrema().len(), 4);
+        let [start, end] = f.tangents();
+        // Leaving and arriving at the same point, in the same direction
+        assert!((start + end).norm() < 1e-12);
+        assert!(start.close_enough(point(0.0, -1.0), 1e-12));
+    }
+
+    #[test]
+    fn test_full_circle_validity() {
+        // Still reported as collapsed by the validity checks
+        assert!(full().is_collapsed_ends(1e-10));
+        assert!(!full().is_valid(1e-10));
+        assert!(full().is_consistent(1e-10));
+    }
+}
+
+/// Upper bound on the number of Béziers produced by [`Arc::to_quadratic_beziers`].
+const MAX_QUADRATIC_BEZIERS: usize = 4096;
+
+impl Arc {
+    /// Approximates the arc with quadratic Bézier curves.
+    ///
+    /// The arc is split into equal sub-arcs of at most 90° each, using more
+    /// sub-arcs when needed to stay within `max_error`. Each Bézier starts and
+    /// ends on the arc and its control point is the intersection of the end
+    /// tangents, so consecutive curves join with continuous tangents.
+    /// The largest radial deviation of such a curve is at its middle,
+    /// `r * (1 - cos(h))^2 / (2 * cos(h))` for a sub-arc of half-sweep `h`.
+    ///
+    /// A line segment becomes a single Bézier with the control point at its middle.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_error` - The allowed distance between the curves and the arc
+    ///
+    /// # Returns
+    ///
+    /// The control points `[start, control, end]` of each Bézier, in order from `a` to `b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// let beziers = half.to_quadratic_beziers(0.1);
+    /// assert_eq!(beziers.len(), 2);
+    /// assert!(beziers[0][1].close_enough(point(1.0, 1.0), 1e-12));
+    /// assert!(beziers[1][0].close_enough(point(0.0, 1.0), 1e-12));
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_quadratic_beziers(&self, max_error: f64) -> Vec<[Point; 3]> {
+        if self.is_seg() {
+            return vec![[self.a, (self.a + self.b) * 0.5, self.b]];
+        }
+        let sweep = self.sweep_angle();
+        let mut n = ((sweep / core::f64::consts::FRAC_PI_2 - GEOMETRIC_EPSILON).ceil() as usize).max(1);
+        while n < MAX_QUADRATIC_BEZIERS {
+            let h = sweep / (2.0 * n as f64);
+            let error = self.r * (1.0 - h.cos()).powi(2) / (2.0 * h.cos());
+            if error <= max_error {
+                break;
+            }
+            n += 1;
+        }
+
+        let scale = 1.0 / (sweep / (2.0 * n as f64)).cos();
+        let mut result = Vec::with_capacity(n);
+        let mut start = self.a;
+        for i in 1..=n {
+            let end = if i == n {
+                self.b
+            } else {
+                self.point_at(i as f64 / n as f64)
+            };
+            let middle = self.point_at((i as f64 - 0.5) / n as f64);
+            let control = self.c + (middle - self.c) * scale;
+            result.push([start, control, end]);
+            start = end;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_to_quadratic_beziers {
+    use super::*;
+    use crate::distance::dist_point_arc_dist;
+
+    fn bezier_at(q: &[Point; 3], t: f64) -> Point {
+        let s = 1.0 - t;
+        q[0] * (s * s) + q[1] * (2.0 * s * t) + q[2] * (t * t)
+    }
+
+    fn flatten_beziers(beziers: &[[Point; 3]], steps: usize) -> Vec<Point> {
+        let mut points = vec![beziers[0][0]];
+        for q in beziers {
+            for k in 1..=steps {
+                points.push(bezier_at(q, k as f64 / steps as f64));
+            }
+        }
+        points
+    }
+
+    fn dist_to_polyline(p: Point, points: &[Point]) -> f64 {
+        points
+            .windows(2)
+            .map(|w| dist_point_segment(&p, &segment(w[0], w[1])).0)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn check_deviation(a: &Arc, max_error: f64) {
+        let beziers = a.to_quadratic_beziers(max_error);
+        for q in &beziers {
+            assert!(((q[0] - a.c).norm() - a.r).abs() < 1e-9);
+        }
+        assert_eq!(beziers[0][0], a.a);
+        assert_eq!(beziers[beziers.len() - 1][2], a.b);
+        for w in beziers.windows(2) {
+            assert_eq!(w[0][2], w[1][0]);
+        }
+
+        // Beziers stay within max_error of the arc
+        let bezier_points = flatten_beziers(&beziers, 64);
+        for p in &bezier_points {
+            assert!(dist_point_arc_dist(p, a) <= max_error + 1e-12);
+        }
+        // The arc stays within max_error of the beziers (up to the flattening error)
+        for k in 0..=500 {
+            let p = a.point_at(k as f64 / 500.0);
+            assert!(dist_to_polyline(p, &bezier_points) <= max_error + 1e-4 * a.r);
+        }
+    }
+
+    #[test]
+    fn test_to_quadratic_beziers_deviation() {
+        let a = arc(point(3.0, 1.0), point(1.0, 3.0), point(1.0, 1.0), 2.0);
+        check_deviation(&a, 0.1);
+        check_deviation(&a, 1e-3);
+        check_deviation(&a, 1e-6);
+        let big = arc_from_bulge(point(0.0, 0.0), point(4.0, 1.0), 2.5);
+        check_deviation(&big, 1e-2);
+        check_deviation(&big, 1e-5);
+        let full = arc(point(2.0, 0.0), point(2.0, 0.0), point(0.0, 0.0), 2.0);
+        check_deviation(&full, 1e-3);
+    }
+
+    #[test]
+    fn test_to_quadratic_beziers_at_most_quarter() {
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        let beziers = full.to_quadratic_beziers(1.0);
+        assert_eq!(beziers.len(), 4);
+        assert!(beziers[0][1].close_enough(point(1.0, 1.0), 1e-12));
+        assert!(beziers[2][2].close_enough(point(0.0, -1.0), 1e-12));
+        // Smaller tolerance needs more pieces
+        assert!(full.to_quadratic_beziers(1e-4).len() > 4);
+    }
+
+    #[test]
+    fn test_to_quadratic_beziers_segment() {
+        let seg = arcseg(point(0.0, 0.0), point(2.0, 4.0));
+        assert_eq!(
+            seg.to_quadratic_beziers(1e-6),
+            vec![[point(0.0, 0.0), point(1.0, 2.0), point(2.0, 4.0)]]
+        );
+    }
+}
+
+/// Number of elements checked linearly before the spatial index is first built.
+#[cfg(feature = "std")]
+const BUILDER_MIN_PENDING: usize = 16;
+
+/// Builds an arcline one element at a time, validating each push.
+///
+/// Each pushed element is checked the way [`arcline_is_valid`] checks a whole
+/// arcline: the element must be valid, connect to the previous element without
+/// a zero-degree angle, and not intersect any earlier element. Instead of
+/// re-running the O(n²) validation after every edit, earlier elements are kept
+/// in a [`HilbertRTree`]. The index is static, so the latest elements are
+/// checked linearly and the index is rebuilt when they outnumber the indexed
+/// ones, keeping the amortized cost of a push logarithmic.
+///
+/// Closing the loop (the last element ending at the start of the first one)
+/// is left to the caller.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let mut builder = ArclineBuilder::new();
+/// assert!(builder.push(arcseg(point(0.0, 0.0), point(2.0, 0.0))).is_ok());
+/// assert!(builder.push(arcseg(point(2.0, 0.0), point(2.0, 2.0))).is_ok());
+/// // Not connected to the previous element
+/// let gap = arcseg(point(3.0, 3.0), point(0.0, 2.0));
+/// assert_eq!(builder.push(gap), Err(ArclineValidation::GapBetweenArcs(gap)));
+/// assert_eq!(builder.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub struct ArclineBuilder {
+    arcs: Arcline,
+    tree: HilbertRTree,
+    // Number of leading elements stored in `tree`
+    indexed: usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for ArclineBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ArclineBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        ArclineBuilder {
+            arcs: Arcline::new(),
+            tree: HilbertRTree::new(),
+            indexed: 0,
+        }
+    }
+
+    /// Validates `arc` against the elements pushed so far and appends it.
+    ///
+    /// # Arguments
+    ///
+    /// * `arc` - The next element of the arcline
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the element was appended, otherwise the reason it was
+    /// rejected; a rejected element is not appended:
+    /// - `InvalidArc` if the element itself is not valid
+    /// - `GapBetweenArcs` if it does not share an end with the previous element
+    /// - `ZeroDegreeAngle` if it turns back along the previous element
+    /// - `IntersectingArcs` if it intersects an earlier, non-adjacent element
+    #[allow(clippy::result_large_err)]
+    pub fn push(&mut self, arc: Arc) -> Result<(), ArclineValidation> {
+        if !arc.is_valid(1e-8) {
+            return Err(ArclineValidation::InvalidArc(arc));
+        }
+        let Some(&last) = self.arcs.last() else {
+            self.arcs.push(arc);
+            return Ok(());
+        };
+        let connected = last.b == arc.a || last.b == arc.b || last.a == arc.a || last.a == arc.b;
+        if !connected {
+            return Err(ArclineValidation::GapBetweenArcs(arc));
+        }
+        if arc_tangents_are_collinear(&last, &arc) {
+            return Err(ArclineValidation::ZeroDegreeAngle(last, arc));
+        }
+
+        // The previous element is adjacent and shares an end
+        let adjacent = self.arcs.len() - 1;
+        let mut candidates = Vec::new();
+        if self.indexed > 0 {
+            let bounds = crate::algo::arc_bounding_rect(&arc);
+            self.tree
+                .query_intersecting(bounds.p1.x, bounds.p1.y, bounds.p2.x, bounds.p2.y, &mut candidates);
+        }
+        candidates.extend(self.indexed..adjacent);
+        for j in candidates {
+            if j < adjacent && is_really_intersecting(&self.arcs[j], &arc) {
+                return Err(ArclineValidation::IntersectingArcs(self.arcs[j], arc));
+            }
+        }
+
+        self.arcs.push(arc);
+        if self.arcs.len() - self.indexed > self.indexed.max(BUILDER_MIN_PENDING) {
+            self.rebuild_index();
+        }
+        Ok(())
+    }
+
+    /// Returns the elements pushed so far.
+    #[must_use]
+    pub fn arcs(&self) -> &Arcline {
+        &self.arcs
+    }
+
+    /// Returns the number of elements pushed so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Returns `true` if no element was pushed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.arcs.is_empty()
+    }
+
+    /// Consumes the builder, returning the arcline.
+    #[must_use]
+    pub fn into_arcline(self) -> Arcline {
+        self.arcs
+    }
+
+    fn rebuild_index(&mut self) {
+        let mut tree = HilbertRTree::with_capacity(self.arcs.len());
+        for a in &self.arcs {
+            let bounds = crate::algo::arc_bounding_rect(a);
+            tree.add(bounds.p1.x, bounds.p1.y, bounds.p2.x, bounds.p2.y);
+        }
+        tree.build();
+        self.tree = tree;
+        self.indexed = self.arcs.len();
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_builder {
+    use super::*;
+
+    // Closed zig-zag band of 2 * teeth + 2 segments
+    fn comb(teeth: usize) -> Arcline {
+        let mut pts = Vec::new();
+        for k in 0..=teeth {
+            pts.push(point(k as f64, if k % 2 == 0 { 0.0 } else { 0.5 }));
+        }
+        for k in (0..=teeth).rev() {
+            pts.push(point(k as f64, if k % 2 == 0 { 2.0 } else { 2.5 }));
+        }
+        (0..pts.len())
+            .map(|i| arcseg(pts[i], pts[(i + 1) % pts.len()]))
+            .collect()
+    }
+
+    #[test]
+    fn test_builder_valid_sequence() {
+        let arcs = comb(40);
+        let mut builder = ArclineBuilder::new();
+        for a in &arcs {
+            assert_eq!(builder.push(*a), Ok(()));
+        }
+        assert_eq!(builder.len(), arcs.len());
+        assert_eq!(builder.arcs(), &arcs);
+        assert_eq!(arcline_is_valid(&arcs), ArclineValidation::Valid);
+        assert_eq!(builder.into_arcline(), arcs);
+    }
+
+    #[test]
+    fn test_builder_valid_with_arcs() {
+        // Rounded slot with a concave notch traversed backward
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arc(point(4.0, 0.0), point(4.0, 2.0), point(4.0, 1.0), 1.0),
+            arcseg(point(4.0, 2.0), point(3.0, 2.0)),
+            arc(point(1.0, 2.0), point(3.0, 2.0), point(2.0, 2.0), 1.0),
+            arcseg(point(1.0, 2.0), point(0.0, 2.0)),
+            arc(point(0.0, 2.0), point(0.0, 0.0), point(0.0, 1.0), 1.0),
+        ];
+        let mut builder = ArclineBuilder::default();
+        for a in &arcs {
+            assert_eq!(builder.push(*a), Ok(()));
+        }
+        assert_eq!(arcline_is_valid(&arcs), ArclineValidation::Valid);
+    }
+
+    #[test]
+    fn test_builder_self_intersecting_push() {
+        let arcs = comb(40);
+        let mut builder = ArclineBuilder::new();
+        // Stop on the upper side, then cut back down across the lower zig-zag
+        let upper = arcs.len() - 10;
+        for a in &arcs[..upper] {
+            assert_eq!(builder.push(*a), Ok(()));
+        }
+        let last = arcs[upper - 1];
+        let cut = arcseg(last.b, point(last.b.x - 0.5, -1.0));
+        match builder.push(cut) {
+            Err(ArclineValidation::IntersectingArcs(earlier, pushed)) => {
+                assert_eq!(pushed, cut);
+                assert!(earlier.a.y <= 0.5 && earlier.b.y <= 0.5);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        // The rejected element is not appended
+        assert_eq!(builder.len(), upper);
+        assert_eq!(builder.push(arcs[upper]), Ok(()));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_elements() {
+        let mut builder = ArclineBuilder::new();
+        let collapsed = arcseg(point(1.0, 1.0), point(1.0, 1.0));
+        assert_eq!(builder.push(collapsed), Err(ArclineValidation::InvalidArc(collapsed)));
+        assert!(builder.is_empty());
+
+        let first = arcseg(point(0.0, 0.0), point(2.0, 0.0));
+        assert_eq!(builder.push(first), Ok(()));
+        // Going back along the previous segment
+        let back = arcseg(point(2.0, 0.0), point(1.0, 0.0));
+        assert_eq!(builder.push(back), Err(ArclineValidation::ZeroDegreeAngle(first, back)));
+        let gap = arcseg(point(2.5, 0.0), point(3.0, 1.0));
+        assert_eq!(builder.push(gap), Err(ArclineValidation::GapBetweenArcs(gap)));
+        assert_eq!(builder.len(), 1);
+    }
+}
+
+/// Creates the shorter CCW arc between two points on a circle.
+///
+/// Given only the end points and the center, two arcs are possible. This returns
+/// the one sweeping less than π, starting at `a` when `b` lies CCW of `a` and at
+/// `b` otherwise. The radius is the distance from `center` to `a`. For points
+/// diametrically opposite each other both arcs are half circles, and the arc
+/// from `b` to `a` is returned.
+///
+/// # Arguments
+///
+/// * `a` - The first end point
+/// * `b` - The second end point
+/// * `center` - The center of the circle
+///
+/// # Returns
+///
+/// The minor arc, see also [`arc_major`]
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let minor = arc_minor(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0));
+/// assert_eq!((minor.a, minor.b), (point(1.0, 0.0), point(0.0, 1.0)));
+/// assert!(minor.sweep_angle() < std::f64::consts::PI);
+/// ```
+#[must_use]
+pub fn arc_minor(a: Point, b: Point, center: Point) -> Arc {
+    let r = (a - center).norm();
+    if points_order(center, a, b) > 0.0 {
+        arc(a, b, center, r)
+    } else {
+        arc(b, a, center, r)
+    }
+}
+
+/// Creates the longer CCW arc between two points on a circle.
+///
+/// This is the complement of [`arc_minor`] on the same circle, sweeping at
+/// least π. The radius is the distance from `center` to `a`.
+///
+/// # Arguments
+///
+/// * `a` - The first end point
+/// * `b` - The second end point
+/// * `center` - The center of the circle
+///
+/// # Returns
+///
+/// The major arc
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let major = arc_major(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0));
+/// assert_eq!((major.a, major.b), (point(0.0, 1.0), point(1.0, 0.0)));
+/// assert!(major.sweep_angle() > std::f64::consts::PI);
+/// ```
+#[must_use]
+pub fn arc_major(a: Point, b: Point, center: Point) -> Arc {
+    arc_minor(a, b, center).reverse()
+}
+
+#[cfg(test)]
+mod test_arc_minor_major {
+    use super::*;
+
+    #[test]
+    fn test_minor_major_quarter_chord() {
+        let c = point(1.0, 2.0);
+        let a = c + point(2.0, 0.0);
+        let b = c + point(0.0, 2.0);
+        for (p, q) in [(a, b), (b, a)] {
+            let minor = arc_minor(p, q, c);
+            let major = arc_major(p, q, c);
+            assert_eq!((minor.a, minor.b), (a, b));
+            assert_eq!((major.a, major.b), (b, a));
+            assert_eq!((minor.r, major.r), (2.0, 2.0));
+            assert!((minor.sweep_angle() - PI / 2.0).abs() < 1e-12);
+            assert!((major.sweep_angle() - 1.5 * PI).abs() < 1e-12);
+            assert!(minor.sweep_angle() < PI);
+            assert!(major.sweep_angle() >= PI);
+        }
+    }
+
+    #[test]
+    fn test_minor_major_complement() {
+        let c = point(-1.0, 0.5);
+        let a = c + point(3.0_f64.cos(), 3.0_f64.sin()) * 1.5;
+        let b = c + point(0.2_f64.cos(), 0.2_f64.sin()) * 1.5;
+        let minor = arc_minor(a, b, c);
+        let major = arc_major(a, b, c);
+        assert!((minor.sweep_angle() + major.sweep_angle() - 2.0 * PI).abs() < 1e-12);
+        assert!(minor.sweep_angle() < PI);
+        assert!(minor.contains(c + point(1.6_f64.cos(), 1.6_f64.sin()) * 1.5));
+        assert!(major.contains(c + point(4.0_f64.cos(), 4.0_f64.sin()) * 1.5));
+    }
+
+    #[test]
+    fn test_minor_major_half_circle() {
+        let c = point(0.0, 0.0);
+        let minor = arc_minor(point(1.0, 0.0), point(-1.0, 0.0), c);
+        let major = arc_major(point(1.0, 0.0), point(-1.0, 0.0), c);
+        assert!((minor.sweep_angle() - PI).abs() < 1e-12);
+        assert!((major.sweep_angle() - PI).abs() < 1e-12);
+        assert_eq!((minor.a, major.a), (point(-1.0, 0.0), point(1.0, 0.0)));
+    }
+}
+
+impl Arc {
+    /// Checks that the CCW sweep from `a` to `b` about `c` is the intended minor arc.
+    ///
+    /// Arcs are always traversed CCW, so the center alone selects which of the
+    /// two arcs between the end points is meant: a center left of the `a`→`b`
+    /// chord gives the minor arc, while a center mirrored to the right of the
+    /// chord, a common mistake when the arc was meant to go CW, turns it into
+    /// the major arc. This returns true when the end points are at distance
+    /// `r` from the center (see [`Arc::is_consistent`]) and the center is not
+    /// right of the chord, so half circles are accepted.
+    ///
+    /// Segments are always consistent. Full circles only need a consistent
+    /// radius. Arcs intentionally sweeping more than π, like those made by
+    /// [`arc_major`], are reported as not consistent.
+    ///
+    /// # Returns
+    ///
+    /// True if the arc is a well-formed CCW minor arc, a segment or a full circle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// assert!(quarter.is_ccw_consistent());
+    /// // Center mirrored across the chord: CCW about it is three quarters of a circle
+    /// let mirrored = arc(point(1.0, 0.0), point(0.0, 1.0), point(1.0, 1.0), 1.0);
+    /// assert!(!mirrored.is_ccw_consistent());
+    /// ```
+    #[must_use]
+    pub fn is_ccw_consistent(&self) -> bool {
+        if self.is_seg() {
+            return true;
+        }
+        if !self.is_consistent(GEOMETRIC_EPSILON) {
+            return false;
+        }
+        if self.is_full_circle() {
+            return true;
+        }
+        let side = orient2d(
+            Coord { x: self.a.x, y: self.a.y },
+            Coord { x: self.b.x, y: self.b.y },
+            Coord { x: self.c.x, y: self.c.y },
+        );
+        side >= 0.0
+    }
+}
+
+#[cfg(test)]
+mod test_is_ccw_consistent {
+    use super::*;
+
+    #[test]
+    fn test_ccw_minor_arcs() {
+        let c = point(1.0, 2.0);
+        let quarter = arc(c + point(2.0, 0.0), c + point(0.0, 2.0), c, 2.0);
+        assert!(quarter.is_ccw_consistent());
+        let half = arc(c + point(2.0, 0.0), c - point(2.0, 0.0), c, 2.0);
+        assert!(half.is_ccw_consistent());
+        assert!(arc_minor(c + point(0.0, 2.0), c + point(2.0, 0.0), c).is_ccw_consistent());
+        assert!(arc_from_bulge(point(0.0, 0.0), point(2.0, 0.0), 0.5).is_ccw_consistent());
+    }
+
+    #[test]
+    fn test_center_on_wrong_side() {
+        // Center mirrored across the chord of the quarter arc
+        let wrong = arc(point(2.0, 0.0), point(0.0, 2.0), point(2.0, 2.0), 2.0);
+        assert!(!wrong.is_ccw_consistent());
+        assert!(wrong.sweep_angle() > PI);
+        assert!(!arc_major(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0)).is_ccw_consistent());
+    }
+
+    #[test]
+    fn test_center_off_bisector() {
+        let off = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.1, 0.0), 1.0);
+        assert!(!off.is_ccw_consistent());
+        let wrong_radius = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.5);
+        assert!(!wrong_radius.is_ccw_consistent());
+    }
+
+    #[test]
+    fn test_segments_and_full_circles() {
+        assert!(arcseg(point(0.0, 0.0), point(1.0, 1.0)).is_ccw_consistent());
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert!(full.is_ccw_consistent());
+    }
+}
+
+/// Returns the angle subtended by an arc as seen from a point.
+///
+/// This is the opening angle of the narrowest cone with apex `from` that
+/// contains the whole arc. Its bounding rays pass through the arc ends, or
+/// through the tangent points of the circle when they lie on the arc and the
+/// arc bulges past its ends as seen from `from`.
+///
+/// From a point outside the circle the angle is less than π. From a point
+/// inside the circle (or on it) every ray meets the circle once, so the angle
+/// is the CCW angle from the direction of `a` to the direction of `b`, up to 2π
+/// for a full circle seen from inside. For a segment it is the angle between
+/// the directions to its ends.
+///
+/// # Arguments
+///
+/// * `a` - The arc
+/// * `from` - The point of view
+///
+/// # Returns
+///
+/// The subtended angle in radians, in [0, 2π]
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Upper half of the unit circle seen from above: the tangent rays bound the view
+/// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+/// let angle = arc_subtended_angle(&half, point(0.0, 2.0));
+/// assert!((angle - std::f64::consts::PI / 3.0).abs() < 1e-12);
+///
+/// // Seen from the center, the angle is the sweep
+/// assert!((arc_subtended_angle(&half, point(0.0, 0.0)) - std::f64::consts::PI).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn arc_subtended_angle(a: &Arc, from: Point) -> f64 {
+    let va = a.a - from;
+    let vb = a.b - from;
+    if a.is_seg() {
+        return va.perp(vb).atan2(va.dot(vb)).abs();
+    }
+    let to_center = a.c - from;
+    let d = to_center.norm();
+    if d <= a.r {
+        if a.is_full_circle() {
+            return 2.0 * PI;
+        }
+        let angle = va.perp(vb).atan2(va.dot(vb));
+        return if angle < 0.0 { angle + 2.0 * PI } else { angle };
+    }
+
+    // Outside the circle, angles from the direction to the center are in (-π/2, π/2)
+    let u = to_center / d;
+    let angle_of = |q: Point| {
+        let v = q - from;
+        u.perp(v).atan2(u.dot(v))
+    };
+    let mut min = angle_of(a.a).min(angle_of(a.b));
+    let mut max = angle_of(a.a).max(angle_of(a.b));
+    if let Some((t1, t2)) = tangent_point_to_circle(from, circle(a.c, a.r)) {
+        for t in [t1, t2] {
+            if a.is_full_circle() || a.contains(t) {
+                min = min.min(angle_of(t));
+                max = max.max(angle_of(t));
+            }
+        }
+    }
+    max - min
+}
+
+#[cfg(test)]
+mod test_arc_subtended_angle {
+    use super::*;
+
+    #[test]
+    fn test_subtended_far_from_small_arc() {
+        // A short arc seen from far away subtends about its chord over the distance
+        let small = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        let from = point(100.0, 100.0);
+        let angle = arc_subtended_angle(&small, from);
+        let chord = 2.0_f64.sqrt();
+        let dist = (from - point(0.5, 0.5)).norm();
+        assert!(angle > 0.0 && angle < 0.02);
+        assert!((angle - chord / dist).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_subtended_close_to_large_arc() {
+        // Three quarters of the unit circle, bulging towards the point
+        let large = arc(point(0.0, -1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+        let from = point(1.25, 0.0);
+        let angle = arc_subtended_angle(&large, from);
+        // Bounded by both tangent rays
+        assert!((angle - 2.0 * 0.8_f64.asin()).abs() < 1e-12);
+        assert!(angle > 1.8);
+        // From the center the whole sweep is seen
+        assert!((arc_subtended_angle(&large, point(0.0, 0.0)) - 1.5 * PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_subtended_ends_bound_the_view() {
+        // Upper half circle seen from below: the tangent points are not on the arc
+        let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+        let angle = arc_subtended_angle(&half, point(0.0, -1.5));
+        assert!((angle - 2.0 * (1.0_f64 / 1.5).atan()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_subtended_inside_and_segment() {
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(arc_subtended_angle(&full, point(0.3, -0.2)), 2.0 * PI);
+        // Inscribed angle: from a point of the circle, half the sweep
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        assert!((arc_subtended_angle(&quarter, point(0.0, -1.0)) - PI / 4.0).abs() < 1e-12);
+        let seg = arcseg(point(1.0, 0.0), point(0.0, 1.0));
+        assert!((arc_subtended_angle(&seg, point(0.0, 0.0)) - PI / 2.0).abs() < 1e-12);
+        assert!((arc_subtended_angle(&seg, point(1.0, 1.0)) - PI / 2.0).abs() < 1e-12);
+    }
+}
+
+impl Arc {
+    /// Returns the side of the `a`→`b` chord the arc bows to.
+    ///
+    /// The side is the sign of `orient2d(a, b, m)` for the arc midpoint `m`.
+    /// Since arcs are CCW, a consistent arc always bows to the right of its own
+    /// chord; a path traversing a concave arc from `b` to `a` sees it bow to the
+    /// left. Full circles, with coincident end points, have no chord side.
+    ///
+    /// # Returns
+    ///
+    /// `1` if the arc bows to the left of the chord, `-1` if to the right, and `0`
+    /// for a segment or a full circle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let upper = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+    /// assert_eq!(upper.bulge_side(), -1);
+    /// assert_eq!(arcseg(point(1.0, 0.0), point(-1.0, 0.0)).bulge_side(), 0);
+    /// ```
+    #[must_use]
+    pub fn bulge_side(&self) -> i32 {
+        if self.is_seg() {
+            return 0;
+        }
+        let m = self.point_at(0.5);
+        let side = orient2d(
+            Coord { x: self.a.x, y: self.a.y },
+            Coord { x: self.b.x, y: self.b.y },
+            Coord { x: m.x, y: m.y },
+        );
+        if side > 0.0 {
+            1
+        } else if side < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_bulge_side {
+    use super::*;
+
+    #[test]
+    fn test_bulge_side_convex_arc_and_complement() {
+        let c = point(0.0, 0.0);
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0);
+        assert_eq!(quarter.bulge_side(), -1);
+        // The complement, traversed from the same start, bows to the left
+        let complement = arc(point(0.0, 1.0), point(1.0, 0.0), c, 1.0);
+        let m = complement.point_at(0.5);
+        assert!(points_order(quarter.a, quarter.b, m) > 0.0);
+        assert_eq!(complement.bulge_side(), -1);
+    }
+
+    #[test]
+    fn test_bulge_side_from_bulge() {
+        // Positive bulge is a CCW arc from p1 to p2, negative one is stored reversed
+        let p1 = point(0.0, 0.0);
+        let p2 = point(2.0, 0.0);
+        let ccw = arc_from_bulge(p1, p2, 0.5);
+        assert_eq!((ccw.a, ccw.bulge_side()), (p1, -1));
+        let cw = arc_from_bulge(p1, p2, -0.5);
+        assert_eq!((cw.a, cw.bulge_side()), (p2, -1));
+        // Large bulge, more than a half circle
+        assert_eq!(arc_from_bulge(p1, p2, 3.0).bulge_side(), -1);
+    }
+
+    #[test]
+    fn test_bulge_side_degenerate() {
+        assert_eq!(arcseg(point(0.0, 0.0), point(1.0, 1.0)).bulge_side(), 0);
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(full.bulge_side(), 0);
+    }
+}
+
+/// Upper limit of the number of pieces a flattened arc is split into.
+#[cfg(feature = "std")]
+const MAX_FLATTEN_PIECES: usize = 4096;
+
+#[cfg(feature = "std")]
+impl Arc {
+    /// Number of chords approximating the arc within `max_sagitta`.
+    ///
+    /// A chord spanning the angle `θ` deviates from the arc by the sagitta
+    /// `r * (1 - cos(θ / 2))`. Each chord spans at most a half circle, segments
+    /// need a single chord.
+    pub(crate) fn flatten_pieces(&self, max_sagitta: f64) -> usize {
+        if self.is_seg() {
+            return 1;
+        }
+        let ratio = (1.0 - max_sagitta / self.r).clamp(0.0, 1.0);
+        let max_angle = 2.0 * ratio.acos();
+        if max_angle.is_nan() || max_angle <= 0.0 {
+            return MAX_FLATTEN_PIECES;
+        }
+        ((self.sweep_angle() / max_angle).ceil() as usize).clamp(1, MAX_FLATTEN_PIECES)
+    }
+}
+
+/// Lazily flattens an arcline into points, without allocating.
+///
+/// The arcs are replaced by chords deviating from them by at most `max_sagitta`,
+/// segments are kept. The iterator yields the start point of the arcline and
+/// then the end point of every chord, so points can be streamed directly into
+/// a renderer or a file. Each element is traversed from the end shared with the
+/// previous element: concave arcs, stored reversed, are flattened from `b` to `a`.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to flatten
+/// * `max_sagitta` - The maximum deviation between an arc and its chords
+///
+/// # Returns
+///
+/// An iterator over the flattened points, empty for an empty arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(-1.0, -1.0), point(1.0, 0.0)),
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// let mut points = arcline_flatten_iter(&arcs, 0.01);
+/// assert_eq!(points.next(), Some(point(-1.0, -1.0)));
+/// assert_eq!(points.next(), Some(point(1.0, 0.0)));
+/// // The half circle chords follow
+/// assert!(points.all(|p| (p.norm() - 1.0).abs() < 1e-12));
+/// ```
+#[cfg(feature = "std")]
+pub fn arcline_flatten_iter<'a>(arcs: &'a Arcline, max_sagitta: f64) -> impl Iterator<Item = Point> + 'a {
+    // The first element is reversed when its start touches the next element
+    let start = arcs.first().map(|first| match arcs.get(1) {
+        Some(next) => {
+            let gap = |p: Point| (p - next.a).norm().min((p - next.b).norm());
+            if gap(first.b) <= gap(first.a) { first.a } else { first.b }
+        }
+        None => first.a,
+    });
+    let mut end = start.unwrap_or_default();
+    start.into_iter().chain(arcs.iter().flat_map(move |element| {
+        let forward = (element.a - end).norm() <= (element.b - end).norm();
+        let pieces = element.flatten_pieces(max_sagitta);
+        end = if forward { element.b } else { element.a };
+        (1..=pieces).map(move |k| {
+            let t = k as f64 / pieces as f64;
+            element.point_at(if forward { t } else { 1.0 - t })
+        })
+    }))
+}
+
+/// Flattens an arcline into a polyline of straight segments.
+///
+/// Each arc is split into equal chords, their number derived from the radius
+/// and the sweep angle so that the sagitta of every chord is at most `tol`.
+/// Segments are copied directly. The vertices are the points of
+/// [`arcline_flatten_iter`] and all bulges are zero.
+///
+/// Polylines are implicitly closed: for a closed arcline (last point connects
+/// back to first) the repeated start point is not added at the end.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to flatten
+/// * `tol` - The maximum deviation between an arc and its chords
+///
+/// # Returns
+///
+/// The flattened polyline, empty for an empty arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Closed half disc
+/// let arcs = vec![
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// let poly = arcline_to_polyline(&arcs, 0.01);
+/// assert_eq!(poly[0], pvertex(point(-1.0, 0.0), 0.0));
+/// assert_eq!(poly[1], pvertex(point(1.0, 0.0), 0.0));
+/// assert!(poly.iter().all(|v| v.b == 0.0 && (v.p.norm() - 1.0).abs() < 1e-12));
+/// assert_ne!(poly[poly.len() - 1].p, poly[0].p);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_to_polyline(arcs: &Arcline, tol: f64) -> Polyline {
+    let mut poly: Polyline = arcline_flatten_iter(arcs, tol).map(|p| pvertex(p, 0.0)).collect();
+    let n = poly.len();
+    if n > 3 && poly[0].p.close_enough(poly[n - 1].p, GEOMETRIC_EPSILON) {
+        poly.pop();
+    }
+    poly
+}
+
+/// Converts an arcline to a GeoJSON geometry object.
+///
+/// The arcs are flattened into chords deviating from the arcs by at most
+/// `max_sagitta`. A closed arcline (last point connects back to first) becomes a
+/// `Polygon` with a single CCW ring, as required for exterior rings by RFC 7946:
+/// CW arclines are reversed. An open arcline becomes a `LineString`.
+///
+/// Coordinates are written in GeoJSON order, `[x, y]`, i.e. longitude before
+/// latitude for geographic data. Polygon rings repeat the first position at the end.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to convert
+/// * `max_sagitta` - The maximum deviation between an arc and its chords
+///
+/// # Returns
+///
+/// The GeoJSON geometry object as a compact JSON string
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(2.0, 1.5)),
+/// ];
+/// assert_eq!(
+///     arcline_to_geojson(&arcs, 0.01),
+///     r#"{"type":"LineString","coordinates":[[0,0],[2,0],[2,1.5]]}"#
+/// );
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_to_geojson(arcs: &Arcline, max_sagitta: f64) -> String {
+    let mut points: Pointline = arcline_flatten_iter(arcs, max_sagitta).collect();
+    let n = points.len();
+    let closed = n > 3 && points[0].close_enough(points[n - 1], GEOMETRIC_EPSILON);
+    let coordinates = |points: &Pointline| {
+        let positions: Vec<String> = points.iter().map(|p| format!("[{},{}]", p.x, p.y)).collect();
+        format!("[{}]", positions.join(","))
+    };
+    if closed {
+        points[n - 1] = points[0];
+        if pointline_area(&points[..n - 1].to_vec()) < 0.0 {
+            points.reverse();
+        }
+        format!(r#"{{"type":"Polygon","coordinates":[{}]}}"#, coordinates(&points))
+    } else {
+        format!(r#"{{"type":"LineString","coordinates":{}}}"#, coordinates(&points))
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_flatten_iter {
+    use super::*;
+
+    // Rectangle with an inward arc on top, stored reversed
+    fn bitten_rectangle() -> Arcline {
+        vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arcseg(point(4.0, 0.0), point(4.0, 2.0)),
+            arc(point(3.0, 2.0), point(4.0, 2.0), point(3.5, 2.0), 0.5),
+            arcseg(point(3.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_flatten_iter_matches_element_chords() {
+        let arcs = bitten_rectangle();
+        let points: Pointline = arcline_flatten_iter(&arcs, 0.001).collect();
+        let pieces = arcs[2].flatten_pieces(0.001);
+        assert!(pieces > 4);
+        assert_eq!(points.len(), 5 + pieces);
+        assert_eq!(points[..3], [point(0.0, 0.0), point(4.0, 0.0), point(4.0, 2.0)]);
+        // The concave arc dips below the top edge, from right to left
+        for (k, p) in points[3..3 + pieces].iter().enumerate() {
+            let expected = arcs[2].point_at(1.0 - (k + 1) as f64 / pieces as f64);
+            assert_eq!(*p, expected);
+            assert!(p.y <= 2.0);
+        }
+        assert_eq!(points[2 + pieces], point(3.0, 2.0));
+        assert_eq!(points[points.len() - 1], point(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_flatten_iter_is_lazy() {
+        // A tiny sagitta gives thousands of chords, only the first are evaluated
+        let full = vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)];
+        let first: Pointline = arcline_flatten_iter(&full, 1e-12).take(3).collect();
+        assert_eq!(first.len(), 3);
+        assert_eq!(first[0], point(1.0, 0.0));
+        assert!(first[1].y > 0.0 && first[1].y < first[2].y);
+        assert_eq!(arcline_flatten_iter(&full, 1e-12).count(), MAX_FLATTEN_PIECES + 1);
+    }
+
+    #[test]
+    fn test_flatten_iter_empty_and_single() {
+        assert_eq!(arcline_flatten_iter(&vec![], 0.1).count(), 0);
+        let seg = vec![arcseg(point(1.0, 2.0), point(3.0, 4.0))];
+        let points: Pointline = arcline_flatten_iter(&seg, 0.1).collect();
+        assert_eq!(points, vec![point(1.0, 2.0), point(3.0, 4.0)]);
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_to_polyline {
+    use super::*;
+
+    // Unit circle made of four quarter arcs
+    fn circle_of_quarters() -> Arcline {
+        let c = point(0.0, 0.0);
+        vec![
+            arc(point(1.0, 0.0), point(0.0, 1.0), c, 1.0),
+            arc(point(0.0, 1.0), point(-1.0, 0.0), c, 1.0),
+            arc(point(-1.0, 0.0), point(0.0, -1.0), c, 1.0),
+            arc(point(0.0, -1.0), point(1.0, 0.0), c, 1.0),
+        ]
+    }
+
+    #[test]
+    fn test_to_polyline_unit_circle() {
+        let tol = 0.001;
+        let poly = arcline_to_polyline(&circle_of_quarters(), tol);
+        // Closed implicitly, the start point is not repeated
+        assert_eq!(poly[0].p, point(1.0, 0.0));
+        assert!(!poly[poly.len() - 1].p.close_enough(poly[0].p, 1e-9));
+        let n = poly.len();
+        for i in 0..n {
+            let p = poly[i].p;
+            let q = poly[(i + 1) % n].p;
+            assert_eq!(poly[i].b, 0.0);
+            assert!((p.norm() - 1.0).abs() <= tol);
+            // Sagitta of the chord
+            assert!(1.0 - ((p + q) * 0.5).norm() <= tol);
+        }
+        // Same chord count in each quarter, enough to respect the tolerance
+        assert_eq!(n % 4, 0);
+        assert!(n >= 4 * 18);
+        assert!((pointline_area(&poly.iter().map(|v| v.p).collect()) - PI).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_to_polyline_matches_flatten_iter() {
+        let arcs = vec![
+            arcseg(point(-2.0, -1.0), point(1.0, 0.0)),
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+        ];
+        let poly = arcline_to_polyline(&arcs, 0.01);
+        let points: Pointline = arcline_flatten_iter(&arcs, 0.01).collect();
+        assert_eq!(poly.iter().map(|v| v.p).collect::<Pointline>(), points);
+    }
+
+    #[test]
+    fn test_to_polyline_segments_copied() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert_eq!(
+            arcline_to_polyline(&square, 0.1),
+            vec![
+                pvertex(point(0.0, 0.0), 0.0),
+                pvertex(point(2.0, 0.0), 0.0),
+                pvertex(point(2.0, 2.0), 0.0),
+                pvertex(point(0.0, 2.0), 0.0),
+            ]
+        );
+        assert!(arcline_to_polyline(&vec![], 0.1).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_arcline_to_geojson {
+    use super::*;
+
+    // Minimal JSON check: the geometry type and the positions, with their nesting depth
+    fn parse_geometry(json: &str) -> (String, usize, Vec<Point>) {
+        assert!(json.starts_with('{') && json.ends_with('}'));
+        let type_start = json.find(r#""type":""#).unwrap() + 8;
+        let type_len = json[type_start..].find('"').unwrap();
+        let kind = json[type_start..type_start + type_len].to_string();
+        let coordinates = &json[json.find(r#""coordinates":"#).unwrap() + 14..json.len() - 1];
+        let depth = coordinates.chars().take_while(|c| *c == '[').count();
+        let mut points = Vec::new();
+        for position in coordinates.split("],[") {
+            let numbers: Vec<f64> = position
+                .trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .map(|v| v.parse().unwrap())
+                .collect();
+            assert_eq!(numbers.len(), 2);
+            points.push(point(numbers[0], numbers[1]));
+        }
+        (kind, depth, points)
+    }
+
+    fn disc(c: Point, r: f64) -> Arcline {
+        vec![
+            arc(c + point(r, 0.0), c + point(0.0, r), c, r),
+            arc(c + point(0.0, r), c + point(-r, 0.0), c, r),
+            arc(c + point(-r, 0.0), c + point(0.0, -r), c, r),
+            arc(c + point(0.0, -r), c + point(r, 0.0), c, r),
+        ]
+    }
+
+    #[test]
+    fn test_geojson_polygon_from_closed_arcline() {
+        let c = point(10.0, 45.0);
+        let json = arcline_to_geojson(&disc(c, 2.0), 0.01);
+        let (kind, depth, points) = parse_geometry(&json);
+        assert_eq!(kind, "Polygon");
+        // Polygon coordinates are an array of rings
+        assert_eq!(depth, 3);
+        assert_eq!(points[0], points[points.len() - 1]);
+        assert!(points.len() > 16);
+        for p in &points {
+            assert!(((*p - c).norm() - 2.0).abs() < 1e-12);
+        }
+        let ring = points[..points.len() - 1].to_vec();
+        assert!(pointline_area(&ring) > 0.0);
+        // Chord midpoints are within the sagitta
+        for i in 0..ring.len() {
+            let m = (ring[i] + ring[(i + 1) % ring.len()]) * 0.5;
+            assert!(2.0 - (m - c).norm() <= 0.01);
+        }
+    }
+
+    #[test]
+    fn test_geojson_cw_ring_is_reversed() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(1.0, 1.0)),
+            arcseg(point(1.0, 1.0), point(1.0, 0.0)),
+            arcseg(point(1.0, 0.0), point(0.0, 0.0)),
+        ];
+        assert_eq!(
+            arcline_to_geojson(&square, 0.1),
+            r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1],[0,1],[0,0]]]}"#
+        );
+    }
+
+    #[test]
+    fn test_geojson_line_string_with_concave_arc() {
+        // Open path, the arc is stored reversed and flattened from b to a
+        let arcs = vec![
+            arcseg(point(-3.0, 1.0), point(-1.0, 0.0)),
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+            arcseg(point(1.0, 0.0), point(3.0, 1.0)),
+        ];
+        let (kind, depth, points) = parse_geometry(&arcline_to_geojson(&arcs, 0.05));
+        assert_eq!(kind, "LineString");
+        assert_eq!(depth, 2);
+        assert_eq!(points[0], point(-3.0, 1.0));
+        assert_eq!(points[1], point(-1.0, 0.0));
+        assert_eq!(points[points.len() - 2], point(1.0, 0.0));
+        assert_eq!(points[points.len() - 1], point(3.0, 1.0));
+        for p in &points[1..points.len() - 1] {
+            assert!((p.norm() - 1.0).abs() < 1e-12);
+            assert!(p.y >= -1e-12);
+        }
+        // Points run from left to right over the top
+        assert!(points.windows(2).all(|w| w[0].x < w[1].x));
+    }
+
+    #[test]
+    fn test_geojson_empty() {
+        assert_eq!(arcline_to_geojson(&vec![], 0.1), r#"{"type":"LineString","coordinates":[]}"#);
+    }
+}
+
+/// Builds an arcline from polyline vertices with bulge values, as in DXF `LWPOLYLINE`.
+///
+/// Each vertex is connected to the next one by [`arc_from_bulge`] with the
+/// bulge of the vertex: zero gives a segment, a positive bulge a CCW arc and a
+/// negative bulge a CW arc, which is stored reversed since arcs are CCW. The
+/// bulge is the tangent of a quarter of the arc sweep, 1 for a half circle.
+/// Consecutive equal vertices are skipped.
+///
+/// # Arguments
+///
+/// * `verts` - The vertices and the bulges of the elements starting at them
+/// * `closed` - Whether the last vertex connects back to the first one; the bulge
+///   of the last vertex is ignored for an open polyline
+///
+/// # Returns
+///
+/// The arcline, see [`arcline_to_bulge_vertices`] for the inverse
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // A half disc: a segment then a half circle back to the start
+/// let verts = vec![(point(-1.0, 0.0), 0.0), (point(1.0, 0.0), 1.0)];
+/// let arcs = arcline_from_bulge_vertices(&verts, true);
+/// assert_eq!(arcs[0], arcseg(point(-1.0, 0.0), point(1.0, 0.0)));
+/// assert_eq!(arcs[1], arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0));
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_from_bulge_vertices(verts: &[(Point, f64)], closed: bool) -> Arcline {
+    let n = verts.len();
+    let count = if closed { n } else { n.saturating_sub(1) };
+    (0..count)
+        .filter_map(|i| {
+            let (p, bulge) = verts[i];
+            let q = verts[(i + 1) % n].0;
+            (p != q).then(|| arc_from_bulge(p, q, bulge))
+        })
+        .collect()
+}
+
+/// Converts an arcline to polyline vertices with bulge values, as in DXF `LWPOLYLINE`.
+///
+/// Every element gives the vertex it starts from in the path direction, with a
+/// zero bulge for segments and `tan(sweep / 4)` for arcs: positive for arcs
+/// traversed CCW from `a` to `b`, and negative for arcs traversed backward, like
+/// the concave arcs of a closed shape. The direction of the elements follows from
+/// their connections, as in [`arcline_flatten_iter`]. Full circles, which no
+/// single bulge describes, give two half circles.
+///
+/// A closed arcline (last point connects back to first) does not repeat its start
+/// point; an open one ends with its end point and a zero bulge.
+///
+/// # Arguments
+///
+/// * `arcs` - The connected arcline to convert
+///
+/// # Returns
+///
+/// The vertices with bulges, see [`arcline_from_bulge_vertices`] for the inverse
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// let verts = arcline_to_bulge_vertices(&arcs);
+/// assert_eq!(verts.len(), 2);
+/// assert_eq!(verts[0], (point(-1.0, 0.0), 0.0));
+/// assert_eq!(verts[1].0, point(1.0, 0.0));
+/// assert!((verts[1].1 - 1.0).abs() < 1e-12);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_to_bulge_vertices(arcs: &Arcline) -> Vec<(Point, f64)> {
+    let Some(first) = arcs.first() else {
+        return Vec::new();
+    };
+    // The first element is reversed when its start touches the next element
+    let mut end = match arcs.get(1) {
+        Some(next) => {
+            let gap = |p: Point| (p - next.a).norm().min((p - next.b).norm());
+            if gap(first.b) <= gap(first.a) { first.a } else { first.b }
+        }
+        None => first.a,
+    };
+    let start = end;
+
+    let mut verts = Vec::with_capacity(arcs.len() + 1);
+    for element in arcs {
+        if element.is_seg() {
+            let forward = (element.a - end).norm() <= (element.b - end).norm();
+            let (from, to) = if forward { (element.a, element.b) } else { (element.b, element.a) };
+            verts.push((from, 0.0));
+            end = to;
+        } else if element.is_full_circle() {
+            let opposite = element.c * 2.0 - element.a;
+            verts.push((element.a, 1.0));
+            verts.push((opposite, 1.0));
+            end = element.a;
+        } else {
+            let forward = (element.a - end).norm() <= (element.b - end).norm();
+            let bulge = (element.sweep_angle() / 4.0).tan();
+            if forward {
+                verts.push((element.a, bulge));
+                end = element.b;
+            } else {
+                verts.push((element.b, -bulge));
+                end = element.a;
+            }
+        }
+    }
+    if !end.close_enough(start, GEOMETRIC_EPSILON) {
+        verts.push((end, 0.0));
+    }
+    verts
+}
+
+#[cfg(test)]
+mod test_bulge_vertices {
+    use super::*;
+
+    fn assert_same(a: &Arcline, b: &Arcline) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert_eq!((x.a, x.b), (y.a, y.b));
+            assert_eq!(x.is_seg(), y.is_seg());
+            if x.is_arc() {
+                assert!(x.c.close_enough(y.c, 1e-12) && (x.r - y.r).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_bulge_gives_segments() {
+        let verts = vec![(point(0.0, 0.0), 0.0), (point(2.0, 0.0), 0.0), (point(2.0, 1.0), 0.5)];
+        let arcs = arcline_from_bulge_vertices(&verts, false);
+        assert_eq!(arcs, vec![arcseg(point(0.0, 0.0), point(2.0, 0.0)), arcseg(point(2.0, 0.0), point(2.0, 1.0))]);
+        assert_eq!(arcline_to_bulge_vertices(&arcs), vec![(point(0.0, 0.0), 0.0), (point(2.0, 0.0), 0.0), (point(2.0, 1.0), 0.0)]);
+        assert!(arcline_from_bulge_vertices(&verts[..1], false).is_empty());
+        assert!(arcline_from_bulge_vertices(&[], true).is_empty());
+    }
+
+    #[test]
+    fn test_bulge_signs_give_ccw_arcs() {
+        // Quarter circle CCW from (1, 0) to (0, 1), and the same path CW
+        let b = (PI / 8.0).tan();
+        let ccw = arcline_from_bulge_vertices(&[(point(1.0, 0.0), b), (point(0.0, 1.0), 0.0)], false);
+        assert_eq!((ccw[0].a, ccw[0].b), (point(1.0, 0.0), point(0.0, 1.0)));
+        assert!(ccw[0].c.close_enough(point(0.0, 0.0), 1e-12));
+        let cw = arcline_from_bulge_vertices(&[(point(1.0, 0.0), -b), (point(0.0, 1.0), 0.0)], false);
+        // Stored CCW from the end of the path back to its start, centered at (1, 1)
+        assert_eq!((cw[0].a, cw[0].b), (point(0.0, 1.0), point(1.0, 0.0)));
+        assert!(cw[0].c.close_enough(point(1.0, 1.0), 1e-12));
+        assert!(cw.iter().chain(&ccw).all(|a| a.is_ccw_consistent()));
+    }
+
+    #[test]
+    fn test_closed_loop_round_trip() {
+        // Rounded corner, a concave notch traversed backward and straight sides
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arc(point(4.0, 0.0), point(5.0, 1.0), point(4.0, 1.0), 1.0),
+            arcseg(point(5.0, 1.0), point(5.0, 4.0)),
+            arcseg(point(5.0, 4.0), point(3.0, 4.0)),
+            arc(point(1.0, 4.0), point(3.0, 4.0), point(2.0, 4.0), 1.0),
+            arcseg(point(1.0, 4.0), point(0.0, 4.0)),
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        let verts = arcline_to_bulge_vertices(&arcs);
+        assert_eq!(verts.len(), arcs.len());
+        assert!((verts[1].1 - (PI / 8.0).tan()).abs() < 1e-12);
+        // The notch goes CW from (3, 4) to (1, 4)
+        assert_eq!(verts[4].0, point(3.0, 4.0));
+        assert!((verts[4].1 + 1.0).abs() < 1e-12);
+        assert!(verts.iter().enumerate().all(|(i, v)| i == 1 || i == 4 || v.1 == 0.0));
+        assert_same(&arcline_from_bulge_vertices(&verts, true), &arcs);
+    }
+
+    #[test]
+    fn test_open_path_and_full_circle() {
+        let open = vec![
+            arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+            arcseg(point(0.0, 1.0), point(-2.0, 1.0)),
+        ];
+        let verts = arcline_to_bulge_vertices(&open);
+        assert_eq!(verts.len(), 3);
+        assert_eq!(verts[2], (point(-2.0, 1.0), 0.0));
+        assert_same(&arcline_from_bulge_vertices(&verts, false), &open);
+
+        let full = vec![arc(point(2.0, 1.0), point(2.0, 1.0), point(1.0, 1.0), 1.0)];
+        let verts = arcline_to_bulge_vertices(&full);
+        assert_eq!(verts, vec![(point(2.0, 1.0), 1.0), (point(0.0, 1.0), 1.0)]);
+        let halves = arcline_from_bulge_vertices(&verts, true);
+        assert_eq!(halves.len(), 2);
+        assert!(halves.iter().all(|a| a.c.close_enough(point(1.0, 1.0), 1e-12) && a.r == 1.0));
+    }
+}
+
+impl Arc {
+    /// Reflects the arc across the x-axis, negating y coordinates.
+    ///
+    /// A reflection turns a CCW arc into a CW one, so the end points of curved
+    /// arcs are swapped to keep the arc CCW: the result runs from the image of
+    /// `b` to the image of `a`. Segments keep their direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let upper = arc(point(3.0, 1.0), point(1.0, 1.0), point(2.0, 1.0), 1.0);
+    /// let lower = upper.mirror_x();
+    /// assert_eq!(lower, arc(point(1.0, -1.0), point(3.0, -1.0), point(2.0, -1.0), 1.0));
+    /// assert!(lower.contains(point(2.0, -2.0)));
+    /// ```
+    #[must_use]
+    pub fn mirror_x(&self) -> Arc {
+        let image = |p: Point| point(p.x, -p.y);
+        if self.is_seg() {
+            return arcseg(image(self.a), image(self.b));
+        }
+        arc(image(self.b), image(self.a), image(self.c), self.r)
+    }
+
+    /// Reflects the arc across the y-axis, negating x coordinates.
+    ///
+    /// As for [`Arc::mirror_x`], the end points of curved arcs are swapped to
+    /// keep the arc CCW and segments keep their direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// let mirrored = quarter.mirror_y();
+    /// assert_eq!(mirrored, arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0));
+    /// ```
+    #[must_use]
+    pub fn mirror_y(&self) -> Arc {
+        let image = |p: Point| point(-p.x, p.y);
+        if self.is_seg() {
+            return arcseg(image(self.a), image(self.b));
+        }
+        arc(image(self.b), image(self.a), image(self.c), self.r)
+    }
+
+    /// Reflects the arc across an infinite line, see [`Point::mirror`].
+    ///
+    /// As for [`Arc::mirror_x`], the end points of curved arcs are swapped to
+    /// keep the arc CCW and segments keep their direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// let mirrored = quarter.mirror(&line(point(2.0, 0.0), point(0.0, 1.0)));
+    /// assert_eq!(mirrored, arc(point(4.0, 1.0), point(3.0, 0.0), point(4.0, 0.0), 1.0));
+    /// ```
+    #[must_use]
+    pub fn mirror(&self, l: &Line) -> Arc {
+        if self.is_seg() {
+            return arcseg(self.a.mirror(l), self.b.mirror(l));
+        }
+        arc(self.b.mirror(l), self.a.mirror(l), self.c.mirror(l), self.r)
+    }
+}
+
+/// Reflects an arcline across an infinite line.
+///
+/// A reflection reverses the orientation of the arcline. To keep a CCW arcline
+/// CCW, the elements are mirrored with [`Arc::mirror`] and the result is
+/// reversed with [`arcline_reverse`]: the element order is reversed and the
+/// segments are flipped, while curved arcs, already swapped by the mirroring,
+/// are kept. Arcs that are convex (or concave) in the arcline stay so.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to mirror
+/// * `l` - The mirror line
+///
+/// # Returns
+///
+/// The mirrored arcline, with the orientation of the input
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let triangle = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let mirrored = arcline_mirror(&triangle, &line(point(0.0, 0.0), point(0.0, 1.0)));
+/// assert_eq!(mirrored[0], arcseg(point(0.0, 0.0), point(0.0, 1.0)));
+/// assert_eq!(arcline_area(&mirrored), arcline_area(&triangle));
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_mirror(arcs: &Arcline, l: &Line) -> Arcline {
+    let mirrored: Arcline = arcs.iter().map(|element| element.mirror(l)).collect();
+    arcline_reverse(&mirrored)
+}
+
+#[cfg(test)]
+mod test_arc_mirror {
+    use super::*;
+
+    fn arcs() -> [Arc; 4] {
+        let c = point(1.5, -0.5);
+        let at = |angle: f64| c + point(angle.cos(), angle.sin()) * 2.0;
+        [
+            arc(at(0.3), at(1.2), c, 2.0),
+            arc(at(2.0), at(5.5), c, 2.0),
+            arc(at(4.0), at(0.5), c, 2.0),
+            arc(at(1.0), at(1.0), c, 2.0),
+        ]
+    }
+
+    fn check_reflected(original: &Arc, mirrored: &Arc, image: impl Fn(Point) -> Point) {
+        assert!(mirrored.is_consistent(1e-12));
+        assert_eq!(mirrored.c, image(original.c));
+        assert_eq!(mirrored.r, original.r);
+        assert!((mirrored.sweep_angle() - original.sweep_angle()).abs() < 1e-12);
+        // CCW from the image of b to the image of a
+        assert_eq!((mirrored.a, mirrored.b), (image(original.b), image(original.a)));
+        // Covers the reflected sweep and nothing else
+        for k in 1..16 {
+            let t = k as f64 / 16.0;
+            let on = image(original.point_at(t));
+            assert!(mirrored.contains(on));
+            assert!(mirrored.point_at(1.0 - t).close_enough(on, 1e-12));
+        }
+        if !original.is_full_circle() {
+            let off = image(original.reverse().point_at(0.5));
+            assert!(!mirrored.contains(off));
+        }
+    }
+
+    #[test]
+    fn test_mirror_x() {
+        for original in arcs() {
+            check_reflected(&original, &original.mirror_x(), |p| point(p.x, -p.y));
+        }
+    }
+
+    #[test]
+    fn test_mirror_y() {
+        for original in arcs() {
+            check_reflected(&original, &original.mirror_y(), |p| point(-p.x, p.y));
+        }
+    }
+
+    #[test]
+    fn test_mirror_line() {
+        let lines = [
+            line(point(-3.0, 0.0), point(2.0, 0.0)),
+            line(point(1.0, 2.0), point(1.0, -3.0)),
+        ];
+        for l in &lines {
+            for original in arcs() {
+                let mirrored = original.mirror(l);
+                assert!(mirrored.is_consistent(1e-12));
+                assert!(mirrored.c.close_enough(original.c.mirror(l), 1e-12));
+                // CCW from the image of b to the image of a
+                assert!(mirrored.a.close_enough(original.b.mirror(l), 1e-12));
+                assert!(mirrored.b.close_enough(original.a.mirror(l), 1e-12));
+                for k in 1..16 {
+                    let t = k as f64 / 16.0;
+                    let on = original.point_at(t).mirror(l);
+                    assert!(mirrored.point_at(1.0 - t).close_enough(on, 1e-12));
+                }
+            }
+        }
+        // The x-axis as a mirror line matches mirror_x
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        let mirrored = quarter.mirror(&lines[0]);
+        let expected = quarter.mirror_x();
+        assert_eq!((mirrored.a, mirrored.b, mirrored.c), (expected.a, expected.b, expected.c));
+    }
+
+    #[test]
+    fn test_arcline_mirror_square_stays_ccw() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let mirrored = arcline_mirror(&square, &line(point(3.0, 0.0), point(0.0, 1.0)));
+        assert_eq!(
+            mirrored,
+            vec![
+                arcseg(point(6.0, 0.0), point(6.0, 2.0)),
+                arcseg(point(6.0, 2.0), point(4.0, 2.0)),
+                arcseg(point(4.0, 2.0), point(4.0, 0.0)),
+                arcseg(point(4.0, 0.0), point(6.0, 0.0)),
+            ]
+        );
+        // Still CCW: positive area, same as the original
+        assert_eq!(arcline_area(&mirrored), 4.0);
+        let vertices: Pointline = mirrored.iter().map(|e| e.a).collect();
+        assert!(pointline_area(&vertices) > 0.0);
+    }
+
+    #[test]
+    fn test_arcline_mirror_rounded_shape() {
+        // Half disc with a segment base, mirrored across a tilted line
+        let half_disc = vec![
+            arc(point(3.0, 1.0), point(-1.0, 1.0), point(1.0, 1.0), 2.0),
+            arcseg(point(-1.0, 1.0), point(3.0, 1.0)),
+        ];
+        let l = line(point(0.0, -1.0), point(1.0, 2.0));
+        let mirrored = arcline_mirror(&half_disc, &l);
+        assert_eq!(mirrored.len(), 2);
+        assert_eq!(arcline_is_valid(&mirrored), ArclineValidation::Valid);
+        assert!((arcline_area(&mirrored) - arcline_area(&half_disc)).abs() < 1e-12);
+        // The mirrored interior point is inside
+        assert!(arcline_contains(&mirrored, point(1.0, 2.0).mirror(&l)));
+        // Mirroring twice gives back the arcline
+        let twice = arcline_mirror(&mirrored, &l);
+        for (e, o) in twice.iter().zip(&half_disc) {
+            assert!(e.a.close_enough(o.a, 1e-12) && e.b.close_enough(o.b, 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_mirror_twice_and_segments() {
+        for original in arcs() {
+            assert_eq!(original.mirror_x().mirror_x(), original);
+            assert_eq!(original.mirror_y().mirror_y(), original);
+        }
+        let seg = arcseg(point(1.0, 2.0), point(3.0, -4.0));
+        assert_eq!(seg.mirror_x(), arcseg(point(1.0, -2.0), point(3.0, 4.0)));
+        assert_eq!(seg.mirror_y(), arcseg(point(-1.0, 2.0), point(-3.0, -4.0)));
+        assert!(seg.mirror_x().is_seg());
+    }
+}
+
+impl Arc {
+    /// Maps the arc by an affine transform.
+    ///
+    /// The end points and the center are mapped and the radius is scaled by
+    /// the scale factor of the transform. Line segments are mapped by their end
+    /// points, with any transform. A transform that mirrors (negative
+    /// determinant) swaps the end points of curved arcs to keep them CCW, as
+    /// [`Arc::mirror`] does.
+    ///
+    /// Curved arcs need a uniform transform, see [`Affine2::is_uniform`]: a
+    /// non-uniform scale or a shear turns circles into ellipses, which an arc
+    /// cannot represent. Such transforms are not rejected, the radius is then
+    /// scaled by the square root of the area factor and the result is not a
+    /// consistent arc.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - The transform
+    ///
+    /// # Returns
+    ///
+    /// The mapped arc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+    /// let m = Affine2::scaling(2.0, 2.0).then(&Affine2::translation(point(1.0, 1.0)));
+    /// assert_eq!(quarter.transform(&m), arc(point(3.0, 1.0), point(1.0, 3.0), point(1.0, 1.0), 2.0));
+    /// ```
+    #[must_use]
+    pub fn transform(&self, m: &Affine2) -> Arc {
+        if self.is_seg() {
+            return arcseg(m.apply(self.a), m.apply(self.b));
+        }
+        let det = m.determinant();
+        let r = self.r * det.abs().sqrt();
+        if det < 0.0 {
+            arc(m.apply(self.b), m.apply(self.a), m.apply(self.c), r)
+        } else {
+            arc(m.apply(self.a), m.apply(self.b), m.apply(self.c), r)
+        }
+    }
+}
+
+/// Maps an arcline by an affine transform.
+///
+/// The elements are mapped with [`Arc::transform`], so curved arcs need a
+/// uniform transform. A transform that mirrors reverses the orientation of the
+/// arcline; as for [`arcline_mirror`], the result is then reversed to keep the
+/// orientation of the input.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to transform
+/// * `m` - The transform
+///
+/// # Returns
+///
+/// The transformed arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let square = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(1.0, 1.0)),
+///     arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let m = Affine2::rotation(std::f64::consts::FRAC_PI_4).then(&Affine2::scaling(2.0, 2.0));
+/// let moved = arcline_transform(&square, &m);
+/// assert!((arcline_area(&moved) - 4.0).abs() < 1e-12);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arcline_transform(arcs: &Arcline, m: &Affine2) -> Arcline {
+    let mapped: Arcline = arcs.iter().map(|element| element.transform(m)).collect();
+    if m.determinant() < 0.0 {
+        arcline_reverse(&mapped)
+    } else {
+        mapped
+    }
+}
+
+#[cfg(test)]
+mod test_arc_transform {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn assert_arc_near(x: &Arc, y: &Arc) {
+        assert!(x.a.close_enough(y.a, 1e-12) && x.b.close_enough(y.b, 1e-12));
+        assert!(x.is_seg() == y.is_seg());
+        if x.is_arc() {
+            assert!(x.c.close_enough(y.c, 1e-12) && (x.r - y.r).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rotate_then_translate() {
+        let angle = 2.0 * PI / 3.0;
+        let shift = point(-4.0, 2.5);
+        let m = Affine2::rotation(angle).then(&Affine2::translation(shift));
+        let elements = [
+            arc(point(3.0, 1.0), point(1.0, 3.0), point(1.0, 1.0), 2.0),
+            arc(point(0.0, 1.0), point(0.0, 1.0), point(0.0, 0.0), 1.0),
+            arcseg(point(1.0, -1.0), point(2.0, 5.0)),
+        ];
+        for original in elements {
+            let mut manual = original;
+            manual.rotate(point(0.0, 0.0), angle);
+            manual.translate(shift);
+            let moved = original.transform(&m);
+            assert_arc_near(&moved, &manual);
+            assert!(moved.is_seg() || moved.is_consistent(1e-12));
+            // Points of the arc map to points of the moved arc
+            let mid = m.apply(original.point_at(0.5));
+            assert!(mid.close_enough(moved.point_at(0.5), 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_scale_and_mirror() {
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        let scaled = quarter.transform(&Affine2::scaling(3.0, 3.0));
+        assert_eq!(scaled, arc(point(3.0, 0.0), point(0.0, 3.0), point(0.0, 0.0), 3.0));
+        // A mirroring transform swaps the end points, like Arc::mirror_y
+        assert_eq!(quarter.transform(&Affine2::scaling(-1.0, 1.0)), quarter.mirror_y());
+        // Segments accept non-uniform scales
+        let seg = arcseg(point(1.0, 1.0), point(2.0, 3.0));
+        assert_eq!(seg.transform(&Affine2::scaling(2.0, 0.5)), arcseg(point(2.0, 0.5), point(4.0, 1.5)));
+    }
+
+    #[test]
+    fn test_arcline_transform() {
+        // Unit square with a convex half disc on its right side
+        let shape = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arc(point(1.0, 0.0), point(1.0, 1.0), point(1.0, 0.5), 0.5),
+            arcseg(point(1.0, 1.0), point(0.0, 1.0)),
+            arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+        ];
+        let area = arcline_area(&shape);
+        let m = Affine2::rotation(0.4).then(&Affine2::translation(point(5.0, -3.0)));
+        let moved = arcline_transform(&shape, &m);
+        assert!((arcline_area(&moved) - area).abs() < 1e-12);
+        for (e, o) in moved.iter().zip(&shape) {
+            assert_arc_near(e, &o.transform(&m));
+        }
+        // Mirroring keeps the orientation, as arcline_mirror
+        let mirror = Affine2::scaling(1.0, -1.0);
+        let mirrored = arcline_transform(&shape, &mirror);
+        assert_eq!(mirrored, arcline_mirror(&shape, &line(point(0.0, 0.0), point(1.0, 0.0))));
+        assert!((arcline_area(&mirrored) - area).abs() < 1e-12);
+        // Uniform scale multiplies the area
+        let doubled = arcline_transform(&shape, &Affine2::scaling(2.0, 2.0));
+        assert!((arcline_area(&doubled) - 4.0 * area).abs() < 1e-12);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_arcline_json_round_trip() {
+        let arcline = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arc(point(2.0, 0.0), point(2.0, 2.0), point(2.0, 1.0), 1.0),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        let json = serde_json::to_string(&arcline).unwrap();
+        let back: Arcline = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, arcline);
+        assert!(back[0].is_seg() && back[1].is_arc());
+        assert_eq!((back[0].c, back[0].r), (arcline[0].c, arcline[0].r));
+    }
+
+    #[test]
+    fn test_id_is_not_serialized() {
+        let mut a = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        a.id = 7;
+        let json = serde_json::to_string(&a).unwrap();
+        assert!(!json.contains("id"));
+        let back: Arc = serde_json::from_str(&json).unwrap();
+        assert_eq!((back, back.id), (a, 0));
+    }
+
+    #[test]
+    fn test_primitives_json_round_trip() {
+        let s = segment(point(1.0, 2.0), point(3.0, 4.0));
+        let back: Segment = serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap();
+        assert_eq!(back, s);
+        let c = circle(point(1.0, -1.0), 2.5);
+        assert_eq!(serde_json::from_str::<Circle>(&serde_json::to_string(&c).unwrap()).unwrap(), c);
+        let l = line(point(0.0, 1.0), point(1.0, 0.0));
+        assert_eq!(serde_json::from_str::<Line>(&serde_json::to_string(&l).unwrap()).unwrap(), l);
+        let r = rect(point(0.0, 0.0), point(2.0, 3.0));
+        assert_eq!(serde_json::from_str::<Rect>(&serde_json::to_string(&r).unwrap()).unwrap(), r);
+        let i = interval(0.5, 1.5);
+        assert_eq!(serde_json::from_str::<Interval>(&serde_json::to_string(&i).unwrap()).unwrap(), i);
+        let pline = vec![pvertex(point(0.0, 0.0), 0.5), pvertex(point(1.0, 0.0), 0.0)];
+        assert_eq!(serde_json::from_str::<Polyline>(&serde_json::to_string(&pline).unwrap()).unwrap(), pline);
+    }
+}
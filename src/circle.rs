@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
-use crate::point::Point;
-use std::fmt::Display;
+use crate::constants::DIVISION_EPSILON;
+use crate::point::{Point, point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
+use core::fmt::Display;
 
 /// A circle defined by a center point and radius.
 ///
@@ -14,6 +17,7 @@ use std::fmt::Display;
 /// ```
 // #00008
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle {
     /// Center of the circle
     pub c: Point,
@@ -22,7 +26,7 @@ pub struct Circle {
 }
 
 impl Display for Circle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {:.20}]", self.c, self.r)
     }
 }
@@ -69,6 +73,72 @@ pub fn circle(c: Point, r: f64) -> Circle {
     Circle::new(c, r)
 }
 
+/// Fits a circle to a set of points in the least-squares sense.
+///
+/// Uses the algebraic (Kåsa) fit, which minimizes the residuals of
+/// `x² + y² + D x + E y + F = 0` over the points. The fit is exact for points
+/// on a circle and close to the geometric fit for well spread samples.
+///
+/// # Arguments
+///
+/// * `points` - The points to fit, at least 3
+///
+/// # Returns
+///
+/// The fitted circle, or `None` for fewer than 3 points or (nearly) collinear points
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let points = [point(2.0, 1.0), point(1.0, 2.0), point(0.0, 1.0), point(1.0, 0.0)];
+/// let c = fit_circle_to_points(&points).unwrap();
+/// assert!(c.c.close_enough(point(1.0, 1.0), 1e-12));
+/// assert!((c.r - 1.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn fit_circle_to_points(points: &[Point]) -> Option<Circle> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let (mut mx, mut my) = (0.0, 0.0);
+    for p in points {
+        mx += p.x;
+        my += p.y;
+    }
+    mx /= n;
+    my /= n;
+
+    // Moments of the centered coordinates
+    let (mut suu, mut svv, mut suv) = (0.0, 0.0, 0.0);
+    let (mut ru, mut rv) = (0.0, 0.0);
+    for p in points {
+        let u = p.x - mx;
+        let v = p.y - my;
+        let uv2 = u * u + v * v;
+        suu += u * u;
+        svv += v * v;
+        suv += u * v;
+        ru += u * uv2;
+        rv += v * uv2;
+    }
+    let det = suu * svv - suv * suv;
+    let scale = suu + svv;
+    if det.abs() <= DIVISION_EPSILON * scale * scale {
+        return None;
+    }
+    let d = (-ru * svv + rv * suv) / det;
+    let e = (-rv * suu + ru * suv) / det;
+    let f = -scale / n;
+    let r2 = (d * d + e * e) / 4.0 - f;
+    if !r2.is_finite() || r2 <= 0.0 {
+        return None;
+    }
+    Some(circle(point(mx - d / 2.0, my - e / 2.0), r2.sqrt()))
+}
+
 #[cfg(test)]
 mod test_circle {
     use super::*;
@@ -89,4 +159,142 @@ mod test_circle {
             format!("{}", c0)
         );
     }
+
+    #[test]
+    fn test_fit_circle_to_points_exact() {
+        let c = point(-2.0, 3.0);
+        let points: Vec<Point> = (0..5)
+            .map(|k| {
+                let t = 0.3 * k as f64;
+                c + point(t.cos(), t.sin()) * 4.0
+            })
+            .collect();
+        let fit = fit_circle_to_points(&points).unwrap();
+        assert!(fit.c.close_enough(c, 1e-9));
+        assert!((fit.r - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_circle_to_points_degenerate() {
+        assert_eq!(fit_circle_to_points(&[point(0.0, 0.0), point(1.0, 0.0)]), None);
+        let line = [point(0.0, 0.0), point(1.0, 1.0), point(2.0, 2.0), point(3.0, 3.0)];
+        assert_eq!(fit_circle_to_points(&line), None);
+    }
+}
+
+/// Finds a pair of overlapping circles, e.g. to validate a disc packing.
+///
+/// Two circles overlap when the distance between their centers is less than
+/// the sum of their radii minus `tolerance`, so circles touching within
+/// `tolerance` are accepted; a negative `tolerance` requires a gap between
+/// the circles. Instead of testing all pairs, the circles are
+/// swept along the x-axis ordered by their left extent: each circle is only
+/// tested against the circles whose x-extent it overlaps.
+///
+/// # Arguments
+///
+/// * `circles` - The circles to check
+/// * `tolerance` - The allowed penetration depth
+///
+/// # Returns
+///
+/// The indices `(i, j)`, with `i < j`, of the first overlapping pair met by the
+/// sweep, or `None` when no two circles overlap
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let circles = [
+///     circle(point(0.0, 0.0), 1.0),
+///     circle(point(2.0, 0.0), 1.0),
+///     circle(point(5.0, 0.0), 1.0),
+///     circle(point(3.5, 0.5), 1.0),
+/// ];
+/// assert_eq!(circles_any_overlap(&circles[..3], 1e-9), None);
+/// assert_eq!(circles_any_overlap(&circles, 1e-9), Some((1, 3)));
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn circles_any_overlap(circles: &[Circle], tolerance: f64) -> Option<(usize, usize)> {
+    let mut order: Vec<usize> = (0..circles.len()).collect();
+    order.sort_by(|&i, &j| (circles[i].c.x - circles[i].r).total_cmp(&(circles[j].c.x - circles[j].r)));
+
+    let mut active: Vec<usize> = Vec::new();
+    for &i in &order {
+        let ci = circles[i];
+        let left = ci.c.x - ci.r;
+        active.retain(|&j| circles[j].c.x + circles[j].r >= left);
+        for &j in &active {
+            let cj = circles[j];
+            if (ci.c - cj.c).norm() < ci.r + cj.r - tolerance {
+                return Some((i.min(j), i.max(j)));
+            }
+        }
+        active.push(i);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test_circles_any_overlap {
+    use super::*;
+
+    // Hexagonal packing of unit discs, touching their neighbours
+    fn hex_packing(rows: usize, cols: usize) -> Vec<Circle> {
+        let mut circles = Vec::new();
+        for row in 0..rows {
+            for col in 0..cols {
+                let shift = if row % 2 == 0 { 0.0 } else { 1.0 };
+                let c = point(2.0 * col as f64 + shift, 3.0_f64.sqrt() * row as f64);
+                circles.push(circle(c, 1.0));
+            }
+        }
+        circles
+    }
+
+    fn brute_force(circles: &[Circle], tolerance: f64) -> bool {
+        (0..circles.len()).any(|i| {
+            (i + 1..circles.len()).any(|j| (circles[i].c - circles[j].c).norm() < circles[i].r + circles[j].r - tolerance)
+        })
+    }
+
+    #[test]
+    fn test_any_overlap_valid_packing() {
+        let circles = hex_packing(15, 20);
+        assert_eq!(circles.len(), 300);
+        assert_eq!(circles_any_overlap(&circles, 1e-9), None);
+        assert!(!brute_force(&circles, 1e-9));
+        // A negative tolerance requires a gap, touching discs are then reported
+        assert!(circles_any_overlap(&circles, -0.1).is_some());
+        assert!(brute_force(&circles, -0.1));
+    }
+
+    #[test]
+    fn test_any_overlap_known_pair() {
+        let mut circles = hex_packing(15, 20);
+        // Grow one disc in the middle of the packing
+        circles[157].r = 1.1;
+        let (i, j) = circles_any_overlap(&circles, 1e-9).unwrap();
+        assert!(i < j);
+        assert!(i == 157 || j == 157);
+        assert!((circles[i].c - circles[j].c).norm() < circles[i].r + circles[j].r - 1e-9);
+
+        // Far apart in index order, close in space
+        let mut circles = hex_packing(3, 3);
+        circles.push(circle(point(2.5, 0.0), 0.2));
+        let (i, j) = circles_any_overlap(&circles, 1e-9).unwrap();
+        assert_eq!(j, 9);
+        assert!(i == 0 || i == 1);
+    }
+
+    #[test]
+    fn test_any_overlap_small_inputs() {
+        assert_eq!(circles_any_overlap(&[], 0.0), None);
+        assert_eq!(circles_any_overlap(&[circle(point(0.0, 0.0), 1.0)], 0.0), None);
+        // Concentric circles overlap
+        let nested = [circle(point(0.0, 0.0), 3.0), circle(point(0.0, 0.0), 1.0)];
+        assert_eq!(circles_any_overlap(&nested, 0.0), Some((0, 1)));
+    }
 }
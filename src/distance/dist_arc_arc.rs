@@ -233,3 +233,176 @@ mod test_dist_arc_arc {
         assert_eq!(dist, 0.9999999999999998);
     }
 }
+
+// Closest point of an arc to `q`: the radial projection when it is within
+// the sweep, otherwise the nearer endpoint.
+fn arc_closest_point(arc: &Arc, q: Point) -> Point {
+    let v = q - arc.c;
+    let len = v.norm();
+    if len > GEOMETRIC_EPSILON {
+        let p = arc.c + v * (arc.r / len);
+        if arc.contains(p) {
+            return p;
+        }
+    }
+    if (q - arc.a).norm() <= (q - arc.b).norm() {
+        arc.a
+    } else {
+        arc.b
+    }
+}
+
+/// Computes the closest approach of two convex arcs.
+///
+/// Specialized version of [`dist_arc_arc`] for two curved arcs that bulge
+/// outward, toward each other, as boundary arcs of two separate convex shapes do.
+/// Then the only interior candidate is the pair of facing points on the line
+/// through the centers; the other candidates are the endpoints of one arc
+/// against the other arc, clamped to its sweep. No line-arc intersections
+/// are needed, and the closest points are returned together with the distance.
+///
+/// # Arguments
+///
+/// * `a1` - The first arc, curved
+/// * `a2` - The second arc, curved
+///
+/// # Returns
+///
+/// A tuple `(distance, p1, p2)` with the closest points `p1` on `a1` and `p2` on `a2`.
+/// Crossing arcs give distance 0 at a common point.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::distance::dist_arc_arc::convex_arc_closest_approach;
+///
+/// // Right half of a unit circle at the origin and left half of one at (4, 0)
+/// let a1 = arc(point(0.0, -1.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+/// let a2 = arc(point(4.0, 1.0), point(4.0, -1.0), point(4.0, 0.0), 1.0);
+/// let (dist, p1, p2) = convex_arc_closest_approach(&a1, &a2);
+/// assert_eq!(dist, 2.0);
+/// assert_eq!(p1, point(1.0, 0.0));
+/// assert_eq!(p2, point(3.0, 0.0));
+/// ```
+#[must_use]
+pub fn convex_arc_closest_approach(a1: &Arc, a2: &Arc) -> (f64, Point, Point) {
+    let crossing = match int_circle_circle(circle(a1.c, a1.r), circle(a2.c, a2.r)) {
+        CircleCircleConfig::NoncocircularOnePoint(p) => [Some(p), None],
+        CircleCircleConfig::NoncocircularTwoPoints(p0, p1) => [Some(p0), Some(p1)],
+        _ => [None, None],
+    };
+    if let Some(p) = crossing
+        .into_iter()
+        .flatten()
+        .find(|&p| a1.contains(p) && a2.contains(p))
+    {
+        return (0.0, p, p);
+    }
+
+    let mut best = (f64::INFINITY, a1.a, a2.a);
+    let mut consider = |p1: Point, p2: Point| {
+        let dist = (p2 - p1).norm();
+        if dist < best.0 {
+            best = (dist, p1, p2);
+        }
+    };
+
+    // Facing points on the line through the centers
+    let d = a2.c - a1.c;
+    let dist_c = d.norm();
+    if dist_c > GEOMETRIC_EPSILON {
+        let u = d / dist_c;
+        let p1 = a1.c + u * a1.r;
+        let p2 = a2.c - u * a2.r;
+        if a1.contains(p1) && a2.contains(p2) {
+            consider(p1, p2);
+        }
+    }
+
+    // Endpoints against the other arc
+    for p1 in [a1.a, a1.b] {
+        consider(p1, arc_closest_point(a2, p1));
+    }
+    for p2 in [a2.a, a2.b] {
+        consider(arc_closest_point(a1, p2), p2);
+    }
+    best
+}
+
+#[cfg(test)]
+mod test_convex_arc_closest_approach {
+    use super::*;
+
+    fn assert_on_arc(p: Point, a: &Arc) {
+        assert!(((p - a.c).norm() - a.r).abs() < 1e-12);
+        assert!(a.contains(p));
+    }
+
+    fn check(a1: &Arc, a2: &Arc) {
+        let (dist, p1, p2) = convex_arc_closest_approach(a1, a2);
+        assert!((dist - dist_arc_arc(a1, a2)).abs() < 1e-12, "{} {}", a1, a2);
+        assert!(((p2 - p1).norm() - dist).abs() < 1e-12);
+        assert_on_arc(p1, a1);
+        assert_on_arc(p2, a2);
+    }
+
+    #[test]
+    fn test_facing_points() {
+        let a1 = arc(point(1.0, -1.0), point(1.0, 1.0), point(0.0, 0.0), 2.0_f64.sqrt());
+        let a2 = arc(point(4.0, 1.0), point(4.0, -1.0), point(5.0, 0.0), 2.0_f64.sqrt());
+        check(&a1, &a2);
+        let (dist, p1, _) = convex_arc_closest_approach(&a1, &a2);
+        assert!((dist - (5.0 - 2.0 * 2.0_f64.sqrt())).abs() < 1e-12);
+        assert!((p1.y).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_endpoint_to_interior() {
+        // Facing point of a1 is outside its sweep
+        let a1 = arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+        let a2 = arc(point(3.0, 3.0), point(1.0, 3.0), point(2.0, 3.0), 1.0);
+        check(&a1, &a2);
+        let (_, p1, _) = convex_arc_closest_approach(&a1, &a2);
+        assert_eq!(p1, point(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_endpoint_to_endpoint() {
+        let a1 = arc(point(0.0, 1.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+        let a2 = arc(point(5.0, -1.0), point(6.0, 0.0), point(5.0, 0.0), 1.0);
+        check(&a1, &a2);
+    }
+
+    #[test]
+    fn test_various_pairs() {
+        let c2 = point(3.0, 1.0);
+        for k in 0..12 {
+            let t0 = k as f64 * 0.5;
+            let a1 = arc(
+                point(t0.cos(), t0.sin()),
+                point((t0 + 1.5).cos(), (t0 + 1.5).sin()),
+                point(0.0, 0.0),
+                1.0,
+            );
+            let s0 = 2.0 + k as f64 * 0.4;
+            let a2 = arc(
+                c2 + point(s0.cos(), s0.sin()) * 0.5,
+                c2 + point((s0 + 2.0).cos(), (s0 + 2.0).sin()) * 0.5,
+                c2,
+                0.5,
+            );
+            check(&a1, &a2);
+        }
+    }
+
+    #[test]
+    fn test_crossing_arcs() {
+        let a1 = arc(point(0.0, -1.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        let a2 = arc(point(1.0, 1.0), point(1.0, -1.0), point(1.0, 0.0), 1.0);
+        let (dist, p1, p2) = convex_arc_closest_approach(&a1, &a2);
+        assert_eq!(dist, 0.0);
+        assert_eq!(p1, p2);
+        assert!((p1.x - 0.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,129 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+/// Computes the distance between a full circle and an arc.
+///
+/// The distance from a point of the arc to the circle is the difference between
+/// its distance to the circle center and the circle radius. Along the arc's
+/// underlying circle, the distance to the center grows monotonically from the
+/// point nearest to the center to the farthest one, so on the arc its extremes
+/// are at the arc end points, or at the nearest and farthest points when the arc
+/// contains them (tested with [`Arc::contains`]). The arc meets the circle when
+/// the radius lies between these extremes; otherwise the closest extreme gives
+/// the distance.
+///
+/// Line segments (infinite radius) are measured with [`dist_segment_circle`].
+///
+/// # Arguments
+///
+/// * `c` - The circle
+/// * `a` - The arc, possibly a full circle or a segment
+///
+/// # Returns
+///
+/// The minimum distance between the circle and the arc, zero when they intersect
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let c = circle(point(0.0, 0.0), 1.0);
+/// // Upper half of a circle of radius 1 at (4, 0), closest to c at (3, 0)
+/// let a = arc(point(5.0, 0.0), point(3.0, 0.0), point(4.0, 0.0), 1.0);
+/// assert_eq!(dist_circle_arc(&c, &a), 2.0);
+/// // The lower half of the circle at (4, -1) has its closest point at an end
+/// let a = arc(point(3.0, -1.0), point(5.0, -1.0), point(4.0, -1.0), 1.0);
+/// assert_eq!(dist_circle_arc(&c, &a), (point(3.0, -1.0) - point(0.0, 0.0)).norm() - 1.0);
+/// ```
+#[must_use]
+pub fn dist_circle_arc(c: &Circle, a: &Arc) -> f64 {
+    if a.is_seg() {
+        return match dist_segment_circle(&segment(a.a, a.b), c) {
+            DistSegmentCircleConfig::OnePoint(dist, _) | DistSegmentCircleConfig::TwoPoints(dist, _, _) => dist,
+        };
+    }
+
+    let mut near = (a.a - c.c).norm().min((a.b - c.c).norm());
+    let mut far = (a.a - c.c).norm().max((a.b - c.c).norm());
+    let (dir, offset) = (c.c - a.c).normalize(false);
+    // Concentric circles have every arc point at the same distance
+    if offset > 0.0 {
+        let nearest = a.c + dir * a.r;
+        if a.contains(nearest) {
+            near = (nearest - c.c).norm();
+        }
+        let farthest = a.c - dir * a.r;
+        if a.contains(farthest) {
+            far = (farthest - c.c).norm();
+        }
+    }
+
+    if c.r < near {
+        near - c.r
+    } else if c.r > far {
+        c.r - far
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod test_dist_circle_arc {
+    use super::*;
+
+    #[test]
+    fn test_separated() {
+        let c = circle(point(0.0, 0.0), 1.0);
+        // Full circle of radius 2 at (5, 0)
+        let full = arc(point(7.0, 0.0), point(7.0, 0.0), point(5.0, 0.0), 2.0);
+        assert_eq!(dist_circle_arc(&c, &full), 2.0);
+        // Left half faces the circle
+        let left = arc(point(5.0, 2.0), point(5.0, -2.0), point(5.0, 0.0), 2.0);
+        assert_eq!(dist_circle_arc(&c, &left), 2.0);
+        // Right half faces away: the closest points are its ends
+        let right = arc(point(5.0, -2.0), point(5.0, 2.0), point(5.0, 0.0), 2.0);
+        assert!((dist_circle_arc(&c, &right) - (29.0_f64.sqrt() - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_intersecting() {
+        let c = circle(point(0.0, 0.0), 2.0);
+        let a = arc(point(3.0, 0.0), point(1.0, 0.0), point(2.0, 0.0), 1.0);
+        assert_eq!(dist_circle_arc(&c, &a), 0.0);
+        // Small arc inside the circle, away from its boundary
+        let inner = arc(point(0.5, 0.0), point(-0.5, 0.0), point(0.0, 0.0), 0.5);
+        assert_eq!(dist_circle_arc(&c, &inner), 1.5);
+    }
+
+    #[test]
+    fn test_concentric() {
+        let c = circle(point(1.0, 1.0), 3.0);
+        let inner = arc(point(2.0, 1.0), point(1.0, 2.0), point(1.0, 1.0), 1.0);
+        assert_eq!(dist_circle_arc(&c, &inner), 2.0);
+        let outer = arc(point(1.0, 6.0), point(-4.0, 1.0), point(1.0, 1.0), 5.0);
+        assert_eq!(dist_circle_arc(&c, &outer), 2.0);
+        let same = arc(point(4.0, 1.0), point(1.0, 4.0), point(1.0, 1.0), 3.0);
+        assert_eq!(dist_circle_arc(&c, &same), 0.0);
+    }
+
+    #[test]
+    fn test_arc_wrapping_to_closest_point() {
+        let c = circle(point(-5.0, 0.0), 1.0);
+        // Three-quarter arc from the top around the left side to the right,
+        // containing the point (-1, 0) nearest to c
+        let wrap = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(dist_circle_arc(&c, &wrap), 3.0);
+        // Its complement misses that point, the end (0, 1) is closest
+        let complement = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        assert!((dist_circle_arc(&c, &complement) - (26.0_f64.sqrt() - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_segment() {
+        let c = circle(point(0.0, 0.0), 1.0);
+        let seg = arcseg(point(3.0, -1.0), point(3.0, 1.0));
+        assert_eq!(dist_circle_arc(&c, &seg), 2.0);
+    }
+}
@@ -2,6 +2,8 @@
 
 use crate::constants::DIVISION_EPSILON;
 use crate::prelude::*;
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 /// Configuration for the distance between a segment and a circle.
 #[derive(Debug, PartialEq)]
@@ -0,0 +1,345 @@
+#![allow(dead_code)]
+
+use crate::algo::bounding::arc_tight_bounding_circle;
+use crate::prelude::*;
+
+/// Number of elements on each side of the hint that are measured exactly first.
+const HINT_WINDOW: usize = 2;
+
+/// Computes the distance between a point and an arcline, starting the search
+/// near a previously found element.
+///
+/// When tracking a moving point, the nearest element rarely changes from one
+/// query to the next. The elements within two positions of `last_index` are
+/// measured first; the search then walks outward from the hint, rejecting
+/// elements using their bounding circles and measuring them exactly only if
+/// they could be closer. The walk stops once the unvisited part of the arcline
+/// cannot come closer than the best distance found. The result is the same as
+/// a full scan, but coherent queries measure only a few elements exactly.
+///
+/// # Arguments
+///
+/// * `p` - The point to measure distance from
+/// * `arcs` - The arcline to measure distance to
+/// * `last_index` - The element index returned by the previous query
+///   (wrapped around the arcline length)
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The minimum distance as a f64
+/// * The index of the nearest element, to be passed as the next hint
+/// * The closest point on the arcline
+///
+/// For an empty arcline the distance is `f64::INFINITY` and the point is `p`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+///     arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+///     arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+///     arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+/// ];
+/// let (dist, index, closest) = dist_point_arcline_hinted(&point(3.0, 1.0), &arcs, 0);
+/// assert_eq!(dist, 1.0);
+/// assert_eq!(index, 1);
+/// assert_eq!(closest, point(2.0, 1.0));
+///
+/// // The next query starts from the returned index
+/// let (dist, index, _) = dist_point_arcline_hinted(&point(3.0, 1.5), &arcs, index);
+/// assert_eq!(dist, 1.0);
+/// assert_eq!(index, 1);
+/// ```
+#[must_use]
+pub fn dist_point_arcline_hinted(p: &Point, arcs: &Arcline, last_index: usize) -> (f64, usize, Point) {
+    let mut evaluations = 0;
+    hinted_search(p, arcs, last_index, &mut evaluations)
+}
+
+/// Hinted search counting the exact element distance evaluations.
+///
+/// The search walks outward from the hint, one element on each side per step.
+/// The unvisited elements form a chain joining the two walk frontiers, so every
+/// unvisited point is at least `(d_right + d_left - remaining) / 2` from `p`,
+/// where `d_*` are the distances to the frontiers and `remaining` is the chain
+/// length; the walk stops once this bound reaches the best distance.
+fn hinted_search(p: &Point, arcs: &Arcline, last_index: usize, evaluations: &mut usize) -> (f64, usize, Point) {
+    let n = arcs.len();
+    if n == 0 {
+        return (f64::INFINITY, 0, *p);
+    }
+    let hint = last_index % n;
+
+    let mut best = (f64::INFINITY, 0, *p);
+    let window = HINT_WINDOW.min((n - 1) / 2);
+    let mut update = |i: usize, best: &mut (f64, usize, Point)| {
+        *evaluations += 1;
+        let (dist, closest) = dist_point_element(p, &arcs[i]);
+        if dist < best.0 || (dist == best.0 && i < best.1) {
+            *best = (dist, i, closest);
+        }
+    };
+    // Distance to the nearer end of an element, a lower bound for the chain beyond it
+    let frontier = |i: usize| (*p - arcs[i].a).norm().min((*p - arcs[i].b).norm());
+
+    update(hint, &mut best);
+    let mut d_right = frontier(hint);
+    let mut d_left = d_right;
+    // Length of the unvisited chain, needed only once the window is measured
+    let mut remaining = f64::INFINITY;
+
+    for offset in 1..=n / 2 {
+        if offset > window && best.0 > 0.0 && remaining == f64::INFINITY {
+            let visited: f64 = (hint + n + 1 - offset..hint + n + offset).map(|i| arcs[i % n].length()).sum();
+            remaining = arcline_length(arcs) - visited;
+        }
+        if offset > window && (best.0 == 0.0 || (d_right + d_left - remaining) / 2.0 >= best.0) {
+            break;
+        }
+        let right = (hint + offset) % n;
+        let left = (hint + n - offset) % n;
+        let sides: &[usize] = if right == left { &[right] } else { &[right, left] };
+        for &i in sides {
+            if offset <= window {
+                update(i, &mut best);
+            } else {
+                // Measured exactly only when the bounding circle is close enough
+                let bound = arc_tight_bounding_circle(&arcs[i]);
+                if (*p - bound.c).norm() - bound.r <= best.0 {
+                    update(i, &mut best);
+                }
+                remaining -= arcs[i].length();
+            }
+        }
+        d_right = frontier(right);
+        d_left = frontier(left);
+    }
+    best
+}
+
+fn dist_point_element(p: &Point, element: &Arc) -> (f64, Point) {
+    if element.is_seg() {
+        dist_point_segment(p, &segment(element.a, element.b))
+    } else {
+        match dist_point_arc(p, element) {
+            DistPointArcConfig::OnePoint(dist, closest) | DistPointArcConfig::Equidistant(dist, closest) => {
+                (dist, closest)
+            }
+        }
+    }
+}
+
+/// Computes the farthest point of an arcline from a point.
+///
+/// On a segment the farthest point is one of its ends. On an arc it is one of
+/// its ends or the point of the circle diametrically opposite to `p`, when that
+/// point lies on the arc; the far side of a circle can be farther than both ends.
+///
+/// # Arguments
+///
+/// * `p` - The point to measure distance from
+/// * `arcs` - The arcline to search
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The maximum distance as a f64
+/// * The index of the element containing the farthest point
+/// * The farthest point
+///
+/// For an empty arcline the distance is `0.0` and the point is `p`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Unit circle made of two half circles
+/// let arcs = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arc(point(-1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// let (dist, index, far) = farthest_point_on_arcline(&point(0.0, 0.5), &arcs);
+/// assert_eq!(dist, 1.5);
+/// assert_eq!(index, 1);
+/// assert_eq!(far, point(0.0, -1.0));
+/// ```
+#[must_use]
+pub fn farthest_point_on_arcline(p: &Point, arcs: &Arcline) -> (f64, usize, Point) {
+    let mut best = (0.0, 0, *p);
+    for (i, element) in arcs.iter().enumerate() {
+        let mut candidates = [Some(element.a), Some(element.b), None];
+        if element.is_arc() {
+            let away = element.c - *p;
+            let len = away.norm();
+            if len > 0.0 {
+                let opposite = element.c + away * (element.r / len);
+                if element.is_full_circle() || element.contains(opposite) {
+                    candidates[2] = Some(opposite);
+                }
+            }
+        }
+        for q in candidates.into_iter().flatten() {
+            let dist = (q - *p).norm();
+            if dist > best.0 {
+                best = (dist, i, q);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod test_dist_point_arcline_hinted {
+    use super::*;
+
+    // A long zig-zag with rounded ends, 2 * count elements
+    fn track(count: usize) -> Arcline {
+        let mut arcs = Vec::new();
+        for k in 0..count {
+            let x = k as f64;
+            arcs.push(arcseg(point(x, 0.0), point(x + 1.0, 0.0)));
+        }
+        let end = count as f64;
+        arcs.push(arc(point(end, 0.0), point(end, 2.0), point(end, 1.0), 1.0));
+        for k in (0..count).rev() {
+            let x = k as f64;
+            arcs.push(arcseg(point(x + 1.0, 2.0), point(x, 2.0)));
+        }
+        arcs.push(arc(point(0.0, 2.0), point(0.0, 0.0), point(0.0, 1.0), 1.0));
+        arcs
+    }
+
+    fn full_scan(p: &Point, arcs: &Arcline) -> (f64, usize, Point) {
+        let mut best = (f64::INFINITY, 0, *p);
+        for (i, element) in arcs.iter().enumerate() {
+            let (dist, closest) = dist_point_element(p, element);
+            if dist < best.0 {
+                best = (dist, i, closest);
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_hinted_empty() {
+        let p = point(1.0, 2.0);
+        assert_eq!(dist_point_arcline_hinted(&p, &vec![], 3), (f64::INFINITY, 0, p));
+    }
+
+    #[test]
+    fn test_hint_matches_avoids_full_scan() {
+        let arcs = track(20);
+        let p = point(7.5, -0.5);
+        let mut evaluations = 0;
+        let (dist, index, closest) = hinted_search(&p, &arcs, 7, &mut evaluations);
+        assert_eq!((dist, index, closest), full_scan(&p, &arcs));
+        assert_eq!(index, 7);
+        assert_eq!(dist, 0.5);
+        assert_eq!(closest, point(7.5, 0.0));
+        // Only the window around the hint is measured, not the 42 elements
+        assert_eq!(evaluations, 2 * HINT_WINDOW + 1);
+    }
+
+    #[test]
+    fn test_hint_far_off_still_correct() {
+        let arcs = track(20);
+        let p = point(12.25, 2.75);
+        let mut evaluations = 0;
+        let result = hinted_search(&p, &arcs, 3, &mut evaluations);
+        assert_eq!(result, full_scan(&p, &arcs));
+        assert_eq!(result.1, 28);
+        assert!(evaluations < arcs.len());
+    }
+
+    #[test]
+    fn test_hint_out_of_range_wraps() {
+        let arcs = track(5);
+        let p = point(-2.0, 1.0);
+        let (dist, index, closest) = dist_point_arcline_hinted(&p, &arcs, 1000);
+        assert_eq!(index, arcs.len() - 1);
+        assert!((dist - 1.0).abs() < 1e-12);
+        assert!(closest.close_enough(point(-1.0, 1.0), 1e-12));
+    }
+
+    #[test]
+    fn test_tracking_matches_full_scan() {
+        let arcs = track(10);
+        let mut hint = 0;
+        for k in 0..200 {
+            let t = k as f64 * 0.13;
+            let p = point(5.0 + 6.0 * t.cos(), 1.0 + 2.5 * t.sin());
+            let hinted = dist_point_arcline_hinted(&p, &arcs, hint);
+            let full = full_scan(&p, &arcs);
+            assert!((hinted.0 - full.0).abs() < 1e-12, "k = {}", k);
+            hint = hinted.1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_farthest_point_on_arcline {
+    use super::*;
+
+    // Disc of radius 2 at (1, 1) made of four quarter arcs
+    fn disc() -> Arcline {
+        let c = point(1.0, 1.0);
+        vec![
+            arc(point(3.0, 1.0), point(1.0, 3.0), c, 2.0),
+            arc(point(1.0, 3.0), point(-1.0, 1.0), c, 2.0),
+            arc(point(-1.0, 1.0), point(1.0, -1.0), c, 2.0),
+            arc(point(1.0, -1.0), point(3.0, 1.0), c, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_farthest_on_far_side_of_circle() {
+        // The farthest point is in the middle of an arc, not at an end
+        let p = point(1.5, 1.5);
+        let (dist, index, far) = farthest_point_on_arcline(&p, &disc());
+        let s = std::f64::consts::FRAC_1_SQRT_2 * 2.0;
+        assert_eq!(index, 2);
+        assert!(far.close_enough(point(1.0 - s, 1.0 - s), 1e-12));
+        assert!((dist - (2.0 + 0.5_f64.sqrt())).abs() < 1e-12);
+        // Farther than every element end
+        for a in &disc() {
+            assert!((a.a - p).norm() < dist - 0.1);
+        }
+    }
+
+    #[test]
+    fn test_farthest_outside_the_circle() {
+        let p = point(6.0, 1.0);
+        let (dist, index, far) = farthest_point_on_arcline(&p, &disc());
+        assert!((dist - 7.0).abs() < 1e-12);
+        assert!(far.close_enough(point(-1.0, 1.0), 1e-12));
+        assert!(index == 1 || index == 2);
+    }
+
+    #[test]
+    fn test_farthest_segments_and_arc() {
+        // Half disc: the arc side is farther from a point below the base
+        let arcs = vec![
+            arcseg(point(-1.0, 0.0), point(1.0, 0.0)),
+            arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+        ];
+        let (dist, index, far) = farthest_point_on_arcline(&point(0.0, -0.5), &arcs);
+        assert_eq!((dist, index, far), (1.5, 1, point(0.0, 1.0)));
+        // From above the opposite point is off the arc, the base ends are farthest
+        let (dist, index, far) = farthest_point_on_arcline(&point(0.0, 3.0), &arcs);
+        assert_eq!((dist, index, far), (10.0_f64.sqrt(), 0, point(-1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_farthest_center_and_empty() {
+        let (dist, _, far) = farthest_point_on_arcline(&point(1.0, 1.0), &disc());
+        assert_eq!(dist, 2.0);
+        assert_eq!((far - point(1.0, 1.0)).norm(), 2.0);
+        let p = point(1.0, 2.0);
+        assert_eq!(farthest_point_on_arcline(&p, &vec![]), (0.0, 0, p));
+    }
+}
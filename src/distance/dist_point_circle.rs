@@ -2,6 +2,8 @@
 
 use crate::circle::Circle;
 use crate::point::{Point, point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 const ZERO: f64 = 0.0;
 // #00015
@@ -0,0 +1,178 @@
+#![allow(dead_code)]
+
+use crate::ellipse::EllipseArc;
+use crate::point::{Point, point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
+
+/// Number of samples used to seed the Newton iteration.
+const SEED_SAMPLES: usize = 32;
+const MAX_NEWTON_ITERATIONS: usize = 32;
+
+/// Computes the distance between a point and an elliptical arc.
+///
+/// The closest point on the ellipse satisfies `(E(t) - p) · E'(t) = 0` for the
+/// parametric angle `t`. The equation is solved with Newton iteration, seeded
+/// from the nearest of a few samples along the arc and kept within the arc's
+/// angular range. When no stationary point inside the range is closer, the
+/// nearest arc end is returned.
+///
+/// # Arguments
+///
+/// * `p` - The point to measure distance from
+/// * `e` - The elliptical arc to measure distance to
+///
+/// # Returns
+///
+/// A tuple containing the distance and the closest point on the arc
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, std::f64::consts::PI);
+/// let (dist, closest) = dist_point_ellipse_arc(&point(0.0, 3.0), &e);
+/// assert!((dist - 2.0).abs() < 1e-12);
+/// assert!(closest.close_enough(point(0.0, 1.0), 1e-12));
+/// ```
+#[must_use]
+pub fn dist_point_ellipse_arc(p: &Point, e: &EllipseArc) -> (f64, Point) {
+    // Work in the frame of the ellipse axes
+    let q = e.rotate_to_local(*p - e.c);
+    let local = |t: f64| point(e.rx * t.cos(), e.ry * t.sin());
+    let dist2 = |t: f64| {
+        let d = local(t) - q;
+        d.dot(d)
+    };
+    // Angular range of the arc; min/max rather than clamp, which panics on a
+    // negative or NaN sweep
+    let end = e.start + e.sweep;
+    let (lo, hi) = (e.start.min(end), e.start.max(end));
+
+    // Seed from the nearest sample, the arc ends included
+    let mut best_t = e.start;
+    let mut best = dist2(e.start);
+    for k in 1..=SEED_SAMPLES {
+        let t = e.start + e.sweep * k as f64 / SEED_SAMPLES as f64;
+        let d = dist2(t);
+        if d < best {
+            best = d;
+            best_t = t;
+        }
+    }
+
+    // Newton iteration on g(t) = (E(t) - q) · E'(t) / 2, clamped to the arc range
+    let k = e.ry * e.ry - e.rx * e.rx;
+    let mut t = best_t;
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let (sin, cos) = (t.sin(), t.cos());
+        let g = k * sin * cos + q.x * e.rx * sin - q.y * e.ry * cos;
+        let dg = k * (cos * cos - sin * sin) + q.x * e.rx * cos + q.y * e.ry * sin;
+        if dg <= 0.0 {
+            // Not a minimum nearby
+            break;
+        }
+        let next = (t - g / dg).max(lo).min(hi);
+        if (next - t).abs() < 1e-15 * (1.0 + t.abs()) {
+            t = next;
+            break;
+        }
+        t = next;
+    }
+    let d = dist2(t);
+    if d < best {
+        best = d;
+        best_t = t;
+    }
+    (best.sqrt(), e.point_at_angle(best_t))
+}
+
+#[cfg(test)]
+mod test_dist_point_ellipse_arc {
+    use super::*;
+    use crate::ellipse::ellipse_arc;
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    // Brute force distance over a dense sampling of the arc
+    fn dense(p: &Point, e: &EllipseArc) -> f64 {
+        (0..=200_000)
+            .map(|k| (e.point_at_angle(e.start + e.sweep * k as f64 / 200_000.0) - *p).norm())
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn on_ellipse(e: &EllipseArc, x: Point) -> bool {
+        let l = e.rotate_to_local(x - e.c);
+        ((l.x / e.rx).powi(2) + (l.y / e.ry).powi(2) - 1.0).abs() < 1e-12
+    }
+
+    #[test]
+    fn test_dist_point_ellipse_arc_outside() {
+        let e = ellipse_arc(point(1.0, -1.0), 3.0, 1.5, 0.4, -0.5, 2.5);
+        for p in [point(4.0, 2.0), point(1.5, 3.0), point(-2.0, 2.5)] {
+            let (dist, closest) = dist_point_ellipse_arc(&p, &e);
+            assert!(on_ellipse(&e, closest));
+            assert!(((closest - p).norm() - dist).abs() < 1e-12);
+            assert!(dist <= dense(&p, &e) + 1e-9);
+            assert!(dist >= dense(&p, &e) - 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_dist_point_ellipse_arc_inside() {
+        let e = ellipse_arc(point(0.0, 0.0), 4.0, 2.0, 0.0, 0.0, TAU);
+        // Near the flat side the closest point is straight above
+        let (dist, closest) = dist_point_ellipse_arc(&point(0.0, 1.5), &e);
+        assert!((dist - 0.5).abs() < 1e-12);
+        assert!(closest.close_enough(point(0.0, 2.0), 1e-12));
+        // Near the end of the long axis, inside the evolute
+        let p = point(3.0, 0.3);
+        let (dist, closest) = dist_point_ellipse_arc(&p, &e);
+        assert!(on_ellipse(&e, closest));
+        assert!((dist - dense(&p, &e)).abs() < 1e-6);
+        // The center is equidistant from both minor vertices
+        let (dist, closest) = dist_point_ellipse_arc(&point(0.0, 0.0), &e);
+        assert!((dist - 2.0).abs() < 1e-12);
+        assert!(on_ellipse(&e, closest));
+    }
+
+    #[test]
+    fn test_dist_point_ellipse_arc_beyond_ends() {
+        // Quarter ellipse from (2, 0) to (0, 1)
+        let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, FRAC_PI_2);
+        let (dist, closest) = dist_point_ellipse_arc(&point(3.0, -2.0), &e);
+        assert_eq!(closest, point(2.0, 0.0));
+        assert!((dist - 5.0_f64.sqrt()).abs() < 1e-12);
+        let (dist, closest) = dist_point_ellipse_arc(&point(-2.0, 1.5), &e);
+        assert!(closest.close_enough(point(0.0, 1.0), 1e-12));
+        assert!((dist - 4.25_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dist_point_ellipse_arc_rotated() {
+        // Rotated by 90 degrees, the long axis is vertical
+        let e = ellipse_arc(point(1.0, 1.0), 3.0, 1.0, FRAC_PI_2, -PI, TAU);
+        let (dist, closest) = dist_point_ellipse_arc(&point(1.0, 6.0), &e);
+        assert!((dist - 2.0).abs() < 1e-12);
+        assert!(closest.close_enough(point(1.0, 4.0), 1e-12));
+        let (dist, closest) = dist_point_ellipse_arc(&point(4.0, 1.0), &e);
+        assert!((dist - 2.0).abs() < 1e-12);
+        assert!(closest.close_enough(point(2.0, 1.0), 1e-12));
+    }
+
+    #[test]
+    fn test_dist_point_ellipse_arc_bad_sweep() {
+        // A negative sweep covers the same arc as the positive one from its end
+        let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, FRAC_PI_2, -FRAC_PI_2);
+        let forward = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, FRAC_PI_2);
+        for p in [point(3.0, 2.0), point(1.0, 0.2), point(-1.0, -1.0)] {
+            let (dist, closest) = dist_point_ellipse_arc(&p, &e);
+            let (expected, _) = dist_point_ellipse_arc(&p, &forward);
+            assert!((dist - expected).abs() < 1e-12);
+            assert!(on_ellipse(&e, closest));
+        }
+        // NaN does not panic
+        let nan = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, f64::NAN);
+        let _ = dist_point_ellipse_arc(&point(3.0, 2.0), &nan);
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+/// Computes the distance between a point and an infinite line.
+///
+/// Unlike [`dist_point_segment`], the projection of the point is not clamped:
+/// the closest point is the foot of the perpendicular from the point to the line.
+///
+/// # Arguments
+///
+/// * `p` - The point to measure distance from
+/// * `l` - The line, given by an origin and a direction (not necessarily unit)
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The perpendicular distance as a f64
+/// * The foot of the perpendicular on the line
+///
+/// For a line with a zero direction the distance to its origin is returned.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let l = line(point(0.0, 0.0), point(2.0, 0.0));
+/// let (dist, foot) = dist_point_line(&point(5.0, 3.0), &l);
+/// assert_eq!(dist, 3.0);
+/// assert_eq!(foot, point(5.0, 0.0));
+/// ```
+#[must_use]
+pub fn dist_point_line(p: &Point, l: &Line) -> (f64, Point) {
+    let sqr_length = l.dir.dot(l.dir);
+    if sqr_length == 0.0 {
+        return ((*p - l.origin).norm(), l.origin);
+    }
+    let t = (*p - l.origin).dot(l.dir) / sqr_length;
+    let foot = l.origin + l.dir * t;
+    ((*p - foot).norm(), foot)
+}
+
+#[cfg(test)]
+mod test_dist_point_line {
+    use super::*;
+
+    #[test]
+    fn test_point_off_horizontal_line() {
+        let l = line(point(1.0, 2.0), point(1.0, 0.0));
+        let (dist, foot) = dist_point_line(&point(-4.0, -1.0), &l);
+        assert_eq!(dist, 3.0);
+        assert_eq!(foot, point(-4.0, 2.0));
+        // No clamping, unlike the segment with the same ends
+        let (seg_dist, _) = dist_point_segment(&point(-4.0, -1.0), &segment(point(1.0, 2.0), point(2.0, 2.0)));
+        assert!(seg_dist > dist);
+    }
+
+    #[test]
+    fn test_point_on_line() {
+        let l = line(point(0.0, 0.0), point(1.0, 1.0));
+        let (dist, foot) = dist_point_line(&point(3.0, 3.0), &l);
+        assert_eq!(dist, 0.0);
+        assert_eq!(foot, point(3.0, 3.0));
+    }
+
+    #[test]
+    fn test_point_non_axis_aligned_line() {
+        // Line x + y = 2, through (2, 0) with direction (-3, 3)
+        let l = line(point(2.0, 0.0), point(-3.0, 3.0));
+        let (dist, foot) = dist_point_line(&point(2.0, 2.0), &l);
+        assert!((dist - 2.0_f64.sqrt()).abs() < 1e-12);
+        assert!(foot.close_enough(point(1.0, 1.0), 1e-12));
+        // The perpendicular meets the line at a right angle
+        assert!(((point(2.0, 2.0) - foot).dot(l.dir)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_direction() {
+        let l = line(point(1.0, 1.0), point(0.0, 0.0));
+        assert_eq!(dist_point_line(&point(4.0, 5.0), &l), (5.0, point(1.0, 1.0)));
+    }
+}
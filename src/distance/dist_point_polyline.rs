@@ -0,0 +1,127 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+/// Computes the distance between a point and a closed polyline with bulges.
+///
+/// Each vertex `i` starts the element going to vertex `i + 1`; the last vertex
+/// closes the polyline back to the first one. Elements are converted to arcs
+/// with [`arc_from_bulge`], so bulged elements are measured along the arc,
+/// not along the chord.
+///
+/// # Arguments
+///
+/// * `p` - The point to measure distance from
+/// * `poly` - The polyline to measure distance to
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * The minimum distance as a f64
+/// * The index of the vertex starting the nearest element
+/// * The closest point on the polyline
+///
+/// For an empty polyline the distance is `f64::INFINITY` and the point is `p`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Unit square with the top side bulged outward into a half circle
+/// let poly = vec![
+///     pvertex(point(0.0, 0.0), 0.0),
+///     pvertex(point(1.0, 0.0), 0.0),
+///     pvertex(point(1.0, 1.0), 1.0),
+///     pvertex(point(0.0, 1.0), 0.0),
+/// ];
+/// let (dist, index, closest) = dist_point_polyline(&point(0.5, 2.0), &poly);
+/// assert!((dist - 0.5).abs() < 1e-12);
+/// assert_eq!(index, 2);
+/// assert!(closest.close_enough(point(0.5, 1.5), 1e-12));
+/// ```
+pub fn dist_point_polyline(p: &Point, poly: &Polyline) -> (f64, usize, Point) {
+    let n = poly.len();
+    if n == 0 {
+        return (f64::INFINITY, 0, *p);
+    }
+    if n == 1 {
+        return ((*p - poly[0].p).norm(), 0, poly[0].p);
+    }
+
+    let mut best = (f64::INFINITY, 0, *p);
+    for i in 0..n {
+        let v0 = poly[i];
+        let v1 = poly[(i + 1) % n];
+        let element = arc_from_bulge(v0.p, v1.p, v0.b);
+        let (dist, closest) = if element.is_seg() {
+            dist_point_segment(p, &segment(element.a, element.b))
+        } else {
+            match dist_point_arc(p, &element) {
+                DistPointArcConfig::OnePoint(dist, closest)
+                | DistPointArcConfig::Equidistant(dist, closest) => (dist, closest),
+            }
+        };
+        if dist < best.0 {
+            best = (dist, i, closest);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod test_dist_point_polyline {
+    use super::*;
+
+    // Unit square with the top side bulged outward into a half circle
+    fn bulged_square() -> Polyline {
+        vec![
+            pvertex(point(0.0, 0.0), 0.0),
+            pvertex(point(1.0, 0.0), 0.0),
+            pvertex(point(1.0, 1.0), 1.0),
+            pvertex(point(0.0, 1.0), 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_dist_point_polyline_empty() {
+        let poly: Polyline = vec![];
+        let p = point(1.0, 2.0);
+        assert_eq!(dist_point_polyline(&p, &poly), (f64::INFINITY, 0, p));
+    }
+
+    #[test]
+    fn test_dist_point_polyline_single_vertex() {
+        let poly = vec![pvertex(point(1.0, 1.0), 0.5)];
+        let (dist, index, closest) = dist_point_polyline(&point(4.0, 5.0), &poly);
+        assert_eq!(dist, 5.0);
+        assert_eq!(index, 0);
+        assert_eq!(closest, point(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_dist_point_polyline_on_arc_not_chord() {
+        let poly = bulged_square();
+        // Above the chord of the bulge, the arc apex is at (0.5, 1.5)
+        let (dist, index, closest) = dist_point_polyline(&point(0.5, 1.25), &poly);
+        assert_eq!(index, 2);
+        assert!((dist - 0.25).abs() < 1e-12, "dist = {}", dist);
+        assert!(closest.close_enough(point(0.5, 1.5), 1e-12));
+        // The closest point lies on the arc circle, away from the chord y = 1
+        assert!(((closest - point(0.5, 1.0)).norm() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dist_point_polyline_segments() {
+        let poly = bulged_square();
+        let (dist, index, closest) = dist_point_polyline(&point(0.5, -2.0), &poly);
+        assert_eq!(dist, 2.0);
+        assert_eq!(index, 0);
+        assert_eq!(closest, point(0.5, 0.0));
+        // Closing element from the last vertex back to the first one
+        let (dist, index, closest) = dist_point_polyline(&point(-1.0, 0.5), &poly);
+        assert_eq!(dist, 1.0);
+        assert_eq!(index, 3);
+        assert_eq!(closest, point(0.0, 0.5));
+    }
+}
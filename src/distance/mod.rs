@@ -7,19 +7,33 @@
 
 // Module declarations
 pub mod dist_arc_arc;
+pub mod dist_circle_arc;
 pub mod dist_line_circle;
 pub mod dist_point_arc;
 pub mod dist_point_circle;
+pub mod dist_point_ellipse_arc;
+pub mod dist_point_line;
+#[cfg(feature = "std")]
+pub mod dist_point_arcline;
+#[cfg(feature = "std")]
+pub mod dist_point_polyline;
 pub mod dist_point_segment;
 pub mod dist_segment_arc;
 pub mod dist_segment_circle;
 pub mod dist_segment_segment;
 
 // Re-export all public types and functions for easy access
-pub use dist_arc_arc::dist_arc_arc;
+pub use dist_arc_arc::{convex_arc_closest_approach, dist_arc_arc};
+pub use dist_circle_arc::dist_circle_arc;
 pub use dist_line_circle::{DistLineCircleConfig, dist_line_circle};
 pub use dist_point_arc::{DistPointArcConfig, dist_point_arc, dist_point_arc_dist};
 pub use dist_point_circle::dist_point_circle;
+pub use dist_point_ellipse_arc::dist_point_ellipse_arc;
+pub use dist_point_line::dist_point_line;
+#[cfg(feature = "std")]
+pub use dist_point_arcline::{dist_point_arcline_hinted, farthest_point_on_arcline};
+#[cfg(feature = "std")]
+pub use dist_point_polyline::dist_point_polyline;
 pub use dist_point_segment::dist_point_segment;
 pub use dist_segment_arc::dist_segment_arc;
 pub use dist_segment_circle::{DistSegmentCircleConfig, dist_segment_circle};
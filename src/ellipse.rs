@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use crate::point::{Point, point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
+use core::fmt::Display;
+
+/// An elliptical arc, e.g. imported from DXF `ELLIPSE` or SVG `A` commands.
+///
+/// The ellipse is centered at `c` with semi-axes `rx` and `ry`, its `rx` axis
+/// rotated by `rotation` radians from the x-axis. Points of the ellipse are
+/// given by the parametric (eccentric) angle `t`:
+/// `c + R(rotation) * (rx * cos(t), ry * sin(t))`. Like [`Arc`](crate::prelude::Arc),
+/// the arc is CCW: it starts at parameter `start` and spans `sweep` radians,
+/// with `0 < sweep <= 2π`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Upper half of an ellipse with semi-axes 2 and 1
+/// let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, std::f64::consts::PI);
+/// assert!(e.point_at_angle(std::f64::consts::FRAC_PI_2).close_enough(point(0.0, 1.0), 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EllipseArc {
+    /// Center of the ellipse
+    pub c: Point,
+    /// Semi-axis along the rotated x direction
+    pub rx: f64,
+    /// Semi-axis along the rotated y direction
+    pub ry: f64,
+    /// Rotation of the `rx` axis from the x-axis, in radians
+    pub rotation: f64,
+    /// Parametric angle of the start point
+    pub start: f64,
+    /// CCW parametric sweep from the start point
+    pub sweep: f64,
+}
+
+impl Display for EllipseArc {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "[{}, {:.20}, {:.20}, {:.20}, {:.20}, {:.20}]",
+            self.c, self.rx, self.ry, self.rotation, self.start, self.sweep
+        )
+    }
+}
+
+impl EllipseArc {
+    /// Creates a new elliptical arc.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - Center of the ellipse
+    /// * `rx` - Semi-axis along the rotated x direction (positive)
+    /// * `ry` - Semi-axis along the rotated y direction (positive)
+    /// * `rotation` - Rotation of the `rx` axis, in radians
+    /// * `start` - Parametric angle of the start point
+    /// * `sweep` - CCW parametric sweep, in `(0, 2π]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let e = EllipseArc::new(point(1.0, 1.0), 3.0, 2.0, 0.0, 0.0, 1.0);
+    /// ```
+    #[inline]
+    pub fn new(c: Point, rx: f64, ry: f64, rotation: f64, start: f64, sweep: f64) -> Self {
+        EllipseArc {
+            c,
+            rx,
+            ry,
+            rotation,
+            start,
+            sweep,
+        }
+    }
+
+    /// Returns the point of the ellipse at parametric angle `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(e.point_at_angle(0.0), point(2.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn point_at_angle(&self, t: f64) -> Point {
+        self.c + self.rotate_to_world(point(self.rx * t.cos(), self.ry * t.sin()))
+    }
+
+    /// Start point of the arc.
+    #[must_use]
+    pub fn start_point(&self) -> Point {
+        self.point_at_angle(self.start)
+    }
+
+    /// End point of the arc.
+    #[must_use]
+    pub fn end_point(&self) -> Point {
+        self.point_at_angle(self.start + self.sweep)
+    }
+
+    /// Rotates a vector from the ellipse axes frame to world coordinates.
+    pub(crate) fn rotate_to_world(&self, v: Point) -> Point {
+        let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+        point(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+    }
+
+    /// Rotates a vector from world coordinates to the ellipse axes frame.
+    pub(crate) fn rotate_to_local(&self, v: Point) -> Point {
+        let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+        point(v.x * cos + v.y * sin, -v.x * sin + v.y * cos)
+    }
+}
+
+/// Creates a new elliptical arc.
+///
+/// This is a convenience function equivalent to `EllipseArc::new(...)`.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let e = ellipse_arc(point(0.0, 0.0), 2.0, 1.0, 0.0, 0.0, std::f64::consts::TAU);
+/// assert_eq!(e.start_point(), point(2.0, 0.0));
+/// ```
+#[inline]
+pub fn ellipse_arc(c: Point, rx: f64, ry: f64, rotation: f64, start: f64, sweep: f64) -> EllipseArc {
+    EllipseArc::new(c, rx, ry, rotation, start, sweep)
+}
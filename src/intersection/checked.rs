@@ -0,0 +1,236 @@
+#![allow(dead_code)]
+
+//! Validated intersection functions.
+//!
+//! The `int_*` functions assume valid input: on non-finite coordinates or arcs
+//! whose end points are not on their circle they silently return meaningless
+//! configurations. The `try_int_*` wrappers here check their arguments first
+//! and return a [`GeometryError`] instead, so that pipelines fail loudly.
+
+use core::fmt::Display;
+
+use crate::prelude::*;
+
+/// Tolerance of the arc checks, the same as used by [`arcline_is_valid`].
+const CHECK_TOLERANCE: f64 = 1e-8;
+
+/// Reason why a `try_int_*` function refused its arguments.
+///
+/// The value is the position of the offending argument, `0` for the first one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeometryError {
+    /// The argument has a NaN or infinite coordinate or radius.
+    NonFinite(usize),
+    /// The arc is degenerate: collapsed end points or radius, or end points
+    /// not on its circle, see [`Arc::is_valid`]. Also returned for a line
+    /// segment (infinite radius) passed where a curved arc is expected.
+    InvalidArc(usize),
+}
+
+impl Display for GeometryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GeometryError::NonFinite(argument) => {
+                write!(f, "argument {} has non-finite coordinates", argument)
+            }
+            GeometryError::InvalidArc(argument) => {
+                write!(f, "argument {} is a degenerate or inconsistent arc", argument)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GeometryError {}
+
+fn is_finite_point(p: Point) -> bool {
+    p.x.is_finite() && p.y.is_finite()
+}
+
+fn check_segment(segment: &Segment, argument: usize) -> Result<(), GeometryError> {
+    if is_finite_point(segment.a) && is_finite_point(segment.b) {
+        Ok(())
+    } else {
+        Err(GeometryError::NonFinite(argument))
+    }
+}
+
+fn check_arc(arc: &Arc, argument: usize) -> Result<(), GeometryError> {
+    if !is_finite_point(arc.a) || !is_finite_point(arc.b) {
+        return Err(GeometryError::NonFinite(argument));
+    }
+    if arc.is_seg() {
+        // Infinite radius, handled by the segment functions only
+        return Err(GeometryError::InvalidArc(argument));
+    }
+    if !is_finite_point(arc.c) || arc.r.is_nan() {
+        return Err(GeometryError::NonFinite(argument));
+    }
+    // Full circles have coinciding end points by design
+    let valid = if arc.is_full_circle() {
+        !arc.is_collapsed_radius(CHECK_TOLERANCE) && arc.is_consistent(CHECK_TOLERANCE)
+    } else {
+        arc.is_valid(CHECK_TOLERANCE)
+    };
+    if valid { Ok(()) } else { Err(GeometryError::InvalidArc(argument)) }
+}
+
+/// Computes the intersection of two arcs, rejecting invalid arcs.
+///
+/// Same as [`int_arc_arc`], after checking that both arcs have finite
+/// coordinates and are valid curved arcs (see [`Arc::is_valid`]; full circles
+/// are accepted, line segments are not).
+///
+/// # Arguments
+///
+/// * `arc0` - First arc
+/// * `arc1` - Second arc
+///
+/// # Returns
+///
+/// The intersection configuration, or the error found in the first invalid argument
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::intersection::checked::{GeometryError, try_int_arc_arc};
+///
+/// let arc0 = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+/// let arc1 = arc(point(2.0, 1.0), point(0.0, 1.0), point(1.0, 1.0), 1.0);
+/// assert!(try_int_arc_arc(&arc0, &arc1).is_ok());
+///
+/// // End point not on the circle
+/// let bad = arc(point(1.0, 0.0), point(-2.0, 0.0), point(0.0, 0.0), 1.0);
+/// assert_eq!(try_int_arc_arc(&arc0, &bad), Err(GeometryError::InvalidArc(1)));
+/// ```
+pub fn try_int_arc_arc(arc0: &Arc, arc1: &Arc) -> Result<ArcArcConfig, GeometryError> {
+    check_arc(arc0, 0)?;
+    check_arc(arc1, 1)?;
+    Ok(int_arc_arc(arc0, arc1))
+}
+
+/// Computes the intersection of a segment and an arc, rejecting invalid input.
+///
+/// Same as [`int_segment_arc`], after checking that the segment has finite
+/// coordinates and the arc is a valid curved arc.
+///
+/// # Arguments
+///
+/// * `segment` - The segment
+/// * `arc` - The arc
+///
+/// # Returns
+///
+/// The intersection configuration, or the error found in the first invalid argument
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::intersection::checked::{GeometryError, try_int_segment_arc};
+///
+/// let seg = segment(point(f64::NAN, 0.0), point(1.0, 1.0));
+/// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+/// assert_eq!(try_int_segment_arc(&seg, &half), Err(GeometryError::NonFinite(0)));
+/// ```
+pub fn try_int_segment_arc(segment: &Segment, arc: &Arc) -> Result<SegmentArcConfig, GeometryError> {
+    check_segment(segment, 0)?;
+    check_arc(arc, 1)?;
+    Ok(int_segment_arc(segment, arc))
+}
+
+/// Computes the intersection of two segments, rejecting non-finite input.
+///
+/// Same as [`int_segment_segment`], after checking that both segments have
+/// finite coordinates.
+///
+/// # Arguments
+///
+/// * `segment0` - First segment
+/// * `segment1` - Second segment
+///
+/// # Returns
+///
+/// The intersection configuration, or the error found in the first invalid argument
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::intersection::checked::try_int_segment_segment;
+///
+/// let s0 = segment(point(0.0, 0.0), point(2.0, 2.0));
+/// let s1 = segment(point(0.0, 2.0), point(2.0, 0.0));
+/// let result = try_int_segment_segment(&s0, &s1).unwrap();
+/// assert!(matches!(result, SegmentSegmentConfig::OnePoint(..)));
+/// ```
+pub fn try_int_segment_segment(
+    segment0: &Segment,
+    segment1: &Segment,
+) -> Result<SegmentSegmentConfig, GeometryError> {
+    check_segment(segment0, 0)?;
+    check_segment(segment1, 1)?;
+    Ok(int_segment_segment(segment0, segment1))
+}
+
+#[cfg(test)]
+mod test_checked_intersections {
+    use super::*;
+
+    fn half() -> Arc {
+        arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0)
+    }
+
+    #[test]
+    fn test_try_int_arc_arc_inconsistent_arc() {
+        // Radius does not match the end points
+        let inconsistent = arc(point(0.0, 0.0), point(2.0, 0.0), point(1.0, 0.0), 2.0);
+        assert_eq!(try_int_arc_arc(&inconsistent, &half()), Err(GeometryError::InvalidArc(0)));
+        assert_eq!(try_int_arc_arc(&half(), &inconsistent), Err(GeometryError::InvalidArc(1)));
+        // Collapsed radius
+        let collapsed = arc(point(0.0, 0.0), point(0.0, 0.0), point(0.0, 0.0), 0.0);
+        assert_eq!(try_int_arc_arc(&collapsed, &half()), Err(GeometryError::InvalidArc(0)));
+    }
+
+    #[test]
+    fn test_try_int_arc_arc_non_finite() {
+        let nan_center = arc(point(1.0, 0.0), point(-1.0, 0.0), point(f64::NAN, 0.0), 1.0);
+        assert_eq!(try_int_arc_arc(&half(), &nan_center), Err(GeometryError::NonFinite(1)));
+        let inf_end = arcseg(point(0.0, 0.0), point(f64::INFINITY, 1.0));
+        assert_eq!(try_int_arc_arc(&inf_end, &half()), Err(GeometryError::NonFinite(0)));
+    }
+
+    #[test]
+    fn test_try_int_arc_arc_matches_unchecked() {
+        let other = arc(point(2.0, 1.0), point(0.0, 1.0), point(1.0, 1.0), 1.0);
+        assert_eq!(try_int_arc_arc(&half(), &other), Ok(int_arc_arc(&half(), &other)));
+        // Full circles are valid arguments, segments are not
+        let full = arc(point(0.5, 0.0), point(0.5, 0.0), point(0.0, 0.0), 0.5);
+        assert_eq!(try_int_arc_arc(&full, &other), Ok(int_arc_arc(&full, &other)));
+        let seg = arcseg(point(-2.0, 0.5), point(2.0, 0.5));
+        assert_eq!(try_int_arc_arc(&full, &seg), Err(GeometryError::InvalidArc(1)));
+    }
+
+    #[test]
+    fn test_try_int_segment_functions() {
+        let s0 = segment(point(0.0, 0.0), point(2.0, 2.0));
+        let s1 = segment(point(0.0, 2.0), point(2.0, 0.0));
+        assert_eq!(try_int_segment_segment(&s0, &s1), Ok(int_segment_segment(&s0, &s1)));
+        let bad = segment(point(0.0, f64::NAN), point(2.0, 0.0));
+        assert_eq!(try_int_segment_segment(&s0, &bad), Err(GeometryError::NonFinite(1)));
+        assert_eq!(try_int_segment_arc(&s0, &half()), Ok(int_segment_arc(&s0, &half())));
+        assert_eq!(try_int_segment_arc(&bad, &half()), Err(GeometryError::NonFinite(0)));
+        let inconsistent = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.5), 1.0);
+        assert_eq!(try_int_segment_arc(&s0, &inconsistent), Err(GeometryError::InvalidArc(1)));
+    }
+
+    #[test]
+    fn test_geometry_error_display() {
+        assert_eq!(GeometryError::NonFinite(0).to_string(), "argument 0 has non-finite coordinates");
+        assert_eq!(
+            GeometryError::InvalidArc(1).to_string(),
+            "argument 1 is a degenerate or inconsistent arc"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+#![allow(dead_code)]
+
+use std::f64::consts::TAU;
+
+use crate::constants::GEOMETRIC_EPSILON;
+use crate::prelude::*;
+
+/// Clips an arc by a half-plane.
+///
+/// Returns the parts of the arc on the left side of the directed line, points
+/// on the line included. The arc is split at its crossings with the line, found
+/// with [`int_line_arc`], and the pieces between crossings are kept when their
+/// middle point is on the left. A curved arc crossing the line twice may keep
+/// two pieces, its two ends; a line tangent to the arc does not split it.
+///
+/// Line segments (infinite radius) are clipped as segments. The pieces keep the
+/// `id` of the arc and are CCW like it, ordered from `a` to `b`.
+///
+/// # Arguments
+///
+/// * `a` - The arc to clip
+/// * `l` - The directed line bounding the half-plane
+///
+/// # Returns
+///
+/// The pieces of the arc on the left side of the line, at most two
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Upper half of the unit circle, keeping the part above y = 0.5
+/// let half = arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0);
+/// let pieces = int_arc_halfplane(&half, &line(point(0.0, 0.5), point(1.0, 0.0)));
+/// assert_eq!(pieces.len(), 1);
+/// assert!(pieces[0].point_at(0.5).close_enough(point(0.0, 1.0), 1e-12));
+/// ```
+#[must_use]
+pub fn int_arc_halfplane(a: &Arc, l: &Line) -> Vec<Arc> {
+    let side = |p: Point| l.dir.perp(p - l.origin);
+    let with_id = |mut piece: Arc| {
+        piece.id = a.id;
+        piece
+    };
+
+    if a.is_seg() {
+        let (sa, sb) = (side(a.a), side(a.b));
+        return if sa >= 0.0 && sb >= 0.0 {
+            vec![*a]
+        } else if sa > 0.0 && sb < 0.0 {
+            let q = a.a + (a.b - a.a) * (sa / (sa - sb));
+            vec![with_id(arcseg(a.a, q))]
+        } else if sa < 0.0 && sb > 0.0 {
+            let q = a.a + (a.b - a.a) * (sa / (sa - sb));
+            vec![with_id(arcseg(q, a.b))]
+        } else {
+            Vec::new()
+        };
+    }
+
+    // Crossings in CCW order from the start of the arc
+    let va = a.a - a.c;
+    let start = va.y.atan2(va.x);
+    let offset = |p: Point| {
+        let v = p - a.c;
+        let t = v.y.atan2(v.x) - start;
+        if t < 0.0 { t + TAU } else { t }
+    };
+    let mut cuts = match int_line_arc(l, a) {
+        LineArcConfig::NoIntersection() => Vec::new(),
+        LineArcConfig::OnePoint(p, _) => vec![p],
+        LineArcConfig::TwoPoints(p0, p1, _, _) => vec![p0, p1],
+    };
+    cuts.sort_by(|p, q| offset(*p).total_cmp(&offset(*q)));
+
+    let breaks = if a.is_full_circle() && !cuts.is_empty() {
+        // A full circle starts and ends at the first crossing
+        let mut breaks = cuts.clone();
+        breaks.push(cuts[0]);
+        breaks
+    } else {
+        let mut breaks = vec![a.a];
+        breaks.extend(cuts);
+        breaks.push(a.b);
+        breaks
+    };
+
+    let mut pieces: Vec<Arc> = Vec::new();
+    for pair in breaks.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let full = breaks.len() == 2 && a.is_full_circle();
+        if !full && from.close_enough(to, GEOMETRIC_EPSILON) {
+            continue;
+        }
+        let piece = with_id(arc(from, to, a.c, a.r));
+        if side(piece.point_at(0.5)) < 0.0 {
+            continue;
+        }
+        // Rejoin pieces split at a tangent point
+        match pieces.last_mut() {
+            Some(last) if last.b == from => last.b = to,
+            _ => pieces.push(piece),
+        }
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test_int_arc_halfplane {
+    use super::*;
+
+    fn upper_half() -> Arc {
+        arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0)
+    }
+
+    #[test]
+    fn test_semicircle_keep_upper_portion() {
+        let half = upper_half();
+        let pieces = int_arc_halfplane(&half, &line(point(0.0, 0.5), point(1.0, 0.0)));
+        assert_eq!(pieces.len(), 1);
+        let h = 0.75_f64.sqrt();
+        assert!(pieces[0].a.close_enough(point(h, 0.5), 1e-12));
+        assert!(pieces[0].b.close_enough(point(-h, 0.5), 1e-12));
+        assert_eq!((pieces[0].c, pieces[0].r), (half.c, half.r));
+        assert!((pieces[0].sweep_angle() - 2.0 * std::f64::consts::PI / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_semicircle_keep_two_ends() {
+        let half = upper_half();
+        // Left of the line going in -x is below y = 0.5
+        let pieces = int_arc_halfplane(&half, &line(point(0.0, 0.5), point(-1.0, 0.0)));
+        assert_eq!(pieces.len(), 2);
+        let h = 0.75_f64.sqrt();
+        assert_eq!(pieces[0].a, point(1.0, 0.0));
+        assert!(pieces[0].b.close_enough(point(h, 0.5), 1e-12));
+        assert!(pieces[1].a.close_enough(point(-h, 0.5), 1e-12));
+        assert_eq!(pieces[1].b, point(-1.0, 0.0));
+        let kept: f64 = pieces.iter().map(Arc::sweep_angle).sum();
+        assert!((kept - std::f64::consts::PI / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_semicircle_line_misses() {
+        let half = upper_half();
+        let above = line(point(0.0, 2.0), point(1.0, 0.0));
+        assert!(int_arc_halfplane(&half, &above).is_empty());
+        // Reversed direction keeps the whole arc
+        let below = line(point(0.0, 2.0), point(-1.0, 0.0));
+        assert_eq!(int_arc_halfplane(&half, &below), vec![half]);
+        // Crossing the circle but not the arc
+        let under = line(point(0.0, -0.5), point(1.0, 0.0));
+        assert_eq!(int_arc_halfplane(&half, &under), vec![half]);
+    }
+
+    #[test]
+    fn test_tangent_line_keeps_arc_whole() {
+        let half = upper_half();
+        let tangent = line(point(0.0, 1.0), point(-1.0, 0.0));
+        assert_eq!(int_arc_halfplane(&half, &tangent), vec![half]);
+    }
+
+    #[test]
+    fn test_full_circle_and_segment() {
+        let full = arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        let pieces = int_arc_halfplane(&full, &line(point(0.0, 0.0), point(0.0, 1.0)));
+        // Left half of the circle, from the top crossing to the bottom one
+        assert_eq!(pieces.len(), 1);
+        assert!(pieces[0].a.close_enough(point(0.0, 1.0), 1e-12));
+        assert!(pieces[0].b.close_enough(point(0.0, -1.0), 1e-12));
+
+        let seg = arcseg(point(0.0, 0.0), point(4.0, 2.0));
+        let pieces = int_arc_halfplane(&seg, &line(point(2.0, 0.0), point(0.0, 1.0)));
+        assert_eq!(pieces.len(), 1);
+        assert_eq!((pieces[0].a, pieces[0].b), (point(0.0, 0.0), point(2.0, 1.0)));
+        assert!(int_arc_halfplane(&seg, &line(point(5.0, 0.0), point(0.0, -1.0))).is_empty());
+    }
+}
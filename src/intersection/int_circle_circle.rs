@@ -4,6 +4,8 @@ use crate::constants::CIRCLE_TOLERANCE;
 use crate::point::point;
 use crate::utils::diff_of_prod;
 use crate::{circle::Circle, point::Point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 // #00019
 /// Configuration for circle-circle intersection results.
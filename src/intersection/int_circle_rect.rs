@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+/// Tests whether a circle and an axis-aligned rectangle overlap.
+///
+/// The point of the rectangle closest to the circle center is the center
+/// clamped to the rectangle; the shapes overlap when it is within the radius.
+/// Both shapes are closed, so a circle touching the rectangle overlaps it.
+///
+/// # Arguments
+/// * `c` - The circle
+/// * `r` - The rectangle, its corners in any order
+///
+/// # Returns
+/// True if the circle and the rectangle (boundaries or interiors) have a common point
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let r = rect(point(0.0, 0.0), point(2.0, 2.0));
+/// assert!(int_circle_rect(circle(point(3.0, 1.0), 1.5), &r));
+/// // Near the corner (2, 2) but farther than the radius from it
+/// assert!(!int_circle_rect(circle(point(3.0, 3.0), 1.0), &r));
+/// ```
+#[must_use]
+pub fn int_circle_rect(c: Circle, r: &Rect) -> bool {
+    let (min, max) = corners(r);
+    let closest = point(c.c.x.clamp(min.x, max.x), c.c.y.clamp(min.y, max.y));
+    let d = closest - c.c;
+    d.dot(d) <= c.r * c.r
+}
+
+/// Tests whether a circle contains an axis-aligned rectangle.
+///
+/// The rectangle is inside the circle when its corner farthest from the
+/// circle center is. Boundaries may touch.
+///
+/// # Arguments
+/// * `c` - The circle
+/// * `r` - The rectangle, its corners in any order
+///
+/// # Returns
+/// True if every point of the rectangle is in the circle
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let r = rect(point(-1.0, -1.0), point(1.0, 1.0));
+/// assert!(circle_contains_rect(circle(point(0.0, 0.0), 2.0_f64.sqrt()), &r));
+/// assert!(!circle_contains_rect(circle(point(0.0, 0.0), 1.0), &r));
+/// ```
+#[must_use]
+pub fn circle_contains_rect(c: Circle, r: &Rect) -> bool {
+    let (min, max) = corners(r);
+    let dx = (c.c.x - min.x).abs().max((max.x - c.c.x).abs());
+    let dy = (c.c.y - min.y).abs().max((max.y - c.c.y).abs());
+    dx * dx + dy * dy <= c.r * c.r
+}
+
+/// Tests whether an axis-aligned rectangle contains a circle.
+///
+/// The circle is inside the rectangle when its center is at least the radius
+/// away from each side. Boundaries may touch.
+///
+/// # Arguments
+/// * `r` - The rectangle, its corners in any order
+/// * `c` - The circle
+///
+/// # Returns
+/// True if every point of the circle is in the rectangle
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let r = rect(point(0.0, 0.0), point(4.0, 2.0));
+/// assert!(rect_contains_circle(&r, circle(point(1.0, 1.0), 1.0)));
+/// assert!(!rect_contains_circle(&r, circle(point(2.0, 1.0), 1.5)));
+/// ```
+#[must_use]
+pub fn rect_contains_circle(r: &Rect, c: Circle) -> bool {
+    let (min, max) = corners(r);
+    c.c.x - c.r >= min.x && c.c.x + c.r <= max.x && c.c.y - c.r >= min.y && c.c.y + c.r <= max.y
+}
+
+// Lower-left and upper-right corners of the rectangle
+fn corners(r: &Rect) -> (Point, Point) {
+    (
+        point(r.p1.x.min(r.p2.x), r.p1.y.min(r.p2.y)),
+        point(r.p1.x.max(r.p2.x), r.p1.y.max(r.p2.y)),
+    )
+}
+
+#[cfg(test)]
+mod test_int_circle_rect {
+    use super::*;
+
+    fn square() -> Rect {
+        rect(point(0.0, 0.0), point(2.0, 2.0))
+    }
+
+    #[test]
+    fn test_center_inside_rect() {
+        assert!(int_circle_rect(circle(point(1.0, 1.0), 0.5), &square()));
+        // Much larger than the rectangle
+        assert!(int_circle_rect(circle(point(1.5, 0.5), 10.0), &square()));
+        // Corners swapped
+        let swapped = rect(point(2.0, 2.0), point(0.0, 0.0));
+        assert!(int_circle_rect(circle(point(1.0, 1.0), 0.5), &swapped));
+    }
+
+    #[test]
+    fn test_touching_edge() {
+        assert!(int_circle_rect(circle(point(3.0, 1.0), 1.0), &square()));
+        assert!(int_circle_rect(circle(point(1.0, -0.5), 0.5), &square()));
+        assert!(!int_circle_rect(circle(point(3.0, 1.0), 0.999), &square()));
+    }
+
+    #[test]
+    fn test_near_corner() {
+        // Within the radius of the corner (2, 2) along both axes, but not diagonally
+        let c = circle(point(2.8, 2.8), 1.0);
+        assert!(!int_circle_rect(c, &square()));
+        assert!(int_circle_rect(circle(point(2.7, 2.7), 1.0), &square()));
+    }
+
+    #[test]
+    fn test_containment() {
+        let r = square();
+        // Circumscribed circle contains the square, the inscribed one is contained
+        let outer = circle(point(1.0, 1.0), 2.0_f64.sqrt());
+        let inner = circle(point(1.0, 1.0), 1.0);
+        assert!(circle_contains_rect(outer, &r));
+        assert!(!circle_contains_rect(inner, &r));
+        assert!(rect_contains_circle(&r, inner));
+        assert!(!rect_contains_circle(&r, outer));
+        // Off-center: the far corner decides
+        assert!(!circle_contains_rect(circle(point(0.5, 0.5), 2.0), &r));
+        assert!(circle_contains_rect(circle(point(0.5, 0.5), 2.2), &r));
+        assert!(!rect_contains_circle(&r, circle(point(0.5, 1.0), 0.6)));
+        // Containment implies overlap
+        assert!(int_circle_rect(outer, &r) && int_circle_rect(inner, &r));
+    }
+}
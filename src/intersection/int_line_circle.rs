@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
 use crate::{circle::Circle, line::Line, point::Point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 // #00022
 /// Represents the configuration of the intersection between a line and a circle.
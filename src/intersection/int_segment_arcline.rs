@@ -0,0 +1,113 @@
+#![allow(dead_code)]
+
+use crate::algo::bounding::arc_bounding_rect;
+use crate::prelude::*;
+
+/// Checks whether a segment intersects an arcline.
+///
+/// Intended for pick and hit testing: instead of collecting intersection
+/// points, the elements are tested one by one and the function returns as soon
+/// as one of them meets the segment. Elements whose bounding rectangle does not
+/// overlap the segment's bounding rectangle are skipped without an exact test.
+///
+/// Touching counts as intersecting, including a segment end lying on an
+/// element or an element end lying on the segment.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to test
+/// * `seg` - The segment to test
+///
+/// # Returns
+///
+/// True if the segment has at least one point in common with an element of the arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::intersection::int_segment_arcline::arcline_intersects_segment;
+///
+/// // Unit circle made of two half circles
+/// let arcs = vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arc(point(-1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ];
+/// assert!(arcline_intersects_segment(&arcs, &segment(point(0.0, 0.0), point(2.0, 1.0))));
+/// assert!(!arcline_intersects_segment(&arcs, &segment(point(-0.5, 0.0), point(0.5, 0.0))));
+/// ```
+#[must_use]
+pub fn arcline_intersects_segment(arcs: &Arcline, seg: &Segment) -> bool {
+    let seg_min = point(seg.a.x.min(seg.b.x), seg.a.y.min(seg.b.y));
+    let seg_max = point(seg.a.x.max(seg.b.x), seg.a.y.max(seg.b.y));
+    arcs.iter().any(|element| {
+        let rect = arc_bounding_rect(element);
+        if rect.p1.x > seg_max.x || rect.p2.x < seg_min.x || rect.p1.y > seg_max.y || rect.p2.y < seg_min.y {
+            return false;
+        }
+        if element.is_seg() {
+            !matches!(
+                int_segment_segment(seg, &segment(element.a, element.b)),
+                SegmentSegmentConfig::NoIntersection()
+            )
+        } else {
+            !matches!(int_segment_arc(seg, element), SegmentArcConfig::NoIntersection())
+        }
+    })
+}
+
+#[cfg(test)]
+mod test_arcline_intersects_segment {
+    use super::*;
+
+    // Disc of radius 2 at (1, 1) made of four quarter arcs
+    fn disc() -> Arcline {
+        let c = point(1.0, 1.0);
+        vec![
+            arc(point(3.0, 1.0), point(1.0, 3.0), c, 2.0),
+            arc(point(1.0, 3.0), point(-1.0, 1.0), c, 2.0),
+            arc(point(-1.0, 1.0), point(1.0, -1.0), c, 2.0),
+            arc(point(1.0, -1.0), point(3.0, 1.0), c, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_segment_crossing_disc() {
+        let arcs = disc();
+        // Through the whole disc, crossing two arcs
+        assert!(arcline_intersects_segment(&arcs, &segment(point(-2.0, 0.5), point(4.0, 1.5))));
+        // From the inside out through a single arc
+        assert!(arcline_intersects_segment(&arcs, &segment(point(1.0, 1.0), point(0.0, 4.0))));
+    }
+
+    #[test]
+    fn test_segment_inside_disc() {
+        let arcs = disc();
+        assert!(!arcline_intersects_segment(&arcs, &segment(point(0.0, 0.5), point(2.0, 1.5))));
+        // Its bounding rectangle overlaps every arc, the exact test rejects it
+        assert!(!arcline_intersects_segment(&arcs, &segment(point(-0.3, 1.0), point(2.3, 1.0))));
+    }
+
+    #[test]
+    fn test_segment_outside_and_touching() {
+        let arcs = disc();
+        assert!(!arcline_intersects_segment(&arcs, &segment(point(4.0, -2.0), point(5.0, 4.0))));
+        // Tangent to the disc at its top
+        assert!(arcline_intersects_segment(&arcs, &segment(point(0.0, 3.0), point(2.0, 3.0))));
+        // Ending on the boundary
+        assert!(arcline_intersects_segment(&arcs, &segment(point(1.0, 1.0), point(3.0, 1.0))));
+    }
+
+    #[test]
+    fn test_segments_arcline() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert!(arcline_intersects_segment(&square, &segment(point(1.0, 1.0), point(1.0, 3.0))));
+        assert!(!arcline_intersects_segment(&square, &segment(point(0.5, 0.5), point(1.5, 1.5))));
+        assert!(!arcline_intersects_segment(&vec![], &segment(point(0.0, 0.0), point(1.0, 1.0))));
+    }
+}
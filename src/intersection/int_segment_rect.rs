@@ -0,0 +1,151 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+/// Represents the configuration of the intersection between a segment and a rectangle.
+///
+/// Parameters run from 0 at the segment start `a` to 1 at its end `b`.
+#[derive(Debug, PartialEq)]
+pub enum SegmentRectConfig {
+    NoIntersection(),
+    /// The segment only touches the rectangle at one point, e.g. grazing a corner
+    OnePoint(Point, f64),
+    /// The part of the segment inside the rectangle, with its start and end parameters
+    Clipped(Segment, f64, f64),
+    /// The whole segment is inside the rectangle
+    Contained(),
+}
+
+/// Computes the intersection of a segment and an axis-aligned rectangle.
+///
+/// Clips the segment with the Liang–Barsky algorithm: the parameter range
+/// `[0, 1]` of the segment is narrowed by each of the four half-planes bounding
+/// the rectangle. The rectangle is closed, points on its boundary are inside.
+///
+/// # Arguments
+/// * `seg` - The segment to clip
+/// * `r` - The rectangle, its corners in any order
+///
+/// # Returns
+/// A `SegmentRectConfig` enum indicating the type of intersection:
+/// - `NoIntersection` if the segment misses the rectangle
+/// - `OnePoint(p, t)` if the segment touches the rectangle only at `p` with parameter `t`
+/// - `Clipped(s, t0, t1)` if the part `s` of the segment, between parameters `t0` and `t1`, is inside
+/// - `Contained` if the whole segment is inside the rectangle
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let r = rect(point(0.0, 0.0), point(2.0, 2.0));
+/// let seg = segment(point(-1.0, 1.0), point(3.0, 1.0));
+/// assert_eq!(
+///     int_segment_rect(&seg, &r),
+///     SegmentRectConfig::Clipped(segment(point(0.0, 1.0), point(2.0, 1.0)), 0.25, 0.75)
+/// );
+/// ```
+#[must_use]
+pub fn int_segment_rect(seg: &Segment, r: &Rect) -> SegmentRectConfig {
+    let (min_x, max_x) = (r.p1.x.min(r.p2.x), r.p1.x.max(r.p2.x));
+    let (min_y, max_y) = (r.p1.y.min(r.p2.y), r.p1.y.max(r.p2.y));
+    let d = seg.b - seg.a;
+
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+    // Each bound as p * t <= q
+    for (p, q) in [
+        (-d.x, seg.a.x - min_x),
+        (d.x, max_x - seg.a.x),
+        (-d.y, seg.a.y - min_y),
+        (d.y, max_y - seg.a.y),
+    ] {
+        if p == 0.0 {
+            // Parallel to the bound and outside of it
+            if q < 0.0 {
+                return SegmentRectConfig::NoIntersection();
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+
+    if t0 > t1 {
+        return SegmentRectConfig::NoIntersection();
+    }
+    if t0 == 0.0 && t1 == 1.0 {
+        return SegmentRectConfig::Contained();
+    }
+    let at = |t: f64| match t {
+        0.0 => seg.a,
+        1.0 => seg.b,
+        _ => seg.a + d * t,
+    };
+    if t0 == t1 {
+        return SegmentRectConfig::OnePoint(at(t0), t0);
+    }
+    SegmentRectConfig::Clipped(segment(at(t0), at(t1)), t0, t1)
+}
+
+#[cfg(test)]
+mod test_int_segment_rect {
+    use super::*;
+
+    fn square() -> Rect {
+        rect(point(0.0, 0.0), point(2.0, 2.0))
+    }
+
+    #[test]
+    fn test_segment_inside() {
+        let seg = segment(point(0.5, 0.5), point(1.5, 1.0));
+        assert_eq!(int_segment_rect(&seg, &square()), SegmentRectConfig::Contained());
+        // Along the boundary and degenerate
+        let edge = segment(point(0.0, 0.0), point(2.0, 0.0));
+        assert_eq!(int_segment_rect(&edge, &square()), SegmentRectConfig::Contained());
+        let dot = segment(point(1.0, 1.0), point(1.0, 1.0));
+        assert_eq!(int_segment_rect(&dot, &square()), SegmentRectConfig::Contained());
+    }
+
+    #[test]
+    fn test_segment_outside() {
+        let above = segment(point(-1.0, 3.0), point(3.0, 3.0));
+        assert_eq!(int_segment_rect(&above, &square()), SegmentRectConfig::NoIntersection());
+        // Passing by the corner diagonally
+        let diagonal = segment(point(1.0, 4.0), point(4.0, 1.0));
+        assert_eq!(int_segment_rect(&diagonal, &square()), SegmentRectConfig::NoIntersection());
+        // On the line through an edge but beyond it
+        let beyond = segment(point(3.0, 0.0), point(5.0, 0.0));
+        assert_eq!(int_segment_rect(&beyond, &square()), SegmentRectConfig::NoIntersection());
+    }
+
+    #[test]
+    fn test_segment_crossing_two_edges() {
+        let seg = segment(point(-1.0, 0.0), point(3.0, 2.0));
+        assert_eq!(
+            int_segment_rect(&seg, &square()),
+            SegmentRectConfig::Clipped(segment(point(0.0, 0.5), point(2.0, 1.5)), 0.25, 0.75)
+        );
+        // Entering through the left edge and ending inside, corners swapped
+        let swapped = rect(point(2.0, 2.0), point(0.0, 0.0));
+        let seg = segment(point(-2.0, 1.0), point(1.0, 1.0));
+        assert_eq!(
+            int_segment_rect(&seg, &swapped),
+            SegmentRectConfig::Clipped(segment(point(0.0, 1.0), point(1.0, 1.0)), 2.0 / 3.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_segment_grazing_corner() {
+        let seg = segment(point(1.0, 3.0), point(3.0, 1.0));
+        assert_eq!(
+            int_segment_rect(&seg, &square()),
+            SegmentRectConfig::OnePoint(point(2.0, 2.0), 0.5)
+        );
+        // Ending at a corner
+        let seg = segment(point(-1.0, -1.0), point(0.0, 0.0));
+        assert_eq!(
+            int_segment_rect(&seg, &square()),
+            SegmentRectConfig::OnePoint(point(0.0, 0.0), 1.0)
+        );
+    }
+}
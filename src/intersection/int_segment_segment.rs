@@ -8,7 +8,7 @@ use crate::intersection::int_line_line::{LineLineConfig, int_line_line};
 use crate::interval::interval;
 
 use crate::utils::close_enough;
-use crate::{line::line, point::Point, segment::Segment};
+use crate::{line::line, point::Point, segment::Segment, segment::segment};
 
 // #00026
 /// Represents the configuration of the intersection between two segments.
@@ -203,6 +203,114 @@ pub fn if_really_intersecting_segment_segment(part0: &Segment, part1: &Segment)
     }
 }
 
+/// Computes the single intersection point of two segments with its parameters on both segments.
+///
+/// Unlike [`int_segment_segment`], whose parameters are signed distances from the
+/// segment centers, the parameters returned here are normalized: `0` at `a` and
+/// `1` at `b` of each segment, so `segment.a + (segment.b - segment.a) * t`
+/// reconstructs the intersection point. This is the form needed to split edges
+/// consistently in boolean operations. Touching ends are reported too.
+///
+/// # Arguments
+/// * `segment0` - The first segment
+/// * `segment1` - The second segment
+///
+/// # Returns
+/// `Some((p, t0, t1))` with the intersection point and its parameters in `[0, 1]`,
+/// or `None` if the segments do not intersect or overlap along a shared piece
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let segment0 = segment(point(0.0, 0.0), point(4.0, 0.0));
+/// let segment1 = segment(point(1.0, -1.0), point(1.0, 3.0));
+/// let (p, t0, t1) = int_segment_segment_params(&segment0, &segment1).unwrap();
+/// assert_eq!(p, point(1.0, 0.0));
+/// assert_eq!(t0, 0.25);
+/// assert_eq!(t1, 0.25);
+/// ```
+pub fn int_segment_segment_params(segment0: &Segment, segment1: &Segment) -> Option<(Point, f64, f64)> {
+    match int_segment_segment(segment0, segment1) {
+        SegmentSegmentConfig::OnePoint(p, _, _) | SegmentSegmentConfig::OnePointTouching(p, _, _) => {
+            Some((p, segment_param(segment0, p), segment_param(segment1, p)))
+        }
+        _ => None,
+    }
+}
+
+// Normalized parameter of the projection of `p` on the segment, clamped to [0, 1]
+fn segment_param(segment: &Segment, p: Point) -> f64 {
+    let d = segment.b - segment.a;
+    let len2 = d.dot(d);
+    if len2 == ZERO {
+        return ZERO;
+    }
+    (d.dot(p - segment.a) / len2).clamp(ZERO, 1.0)
+}
+
+/// Computes the overlapping piece of two collinear segments.
+///
+/// The segments are collinear when both ends of the shorter one are within
+/// `eps` of the line through the longer one. Their overlap is found along that
+/// line; each end of the result is an end point of one of the segments. Ends
+/// closer than `eps` are treated as touching, giving a zero-length overlap.
+///
+/// Unlike [`int_segment_segment`], this reports the shared piece of segments
+/// that lie on top of each other, e.g. to merge edges after boolean operations.
+///
+/// # Arguments
+/// * `s1` - The first segment
+/// * `s2` - The second segment
+/// * `eps` - The tolerance for collinearity and touching ends
+///
+/// # Returns
+/// The overlapping piece, oriented like the longer segment, or `None` if the
+/// segments are not collinear or do not overlap
+///
+/// # Examples
+/// ```
+/// use togo::prelude::*;
+/// let s1 = segment(point(0.0, 0.0), point(4.0, 0.0));
+/// let s2 = segment(point(6.0, 0.0), point(3.0, 0.0));
+/// let overlap = collinear_segment_overlap(&s1, &s2, 1e-10).unwrap();
+/// assert_eq!((overlap.a, overlap.b), (point(3.0, 0.0), point(4.0, 0.0)));
+/// ```
+pub fn collinear_segment_overlap(s1: &Segment, s2: &Segment, eps: f64) -> Option<Segment> {
+    let (long, short) = if (s1.b - s1.a).norm() >= (s2.b - s2.a).norm() {
+        (s1, s2)
+    } else {
+        (s2, s1)
+    };
+    let (dir, len) = (long.b - long.a).normalize(false);
+    if len <= eps {
+        // Both segments are points
+        if (short.a - long.a).norm() > eps {
+            return None;
+        }
+        return Some(segment(long.a, long.a));
+    }
+    let offset = |p: Point| (p - long.a).perp(dir).abs();
+    if offset(short.a) > eps || offset(short.b) > eps {
+        return None;
+    }
+
+    // Positions along the longer segment, with the end points defining them
+    let along = |p: Point| ((p - long.a).dot(dir), p);
+    let (mut s_start, mut s_end) = (along(short.a), along(short.b));
+    if s_start.0 > s_end.0 {
+        core::mem::swap(&mut s_start, &mut s_end);
+    }
+    let start = if s_start.0 > 0.0 { s_start } else { (0.0, long.a) };
+    let end = if s_end.0 < len { s_end } else { (len, long.b) };
+    if end.0 < start.0 - eps {
+        return None;
+    }
+    if end.0 <= start.0 {
+        return Some(segment(start.1, start.1));
+    }
+    Some(segment(start.1, end.1))
+}
+
 #[cfg(test)]
 mod test_int_segment_segment {
     use crate::point::point;
@@ -483,3 +591,124 @@ mod test_int_segment_segment {
         }
     }
 }
+
+#[cfg(test)]
+mod test_int_segment_segment_params {
+    use crate::point::point;
+    use crate::segment::segment;
+
+    use super::*;
+
+    fn reconstruct(s: &Segment, t: f64) -> Point {
+        s.a + (s.b - s.a) * t
+    }
+
+    #[test]
+    fn test_params_reconstruct_crossing() {
+        let s0 = segment(point(-1.0, 2.0), point(3.0, -2.0));
+        let s1 = segment(point(0.0, -1.0), point(2.0, 3.0));
+        let (p, t0, t1) = int_segment_segment_params(&s0, &s1).unwrap();
+        assert!((0.0..=1.0).contains(&t0));
+        assert!((0.0..=1.0).contains(&t1));
+        assert!(reconstruct(&s0, t0).close_enough(p, 1e-12));
+        assert!(reconstruct(&s1, t1).close_enough(p, 1e-12));
+        assert!(p.close_enough(point(2.0 / 3.0, 1.0 / 3.0), 1e-12));
+    }
+
+    #[test]
+    fn test_params_many_crossings() {
+        // Spokes through a common point cross a fixed segment
+        let s0 = segment(point(-3.0, 1.0), point(5.0, 2.0));
+        for k in 0..20 {
+            let angle = 0.1 + k as f64 * 0.15;
+            let d = point(angle.cos(), angle.sin()) * 10.0;
+            let c = point(1.0, 1.5);
+            let s1 = segment(c - d, c + d);
+            let (p, t0, t1) = int_segment_segment_params(&s0, &s1).unwrap();
+            assert!(reconstruct(&s0, t0).close_enough(p, 1e-10));
+            assert!(reconstruct(&s1, t1).close_enough(p, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_params_touching_ends() {
+        let s0 = segment(point(0.0, 0.0), point(2.0, 2.0));
+        let s1 = segment(point(2.0, 2.0), point(4.0, 0.0));
+        assert_eq!(
+            int_segment_segment_params(&s0, &s1),
+            Some((point(2.0, 2.0), 1.0, 0.0))
+        );
+        // T-junction in the middle of the second segment
+        let s2 = segment(point(1.0, 5.0), point(1.0, 1.0));
+        let s3 = segment(point(0.0, 1.0), point(4.0, 1.0));
+        assert_eq!(
+            int_segment_segment_params(&s2, &s3),
+            Some((point(1.0, 1.0), 1.0, 0.25))
+        );
+    }
+
+    #[test]
+    fn test_params_none() {
+        let s0 = segment(point(0.0, 0.0), point(2.0, 2.0));
+        let s1 = segment(point(2.0, 1.0), point(4.0, -1.0));
+        assert_eq!(int_segment_segment_params(&s0, &s1), None);
+        // Overlapping collinear segments share a piece, not a point
+        let s2 = segment(point(1.0, 1.0), point(3.0, 3.0));
+        assert_eq!(int_segment_segment_params(&s0, &s2), None);
+    }
+}
+
+#[cfg(test)]
+mod test_collinear_segment_overlap {
+    use crate::point::point;
+
+    use super::*;
+
+    #[test]
+    fn test_overlap_partial_and_contained() {
+        let s1 = segment(point(0.0, 0.0), point(4.0, 2.0));
+        let s2 = segment(point(7.0, 3.5), point(2.0, 1.0));
+        // Oriented like the longer segment, whatever the argument order
+        for (x, y) in [(&s1, &s2), (&s2, &s1)] {
+            let overlap = collinear_segment_overlap(x, y, 1e-10).unwrap();
+            assert_eq!((overlap.a, overlap.b), (point(4.0, 2.0), point(2.0, 1.0)));
+        }
+
+        // The shorter segment lies inside the longer one
+        let inner = segment(point(3.0, 1.5), point(1.0, 0.5));
+        let overlap = collinear_segment_overlap(&s1, &inner, 1e-10).unwrap();
+        assert_eq!((overlap.a, overlap.b), (point(1.0, 0.5), point(3.0, 1.5)));
+
+        // Identical segments
+        let overlap = collinear_segment_overlap(&s1, &s1, 1e-10).unwrap();
+        assert_eq!((overlap.a, overlap.b), (s1.a, s1.b));
+    }
+
+    #[test]
+    fn test_overlap_touching_zero_length() {
+        let s1 = segment(point(0.0, 0.0), point(2.0, 0.0));
+        let s2 = segment(point(2.0, 0.0), point(5.0, 0.0));
+        let overlap = collinear_segment_overlap(&s1, &s2, 1e-10).unwrap();
+        assert_eq!((overlap.a, overlap.b), (point(2.0, 0.0), point(2.0, 0.0)));
+        // A gap below the tolerance still touches
+        let s3 = segment(point(2.0 + 1e-12, 0.0), point(5.0, 0.0));
+        let overlap = collinear_segment_overlap(&s1, &s3, 1e-10).unwrap();
+        assert!((overlap.b - overlap.a).norm() == 0.0);
+        assert!(overlap.a.close_enough(point(2.0, 0.0), 1e-10));
+    }
+
+    #[test]
+    fn test_overlap_none() {
+        let s1 = segment(point(0.0, 0.0), point(2.0, 0.0));
+        // Collinear with a gap
+        let apart = segment(point(3.0, 0.0), point(5.0, 0.0));
+        assert_eq!(collinear_segment_overlap(&s1, &apart, 1e-10), None);
+        // Parallel, not collinear
+        let parallel = segment(point(0.0, 1e-6), point(2.0, 1e-6));
+        assert_eq!(collinear_segment_overlap(&s1, &parallel, 1e-10), None);
+        assert!(collinear_segment_overlap(&s1, &parallel, 1e-5).is_some());
+        // Crossing
+        let crossing = segment(point(1.0, -1.0), point(1.0, 1.0));
+        assert_eq!(collinear_segment_overlap(&s1, &crossing, 1e-10), None);
+    }
+}
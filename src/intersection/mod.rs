@@ -6,27 +6,44 @@
 //! various geometric primitives such as arcs, circles, lines, segments, and intervals.
 
 // Module declarations
+pub mod checked;
 pub mod int_arc_arc;
+#[cfg(feature = "std")]
+pub mod int_arc_halfplane;
 pub mod int_circle_circle;
+pub mod int_circle_rect;
 pub mod int_interval_interval;
 pub mod int_line_arc;
 pub mod int_line_circle;
 pub mod int_line_line;
 pub mod int_segment_arc;
+#[cfg(feature = "std")]
+pub mod int_segment_arcline;
 pub mod int_segment_circle;
+pub mod int_segment_rect;
 pub mod int_segment_segment;
 pub mod tangent;
 
 // Re-export all public types and functions for easy access
+pub use checked::{GeometryError, try_int_arc_arc, try_int_segment_arc, try_int_segment_segment};
 pub use int_arc_arc::{ArcArcConfig, if_really_intersecting_arc_arc, int_arc_arc};
+#[cfg(feature = "std")]
+pub use int_arc_halfplane::int_arc_halfplane;
 pub use int_circle_circle::{CircleCircleConfig, int_circle_circle};
+pub use int_circle_rect::{circle_contains_rect, int_circle_rect, rect_contains_circle};
 pub use int_interval_interval::{IntervalConfig, int_interval_interval};
 pub use int_line_arc::{LineArcConfig, int_line_arc};
 pub use int_line_circle::{LineCircleConfig, int_line_circle};
 pub use int_line_line::{LineLineConfig, int_line_line};
 pub use int_segment_arc::{SegmentArcConfig, if_really_intersecting_segment_arc, int_segment_arc};
+#[cfg(feature = "std")]
+pub use int_segment_arcline::arcline_intersects_segment;
 pub use int_segment_circle::{SegmentCircleConfig, int_segment_circle};
+pub use int_segment_rect::{SegmentRectConfig, int_segment_rect};
 pub use int_segment_segment::{
-    SegmentSegmentConfig, if_really_intersecting_segment_segment, int_segment_segment,
+    SegmentSegmentConfig, collinear_segment_overlap, if_really_intersecting_segment_segment,
+    int_segment_segment, int_segment_segment_params,
 };
+#[cfg(feature = "std")]
+pub use tangent::arc_tangent_to_two_circles;
 pub use tangent::{external_tangents_between_circles, tangent_point_to_circle};
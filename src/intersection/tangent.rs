@@ -6,6 +6,8 @@
 //! All algorithms use pure geometric approaches based on vector operations and the Pythagorean theorem.
 
 use crate::prelude::*;
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 /// Computes the two tangent points from an external point to a circle.
 ///
@@ -290,6 +292,81 @@ pub fn external_tangents_between_circles(
     Some((t1_c1, t1_c2, t2_c1, t2_c2))
 }
 
+/// Computes the arcs of a given radius tangent to two circles.
+///
+/// The centers of the tangent arcs are found by intersecting offset circles:
+/// - arcs touching both circles from outside have their center at distance
+///   `r1 + radius` from `c1` and `r2 + radius` from `c2`,
+/// - arcs enclosing both circles, when `radius` is larger than both radii,
+///   have their center at distance `radius - r1` and `radius - r2`.
+///
+/// Each kind gives up to two centers, symmetric about the line through the
+/// circle centers. Every arc connects its two tangent points, sweeping less
+/// than a half circle, so it bridges the circles like a fillet.
+///
+/// # Arguments
+///
+/// * `c1` - The first circle
+/// * `c2` - The second circle
+/// * `radius` - The radius of the tangent arcs
+///
+/// # Returns
+///
+/// Between 0 and 4 CCW arcs, the outside tangent arcs first. Empty when the
+/// radius is not positive, the circles are the same or no arc fits.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+/// use togo::intersection::tangent::arc_tangent_to_two_circles;
+///
+/// let c1 = circle(point(-3.0, 0.0), 1.0);
+/// let c2 = circle(point(3.0, 0.0), 1.0);
+/// // Too small to enclose both circles, only the outside arcs
+/// let arcs = arc_tangent_to_two_circles(&c1, &c2, 3.0);
+/// assert_eq!(arcs.len(), 2);
+/// // Large enough to also enclose them
+/// let arcs = arc_tangent_to_two_circles(&c1, &c2, 5.0);
+/// assert_eq!(arcs.len(), 4);
+/// ```
+#[must_use]
+#[cfg(feature = "std")]
+pub fn arc_tangent_to_two_circles(c1: &Circle, c2: &Circle, radius: f64) -> Vec<Arc> {
+    let mut arcs = Vec::new();
+    if radius <= 0.0 || c1.r <= 0.0 || c2.r <= 0.0 {
+        return arcs;
+    }
+    let mut offsets = vec![(c1.r + radius, c2.r + radius)];
+    if radius > c1.r && radius > c2.r {
+        offsets.push((radius - c1.r, radius - c2.r));
+    }
+    for (d1, d2) in offsets {
+        let centers = match int_circle_circle(circle(c1.c, d1), circle(c2.c, d2)) {
+            CircleCircleConfig::NoncocircularTwoPoints(p0, p1) => vec![p0, p1],
+            CircleCircleConfig::NoncocircularOnePoint(p0) => vec![p0],
+            CircleCircleConfig::NoIntersection() | CircleCircleConfig::SameCircles() => vec![],
+        };
+        for center in centers {
+            arcs.push(tangent_bridge(center, radius, c1.c, c2.c));
+        }
+    }
+    arcs
+}
+
+// Arc of the circle at `center` between its tangent points with circles at `p1` and `p2`
+fn tangent_bridge(center: Point, radius: f64, p1: Point, p2: Point) -> Arc {
+    let (u1, _) = (p1 - center).normalize(false);
+    let (u2, _) = (p2 - center).normalize(false);
+    let t1 = center + u1 * radius;
+    let t2 = center + u2 * radius;
+    if u1.perp(u2) >= 0.0 {
+        arc(t1, t2, center, radius)
+    } else {
+        arc(t2, t1, center, radius)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,3 +494,79 @@ mod tests {
         assert!(result.is_none());
     }
 }
+
+#[cfg(test)]
+mod test_arc_tangent_to_two_circles {
+    use super::*;
+
+    fn assert_tangent(a: &Arc, c: &Circle) {
+        // One end on the circle, with the circles touching there
+        let d = (a.c - c.c).norm();
+        assert!((d - (a.r + c.r)).abs() < 1e-9 || (d - (a.r - c.r)).abs() < 1e-9);
+        let on_circle = |p: Point| ((p - c.c).norm() - c.r).abs() < 1e-9;
+        assert!(on_circle(a.a) || on_circle(a.b));
+    }
+
+    #[test]
+    fn test_separated_equal_circles() {
+        let c1 = circle(point(-3.0, 0.0), 1.0);
+        let c2 = circle(point(3.0, 0.0), 1.0);
+        let arcs = arc_tangent_to_two_circles(&c1, &c2, 5.0);
+        assert_eq!(arcs.len(), 4);
+        for a in &arcs {
+            assert!(a.is_consistent(1e-9));
+            assert!(a.sweep_angle() < std::f64::consts::PI);
+            assert_tangent(a, &c1);
+            assert_tangent(a, &c2);
+        }
+        // Outside arcs: centers at distance 6 from both circle centers
+        let y = 27.0_f64.sqrt();
+        assert!(arcs[0].c.close_enough(point(0.0, -y), 1e-9) || arcs[0].c.close_enough(point(0.0, y), 1e-9));
+        assert!(arcs[0].c.close_enough(point(0.0, -arcs[1].c.y), 1e-9));
+        // Enclosing arcs: centers at distance 4
+        let y = 7.0_f64.sqrt();
+        assert!((arcs[2].c.y.abs() - y).abs() < 1e-9);
+        assert!(arcs[2].c.close_enough(point(0.0, -arcs[3].c.y), 1e-9));
+        // Mirror images of each other
+        for (upper, lower) in [(0, 1), (2, 3)] {
+            let (upper, lower) = if arcs[upper].c.y > 0.0 { (upper, lower) } else { (lower, upper) };
+            assert!(arcs[upper].a.close_enough(point(arcs[lower].b.x, -arcs[lower].b.y), 1e-9));
+            assert!(arcs[upper].b.close_enough(point(arcs[lower].a.x, -arcs[lower].a.y), 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_outside_arc_bridges_below_center() {
+        let c1 = circle(point(-3.0, 0.0), 1.0);
+        let c2 = circle(point(3.0, 0.0), 1.0);
+        let arcs = arc_tangent_to_two_circles(&c1, &c2, 3.0);
+        assert_eq!(arcs.len(), 2);
+        let upper = if arcs[0].c.y > 0.0 { arcs[0] } else { arcs[1] };
+        assert!(upper.c.close_enough(point(0.0, 7.0_f64.sqrt()), 1e-9));
+        // Runs from the tangent point on c1 to the one on c2, through the bottom of its circle
+        assert!(upper.a.close_enough(point(-2.25, 7.0_f64.sqrt() / 4.0), 1e-9));
+        assert!(upper.b.close_enough(point(2.25, 7.0_f64.sqrt() / 4.0), 1e-9));
+        assert!(upper.contains(point(0.0, 7.0_f64.sqrt() - 3.0)));
+    }
+
+    #[test]
+    fn test_no_tangent_arcs() {
+        let c1 = circle(point(-10.0, 0.0), 1.0);
+        let c2 = circle(point(10.0, 0.0), 1.0);
+        // Too small to reach both circles
+        assert!(arc_tangent_to_two_circles(&c1, &c2, 2.0).is_empty());
+        assert!(arc_tangent_to_two_circles(&c1, &c2, 0.0).is_empty());
+        assert!(arc_tangent_to_two_circles(&c1, &c1, 3.0).is_empty());
+    }
+
+    #[test]
+    fn test_single_tangent_arc() {
+        // Just reaching both circles, the arc is a half circle
+        let c1 = circle(point(-3.0, 0.0), 1.0);
+        let c2 = circle(point(3.0, 0.0), 1.0);
+        let arcs = arc_tangent_to_two_circles(&c1, &c2, 2.0);
+        assert_eq!(arcs.len(), 1);
+        assert!(arcs[0].c.close_enough(point(0.0, 0.0), 1e-9));
+        assert!((arcs[0].sweep_angle() - std::f64::consts::PI).abs() < 1e-9);
+    }
+}
@@ -1,11 +1,12 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
 /// Represents a closed interval with two endpoints.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval(pub f64, pub f64);
 
 impl Display for Interval {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{:.20}, {:.20}]", self.0, self.1)
     }
 }
@@ -1,4 +1,5 @@
 #![doc(html_no_source)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! Basic 2D geometric operations.
 //!
@@ -10,6 +11,23 @@
 //!
 //! > **Important:** All arcs in this library are **CCW (counter-clockwise)** oriented.
 //!
+//! # Features
+//!
+//! - `std` (default): Arclines, polylines, the geometric algorithms and SVG
+//!   output. Without it (`default-features = false`) the crate is `no_std` and
+//!   only the core primitives (points, segments, lines, circles, arcs,
+//!   intervals, rectangles) and the distance and intersection functions are
+//!   available; they do not allocate. Floating point math is then provided by
+//!   `libm`.
+//! - `hull-debug`: `arcline_convex_hull_debug`, returning the convex hull of an
+//!   arcline together with a trace of the steps that built it.
+//! - `serde`: `Serialize` and `Deserialize` for the core primitives (points,
+//!   segments, lines, circles, arcs, intervals, rectangles and polyline
+//!   vertices), so arclines and polylines serialize as sequences. The
+//!   debugging `id` of arcs and segments is not serialized and reads back as 0.
+//!   The infinite center and radius of line segment arcs are written as missing
+//!   values. Works with or without `std`.
+//!
 //! # Examples
 //!
 //! ## Distance and intersection calculations
@@ -179,17 +197,27 @@
 // Core geometric primitives
 mod arc;
 mod circle;
+mod ellipse;
 mod line;
+#[cfg(feature = "std")]
+mod path;
 mod point;
+#[cfg(feature = "std")]
+mod polygon;
+#[cfg(feature = "std")]
 mod polyline;
+mod ray;
 mod rect;
 mod segment;
+mod transform;
 
 // Centralized constants for numeric stability
 pub mod constants;
 
 // Geometric algorithms and utilities
+#[cfg(feature = "std")]
 pub mod algo;
+#[cfg(feature = "std")]
 pub mod poly;
 mod interval;
 mod utils;
@@ -202,52 +230,93 @@ pub mod intersection;
 
 #[doc(hidden)]
 // Bézier curve support (experimental)
+#[cfg(feature = "std")]
 pub mod bezier;
 
 // Visualization and debugging
+#[cfg(feature = "std")]
 mod svg;
 
 pub mod prelude {
     // Re-export core types and functions
+    #[cfg(feature = "std")]
     pub use crate::algo::{
-        is_convex_pointline, pointline_area, arcline_area, points_convex_hull, pointline_convex_hull,
-        arcline_convex_hull, arc_bounding_circle, arc_bounding_rect, arcline_has_self_intersection, 
-        arcline_self_intersections, arcline_self_intersection_status, SelfIntersectionStatus
+        is_convex_pointline, pointline_is_monotone, pointline_contains, pointline_area, pointline_area_under, arcline_area, points_convex_hull, points_convex_hull_from,
+        points_convex_hull_metrics, pointline_convex_hull,
+        arcline_convex_hull, arclines_convex_hull, arc_bounding_circle, arc_bounding_rect, arcline_bounding_rect, arcline_has_self_intersection, 
+        arcline_self_intersections, arcline_self_intersection_status, SelfIntersectionStatus,
+        arcline_centroid, arcline_contains, arcline_is_convex, arcline_area_checked, AreaError, HalfEdge, PlanarSubdivision, arclines_overlay,
+        tangent_path_around, arcline_area_green, arcline_area_contributions, arcline_convex_hull_tagged, HullEdgeKind,
+        pointline_symmetric_difference, normalize_ring_orientations, pointline_visibility,
+        arcline_simplify, arcline_min_area_obb, ray_arcline_crossing_counts,
+        hilbert_within_radius, hilbert_nearest, HilbertRTreeExt, arcline_arcline_min_distance, hilbert_k_nearest, hilbert_query_rect, hilbert_query_point, pointline_medial_axis, points_voronoi,
+        points_largest_empty_circle, pointline_trapezoidal_decomposition, convex_polygons_penetration,
+        points_min_enclosing_circle, fillet_arcs, pointline_inset_safe,
     };
+    #[cfg(feature = "std")]
     pub use aabb::HilbertRTree;
     pub use crate::arc::{
-        Arc, Arcline, arc, bulge_from_arc, arc_from_bulge,
-        arcline_translate, arcline_scale, arcline_reverse, arcline_is_valid, arcseg, is_really_intersecting,
-        ArclineValidation, is_arc_convex
+        Arc, arc, arc_major, arc_minor, bulge_from_arc, arc_from_bulge, arcseg, is_really_intersecting, ArclineValidation,
+        arc_subtended_angle, merge_cocircular_arcs,
+    };
+    #[cfg(feature = "std")]
+    pub use crate::arc::{
+        Arcline, arcline_translate, arcline_scale, arcline_reverse, arcline_is_valid, is_arc_convex,
+        arcline_split_at_point, arcline_degenerate_report, DegenerateReport,
+        arcline_to_buffer, arcline_from_buffer, ARCLINE_RECORD_LEN, arcline_outward_normals, ArclineBuilder,
+        arcline_to_geojson, arcline_flatten_iter, arcline_curvature_samples, arcline_rotate, arcline_length,
+        arcline_to_polyline, arc_region_hull, arcline_mirror, arcline_from_bulge_vertices, arcline_to_bulge_vertices,
+        arcline_transform,
     };
-    pub use crate::circle::{Circle, circle};
+    pub use crate::circle::{Circle, circle, fit_circle_to_points};
+    #[cfg(feature = "std")]
+    pub use crate::circle::circles_any_overlap;
+    pub use crate::ellipse::{EllipseArc, ellipse_arc};
     pub use crate::interval::{Interval, interval};
     pub use crate::line::{Line, line};
-    pub use crate::point::{Point, Pointline, point, points_order};
+    #[cfg(feature = "std")]
+    pub use crate::line::fit_line_to_points;
+    #[cfg(feature = "std")]
+    pub use crate::path::{Path, path};
+    pub use crate::point::{Point, point, points_order};
+    #[cfg(feature = "std")]
+    pub use crate::point::Pointline;
+    #[cfg(feature = "std")]
+    pub use crate::polygon::{Polygon, polygon};
+    #[cfg(feature = "std")]
     pub use crate::polyline::{
-        PVertex, Polyline, polyline_reverse, polyline_scale, polyline_translate, polylines_reverse,
+        PVertex, Polyline, dxf_lwpolyline_to_polyline, fit_bulges, polyline_reverse, polyline_scale, polyline_translate, polylines_reverse,
         pvertex,
     };
+    pub use crate::ray::{Ray, ray};
     pub use crate::rect::{Rect, rect};
     pub use crate::segment::{Segment, segment};
-    pub use crate::svg::{SVG, svg};
+    pub use crate::transform::Affine2;
+    #[cfg(feature = "std")]
+    pub use crate::svg::{FillRule, ParseError, SVG, arcline_from_svg_path, arcline_to_svg_path, svg};
 
     // Re-export distance computation functions
     pub use crate::distance::{
-        DistLineCircleConfig, DistPointArcConfig, DistSegmentCircleConfig, dist_arc_arc,
-        dist_line_circle, dist_point_arc, dist_point_arc_dist, dist_point_circle,
-        dist_point_segment, dist_segment_arc, dist_segment_circle, dist_segment_segment,
+        DistLineCircleConfig, DistPointArcConfig, DistSegmentCircleConfig, dist_arc_arc, convex_arc_closest_approach,
+        dist_circle_arc, dist_line_circle, dist_point_arc, dist_point_arc_dist, dist_point_circle,
+        dist_point_ellipse_arc, dist_point_line, dist_point_segment, dist_segment_arc, dist_segment_circle, dist_segment_segment,
     };
+    #[cfg(feature = "std")]
+    pub use crate::distance::{dist_point_arcline_hinted, dist_point_polyline, farthest_point_on_arcline};
 
     // Re-export intersection computation functions
     pub use crate::intersection::{
         ArcArcConfig, CircleCircleConfig, IntervalConfig, LineArcConfig, LineCircleConfig,
-        LineLineConfig, SegmentArcConfig, SegmentCircleConfig,
+        LineLineConfig, SegmentArcConfig, SegmentCircleConfig, SegmentRectConfig,
         SegmentSegmentConfig, if_really_intersecting_arc_arc, if_really_intersecting_segment_arc,
-        if_really_intersecting_segment_segment, int_arc_arc, int_circle_circle,
+        if_really_intersecting_segment_segment, int_arc_arc, int_circle_circle, int_circle_rect,
+        circle_contains_rect, rect_contains_circle,
         int_interval_interval, int_line_arc, int_line_circle, int_line_line, int_segment_arc,
-        int_segment_circle, int_segment_segment,
+        int_segment_circle, int_segment_rect, int_segment_segment, int_segment_segment_params, collinear_segment_overlap,
+        GeometryError, try_int_arc_arc, try_int_segment_arc, try_int_segment_segment,
     };
+    #[cfg(feature = "std")]
+    pub use crate::intersection::{arcline_intersects_segment, int_arc_halfplane};
 
     // Re-export utility functions
     pub use crate::utils::{
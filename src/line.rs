@@ -1,17 +1,20 @@
 #![allow(dead_code)]
 
 use crate::point::Point;
-use std::fmt::Display;
+#[cfg(feature = "std")]
+use crate::point::{Pointline, point};
+use core::fmt::Display;
 
 /// Line is defined by origin and direction
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     pub origin: Point,
     pub dir: Point,
 }
 
 impl Display for Line {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}]", self.origin, self.dir)
     }
 }
@@ -63,6 +66,66 @@ pub fn line(origin: Point, dir: Point) -> Line {
     Line::new(origin, dir)
 }
 
+/// Fits a line to a set of points by total least squares.
+///
+/// Unlike ordinary regression, which minimizes vertical offsets, the fit
+/// minimizes the sum of squared perpendicular distances of the points to the
+/// line, the distance measured by `dist_point_line`. The line passes through
+/// the centroid of the points along the principal axis of their covariance
+/// matrix, the eigenvector of its largest eigenvalue.
+///
+/// # Arguments
+///
+/// * `points` - The points to fit
+///
+/// # Returns
+///
+/// The fitted line through the centroid with a unit direction, or `None` for
+/// fewer than two distinct points. For points without a preferred direction,
+/// such as the corners of a square, the direction is along the x-axis.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let points = vec![point(0.0, 1.0), point(1.0, 2.0), point(2.0, 3.0)];
+/// let l = fit_line_to_points(&points).unwrap();
+/// assert_eq!(l.origin, point(1.0, 2.0));
+/// assert!(l.dir.close_enough(point(1.0, 1.0) / 2.0_f64.sqrt(), 1e-12));
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn fit_line_to_points(points: &Pointline) -> Option<Line> {
+    if points.is_empty() {
+        return None;
+    }
+    let n = points.len() as f64;
+    let (mut mx, mut my) = (0.0, 0.0);
+    for p in points {
+        mx += p.x;
+        my += p.y;
+    }
+    mx /= n;
+    my /= n;
+
+    // Covariance of the centered coordinates
+    let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+    for p in points {
+        let u = p.x - mx;
+        let v = p.y - my;
+        sxx += u * u;
+        syy += v * v;
+        sxy += u * v;
+    }
+    if sxx + syy == 0.0 {
+        return None;
+    }
+    // Angle of the principal eigenvector of [[sxx, sxy], [sxy, syy]]
+    let angle = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    Some(line(point(mx, my), point(angle.cos(), angle.sin())))
+}
+
 #[cfg(test)]
 mod test_line {
     use super::*;
@@ -99,6 +162,67 @@ mod test_line {
         assert_eq!(unit_output.dir, point(1.0, 0.0));
     }
 
+    #[test]
+    fn test_fit_line_to_points_exact() {
+        use crate::distance::dist_point_line;
+        // Points on y = -2x + 5, unordered and unevenly spaced
+        let points: Pointline = [3.0, -1.0, 0.5, 10.0, 2.0]
+            .iter()
+            .map(|&x| point(x, -2.0 * x + 5.0))
+            .collect();
+        let fit = fit_line_to_points(&points).unwrap();
+        assert!((fit.dir.norm() - 1.0).abs() < 1e-15);
+        assert!(fit.dir.perp(point(1.0, -2.0)).abs() < 1e-12);
+        for p in &points {
+            assert!(dist_point_line(p, &fit).0 < 1e-12);
+        }
+        // Vertical line, where ordinary regression fails
+        let vertical = vec![point(2.0, 0.0), point(2.0, 3.0), point(2.0, 1.0)];
+        let fit = fit_line_to_points(&vertical).unwrap();
+        assert_eq!(fit.origin, point(2.0, 4.0 / 3.0));
+        assert!(fit.dir.x.abs() < 1e-15 && (fit.dir.y.abs() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_fit_line_to_points_noisy() {
+        use crate::distance::dist_point_line;
+        // Points near y = 0.5x + 1, alternately offset perpendicular to it
+        let normal = point(-0.5, 1.0) / 1.25_f64.sqrt();
+        let points: Pointline = (0..20)
+            .map(|k| {
+                let x = k as f64;
+                let noise = 0.2 * ((k * 7 % 5) as f64 - 2.0) * if k % 2 == 0 { 1.0 } else { -1.0 };
+                point(x, 0.5 * x + 1.0) + normal * noise
+            })
+            .collect();
+        let fit = fit_line_to_points(&points).unwrap();
+        assert!((fit.dir.y / fit.dir.x - 0.5).abs() < 0.05);
+        assert!(dist_point_line(&point(0.0, 1.0), &fit).0 < 0.2);
+
+        let error = |l: &Line| -> f64 { points.iter().map(|p| dist_point_line(p, l).0.powi(2)).sum() };
+        // Best among nearby lines, the true line included
+        let best = error(&fit);
+        assert!(best <= error(&line(point(0.0, 1.0), point(1.0, 0.5)).unitdir()));
+        for angle in [-0.01, 0.01] {
+            let turned = point(
+                fit.dir.x * f64::cos(angle) - fit.dir.y * f64::sin(angle),
+                fit.dir.x * f64::sin(angle) + fit.dir.y * f64::cos(angle),
+            );
+            assert!(best < error(&line(fit.origin, turned)));
+            assert!(best < error(&line(fit.origin + point(0.0, angle), fit.dir)));
+        }
+    }
+
+    #[test]
+    fn test_fit_line_to_points_degenerate() {
+        assert_eq!(fit_line_to_points(&vec![]), None);
+        assert_eq!(fit_line_to_points(&vec![point(1.0, 2.0)]), None);
+        assert_eq!(fit_line_to_points(&vec![point(1.0, 2.0), point(1.0, 2.0)]), None);
+        // Two distinct points give the line through them
+        let fit = fit_line_to_points(&vec![point(1.0, 1.0), point(3.0, 1.0)]).unwrap();
+        assert_eq!(fit, line(point(2.0, 1.0), point(1.0, 0.0)));
+    }
+
     #[test]
     fn test_unitdir_zero_vector() {
         // Edge case: zero direction vector should handle gracefully
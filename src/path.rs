@@ -0,0 +1,213 @@
+#![allow(dead_code)]
+
+use crate::algo::{arcline_bounding_rect, arcline_centroid, arcline_contains};
+use crate::prelude::*;
+
+/// An arcline with an explicit closed flag.
+///
+/// A bare [`Arcline`] does not say whether the last element connects back to
+/// the first one, and the region functions (area, centroid, containment) assume
+/// it does. `Path` makes this explicit: region methods return `None` (or `false`)
+/// for open paths instead of silently closing them.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = vec![
+///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+///     arcseg(point(1.0, 0.0), point(0.0, 1.0)),
+///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+/// ];
+/// let closed = Path::closed(arcs.clone());
+/// assert_eq!(closed.area(), Some(0.5));
+///
+/// let open = Path::open(arcs);
+/// assert_eq!(open.area(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    /// Elements of the path, in traversal order.
+    pub arcs: Arcline,
+    /// True if the last element connects back to the first one.
+    pub closed: bool,
+}
+
+impl Path {
+    /// Creates a new path from an arcline and a closed flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `arcs` - The elements of the path
+    /// * `closed` - Whether the last element connects back to the first one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let p = Path::new(vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))], false);
+    /// assert!(!p.is_closed());
+    /// ```
+    #[inline]
+    pub fn new(arcs: Arcline, closed: bool) -> Self {
+        Path { arcs, closed }
+    }
+
+    /// Creates an open path.
+    #[inline]
+    pub fn open(arcs: Arcline) -> Self {
+        Path::new(arcs, false)
+    }
+
+    /// Creates a closed path.
+    #[inline]
+    pub fn closed(arcs: Arcline) -> Self {
+        Path::new(arcs, true)
+    }
+
+    /// Returns true if the path is closed.
+    #[inline]
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the area enclosed by a closed path, see [`arcline_area`].
+    ///
+    /// Returns `None` for open paths.
+    #[must_use]
+    pub fn area(&self) -> Option<f64> {
+        self.closed.then(|| arcline_area(&self.arcs))
+    }
+
+    /// Returns the centroid of the region enclosed by a closed path,
+    /// see [`arcline_centroid`].
+    ///
+    /// Returns `None` for open paths.
+    #[must_use]
+    pub fn centroid(&self) -> Option<Point> {
+        if self.closed {
+            arcline_centroid(&self.arcs)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the point is inside or on the boundary of the region enclosed
+    /// by a closed path, see [`arcline_contains`].
+    ///
+    /// Always returns `false` for open paths.
+    #[must_use]
+    pub fn contains(&self, p: Point) -> bool {
+        self.closed && arcline_contains(&self.arcs, p)
+    }
+
+    /// Returns the axis-aligned bounding rectangle of the path,
+    /// see [`arcline_bounding_rect`].
+    #[must_use]
+    pub fn bounding_rect(&self) -> Rect {
+        arcline_bounding_rect(&self.arcs)
+    }
+
+    /// Returns the path traversed in the opposite direction, see [`arcline_reverse`].
+    #[must_use]
+    pub fn reverse(&self) -> Path {
+        Path::new(arcline_reverse(&self.arcs), self.closed)
+    }
+
+    /// Converts a closed path into a [`Polygon`].
+    ///
+    /// Returns `None` for open paths.
+    #[must_use]
+    pub fn into_polygon(self) -> Option<Polygon> {
+        self.closed.then(|| polygon(self.arcs))
+    }
+}
+
+/// Creates a new path from an arcline and a closed flag.
+///
+/// This is a convenience function equivalent to `Path::new(arcs, closed)`.
+///
+/// # Arguments
+///
+/// * `arcs` - The elements of the path
+/// * `closed` - Whether the last element connects back to the first one
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let p = path(vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))], false);
+/// ```
+#[inline]
+pub fn path(arcs: Arcline, closed: bool) -> Path {
+    Path::new(arcs, closed)
+}
+
+#[cfg(test)]
+mod test_path {
+    use super::*;
+
+    fn square() -> Arcline {
+        vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Path::new(square(), true), Path::closed(square()));
+        assert_eq!(Path::new(square(), false), Path::open(square()));
+        assert_eq!(path(square(), true), Path::closed(square()));
+        assert!(Path::closed(square()).is_closed());
+        assert!(!Path::open(square()).is_closed());
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(Path::closed(square()).area(), Some(4.0));
+        assert_eq!(Path::open(square()).area(), None);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let c = Path::closed(square()).centroid().unwrap();
+        assert!(c.close_enough(point(1.0, 1.0), 1e-10), "got {}", c);
+        assert_eq!(Path::open(square()).centroid(), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(Path::closed(square()).contains(point(1.0, 1.0)));
+        assert!(!Path::closed(square()).contains(point(3.0, 1.0)));
+        assert!(!Path::open(square()).contains(point(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let open = Path::open(square());
+        assert_eq!(open.bounding_rect(), rect(point(0.0, 0.0), point(2.0, 2.0)));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let open = Path::open(square());
+        let reversed = open.reverse();
+        assert!(!reversed.is_closed());
+        assert_eq!(reversed.arcs.len(), 4);
+        assert_eq!(reversed.arcs[0].a, point(0.0, 0.0));
+        assert_eq!(reversed.arcs[0].b, point(0.0, 2.0));
+    }
+
+    #[test]
+    fn test_into_polygon() {
+        assert_eq!(Path::closed(square()).into_polygon(), Some(polygon(square())));
+        assert_eq!(Path::open(square()).into_polygon(), None);
+    }
+}
@@ -3,11 +3,14 @@
 
 use robust::{Coord, orient2d};
 
+use crate::line::Line;
 pub use crate::utils::almost_equal_as_int;
 use crate::utils::{diff_of_prod, sum_of_prod};
-use std::fmt::Display;
-use std::ops;
-use std::ops::{Div, Mul, Neg};
+use core::fmt::Display;
+use core::ops;
+use core::ops::{Div, Mul, Neg};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
 
 const ZERO: f64 = 0f64;
 
@@ -26,6 +29,7 @@ const ZERO: f64 = 0f64;
 /// let sum = p1 + p2; // Point arithmetic is supported
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// X coordinate
     pub x: f64,
@@ -33,6 +37,7 @@ pub struct Point {
     pub y: f64,
 }
 
+#[cfg(feature = "std")]
 pub type Pointline = Vec<Point>;
 
 impl Point {
@@ -130,7 +135,7 @@ pub fn points_order(a: Point, b: Point, p: Point) -> f64 {
 }
 
 impl Display for Point {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{:.20}, {:.20}]", self.x, self.y)
     }
 }
@@ -430,6 +435,31 @@ impl Point {
         self + (other - self) * t
     }
 
+    /// Reflects the point across an infinite line.
+    ///
+    /// The image is on the perpendicular from the point to the line, at the same
+    /// distance on the other side. For a line with a zero direction the point
+    /// is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let l = line(point(2.0, 0.0), point(0.0, 1.0));
+    /// assert_eq!(point(5.0, 1.0).mirror(&l), point(-1.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn mirror(&self, l: &Line) -> Point {
+        let sqr_length = l.dir.dot(l.dir);
+        if sqr_length == 0.0 {
+            return *self;
+        }
+        let t = (*self - l.origin).dot(l.dir) / sqr_length;
+        let foot = l.origin + l.dir * t;
+        foot * 2.0 - *self
+    }
+
     /// Sorts four collinear points.
     ///
     /// This function sorts four points that are expected to be collinear,
@@ -1154,6 +1184,37 @@ mod test_point {
     }
 }
 
+#[cfg(test)]
+mod test_mirror {
+    use super::*;
+    use crate::line::line;
+
+    #[test]
+    fn test_mirror_axis_aligned() {
+        let vertical = line(point(1.0, 5.0), point(0.0, -2.0));
+        assert_eq!(point(4.0, 2.0).mirror(&vertical), point(-2.0, 2.0));
+        let horizontal = line(point(0.0, -1.0), point(3.0, 0.0));
+        assert_eq!(point(4.0, 2.0).mirror(&horizontal), point(4.0, -4.0));
+    }
+
+    #[test]
+    fn test_mirror_diagonal_and_on_line() {
+        let diagonal = line(point(0.0, 0.0), point(1.0, 1.0));
+        assert!(point(3.0, 1.0).mirror(&diagonal).close_enough(point(1.0, 3.0), 1e-12));
+        // Points on the line are fixed, mirroring twice is the identity
+        assert_eq!(point(2.0, 2.0).mirror(&diagonal), point(2.0, 2.0));
+        let p = point(-1.5, 0.25);
+        let tilted = line(point(1.0, 2.0), point(2.0, -1.0));
+        assert!(p.mirror(&tilted).mirror(&tilted).close_enough(p, 1e-12));
+    }
+
+    #[test]
+    fn test_mirror_zero_direction() {
+        let degenerate = line(point(1.0, 1.0), point(0.0, 0.0));
+        assert_eq!(point(3.0, 4.0).mirror(&degenerate), point(3.0, 4.0));
+    }
+}
+
 #[cfg(test)]
 mod test_normalize {
     use crate::point::point;
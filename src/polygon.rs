@@ -0,0 +1,167 @@
+#![allow(dead_code)]
+
+use crate::algo::{arcline_bounding_rect, arcline_centroid, arcline_contains};
+use crate::prelude::*;
+
+/// A closed region bounded by an arcline.
+///
+/// `Polygon` is a thin wrapper around a closed [`Arcline`] that exposes the
+/// region operations as methods. Each method delegates to the corresponding
+/// free function, which remains available.
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Unit disc made of two half-circle arcs
+/// let disc = polygon(vec![
+///     arc(point(1.0, 0.0), point(-1.0, 0.0), point(0.0, 0.0), 1.0),
+///     arc(point(-1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0),
+/// ]);
+/// assert!((disc.area() - std::f64::consts::PI).abs() < 1e-10);
+/// assert!(disc.contains(point(0.5, 0.5)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    /// Closed boundary of the region (CCW).
+    pub arcs: Arcline,
+}
+
+impl Polygon {
+    /// Creates a new polygon from a closed arcline.
+    ///
+    /// # Arguments
+    ///
+    /// * `arcs` - The closed arcline bounding the region
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+    ///     arcseg(point(1.0, 0.0), point(0.0, 1.0)),
+    ///     arcseg(point(0.0, 1.0), point(0.0, 0.0)),
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn new(arcs: Arcline) -> Self {
+        Polygon { arcs }
+    }
+
+    /// Returns the area of the region, see [`arcline_area`].
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        arcline_area(&self.arcs)
+    }
+
+    /// Returns the centroid of the region, see [`arcline_centroid`].
+    #[must_use]
+    pub fn centroid(&self) -> Option<Point> {
+        arcline_centroid(&self.arcs)
+    }
+
+    /// Checks if the point is inside or on the boundary of the region,
+    /// see [`arcline_contains`].
+    #[must_use]
+    pub fn contains(&self, p: Point) -> bool {
+        arcline_contains(&self.arcs, p)
+    }
+
+    /// Returns the axis-aligned bounding rectangle of the region,
+    /// see [`arcline_bounding_rect`].
+    #[must_use]
+    pub fn bounding_rect(&self) -> Rect {
+        arcline_bounding_rect(&self.arcs)
+    }
+
+    /// Returns true if the boundary is a valid arcline.
+    ///
+    /// Use [`arcline_is_valid`] to get the reason for invalidity.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        arcline_is_valid(&self.arcs) == ArclineValidation::Valid
+    }
+}
+
+/// Creates a new polygon from a closed arcline.
+///
+/// This is a convenience function equivalent to `Polygon::new(arcs)`.
+///
+/// # Arguments
+///
+/// * `arcs` - The closed arcline bounding the region
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let disc = polygon(vec![arc(point(1.0, 0.0), point(1.0, 0.0), point(0.0, 0.0), 1.0)]);
+/// ```
+#[inline]
+pub fn polygon(arcs: Arcline) -> Polygon {
+    Polygon::new(arcs)
+}
+
+#[cfg(test)]
+mod test_polygon {
+    use super::*;
+    use std::f64::consts::PI;
+
+    // Disc of radius 2 centered at (1, 1), made of four quarter arcs
+    fn disc_of_arcs() -> Polygon {
+        let c = point(1.0, 1.0);
+        polygon(vec![
+            arc(point(3.0, 1.0), point(1.0, 3.0), c, 2.0),
+            arc(point(1.0, 3.0), point(-1.0, 1.0), c, 2.0),
+            arc(point(-1.0, 1.0), point(1.0, -1.0), c, 2.0),
+            arc(point(1.0, -1.0), point(3.0, 1.0), c, 2.0),
+        ])
+    }
+
+    #[test]
+    fn test_new() {
+        let arcs = disc_of_arcs().arcs;
+        assert_eq!(Polygon::new(arcs.clone()), polygon(arcs));
+    }
+
+    #[test]
+    fn test_area() {
+        let p = disc_of_arcs();
+        assert!((p.area() - 4.0 * PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let p = disc_of_arcs();
+        let c = p.centroid().unwrap();
+        assert!(c.close_enough(point(1.0, 1.0), 1e-10), "got {}", c);
+    }
+
+    #[test]
+    fn test_contains() {
+        let p = disc_of_arcs();
+        assert!(p.contains(point(1.0, 1.0)));
+        assert!(p.contains(point(2.4, 2.4)));
+        assert!(p.contains(point(3.0, 1.0))); // on boundary
+        assert!(!p.contains(point(2.5, 2.5)));
+        assert!(!p.contains(point(-5.0, 1.0)));
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let p = disc_of_arcs();
+        assert_eq!(p.bounding_rect(), rect(point(-1.0, -1.0), point(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let p = disc_of_arcs();
+        assert!(p.is_valid());
+        let open = polygon(vec![arcseg(point(0.0, 0.0), point(1.0, 0.0))]);
+        assert!(!open.is_valid());
+    }
+}
@@ -38,6 +38,7 @@ use crate::prelude::*;
 /// let semicircle = pvertex(point(0.0, 0.0), 1.0); // tan(180°/4) = tan(45°) = 1.0
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PVertex {
     /// Starting point of the arc or line.
     pub p: Point,
@@ -102,8 +103,11 @@ pub type Polyline = Vec<PVertex>;
 /// Reverses the direction of a polyline.
 ///
 /// This function creates a new polyline that traces the same path but in
-/// the opposite direction. The bulge values are negated to maintain the
-/// same geometric shape while reversing the orientation.
+/// the opposite direction. The bulge of a vertex belongs to the segment that
+/// starts at it, so each bulge moves to the vertex at the other end of its
+/// segment and is negated, since the arc is now traversed the other way.
+/// The bulge of the last vertex, used by the closing segment of a closed
+/// polyline, stays with the closing segment.
 ///
 /// # Arguments
 ///
@@ -356,6 +360,54 @@ mod test_pvertex {
         assert_eq!(reversed, vec![]);
     }
 
+    // Arcline of a polyline, the last vertex bulge closes the path when `closed`
+    fn to_arcline(poly: &Polyline, closed: bool) -> Arcline {
+        let n = poly.len();
+        let count = if closed { n } else { n - 1 };
+        (0..count)
+            .map(|i| arc_from_bulge(poly[i].p, poly[(i + 1) % n].p, poly[i].b))
+            .collect()
+    }
+
+    fn same_elements(a: &Arcline, b: &Arcline) -> bool {
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(x, y)| {
+                x.a.close_enough(y.a, 1e-12)
+                    && x.b.close_enough(y.b, 1e-12)
+                    && (x.is_seg() && y.is_seg() || x.c.close_enough(y.c, 1e-12) && (x.r - y.r).abs() < 1e-12)
+            })
+    }
+
+    #[test]
+    fn test_polyline_reverse_matches_arcline_reverse() {
+        let poly = vec![
+            pvertex(point(0.0, 0.0), 0.0),
+            pvertex(point(4.0, 0.0), 0.4),
+            pvertex(point(4.0, 3.0), -0.25),
+            pvertex(point(1.0, 3.0), 0.0),
+            pvertex(point(0.0, 2.0), 1.0),
+        ];
+        for closed in [false, true] {
+            let reversed = to_arcline(&polyline_reverse(&poly), closed);
+            let mut expected = arcline_reverse(&to_arcline(&poly, closed));
+            if closed {
+                // The reversed polyline starts at the last vertex, not at the closing segment
+                expected.rotate_left(1);
+            }
+            assert!(same_elements(&reversed, &expected), "closed = {}", closed);
+        }
+    }
+
+    #[test]
+    fn test_polyline_reverse_twice_is_identity() {
+        let poly = vec![
+            pvertex(point(0.0, 0.0), 0.3),
+            pvertex(point(2.0, 0.0), -0.6),
+            pvertex(point(2.0, 2.0), 0.1),
+        ];
+        assert_eq!(polyline_reverse(&polyline_reverse(&poly)), poly);
+    }
+
     #[test]
     fn test_polyline_reverse_all_zero_bulges() {
         // Polyline with all zero bulges
@@ -371,3 +423,360 @@ mod test_pvertex {
         }
     }
 }
+
+// Bulge of a run of points fitting a line or a circular arc within `tolerance`.
+fn fit_run_bulge(run: &[Point], tolerance: f64) -> Option<f64> {
+    let first = run[0];
+    let last = run[run.len() - 1];
+    let inner = &run[1..run.len() - 1];
+
+    let chord = segment(first, last);
+    if inner
+        .iter()
+        .all(|p| dist_point_segment(p, &chord).0 <= tolerance)
+    {
+        return Some(0.0);
+    }
+
+    // Any three points are on a circle, an arc needs more evidence
+    if run.len() < 4 {
+        return None;
+    }
+    let fit = fit_circle_to_points(run)?;
+    let mid = run[run.len() / 2];
+    let side = points_order(first, mid, last);
+    let angle = |p: Point| (p.y - fit.c.y).atan2(p.x - fit.c.x);
+    let (from, to) = if side > 0.0 { (first, last) } else { (last, first) };
+    let mut sweep = angle(to) - angle(from);
+    if sweep <= 0.0 {
+        sweep += 2.0 * std::f64::consts::PI;
+    }
+    // Nearly closed runs are not representable by a bulge
+    if sweep > 1.9 * std::f64::consts::PI {
+        return None;
+    }
+    let bulge = (sweep / 4.0).tan() * side.signum();
+
+    // Check the residual against the arc actually encoded by the bulge
+    let arc = arc_from_bulge(first, last, bulge);
+    if arc.is_seg() {
+        return None;
+    }
+    inner
+        .iter()
+        .all(|p| dist_point_arc_dist(p, &arc) <= tolerance)
+        .then_some(bulge)
+}
+
+/// Converts densely sampled points into a compact polyline with bulges.
+///
+/// Consecutive points are grouped greedily: a run is extended while its points
+/// stay within `tolerance` of a straight chord or of a circular arc fitted with
+/// [`fit_circle_to_points`]. Arcs need at least four points, since any three points
+/// lie on a circle. Each run becomes one polyline element, with bulge 0 for
+/// straight runs. Runs are not extended past the first point that breaks the fit.
+///
+/// The points are treated as an open path, the last vertex gets bulge 0.
+///
+/// # Arguments
+///
+/// * `points` - The sampled points, in path order
+/// * `tolerance` - The maximum distance of a sample from its fitted element
+///
+/// # Returns
+///
+/// The polyline, with vertices at the first point of every run and at the last point
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Half circle from (1, 0) to (-1, 0), CCW
+/// let points: Vec<Point> = (0..=8)
+///     .map(|k| {
+///         let t = std::f64::consts::PI * k as f64 / 8.0;
+///         point(t.cos(), t.sin())
+///     })
+///     .collect();
+/// let poly = fit_bulges(&points, 1e-9);
+/// assert_eq!(poly.len(), 2);
+/// assert!((poly[0].b - 1.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn fit_bulges(points: &Pointline, tolerance: f64) -> Polyline {
+    let n = points.len();
+    let mut res = Polyline::new();
+    if n == 0 {
+        return res;
+    }
+    let mut i = 0;
+    while i + 1 < n {
+        let mut end = i + 1;
+        let mut bulge = 0.0;
+        for j in (i + 2)..n {
+            match fit_run_bulge(&points[i..=j], tolerance) {
+                Some(b) => {
+                    end = j;
+                    bulge = b;
+                }
+                // A run of three may still extend into an arc
+                None if j > i + 2 => break,
+                None => {}
+            }
+        }
+        res.push(pvertex(points[i], bulge));
+        i = end;
+    }
+    res.push(pvertex(points[n - 1], 0.0));
+    res
+}
+
+#[cfg(test)]
+mod test_fit_bulges {
+    use super::*;
+    use std::f64::consts::PI;
+
+    // Straight edge, CCW half circle and straight edge back, 19 samples
+    fn sampled_path() -> Pointline {
+        let mut points = Vec::new();
+        for k in 0..4 {
+            points.push(point(k as f64, 0.0));
+        }
+        for k in 0..8 {
+            let t = -PI / 2.0 + PI * k as f64 / 8.0;
+            points.push(point(4.0 + t.cos(), 1.0 + t.sin()));
+        }
+        for k in 0..5 {
+            points.push(point(4.0 - k as f64, 2.0));
+        }
+        points
+    }
+
+    #[test]
+    fn test_fit_bulges_arc_and_segments() {
+        let poly = fit_bulges(&sampled_path(), 1e-9);
+        assert_eq!(poly.len(), 4);
+        assert_eq!(poly[0], pvertex(point(0.0, 0.0), 0.0));
+        assert_eq!(poly[1].p, point(4.0, 0.0));
+        assert!((poly[1].b - 1.0).abs() < 1e-9);
+        assert!(poly[2].p.close_enough(point(4.0, 2.0), 1e-12));
+        assert_eq!(poly[2].b, 0.0);
+        assert_eq!(poly[3], pvertex(point(0.0, 2.0), 0.0));
+    }
+
+    #[test]
+    fn test_fit_bulges_cw_arc() {
+        // Quarter circle traversed CW gets a negative bulge
+        let points: Vec<Point> = (0..=6)
+            .map(|k| {
+                let t = PI / 2.0 - PI / 2.0 * k as f64 / 6.0;
+                point(2.0 * t.cos(), 2.0 * t.sin())
+            })
+            .collect();
+        let poly = fit_bulges(&points, 1e-9);
+        assert_eq!(poly.len(), 2);
+        assert!((poly[0].b + (PI / 8.0).tan()).abs() < 1e-9);
+        let arc = arc_from_bulge(poly[0].p, poly[1].p, poly[0].b);
+        assert!(arc.c.close_enough(point(0.0, 0.0), 1e-9));
+    }
+
+    #[test]
+    fn test_fit_bulges_noise_falls_back_to_segments() {
+        let points = vec![
+            point(0.0, 0.0),
+            point(1.0, 0.3),
+            point(2.0, -0.2),
+            point(3.0, 0.4),
+        ];
+        let poly = fit_bulges(&points, 1e-6);
+        assert_eq!(poly.len(), 4);
+        assert!(poly.iter().all(|v| v.b == 0.0));
+    }
+
+    #[test]
+    fn test_fit_bulges_small_input() {
+        assert!(fit_bulges(&vec![], 1e-9).is_empty());
+        let single = fit_bulges(&vec![point(1.0, 1.0)], 1e-9);
+        assert_eq!(single, vec![pvertex(point(1.0, 1.0), 0.0)]);
+    }
+}
+
+/// Parses a single DXF `LWPOLYLINE` entity into a polyline.
+///
+/// The section is read as DXF group code / value line pairs. Group code `10`
+/// starts a vertex with its x coordinate, `20` sets its y coordinate and `42`
+/// its bulge. Bit 1 of the flags (group code `70`) marks the polyline as closed;
+/// for an open polyline the bulge of the last vertex is reset to `0.0`, since
+/// there is no element leaving it. Other group codes are ignored, and a
+/// following entity (group code `0`) ends the section.
+///
+/// # Arguments
+///
+/// * `section` - The DXF text of the entity, optionally starting with `0` / `LWPOLYLINE`
+///
+/// # Returns
+///
+/// The polyline, or an error describing the first malformed group
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Closed half disc: a segment and a CCW half circle
+/// let section = "0\nLWPOLYLINE\n90\n2\n70\n1\n10\n0.0\n20\n0.0\n10\n2.0\n20\n0.0\n42\n1.0\n";
+/// let poly = dxf_lwpolyline_to_polyline(section).unwrap();
+/// assert_eq!(poly, vec![pvertex(point(0.0, 0.0), 0.0), pvertex(point(2.0, 0.0), 1.0)]);
+/// ```
+pub fn dxf_lwpolyline_to_polyline(section: &str) -> Result<Polyline, String> {
+    let lines: Vec<&str> = section.lines().map(str::trim).collect();
+    let mut poly = Polyline::new();
+    let mut closed = false;
+    // Whether the last vertex has its y coordinate
+    let mut has_y = true;
+    let mut started = false;
+    for (pair, group) in lines.chunks(2).enumerate() {
+        if group.len() < 2 {
+            if group[0].is_empty() {
+                break;
+            }
+            return Err(format!("group {}: missing value for code {}", pair, group[0]));
+        }
+        let code: i32 = group[0]
+            .parse()
+            .map_err(|_| format!("group {}: invalid group code '{}'", pair, group[0]))?;
+        let value = group[1];
+        let number = || {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| format!("group {}: invalid number '{}'", pair, value))
+        };
+        match code {
+            0 if !started && poly.is_empty() => {
+                if value != "LWPOLYLINE" {
+                    return Err(format!("group {}: expected LWPOLYLINE, found {}", pair, value));
+                }
+                started = true;
+            }
+            // Next entity
+            0 => break,
+            10 => {
+                if !has_y {
+                    return Err(format!("group {}: vertex {} has no y coordinate", pair, poly.len() - 1));
+                }
+                poly.push(pvertex(point(number()?, 0.0), 0.0));
+                has_y = false;
+            }
+            20 | 42 => {
+                let v = number()?;
+                let Some(last) = poly.last_mut() else {
+                    return Err(format!("group {}: code {} before the first vertex", pair, code));
+                };
+                if code == 20 {
+                    last.p.y = v;
+                    has_y = true;
+                } else {
+                    last.b = v;
+                }
+            }
+            70 => {
+                let flags: i32 = value
+                    .parse()
+                    .map_err(|_| format!("group {}: invalid flags '{}'", pair, value))?;
+                closed = flags & 1 != 0;
+            }
+            _ => {}
+        }
+    }
+    if !has_y {
+        return Err(format!("vertex {} has no y coordinate", poly.len() - 1));
+    }
+    if poly.is_empty() {
+        return Err("no vertices".to_string());
+    }
+    if !closed && let Some(last) = poly.last_mut() {
+        last.b = 0.0;
+    }
+    Ok(poly)
+}
+
+#[cfg(test)]
+mod test_dxf_lwpolyline_to_polyline {
+    use super::*;
+
+    // Writes a polyline the way DXF writers emit LWPOLYLINE entities
+    fn write_lwpolyline(poly: &Polyline, closed: bool) -> String {
+        let mut out = String::new();
+        let mut group = |code: i32, value: String| {
+            out.push_str(&format!("{:>3}\n{}\n", code, value));
+        };
+        group(0, "LWPOLYLINE".to_string());
+        group(5, "2F".to_string());
+        group(100, "AcDbEntity".to_string());
+        group(8, "0".to_string());
+        group(100, "AcDbPolyline".to_string());
+        group(90, poly.len().to_string());
+        group(70, if closed { "1" } else { "0" }.to_string());
+        group(43, "0.0".to_string());
+        for v in poly {
+            group(10, format!("{:?}", v.p.x));
+            group(20, format!("{:?}", v.p.y));
+            if v.b != 0.0 {
+                group(42, format!("{:?}", v.b));
+            }
+        }
+        group(0, "ENDSEC".to_string());
+        out
+    }
+
+    #[test]
+    fn test_dxf_round_trip_closed() {
+        // Rounded slot: two segments and two half circles, plus a CW bulge
+        let poly = vec![
+            pvertex(point(0.0, 0.0), 0.0),
+            pvertex(point(4.0, 0.0), 1.0),
+            pvertex(point(4.0, 2.0), 0.0),
+            pvertex(point(0.0, 2.0), -0.41421356237309503),
+            pvertex(point(-0.1, 1.0), 1.0 / 3.0),
+        ];
+        let text = write_lwpolyline(&poly, true);
+        assert_eq!(dxf_lwpolyline_to_polyline(&text), Ok(poly));
+    }
+
+    #[test]
+    fn test_dxf_round_trip_open() {
+        let poly = vec![
+            pvertex(point(1.5, -2.25), 0.5),
+            pvertex(point(3.0, 1e-3), 0.0),
+            pvertex(point(7.0, 4.0), 0.0),
+        ];
+        let text = write_lwpolyline(&poly, false);
+        assert_eq!(dxf_lwpolyline_to_polyline(&text), Ok(poly));
+        // A bulge stored on the last vertex of an open polyline is dropped
+        let text = text.replace(" 20\n4.0\n", " 20\n4.0\n 42\n1.0\n");
+        assert_eq!(dxf_lwpolyline_to_polyline(&text).unwrap()[2].b, 0.0);
+    }
+
+    #[test]
+    fn test_dxf_crlf_and_without_entity_header() {
+        let text = " 70\r\n1\r\n 10\r\n0\r\n 20\r\n0\r\n 10\r\n1\r\n 20\r\n0\r\n 42\r\n-1\r\n";
+        assert_eq!(
+            dxf_lwpolyline_to_polyline(text),
+            Ok(vec![pvertex(point(0.0, 0.0), 0.0), pvertex(point(1.0, 0.0), -1.0)])
+        );
+    }
+
+    #[test]
+    fn test_dxf_errors() {
+        assert!(dxf_lwpolyline_to_polyline("").is_err());
+        assert!(dxf_lwpolyline_to_polyline("0\nLINE\n10\n0\n20\n0\n").is_err());
+        assert!(dxf_lwpolyline_to_polyline("10\n0\n20\nabc\n").is_err());
+        assert!(dxf_lwpolyline_to_polyline("10\n0\n10\n1\n20\n0\n").is_err());
+        assert!(dxf_lwpolyline_to_polyline("42\n1\n10\n0\n20\n0\n").is_err());
+        assert!(dxf_lwpolyline_to_polyline("x\n1\n").is_err());
+        assert!(dxf_lwpolyline_to_polyline("10\n0\n20\n").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use crate::point::Point;
+use core::fmt::Display;
+
+/// Ray is defined by origin and direction, it extends from the origin only
+/// in the direction `dir`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub dir: Point,
+}
+
+impl Display for Ray {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}, {}]", self.origin, self.dir)
+    }
+}
+
+impl Ray {
+    /// Creates a new ray with the specified origin and direction.
+    ///
+    /// # Arguments
+    /// * `origin` - Origin point of the ray
+    /// * `dir` - Direction vector of the ray
+    ///
+    /// # Examples
+    /// ```
+    /// use togo::prelude::*;
+    /// let ray = Ray::new(point(1.0, 2.0), point(1.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn new(origin: Point, dir: Point) -> Self {
+        Ray { origin, dir }
+    }
+}
+
+/// Creates a new ray with the specified origin and direction.
+///
+/// This is a convenience function equivalent to `Ray::new(origin, dir)`.
+#[inline]
+#[must_use]
+pub fn ray(origin: Point, dir: Point) -> Ray {
+    Ray::new(origin, dir)
+}
+
+#[cfg(test)]
+mod test_ray {
+    use super::*;
+    use crate::point::point;
+
+    #[test]
+    fn test_new() {
+        let r0 = Ray::new(point(1.0, 2.0), point(3.0, 4.0));
+        let r1 = ray(point(1.0, 2.0), point(3.0, 4.0));
+        assert_eq!(r0, r1);
+    }
+
+    #[test]
+    fn test_display() {
+        let r0 = Ray::new(point(1.0, 2.0), point(3.0, 4.0));
+        assert_eq!(
+            "[[1.00000000000000000000, 2.00000000000000000000], [3.00000000000000000000, 4.00000000000000000000]]",
+            format!("{}", r0)
+        );
+    }
+}
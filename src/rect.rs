@@ -1,11 +1,12 @@
 #![allow(dead_code)]
 
 use crate::point::Point;
-use std::fmt::Display;
+use core::fmt::Display;
 
 /// A rectangle defined by a left-bottom point and right-top point.
 ///
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     /// Left-bottom point of the rectangle
     pub p1: Point,
@@ -14,7 +15,7 @@ pub struct Rect {
 }
 
 impl Display for Rect {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}]", self.p1, self.p2)
     }
 }
@@ -1,5 +1,5 @@
-use std::fmt::Display;
-use std::sync::atomic::AtomicUsize;
+use core::fmt::Display;
+use core::sync::atomic::AtomicUsize;
 
 use crate::point::Point;
 
@@ -19,16 +19,18 @@ static ID_COUNT: AtomicUsize = AtomicUsize::new(0);
 /// let seg = Segment::new(point(0.0, 0.0), point(3.0, 4.0));
 /// ```
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Segment {
     pub a: Point,
     pub b: Point,
     /// non-unique id, used for debugging and
     /// checking parts coming from the same segment
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub id: usize,
 }
 
 impl Display for Segment {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}]", self.a, self.b)
     }
 }
@@ -57,7 +59,7 @@ impl Segment {
     /// ```
     #[inline]
     pub fn new(a: Point, b: Point) -> Self {
-        let id = ID_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let id = ID_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
         Segment { a, b, id }
     }
 
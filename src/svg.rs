@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use std::fmt::Write as _;
+use std::fmt::{Display, Write as _};
 
 // Draw geometric elements for debug purposes
 
@@ -10,6 +10,7 @@ use std::{fs::File, io::Write};
 
 use robust::{Coord, orient2d};
 
+use crate::constants::GEOMETRIC_EPSILON;
 use crate::prelude::*;
 
 /// Utility for generating SVG output for visualization of geometric operations.
@@ -94,6 +95,383 @@ pub fn svg(xsize: f64, ysize: f64) -> SVG {
     SVG::new(xsize, ysize, None)
 }
 
+/// Rule deciding which points are inside a filled shape, the SVG `fill-rule`.
+///
+/// Both rules use the winding number of the boundary around a point, the sum
+/// over all rings of the times each ring goes around it, positive for CCW rings.
+/// They differ for points enclosed by several rings: a hole given as a ring with
+/// the same orientation as the outer ring has winding number 2 and is filled
+/// with `NonZero`, while `EvenOdd` leaves it empty. Holes with the opposite
+/// orientation, winding number 0, are empty with both rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside where the winding number is not zero, the SVG default.
+    NonZero,
+    /// Inside where the winding number is odd.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Checks if a point with the given winding number is filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// assert!(FillRule::NonZero.is_inside(2));
+    /// assert!(!FillRule::EvenOdd.is_inside(2));
+    /// assert!(FillRule::EvenOdd.is_inside(-1));
+    /// ```
+    #[must_use]
+    pub fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the value of the SVG `fill-rule` attribute.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FillRule::NonZero => "nonzero",
+            FillRule::EvenOdd => "evenodd",
+        }
+    }
+}
+
+/// Converts an arcline to SVG path data, the `d` attribute of a `<path>`.
+///
+/// Line segments become `L` commands and arcs become `A` (elliptical arc)
+/// commands with equal radii. The path starts with `M` at the first start
+/// point, and a new `M` is emitted wherever an element does not start at the
+/// end of the previous one. Full circles are written as two half arcs, since
+/// an SVG arc with equal end points is not drawn.
+///
+/// Coordinates are written unchanged, with the y axis up: arcs are CCW, that
+/// is in the direction of increasing angle, so their `sweep-flag` is 1. Arcs
+/// traversed backward, like the concave arcs of a closed shape which start at
+/// the end of the previous element, are written from `b` to `a` with
+/// `sweep-flag` 0, so that rings stay connected. The
+/// `large-arc-flag` is set for arcs spanning more than a half circle. To
+/// display the path in the y-down SVG viewport, flip it, for example with
+/// `transform="scale(1, -1)"`.
+///
+/// # Arguments
+///
+/// * `arcs` - The arcline to convert
+///
+/// # Returns
+///
+/// The SVG path data, empty for an empty arcline
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let quarter = vec![arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0)];
+/// assert_eq!(arcline_to_svg_path(&quarter), "M 1 0 A 1 1 0 0 1 0 1");
+/// ```
+#[must_use]
+pub fn arcline_to_svg_path(arcs: &Arcline) -> String {
+    let mut d = String::new();
+    let mut current: Option<Point> = None;
+    for a in arcs {
+        let backward = a.is_arc()
+            && !a.is_full_circle()
+            && match current {
+                Some(p) => p == a.b,
+                // The first arc is backward when the next element continues from its start
+                None => arcs.get(1).is_some_and(|next| {
+                    let touches = |p: Point| p == next.a || (next.is_arc() && p == next.b);
+                    touches(a.a) && !touches(a.b)
+                }),
+            };
+        let (from, to) = if backward { (a.b, a.a) } else { (a.a, a.b) };
+        if current != Some(from) {
+            write!(&mut d, " M {} {}", from.x, from.y).unwrap();
+        }
+        if a.is_seg() {
+            write!(&mut d, " L {} {}", a.b.x, a.b.y).unwrap();
+        } else if a.is_full_circle() {
+            let opposite = a.c * 2.0 - a.a;
+            write!(&mut d, " A {} {} 0 0 1 {} {}", a.r, a.r, opposite.x, opposite.y).unwrap();
+            write!(&mut d, " A {} {} 0 0 1 {} {}", a.r, a.r, a.b.x, a.b.y).unwrap();
+        } else {
+            let pa = Coord { x: a.a.x, y: a.a.y };
+            let pb = Coord { x: a.b.x, y: a.b.y };
+            let pc = Coord { x: a.c.x, y: a.c.y };
+            // The center is right of the chord for arcs longer than a half circle
+            let large_arc_flag: i32 = (orient2d(pa, pb, pc) < 0.0).into();
+            let sweep_flag: i32 = (!backward).into();
+            write!(
+                &mut d,
+                " A {} {} 0 {} {} {} {}",
+                a.r, a.r, large_arc_flag, sweep_flag, to.x, to.y
+            )
+            .unwrap();
+        }
+        current = Some(to);
+    }
+    d.trim_start().to_string()
+}
+
+/// Error returned by [`arcline_from_svg_path`] for path data it cannot convert.
+///
+/// Positions are byte offsets into the path data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// A character that does not start a command or a number where one is expected,
+    /// including path data not starting with a move command.
+    UnexpectedToken { position: usize, found: char },
+    /// The path data ends in the middle of a command.
+    UnexpectedEnd,
+    /// A valid SVG command that arclines cannot represent, like the Bézier curves `C` and `Q`.
+    UnsupportedCommand { position: usize, command: char },
+    /// A number not following the SVG number syntax, or not finite.
+    MalformedNumber { position: usize },
+    /// An arc command with different radii, that is an elliptical arc.
+    EllipticalArc { position: usize, rx: f64, ry: f64 },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { position, found } => {
+                write!(f, "unexpected '{}' at {}", found, position)
+            }
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of path data"),
+            ParseError::UnsupportedCommand { position, command } => {
+                write!(f, "unsupported command '{}' at {}", command, position)
+            }
+            ParseError::MalformedNumber { position } => write!(f, "malformed number at {}", position),
+            ParseError::EllipticalArc { position, rx, ry } => write!(
+                f,
+                "elliptical arc with radii {} and {} at {}, only circular arcs are supported",
+                rx, ry, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Reads numbers and flags from SVG path data
+struct PathLexer<'a> {
+    d: &'a str,
+    pos: usize,
+}
+
+impl PathLexer<'_> {
+    fn skip_separators(&mut self) {
+        let rest = &self.d[self.pos..];
+        let trimmed = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        self.pos += rest.len() - trimmed.len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.d[self.pos..].chars().next()
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(found) => ParseError::UnexpectedToken { position: self.pos, found },
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    fn at_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.peek(), Some(c) if c.is_ascii_digit() || matches!(c, '+' | '-' | '.'))
+    }
+
+    fn number(&mut self) -> Result<f64, ParseError> {
+        if !self.at_number() {
+            return Err(self.unexpected());
+        }
+        let start = self.pos;
+        let bytes = self.d.as_bytes();
+        let digits = |mut i: usize| {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            i
+        };
+        let mut end = start;
+        if matches!(bytes[end], b'+' | b'-') {
+            end += 1;
+        }
+        end = digits(end);
+        if end < bytes.len() && bytes[end] == b'.' {
+            end = digits(end + 1);
+        }
+        if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            end += 1;
+            if end < bytes.len() && matches!(bytes[end], b'+' | b'-') {
+                end += 1;
+            }
+            end = digits(end);
+        }
+        self.pos = end;
+        match self.d[start..end].parse::<f64>() {
+            Ok(x) if x.is_finite() => Ok(x),
+            _ => Err(ParseError::MalformedNumber { position: start }),
+        }
+    }
+
+    fn point(&mut self) -> Result<Point, ParseError> {
+        Ok(point(self.number()?, self.number()?))
+    }
+
+    // Arc flags are single digits, possibly not separated from what follows
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separators();
+        match self.peek() {
+            Some(c @ ('0' | '1')) => {
+                self.pos += 1;
+                Ok(c == '1')
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+// Circular arc of an SVG arc command from p0 to p1, CCW when sweep is set
+fn svg_arc(p0: Point, p1: Point, r: f64, large_arc: bool, sweep: bool) -> Arc {
+    let (dir, chord) = (p1 - p0).normalize(false);
+    // Radii too small to reach the end point are scaled up, as in SVG
+    let r = r.max(0.5 * chord);
+    let h = (r * r - 0.25 * chord * chord).max(0.0).sqrt();
+    // The center is left of the chord for CCW minor and CW major arcs
+    let left = point(-dir.y, dir.x);
+    let side = if large_arc != sweep { 1.0 } else { -1.0 };
+    let c = (p0 + p1) * 0.5 + left * (side * h);
+    if sweep { arc(p0, p1, c, r) } else { arc(p1, p0, c, r) }
+}
+
+/// Parses SVG path data, the `d` attribute of a `<path>`, into an arcline.
+///
+/// Supports the move `M`, line `L`, `H`, `V`, arc `A` and close `Z` commands,
+/// in absolute (uppercase) and relative (lowercase) forms, with the implicit
+/// repetition of commands and the separators allowed by SVG. Lines and the
+/// closing `Z` become segments and arcs with equal radii become circular arcs;
+/// elements of zero length are skipped. Subpaths are concatenated in one
+/// arcline.
+///
+/// Coordinates are read unchanged, as written by [`arcline_to_svg_path`]:
+/// arcs with `sweep-flag` 1 are CCW and kept in the path direction, while CW
+/// arcs are stored reversed, from their end to their start point, as arcs are
+/// always CCW. Arc radii too small to reach the end point are scaled up as in
+/// SVG, and the `x-axis-rotation` of circular arcs is ignored.
+///
+/// # Arguments
+///
+/// * `d` - The SVG path data
+///
+/// # Returns
+///
+/// The arcline, or the first [`ParseError`] in the path data: elliptical arcs
+/// and curve commands like `C` and `Q` are rejected
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// let arcs = arcline_from_svg_path("M 1 0 A 1 1 0 0 1 0 1 L 0 0 Z").unwrap();
+/// assert_eq!(arcs.len(), 3);
+/// assert!(arcs[0].is_arc() && arcs[0].c.close_enough(point(0.0, 0.0), 1e-12));
+/// assert_eq!(arcline_to_svg_path(&arcs), "M 1 0 A 1 1 0 0 1 0 1 L 0 0 L 1 0");
+///
+/// assert!(matches!(
+///     arcline_from_svg_path("M 0 0 A 2 1 0 0 1 1 1"),
+///     Err(ParseError::EllipticalArc { .. })
+/// ));
+/// ```
+pub fn arcline_from_svg_path(d: &str) -> Result<Arcline, ParseError> {
+    let mut lx = PathLexer { d, pos: 0 };
+    let mut arcs = Arcline::new();
+    let mut current = point(0.0, 0.0);
+    let mut start = current;
+    let mut started = false;
+    // Command repeated when numbers follow without a command letter
+    let mut repeat: Option<char> = None;
+    loop {
+        lx.skip_separators();
+        let Some(c) = lx.peek() else {
+            break;
+        };
+        let position = lx.pos;
+        let command = if c.is_ascii_alphabetic() {
+            lx.pos += 1;
+            c
+        } else {
+            match repeat {
+                Some(command) if lx.at_number() => command,
+                _ => return Err(lx.unexpected()),
+            }
+        };
+        if !started && !matches!(command, 'M' | 'm') {
+            return Err(ParseError::UnexpectedToken { position, found: command });
+        }
+        let base = if command.is_ascii_lowercase() { current } else { point(0.0, 0.0) };
+        repeat = Some(command);
+
+        let (end, element) = match command.to_ascii_uppercase() {
+            'M' => {
+                let p = base + lx.point()?;
+                start = p;
+                started = true;
+                // Coordinates following a move are lines
+                repeat = Some(if command == 'm' { 'l' } else { 'L' });
+                (p, None)
+            }
+            'L' => {
+                let p = base + lx.point()?;
+                (p, Some(arcseg(current, p)))
+            }
+            'H' => {
+                let p = point(base.x + lx.number()?, current.y);
+                (p, Some(arcseg(current, p)))
+            }
+            'V' => {
+                let p = point(current.x, base.y + lx.number()?);
+                (p, Some(arcseg(current, p)))
+            }
+            'A' => {
+                let (rx, ry) = (lx.number()?.abs(), lx.number()?.abs());
+                let _rotation = lx.number()?;
+                let (large_arc, sweep) = (lx.flag()?, lx.flag()?);
+                let p = base + lx.point()?;
+                if rx == 0.0 || ry == 0.0 {
+                    // Zero radius arcs are lines in SVG
+                    (p, Some(arcseg(current, p)))
+                } else if (rx - ry).abs() > GEOMETRIC_EPSILON * rx.max(ry) {
+                    return Err(ParseError::EllipticalArc { position, rx, ry });
+                } else {
+                    (p, Some(svg_arc(current, p, 0.5 * (rx + ry), large_arc, sweep)))
+                }
+            }
+            'Z' => {
+                // A close command takes no coordinates
+                repeat = None;
+                (start, Some(arcseg(current, start)))
+            }
+            'C' | 'S' | 'Q' | 'T' => return Err(ParseError::UnsupportedCommand { position, command }),
+            _ => return Err(ParseError::UnexpectedToken { position, found: command }),
+        };
+        if let Some(element) = element
+            && end != current
+        {
+            arcs.push(element);
+        }
+        current = end;
+    }
+    Ok(arcs)
+}
+
 impl SVG {
     pub fn write(&mut self) {
         self.write_stroke_width(0.2);
@@ -225,6 +603,28 @@ impl SVG {
         self.s.push('\n');
     }
 
+    /// Draws an arcline as a filled shape with the given fill rule.
+    ///
+    /// The arcline may hold several rings, e.g. an outer boundary followed by
+    /// holes; each ring starts a subpath of a single `<path>` (see
+    /// [`arcline_to_svg_path`]), so that the `fill-rule` is applied across all
+    /// rings as [`FillRule`] describes. The y axis is flipped like for the other
+    /// drawing methods.
+    pub fn arcline_filled_rule(&mut self, arcs: &Arcline, rule: FillRule, fill: &str) {
+        let mut s = String::new();
+        write!(
+            &mut s,
+            r#"<path d="{}" fill="{}" fill-rule="{}" stroke="none" transform="matrix(1 0 0 -1 0 {})"/>"#,
+            arcline_to_svg_path(arcs),
+            fill,
+            rule.as_str(),
+            self.ysize
+        )
+        .unwrap();
+        self.s.push_str(&s);
+        self.s.push('\n');
+    }
+
     /// Draws a vertex in the SVG format.
     pub fn pvertex(&mut self, p0: Point, p1: Point, g: f64, color: &str) {
         if g == 0f64 {
@@ -344,6 +744,108 @@ mod test_svg {
         svg.write(); // to stdout
     }
 
+    fn square(min: f64, max: f64) -> Arcline {
+        vec![
+            arcseg(point(min, min), point(max, min)),
+            arcseg(point(max, min), point(max, max)),
+            arcseg(point(max, max), point(min, max)),
+            arcseg(point(min, max), point(min, min)),
+        ]
+    }
+
+    #[test]
+    fn test_arcline_filled_rule_ring_with_hole() {
+        let outer = square(0.0, 4.0);
+        let hole = square(1.0, 3.0);
+        let mut shape = outer.clone();
+        shape.extend(hole.iter().copied());
+
+        let mut svg = svg(10.0, 10.0);
+        svg.arcline_filled_rule(&shape, FillRule::EvenOdd, "gray");
+        svg.arcline_filled_rule(&shape, FillRule::NonZero, "gray");
+        let paths: Vec<&str> = svg.s.lines().collect();
+        assert_eq!(
+            paths[0],
+            r#"<path d="M 0 0 L 4 0 L 4 4 L 0 4 L 0 0 M 1 1 L 3 1 L 3 3 L 1 3 L 1 1" fill="gray" fill-rule="evenodd" stroke="none" transform="matrix(1 0 0 -1 0 10)"/>"#
+        );
+        assert!(paths[1].contains(r#"fill-rule="nonzero""#));
+
+        // Winding number at the center of the hole, summed over the rings
+        let winding = |rings: &[&Arcline]| -> i32 {
+            rings
+                .iter()
+                .filter(|ring| arcline_contains(ring, point(2.0, 2.0)))
+                .map(|ring| if arcline_area(ring) > 0.0 { 1 } else { -1 })
+                .sum()
+        };
+        // Hole with the orientation of the outer ring: filled only with non-zero
+        let same = winding(&[&outer, &hole]);
+        assert_eq!(same, 2);
+        assert!(FillRule::NonZero.is_inside(same) && !FillRule::EvenOdd.is_inside(same));
+        // Reversed hole: empty with both rules
+        let opposite = winding(&[&outer, &arcline_reverse(&hole)]);
+        assert_eq!(opposite, 0);
+        assert!(!FillRule::NonZero.is_inside(opposite) && !FillRule::EvenOdd.is_inside(opposite));
+        // Inside the ring itself both rules agree
+        assert!(FillRule::NonZero.is_inside(1) && FillRule::EvenOdd.is_inside(1));
+    }
+
+    #[test]
+    fn test_arcline_to_svg_path_square() {
+        let square = vec![
+            arcseg(point(0.0, 0.0), point(2.0, 0.0)),
+            arcseg(point(2.0, 0.0), point(2.0, 2.0)),
+            arcseg(point(2.0, 2.0), point(0.0, 2.0)),
+            arcseg(point(0.0, 2.0), point(0.0, 0.0)),
+        ];
+        assert_eq!(arcline_to_svg_path(&square), "M 0 0 L 2 0 L 2 2 L 0 2 L 0 0");
+        assert_eq!(arcline_to_svg_path(&vec![]), "");
+    }
+
+    #[test]
+    fn test_arcline_to_svg_path_arcs() {
+        let quarter = arc(point(1.0, 0.0), point(0.0, 1.0), point(0.0, 0.0), 1.0);
+        assert_eq!(arcline_to_svg_path(&vec![quarter]), "M 1 0 A 1 1 0 0 1 0 1");
+        // The complementary three quarters is a large arc
+        let rest = arc(point(0.0, 1.0), point(1.0, 0.0), point(0.0, 0.0), 1.0);
+        assert_eq!(arcline_to_svg_path(&vec![rest]), "M 0 1 A 1 1 0 1 1 1 0");
+        let full = arc(point(2.0, 1.0), point(2.0, 1.0), point(1.0, 1.0), 1.0);
+        assert_eq!(arcline_to_svg_path(&vec![full]), "M 2 1 A 1 1 0 0 1 0 1 A 1 1 0 0 1 2 1");
+    }
+
+    #[test]
+    fn test_arcline_to_svg_path_backward_arcs() {
+        // Square with a concave arc on its top side, stored reversed
+        let notched = vec![
+            arcseg(point(0.0, 0.0), point(4.0, 0.0)),
+            arcseg(point(4.0, 0.0), point(4.0, 4.0)),
+            arcseg(point(4.0, 4.0), point(3.0, 4.0)),
+            arc(point(1.0, 4.0), point(3.0, 4.0), point(2.0, 4.0), 1.0),
+            arcseg(point(1.0, 4.0), point(0.0, 4.0)),
+            arcseg(point(0.0, 4.0), point(0.0, 0.0)),
+        ];
+        let d = arcline_to_svg_path(&notched);
+        assert_eq!(d, "M 0 0 L 4 0 L 4 4 L 3 4 A 1 1 0 0 0 1 4 L 0 4 L 0 0");
+        // Parsed back, the CW arc is stored reversed again
+        assert_eq!(arcline_from_svg_path(&d).unwrap()[3], notched[3]);
+        // A backward arc first in the arcline
+        let rotated: Arcline = notched[3..].iter().chain(&notched[..3]).copied().collect();
+        assert!(arcline_to_svg_path(&rotated).starts_with("M 3 4 A 1 1 0 0 0 1 4 L 0 4"));
+    }
+
+    #[test]
+    fn test_arcline_to_svg_path_gap() {
+        let arcs = vec![
+            arcseg(point(0.0, 0.0), point(1.0, 0.0)),
+            arc(point(1.0, 0.0), point(3.0, 0.0), point(2.0, 0.0), 1.0),
+            arcseg(point(5.0, 0.0), point(6.0, 0.0)),
+        ];
+        assert_eq!(
+            arcline_to_svg_path(&arcs),
+            "M 0 0 L 1 0 A 1 1 0 0 1 3 0 M 5 0 L 6 0"
+        );
+    }
+
     #[test]
     #[ignore = "writes to file, not stdout"]
     fn test_circle_svg_to_file() {
@@ -353,3 +855,104 @@ mod test_svg {
         svg.write(); // to stdout
     }
 }
+
+#[cfg(test)]
+mod test_arcline_from_svg_path {
+    use super::*;
+
+    fn round_trip(d: &str) {
+        let arcs = arcline_from_svg_path(d).unwrap();
+        assert_eq!(arcline_to_svg_path(&arcs), d);
+    }
+
+    #[test]
+    fn test_round_trip_exporter() {
+        round_trip("M 0 0 L 2 0 L 2 2 L 0 2 L 0 0");
+        round_trip("M 1 0 A 1 1 0 0 1 0 1");
+        round_trip("M 0 1 A 1 1 0 1 1 1 0");
+        round_trip("M 0 0 L 1 0 A 1 1 0 0 1 3 0 M 5 0 L 6 0");
+        // A full circle is exported as two half circles
+        round_trip("M 2 1 A 1 1 0 0 1 0 1 A 1 1 0 0 1 2 1");
+
+        let rounded = vec![
+            arcseg(point(0.0, 0.0), point(3.0, 0.0)),
+            arc(point(3.0, 0.0), point(4.0, 1.0), point(3.0, 1.0), 1.0),
+            arcseg(point(4.0, 1.0), point(4.0, 3.0)),
+            arc(point(4.0, 3.0), point(1.5, 3.0), point(2.75, 3.0), 1.25),
+            arcseg(point(1.5, 3.0), point(0.0, 0.0)),
+        ];
+        let parsed = arcline_from_svg_path(&arcline_to_svg_path(&rounded)).unwrap();
+        assert_eq!(parsed.len(), rounded.len());
+        for (p, a) in parsed.iter().zip(&rounded) {
+            assert_eq!((p.a, p.b, p.r), (a.a, a.b, a.r));
+            assert!(p.c.close_enough(a.c, 1e-12) || (p.is_seg() && a.is_seg()));
+        }
+    }
+
+    #[test]
+    fn test_relative_and_shorthand_commands() {
+        let square = vec![
+            arcseg(point(1.0, 1.0), point(3.0, 1.0)),
+            arcseg(point(3.0, 1.0), point(3.0, 3.0)),
+            arcseg(point(3.0, 3.0), point(1.0, 3.0)),
+            arcseg(point(1.0, 3.0), point(1.0, 1.0)),
+        ];
+        assert_eq!(arcline_from_svg_path("m 1 1 h 2 v 2 h -2 z").unwrap(), square);
+        assert_eq!(arcline_from_svg_path("M1,1 H3 V3 H1 Z").unwrap(), square);
+        // Implicit repetition, lines after a move
+        assert_eq!(arcline_from_svg_path("M1 1 3 1 3 3 1 3z").unwrap(), square);
+        assert_eq!(arcline_from_svg_path("m1 1 2 0 0 2-2 0z").unwrap(), square);
+        // Packed arc flags and a relative end point
+        let arcs = arcline_from_svg_path("M1 0a1 1 0 011-1").unwrap();
+        assert_eq!(arcs.len(), 1);
+        assert_eq!((arcs[0].a, arcs[0].b), (point(1.0, 0.0), point(2.0, -1.0)));
+        assert!(arcs[0].c.close_enough(point(2.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_clockwise_and_small_radius_arcs() {
+        // A CW arc is stored reversed, as a CCW arc from its end to its start
+        let arcs = arcline_from_svg_path("M 0 1 A 1 1 0 0 0 1 0").unwrap();
+        assert_eq!((arcs[0].a, arcs[0].b), (point(1.0, 0.0), point(0.0, 1.0)));
+        assert!(arcs[0].c.close_enough(point(0.0, 0.0), 1e-12));
+        // Too small radius is scaled up to a half circle
+        let arcs = arcline_from_svg_path("M 0 0 A 0.5 0.5 0 0 1 4 0").unwrap();
+        assert_eq!((arcs[0].c, arcs[0].r), (point(2.0, 0.0), 2.0));
+        // Zero radius is a line, zero length elements are skipped
+        let arcs = arcline_from_svg_path("M 0 0 A 0 0 0 0 1 4 0 L 4 0 A 1 1 0 0 1 4 0").unwrap();
+        assert_eq!(arcs, vec![arcseg(point(0.0, 0.0), point(4.0, 0.0))]);
+        assert!(arcline_from_svg_path("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            arcline_from_svg_path("M 0 0 A 2 1 0 0 1 1 1"),
+            Err(ParseError::EllipticalArc { position: 6, rx: 2.0, ry: 1.0 })
+        );
+        assert_eq!(
+            arcline_from_svg_path("M 0 0 C 1 1 2 2 3 3"),
+            Err(ParseError::UnsupportedCommand { position: 6, command: 'C' })
+        );
+        assert_eq!(
+            arcline_from_svg_path("L 1 1"),
+            Err(ParseError::UnexpectedToken { position: 0, found: 'L' })
+        );
+        assert_eq!(
+            arcline_from_svg_path("M 0 0 L 1 x"),
+            Err(ParseError::UnexpectedToken { position: 10, found: 'x' })
+        );
+        assert_eq!(
+            arcline_from_svg_path("M 0 0 K 1 1"),
+            Err(ParseError::UnexpectedToken { position: 6, found: 'K' })
+        );
+        assert_eq!(
+            arcline_from_svg_path("M 0 0 Z 1 1"),
+            Err(ParseError::UnexpectedToken { position: 8, found: '1' })
+        );
+        assert_eq!(arcline_from_svg_path("M 0 1e+"), Err(ParseError::MalformedNumber { position: 4 }));
+        assert_eq!(arcline_from_svg_path("M 0 -."), Err(ParseError::MalformedNumber { position: 4 }));
+        assert_eq!(arcline_from_svg_path("M 0 0 L 1"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(arcline_from_svg_path("M 0 0 A 1 1 0 2 1 1 1"), Err(ParseError::UnexpectedToken { position: 14, found: '2' }));
+    }
+}
@@ -0,0 +1,212 @@
+#![allow(dead_code)]
+
+use crate::point::{Point, point};
+#[cfg(not(feature = "std"))]
+use crate::utils::FloatExt;
+use core::fmt::Display;
+
+/// A 2D affine transform, a 2x3 matrix.
+///
+/// The fields follow the SVG `matrix(a b c d e f)` notation, a point is mapped as
+///
+/// ```text
+/// | x' |   | a  c  e |   | x |
+/// | y' | = | b  d  f | * | y |
+///                        | 1 |
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use togo::prelude::*;
+///
+/// // Rotate a quarter turn about the origin, then move right by 2
+/// let m = Affine2::rotation(std::f64::consts::FRAC_PI_2).then(&Affine2::translation(point(2.0, 0.0)));
+/// assert!(m.apply(point(1.0, 0.0)).close_enough(point(2.0, 1.0), 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine2 {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Display for Affine2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "[{:.20}, {:.20}, {:.20}, {:.20}, {:.20}, {:.20}]",
+            self.a, self.b, self.c, self.d, self.e, self.f
+        )
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Affine2::identity()
+    }
+}
+
+impl Affine2 {
+    /// Creates a new transform from the matrix entries, in SVG `matrix(a b c d e f)` order.
+    #[inline]
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Affine2 { a, b, c, d, e, f }
+    }
+
+    /// Returns the transform mapping every point to itself.
+    #[inline]
+    #[must_use]
+    pub fn identity() -> Self {
+        Affine2::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Returns the transform moving points by the vector `v`.
+    #[inline]
+    #[must_use]
+    pub fn translation(v: Point) -> Self {
+        Affine2::new(1.0, 0.0, 0.0, 1.0, v.x, v.y)
+    }
+
+    /// Returns the rotation about the origin by `angle_rad`, counter-clockwise
+    /// for a positive angle.
+    #[inline]
+    #[must_use]
+    pub fn rotation(angle_rad: f64) -> Self {
+        let (sin, cos) = (angle_rad.sin(), angle_rad.cos());
+        Affine2::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Returns the scaling about the origin by `sx` along x and `sy` along y.
+    ///
+    /// A negative factor mirrors across the other axis. Arcs can only be mapped
+    /// by a uniform scaling, see [`Arc::transform`](crate::prelude::Arc::transform).
+    #[inline]
+    #[must_use]
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Affine2::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Returns the composition `self ∘ other`, which applies `other` first and
+    /// then `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use togo::prelude::*;
+    ///
+    /// let scale = Affine2::scaling(2.0, 2.0);
+    /// let shift = Affine2::translation(point(1.0, 0.0));
+    /// // Shift, then scale
+    /// assert_eq!(scale.compose(&shift).apply(point(0.0, 0.0)), point(2.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn compose(&self, other: &Affine2) -> Affine2 {
+        Affine2::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.e + self.c * other.f + self.e,
+            self.b * other.e + self.d * other.f + self.f,
+        )
+    }
+
+    /// Returns the transform applying `self` first and then `next`, that is
+    /// `next.compose(self)`. Chains of `then` read in the order the transforms
+    /// are applied.
+    #[inline]
+    #[must_use]
+    pub fn then(&self, next: &Affine2) -> Affine2 {
+        next.compose(self)
+    }
+
+    /// Maps a point by the transform.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, p: Point) -> Point {
+        point(
+            self.a * p.x + self.c * p.y + self.e,
+            self.b * p.x + self.d * p.y + self.f,
+        )
+    }
+
+    /// Returns the determinant of the linear part, the factor by which areas
+    /// are scaled. It is negative for transforms that mirror.
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Checks whether the transform scales uniformly, maps circles to circles.
+    ///
+    /// Such a transform (a similarity) is a rotation or a reflection combined
+    /// with a uniform scale and a translation. Its linear part has orthogonal
+    /// columns of equal length.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - Allowed deviation, relative to the scale
+    ///
+    /// # Returns
+    ///
+    /// True if the transform is a non-degenerate similarity within the tolerance
+    #[must_use]
+    pub fn is_uniform(&self, tolerance: f64) -> bool {
+        let len_x = self.a * self.a + self.b * self.b;
+        let len_y = self.c * self.c + self.d * self.d;
+        let dot = self.a * self.c + self.b * self.d;
+        let scale = len_x.max(len_y);
+        scale > 0.0 && (len_x - len_y).abs() <= tolerance * scale && dot.abs() <= tolerance * scale
+    }
+}
+
+#[cfg(test)]
+mod test_affine2 {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_rotate_then_translate() {
+        let angle = PI / 6.0;
+        let shift = point(3.0, -1.0);
+        let m = Affine2::rotation(angle).then(&Affine2::translation(shift));
+        assert_eq!(m, Affine2::translation(shift).compose(&Affine2::rotation(angle)));
+        for p in [point(0.0, 0.0), point(1.0, 0.0), point(-2.0, 5.0)] {
+            let manual = point(
+                p.x * angle.cos() - p.y * angle.sin(),
+                p.x * angle.sin() + p.y * angle.cos(),
+            ) + shift;
+            assert!(m.apply(p).close_enough(manual, 1e-12));
+        }
+        // The other order moves first and rotates the moved point
+        let m = Affine2::translation(shift).then(&Affine2::rotation(FRAC_PI_2));
+        assert!(m.apply(point(1.0, 1.0)).close_enough(point(0.0, 4.0), 1e-12));
+    }
+
+    #[test]
+    fn test_identity_and_scaling() {
+        let m = Affine2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(m.compose(&Affine2::identity()), m);
+        assert_eq!(Affine2::identity().compose(&m), m);
+        assert_eq!(Affine2::default(), Affine2::identity());
+        assert_eq!(m.apply(point(1.0, 1.0)), point(9.0, 12.0));
+        assert_eq!(Affine2::scaling(2.0, -3.0).apply(point(1.0, 1.0)), point(2.0, -3.0));
+        assert_eq!(Affine2::scaling(2.0, -3.0).determinant(), -6.0);
+    }
+
+    #[test]
+    fn test_is_uniform() {
+        assert!(Affine2::identity().is_uniform(1e-12));
+        assert!(Affine2::rotation(0.7).then(&Affine2::scaling(3.0, 3.0)).is_uniform(1e-12));
+        assert!(Affine2::scaling(-2.0, 2.0).is_uniform(1e-12));
+        assert!(!Affine2::scaling(2.0, 1.0).is_uniform(1e-12));
+        assert!(!Affine2::new(1.0, 0.0, 0.5, 1.0, 0.0, 0.0).is_uniform(1e-12));
+        assert!(!Affine2::scaling(0.0, 0.0).is_uniform(1e-12));
+    }
+}
@@ -1,5 +1,58 @@
 #![allow(dead_code)]
 
+/// Floating point functions that are not available in `core`.
+///
+/// Without the `std` feature these are provided by `libm`, so the code
+/// can keep calling them as methods on `f64`.
+#[cfg(not(feature = "std"))]
+pub(crate) trait FloatExt {
+    fn sqrt(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatExt for f64 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        libm::fma(self, a, b)
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        libm::hypot(self, other)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        libm::pow(self, f64::from(n))
+    }
+}
+
 const TWO_COMPLEMENT_64: u64 = 0x8000_0000_0000_0000_u64;
 const TWO_COMPLEMENT_CI_64: i64 = TWO_COMPLEMENT_64 as i64;
 /// Compares two f64 values for approximate equality